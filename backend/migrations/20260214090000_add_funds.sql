@@ -0,0 +1,56 @@
+-- ============================================================================
+-- MIGRATION: Restricted and unrestricted funds (designated giving)
+-- ============================================================================
+
+DO $$
+BEGIN
+    IF NOT EXISTS (SELECT 1 FROM pg_type WHERE typname = 'fund_type') THEN
+        CREATE TYPE fund_type AS ENUM ('RESTRICTED', 'UNRESTRICTED');
+    END IF;
+END$$;
+
+-- 1. Named funds per parish (e.g. "Charity", "Church Construction", "Seminarians")
+CREATE TABLE IF NOT EXISTS fund (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    parish_id UUID NOT NULL REFERENCES parish(id) ON DELETE CASCADE,
+    fund_code VARCHAR(20) NOT NULL,
+    fund_name VARCHAR(200) NOT NULL,
+    fund_type fund_type NOT NULL DEFAULT 'UNRESTRICTED',
+    purpose TEXT, -- What a restricted fund may legally be spent on
+    opening_balance DECIMAL(15, 2) NOT NULL DEFAULT 0,
+    is_active BOOLEAN NOT NULL DEFAULT TRUE,
+    created_by UUID REFERENCES app_user(id) ON DELETE SET NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    deleted_at TIMESTAMPTZ,
+    UNIQUE(parish_id, fund_code)
+);
+
+CREATE INDEX IF NOT EXISTS idx_fund_parish ON fund(parish_id) WHERE deleted_at IS NULL;
+
+CREATE TRIGGER set_fund_updated_at BEFORE UPDATE ON fund
+    FOR EACH ROW EXECUTE FUNCTION update_updated_at_column();
+
+-- 2. Tag income and expenses to a fund (NULL = general unrestricted pool)
+ALTER TABLE income_transaction ADD COLUMN IF NOT EXISTS fund_id UUID REFERENCES fund(id) ON DELETE SET NULL;
+CREATE INDEX IF NOT EXISTS idx_income_fund ON income_transaction(fund_id);
+
+ALTER TABLE expense_voucher ADD COLUMN IF NOT EXISTS fund_id UUID REFERENCES fund(id) ON DELETE SET NULL;
+CREATE INDEX IF NOT EXISTS idx_voucher_fund ON expense_voucher(fund_id);
+
+-- 3. Permissions
+INSERT INTO permission (permission_key, permission_group, display_name, description) VALUES
+    ('funds.view', 'finance', 'View Funds', 'View funds and fund balances'),
+    ('funds.manage', 'finance', 'Manage Funds', 'Create and edit restricted/unrestricted funds')
+ON CONFLICT (permission_key) DO NOTHING;
+
+INSERT INTO role_permission (role_id, permission_id)
+SELECT cr.id, p.id FROM custom_role cr CROSS JOIN permission p
+WHERE p.permission_key IN ('funds.view', 'funds.manage')
+  AND cr.role_name IN ('SUPER_ADMIN', 'PARISH_ADMIN', 'ACCOUNTANT')
+ON CONFLICT DO NOTHING;
+
+INSERT INTO role_permission (role_id, permission_id)
+SELECT cr.id, p.id FROM custom_role cr CROSS JOIN permission p
+WHERE p.permission_key = 'funds.view' AND cr.role_name = 'VIEWER'
+ON CONFLICT DO NOTHING;
@@ -0,0 +1,105 @@
+-- ============================================================================
+-- MIGRATION: Diocesan levy rules, periodic assessments and the
+--            parish <-> diocese levy ledger
+-- ============================================================================
+
+DO $$
+BEGIN
+    IF NOT EXISTS (SELECT 1 FROM pg_type WHERE typname = 'levy_period') THEN
+        CREATE TYPE levy_period AS ENUM ('MONTHLY', 'QUARTERLY', 'ANNUAL');
+    END IF;
+    IF NOT EXISTS (SELECT 1 FROM pg_type WHERE typname = 'levy_status') THEN
+        CREATE TYPE levy_status AS ENUM ('OUTSTANDING', 'PARTIALLY_PAID', 'PAID', 'WAIVED');
+    END IF;
+    IF NOT EXISTS (SELECT 1 FROM pg_type WHERE typname = 'levy_entry_type') THEN
+        CREATE TYPE levy_entry_type AS ENUM ('ASSESSMENT', 'PAYMENT', 'ADJUSTMENT', 'WAIVER');
+    END IF;
+END$$;
+
+-- 1. Levy rules configured by the diocese
+CREATE TABLE IF NOT EXISTS levy_rule (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    diocese_id UUID NOT NULL REFERENCES diocese(id) ON DELETE CASCADE,
+    rule_name VARCHAR(200) NOT NULL,
+    rate DECIMAL(7, 4) NOT NULL, -- Percentage, e.g. 10.0000 = 10%
+    income_categories transaction_category[] NOT NULL,
+    period levy_period NOT NULL DEFAULT 'MONTHLY',
+    effective_from DATE NOT NULL,
+    effective_to DATE,
+    due_days INT NOT NULL DEFAULT 30, -- Days after period end before the levy is overdue
+    is_active BOOLEAN NOT NULL DEFAULT TRUE,
+    created_by UUID REFERENCES app_user(id) ON DELETE SET NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    deleted_at TIMESTAMPTZ,
+    CONSTRAINT valid_levy_rate CHECK (rate > 0 AND rate <= 100),
+    CONSTRAINT valid_levy_dates CHECK (effective_to IS NULL OR effective_to >= effective_from)
+);
+
+CREATE INDEX IF NOT EXISTS idx_levy_rule_diocese ON levy_rule(diocese_id) WHERE deleted_at IS NULL;
+
+CREATE TRIGGER set_levy_rule_updated_at BEFORE UPDATE ON levy_rule
+    FOR EACH ROW EXECUTE FUNCTION update_updated_at_column();
+
+-- 2. One assessment per rule, parish and period
+CREATE TABLE IF NOT EXISTS levy_assessment (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    levy_rule_id UUID NOT NULL REFERENCES levy_rule(id) ON DELETE RESTRICT,
+    diocese_id UUID NOT NULL REFERENCES diocese(id) ON DELETE CASCADE,
+    parish_id UUID NOT NULL REFERENCES parish(id) ON DELETE CASCADE,
+    period_start DATE NOT NULL,
+    period_end DATE NOT NULL,
+    assessable_income DECIMAL(15, 2) NOT NULL,
+    rate DECIMAL(7, 4) NOT NULL, -- Copied from the rule at assessment time
+    amount DECIMAL(15, 2) NOT NULL,
+    amount_paid DECIMAL(15, 2) NOT NULL DEFAULT 0,
+    due_date DATE NOT NULL,
+    status levy_status NOT NULL DEFAULT 'OUTSTANDING',
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE(levy_rule_id, parish_id, period_start)
+);
+
+CREATE INDEX IF NOT EXISTS idx_levy_assessment_parish ON levy_assessment(parish_id);
+CREATE INDEX IF NOT EXISTS idx_levy_assessment_open ON levy_assessment(diocese_id, due_date)
+    WHERE status IN ('OUTSTANDING', 'PARTIALLY_PAID');
+
+CREATE TRIGGER set_levy_assessment_updated_at BEFORE UPDATE ON levy_assessment
+    FOR EACH ROW EXECUTE FUNCTION update_updated_at_column();
+
+-- 3. Levy ledger: payable for the parish, receivable for the diocese.
+--    Positive amounts increase what the parish owes, negative amounts reduce it.
+CREATE TABLE IF NOT EXISTS levy_ledger_entry (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    diocese_id UUID NOT NULL REFERENCES diocese(id) ON DELETE CASCADE,
+    parish_id UUID NOT NULL REFERENCES parish(id) ON DELETE CASCADE,
+    assessment_id UUID REFERENCES levy_assessment(id) ON DELETE SET NULL,
+    entry_type levy_entry_type NOT NULL,
+    entry_date DATE NOT NULL,
+    amount DECIMAL(15, 2) NOT NULL,
+    reference_number VARCHAR(100),
+    expense_voucher_id UUID REFERENCES expense_voucher(id) ON DELETE SET NULL,
+    description TEXT,
+    recorded_by UUID REFERENCES app_user(id) ON DELETE SET NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_levy_ledger_parish ON levy_ledger_entry(parish_id, entry_date);
+
+CREATE TRIGGER audit_levy_ledger_entry
+    AFTER INSERT OR UPDATE OR DELETE ON levy_ledger_entry
+    FOR EACH ROW EXECUTE FUNCTION audit_trigger_function();
+
+-- 4. Permissions
+INSERT INTO permission (permission_key, permission_group, display_name, description) VALUES
+    ('levy.view', 'finance', 'View Diocesan Levy', 'View levy assessments, ledger and arrears'),
+    ('levy.pay', 'finance', 'Record Levy Payments', 'Record parish payments of the diocesan levy'),
+    ('levy.manage', 'finance', 'Manage Levy Rules', 'Configure levy rules, run assessments and waive levies')
+ON CONFLICT (permission_key) DO NOTHING;
+
+INSERT INTO role_permission (role_id, permission_id)
+SELECT cr.id, p.id FROM custom_role cr CROSS JOIN permission p
+WHERE (cr.role_name = 'SUPER_ADMIN' AND p.permission_key IN ('levy.view', 'levy.pay', 'levy.manage'))
+   OR (cr.role_name IN ('PARISH_ADMIN', 'ACCOUNTANT') AND p.permission_key IN ('levy.view', 'levy.pay'))
+   OR (cr.role_name = 'VIEWER' AND p.permission_key = 'levy.view')
+ON CONFLICT DO NOTHING;
@@ -0,0 +1,89 @@
+-- ============================================================================
+-- MIGRATION: Scheduled report generation and delivery via the email outbox
+-- ============================================================================
+
+DO $$
+BEGIN
+    IF NOT EXISTS (SELECT 1 FROM pg_type WHERE typname = 'scheduled_report_type') THEN
+        CREATE TYPE scheduled_report_type AS ENUM (
+            'TRIAL_BALANCE', 'INCOME_EXPENDITURE', 'BUDGET_VS_ACTUAL', 'BALANCE_SHEET',
+            'CASH_FLOW', 'FUND_BALANCES', 'COMPARATIVE_INCOME_EXPENDITURE',
+            'COMPARATIVE_BUDGET_VS_ACTUAL', 'MONTHLY_INCOME_EXPENDITURE', 'MONTHLY_BUDGET_VS_ACTUAL'
+        );
+    END IF;
+    IF NOT EXISTS (SELECT 1 FROM pg_type WHERE typname = 'report_date_range') THEN
+        CREATE TYPE report_date_range AS ENUM (
+            'PREVIOUS_MONTH', 'MONTH_TO_DATE', 'PREVIOUS_QUARTER', 'QUARTER_TO_DATE',
+            'PREVIOUS_YEAR', 'YEAR_TO_DATE'
+        );
+    END IF;
+    IF NOT EXISTS (SELECT 1 FROM pg_type WHERE typname = 'export_format') THEN
+        CREATE TYPE export_format AS ENUM ('CSV', 'XLSX', 'PDF');
+    END IF;
+    IF NOT EXISTS (SELECT 1 FROM pg_type WHERE typname = 'report_run_status') THEN
+        CREATE TYPE report_run_status AS ENUM ('SUCCEEDED', 'FAILED');
+    END IF;
+END$$;
+
+-- 1. Saved report definitions. Schedules use standard 5-field cron syntax
+--    (minute hour day-of-month month day-of-week), evaluated in UTC.
+CREATE TABLE IF NOT EXISTS report_schedule (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    parish_id UUID NOT NULL REFERENCES parish(id) ON DELETE CASCADE,
+    schedule_name VARCHAR(200) NOT NULL,
+    report_type scheduled_report_type NOT NULL,
+    date_range report_date_range NOT NULL DEFAULT 'PREVIOUS_MONTH',
+    format export_format NOT NULL DEFAULT 'PDF',
+    cron_expression VARCHAR(100) NOT NULL,
+    recipients TEXT[] NOT NULL DEFAULT '{}',
+    is_active BOOLEAN NOT NULL DEFAULT TRUE,
+    next_run_at TIMESTAMPTZ,
+    last_run_at TIMESTAMPTZ,
+    created_by UUID REFERENCES app_user(id) ON DELETE SET NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    deleted_at TIMESTAMPTZ
+);
+
+CREATE INDEX IF NOT EXISTS idx_report_schedule_parish ON report_schedule(parish_id) WHERE deleted_at IS NULL;
+CREATE INDEX IF NOT EXISTS idx_report_schedule_due ON report_schedule(next_run_at)
+    WHERE is_active = TRUE AND deleted_at IS NULL;
+
+CREATE TRIGGER set_report_schedule_updated_at BEFORE UPDATE ON report_schedule
+    FOR EACH ROW EXECUTE FUNCTION update_updated_at_column();
+
+-- 2. Every generated file, kept for download
+CREATE TABLE IF NOT EXISTS report_run (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    schedule_id UUID NOT NULL REFERENCES report_schedule(id) ON DELETE CASCADE,
+    parish_id UUID NOT NULL REFERENCES parish(id) ON DELETE CASCADE,
+    period_start DATE NOT NULL,
+    period_end DATE NOT NULL,
+    format export_format NOT NULL,
+    status report_run_status NOT NULL,
+    file_path TEXT,
+    file_name VARCHAR(255),
+    file_size BIGINT,
+    error_message TEXT,
+    emails_queued INT NOT NULL DEFAULT 0,
+    generated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_report_run_schedule ON report_run(schedule_id, generated_at DESC);
+
+-- 3. Attachments on outbox emails
+ALTER TABLE email_log ADD COLUMN IF NOT EXISTS attachment_path TEXT;
+ALTER TABLE email_log ADD COLUMN IF NOT EXISTS attachment_name VARCHAR(255);
+ALTER TABLE email_log ADD COLUMN IF NOT EXISTS report_run_id UUID REFERENCES report_run(id) ON DELETE SET NULL;
+
+CREATE INDEX IF NOT EXISTS idx_email_log_pending ON email_log(created_at) WHERE status = 'PENDING';
+
+-- 4. Permissions
+INSERT INTO permission (permission_key, permission_group, display_name, description) VALUES
+    ('reports.schedule', 'finance', 'Schedule Reports', 'Create report schedules that are generated and emailed automatically')
+ON CONFLICT (permission_key) DO NOTHING;
+
+INSERT INTO role_permission (role_id, permission_id)
+SELECT cr.id, p.id FROM custom_role cr CROSS JOIN permission p
+WHERE cr.role_name IN ('SUPER_ADMIN', 'PARISH_ADMIN', 'ACCOUNTANT') AND p.permission_key = 'reports.schedule'
+ON CONFLICT DO NOTHING;
@@ -0,0 +1,93 @@
+-- ============================================================================
+-- MIGRATION: Budget versions, approval workflow and revision history
+-- ============================================================================
+
+DO $$
+BEGIN
+    IF NOT EXISTS (SELECT 1 FROM pg_type WHERE typname = 'budget_status') THEN
+        CREATE TYPE budget_status AS ENUM ('DRAFT', 'SUBMITTED', 'APPROVED', 'SUPERSEDED');
+    END IF;
+END$$;
+
+-- 1. A version groups all budget lines of a parish for one fiscal year.
+--    At most one version per parish and year is APPROVED; approving a
+--    revision marks the previous approved version SUPERSEDED.
+CREATE TABLE IF NOT EXISTS budget_version (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    parish_id UUID NOT NULL REFERENCES parish(id) ON DELETE CASCADE,
+    fiscal_year INT NOT NULL,
+    version_number INT NOT NULL,
+    version_name VARCHAR(200),
+    status budget_status NOT NULL DEFAULT 'DRAFT',
+    based_on_version_id UUID REFERENCES budget_version(id) ON DELETE SET NULL,
+    revision_reason TEXT,
+    rejection_reason TEXT,
+    notes TEXT,
+    submitted_by UUID REFERENCES app_user(id) ON DELETE SET NULL,
+    submitted_at TIMESTAMPTZ,
+    approved_by UUID REFERENCES app_user(id) ON DELETE SET NULL,
+    approved_at TIMESTAMPTZ,
+    created_by UUID REFERENCES app_user(id) ON DELETE SET NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    deleted_at TIMESTAMPTZ,
+    UNIQUE(parish_id, fiscal_year, version_number)
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_budget_version_approved
+    ON budget_version(parish_id, fiscal_year) WHERE status = 'APPROVED' AND deleted_at IS NULL;
+
+CREATE TRIGGER set_budget_version_updated_at BEFORE UPDATE ON budget_version
+    FOR EACH ROW EXECUTE FUNCTION update_updated_at_column();
+
+CREATE TRIGGER audit_budget_version
+    AFTER INSERT OR UPDATE OR DELETE ON budget_version
+    FOR EACH ROW EXECUTE FUNCTION audit_trigger_function();
+
+-- 2. Budget lines belong to a version. Existing lines become an approved
+--    version 1 so budget-vs-actual keeps reporting against them.
+ALTER TABLE budget ADD COLUMN IF NOT EXISTS version_id UUID REFERENCES budget_version(id) ON DELETE CASCADE;
+
+INSERT INTO budget_version (parish_id, fiscal_year, version_number, version_name, status, approved_at, notes)
+SELECT DISTINCT b.parish_id, b.fiscal_year, 1, 'Original budget', 'APPROVED'::budget_status, NOW(),
+       'Created from budget lines that predate budget versions'
+FROM budget b
+WHERE b.version_id IS NULL
+ON CONFLICT (parish_id, fiscal_year, version_number) DO NOTHING;
+
+UPDATE budget b SET version_id = v.id
+FROM budget_version v
+WHERE b.version_id IS NULL AND v.parish_id = b.parish_id AND v.fiscal_year = b.fiscal_year AND v.version_number = 1;
+
+ALTER TABLE budget ALTER COLUMN version_id SET NOT NULL;
+
+ALTER TABLE budget DROP CONSTRAINT IF EXISTS budget_parish_id_category_fiscal_year_fiscal_month_key;
+CREATE UNIQUE INDEX IF NOT EXISTS idx_budget_version_line
+    ON budget(version_id, category, COALESCE(fiscal_month, 0)) WHERE deleted_at IS NULL;
+CREATE INDEX IF NOT EXISTS idx_budget_version ON budget(version_id) WHERE deleted_at IS NULL;
+
+-- 3. Every change to a budget line, with the reason given
+CREATE TABLE IF NOT EXISTS budget_revision (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    version_id UUID NOT NULL REFERENCES budget_version(id) ON DELETE CASCADE,
+    budget_id UUID REFERENCES budget(id) ON DELETE SET NULL,
+    category transaction_category NOT NULL,
+    fiscal_month INT,
+    old_amount DECIMAL(15, 2),
+    new_amount DECIMAL(15, 2),
+    reason TEXT,
+    revised_by UUID REFERENCES app_user(id) ON DELETE SET NULL,
+    revised_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_budget_revision_version ON budget_revision(version_id, revised_at);
+
+-- 4. Permissions
+INSERT INTO permission (permission_key, permission_group, display_name, description) VALUES
+    ('budgets.approve', 'finance', 'Approve Budgets', 'Approve or reject submitted budget versions on behalf of the parish council')
+ON CONFLICT (permission_key) DO NOTHING;
+
+INSERT INTO role_permission (role_id, permission_id)
+SELECT cr.id, p.id FROM custom_role cr CROSS JOIN permission p
+WHERE cr.role_name IN ('SUPER_ADMIN', 'PARISH_ADMIN') AND p.permission_key = 'budgets.approve'
+ON CONFLICT DO NOTHING;
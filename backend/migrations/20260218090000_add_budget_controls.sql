@@ -0,0 +1,43 @@
+-- ============================================================================
+-- MIGRATION: Budget overrun checks on expense vouchers and threshold alerts
+-- ============================================================================
+
+-- 1. Outcome of the budget check made when a voucher is raised
+ALTER TABLE expense_voucher ADD COLUMN IF NOT EXISTS budget_overrun BOOLEAN NOT NULL DEFAULT FALSE;
+ALTER TABLE expense_voucher ADD COLUMN IF NOT EXISTS budget_warning TEXT;
+ALTER TABLE expense_voucher ADD COLUMN IF NOT EXISTS overrun_approval_required BOOLEAN NOT NULL DEFAULT FALSE;
+ALTER TABLE expense_voucher ADD COLUMN IF NOT EXISTS overrun_approved_by UUID REFERENCES app_user(id) ON DELETE SET NULL;
+ALTER TABLE expense_voucher ADD COLUMN IF NOT EXISTS overrun_approved_at TIMESTAMPTZ;
+
+CREATE INDEX IF NOT EXISTS idx_expense_voucher_overrun_pending ON expense_voucher(parish_id)
+    WHERE overrun_approval_required = TRUE AND overrun_approved_at IS NULL AND deleted_at IS NULL;
+
+-- A voucher held for overrun approval cannot be approved before it is signed off
+ALTER TABLE expense_voucher ADD CONSTRAINT voucher_overrun_signed_off CHECK (
+    approval_status IS DISTINCT FROM 'APPROVED' OR NOT overrun_approval_required OR overrun_approved_at IS NOT NULL
+);
+
+-- 2. One alert per budget line, period and threshold (80% / 100%)
+CREATE TABLE IF NOT EXISTS budget_alert (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    parish_id UUID NOT NULL REFERENCES parish(id) ON DELETE CASCADE,
+    version_id UUID NOT NULL REFERENCES budget_version(id) ON DELETE CASCADE,
+    category transaction_category NOT NULL,
+    period_start DATE NOT NULL,
+    period_end DATE NOT NULL,
+    threshold_percent INT NOT NULL,
+    budget_amount DECIMAL(15, 2) NOT NULL,
+    committed_amount DECIMAL(15, 2) NOT NULL,
+    voucher_id UUID REFERENCES expense_voucher(id) ON DELETE SET NULL,
+    emails_queued INT NOT NULL DEFAULT 0,
+    triggered_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE(version_id, category, period_start, threshold_percent)
+);
+
+CREATE INDEX IF NOT EXISTS idx_budget_alert_parish ON budget_alert(parish_id, triggered_at DESC);
+
+-- 3. What happens when a voucher would exceed its budget: WARN, REQUIRE_APPROVAL or BLOCK.
+--    Parishes override the system default with their own app_setting row.
+INSERT INTO app_setting (parish_id, setting_key, setting_value, setting_group, description) VALUES
+    (NULL, 'finance.budget_overrun_policy', 'WARN', 'finance', 'Budget overrun handling: WARN, REQUIRE_APPROVAL or BLOCK')
+ON CONFLICT (setting_key) WHERE parish_id IS NULL DO NOTHING;
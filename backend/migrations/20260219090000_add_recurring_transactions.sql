@@ -0,0 +1,82 @@
+-- ============================================================================
+-- MIGRATION: Recurring expense and income templates
+-- ============================================================================
+
+DO $$
+BEGIN
+    IF NOT EXISTS (SELECT 1 FROM pg_type WHERE typname = 'recurring_kind') THEN
+        CREATE TYPE recurring_kind AS ENUM ('EXPENSE', 'INCOME');
+    END IF;
+    IF NOT EXISTS (SELECT 1 FROM pg_type WHERE typname = 'recurrence_frequency') THEN
+        CREATE TYPE recurrence_frequency AS ENUM ('WEEKLY', 'MONTHLY', 'QUARTERLY', 'ANNUAL');
+    END IF;
+    IF NOT EXISTS (SELECT 1 FROM pg_type WHERE typname = 'occurrence_status') THEN
+        CREATE TYPE occurrence_status AS ENUM (
+            'PLANNED',   -- adjusted ahead of its due date, not yet materialized
+            'SKIPPED',
+            'RAISED',    -- expense voucher created
+            'EXPECTED',  -- income due, waiting to be received
+            'RECEIVED',  -- income transaction recorded
+            'FAILED'     -- voucher refused (budget block, fund overdraw)
+        );
+    END IF;
+END$$;
+
+-- 1. Templates. Due dates are counted from start_date so month-end dates
+--    do not drift (31 Jan, 28 Feb, 31 Mar ...).
+CREATE TABLE IF NOT EXISTS recurring_transaction (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    parish_id UUID NOT NULL REFERENCES parish(id) ON DELETE CASCADE,
+    template_name VARCHAR(200) NOT NULL,
+    kind recurring_kind NOT NULL,
+    category transaction_category NOT NULL,
+    amount DECIMAL(15, 2) NOT NULL CHECK (amount > 0),
+    payment_method payment_method NOT NULL DEFAULT 'CASH',
+    fund_id UUID REFERENCES fund(id) ON DELETE SET NULL,
+    payee_name VARCHAR(200),
+    payee_phone VARCHAR(20),
+    member_id UUID REFERENCES member(id) ON DELETE SET NULL,
+    family_id UUID REFERENCES family(id) ON DELETE SET NULL,
+    description TEXT,
+    frequency recurrence_frequency NOT NULL DEFAULT 'MONTHLY',
+    start_date DATE NOT NULL,
+    end_date DATE,
+    next_due_date DATE,
+    is_active BOOLEAN NOT NULL DEFAULT TRUE,
+    created_by UUID NOT NULL REFERENCES app_user(id) ON DELETE RESTRICT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    deleted_at TIMESTAMPTZ,
+    CONSTRAINT recurring_expense_has_payee CHECK (kind = 'INCOME' OR payee_name IS NOT NULL)
+);
+
+CREATE INDEX IF NOT EXISTS idx_recurring_transaction_parish ON recurring_transaction(parish_id) WHERE deleted_at IS NULL;
+CREATE INDEX IF NOT EXISTS idx_recurring_transaction_due ON recurring_transaction(next_due_date)
+    WHERE is_active = TRUE AND deleted_at IS NULL;
+
+CREATE TRIGGER set_recurring_transaction_updated_at BEFORE UPDATE ON recurring_transaction
+    FOR EACH ROW EXECUTE FUNCTION update_updated_at_column();
+
+-- 2. Individual occurrences: skips and adjustments made in advance, and
+--    what the job produced for each due date
+CREATE TABLE IF NOT EXISTS recurring_occurrence (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    recurring_id UUID NOT NULL REFERENCES recurring_transaction(id) ON DELETE CASCADE,
+    parish_id UUID NOT NULL REFERENCES parish(id) ON DELETE CASCADE,
+    due_date DATE NOT NULL,
+    status occurrence_status NOT NULL,
+    amount DECIMAL(15, 2),
+    note TEXT,
+    expense_voucher_id UUID REFERENCES expense_voucher(id) ON DELETE SET NULL,
+    income_transaction_id UUID REFERENCES income_transaction(id) ON DELETE SET NULL,
+    created_by UUID REFERENCES app_user(id) ON DELETE SET NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE(recurring_id, due_date)
+);
+
+CREATE INDEX IF NOT EXISTS idx_recurring_occurrence_expected ON recurring_occurrence(parish_id, due_date)
+    WHERE status = 'EXPECTED';
+
+CREATE TRIGGER set_recurring_occurrence_updated_at BEFORE UPDATE ON recurring_occurrence
+    FOR EACH ROW EXECUTE FUNCTION update_updated_at_column();
@@ -0,0 +1,104 @@
+-- ============================================================================
+-- MIGRATION: Cashbooks, imprest top-ups, transfers and cash counts
+-- ============================================================================
+
+DO $$
+BEGIN
+    IF NOT EXISTS (SELECT 1 FROM pg_type WHERE typname = 'cashbook_type') THEN
+        CREATE TYPE cashbook_type AS ENUM ('PETTY_CASH', 'MAIN_CASH', 'BANK', 'MOBILE_MONEY');
+    END IF;
+    IF NOT EXISTS (SELECT 1 FROM pg_type WHERE typname = 'cashbook_transfer_type') THEN
+        CREATE TYPE cashbook_transfer_type AS ENUM ('TRANSFER', 'IMPREST_TOPUP');
+    END IF;
+END$$;
+
+-- 1. Where a parish keeps its money: petty cash float, main cash box,
+--    each bank account and each mobile money wallet
+CREATE TABLE IF NOT EXISTS cashbook (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    parish_id UUID NOT NULL REFERENCES parish(id) ON DELETE CASCADE,
+    cashbook_name VARCHAR(200) NOT NULL,
+    cashbook_type cashbook_type NOT NULL,
+    provider VARCHAR(100),          -- bank or mobile money operator
+    account_number VARCHAR(100),
+    imprest_amount DECIMAL(15, 2),  -- fixed float for petty cash
+    opening_balance DECIMAL(15, 2) NOT NULL DEFAULT 0,
+    opening_date DATE NOT NULL DEFAULT CURRENT_DATE,
+    custodian_id UUID REFERENCES app_user(id) ON DELETE SET NULL,
+    is_active BOOLEAN NOT NULL DEFAULT TRUE,
+    created_by UUID REFERENCES app_user(id) ON DELETE SET NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    deleted_at TIMESTAMPTZ,
+    UNIQUE(parish_id, cashbook_name)
+);
+
+CREATE INDEX IF NOT EXISTS idx_cashbook_parish ON cashbook(parish_id) WHERE deleted_at IS NULL;
+
+CREATE TRIGGER set_cashbook_updated_at BEFORE UPDATE ON cashbook
+    FOR EACH ROW EXECUTE FUNCTION update_updated_at_column();
+
+CREATE TRIGGER audit_cashbook
+    AFTER INSERT OR UPDATE OR DELETE ON cashbook
+    FOR EACH ROW EXECUTE FUNCTION audit_trigger_function();
+
+-- 2. Which cashbook a receipt was banked in or a voucher was paid from
+ALTER TABLE income_transaction ADD COLUMN IF NOT EXISTS cashbook_id UUID REFERENCES cashbook(id) ON DELETE SET NULL;
+ALTER TABLE expense_voucher ADD COLUMN IF NOT EXISTS cashbook_id UUID REFERENCES cashbook(id) ON DELETE SET NULL;
+ALTER TABLE recurring_transaction ADD COLUMN IF NOT EXISTS cashbook_id UUID REFERENCES cashbook(id) ON DELETE SET NULL;
+
+CREATE INDEX IF NOT EXISTS idx_income_transaction_cashbook ON income_transaction(cashbook_id, transaction_date) WHERE cashbook_id IS NOT NULL;
+CREATE INDEX IF NOT EXISTS idx_expense_voucher_cashbook ON expense_voucher(cashbook_id, expense_date) WHERE cashbook_id IS NOT NULL;
+
+-- 3. Movements between cashbooks (bank withdrawals, deposits, imprest top-ups)
+CREATE TABLE IF NOT EXISTS cashbook_transfer (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    parish_id UUID NOT NULL REFERENCES parish(id) ON DELETE CASCADE,
+    from_cashbook_id UUID NOT NULL REFERENCES cashbook(id) ON DELETE RESTRICT,
+    to_cashbook_id UUID NOT NULL REFERENCES cashbook(id) ON DELETE RESTRICT,
+    transfer_type cashbook_transfer_type NOT NULL DEFAULT 'TRANSFER',
+    amount DECIMAL(15, 2) NOT NULL CHECK (amount > 0),
+    transfer_date DATE NOT NULL,
+    reference_number VARCHAR(100),
+    description TEXT,
+    created_by UUID REFERENCES app_user(id) ON DELETE SET NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    deleted_at TIMESTAMPTZ,
+    CHECK (from_cashbook_id <> to_cashbook_id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_cashbook_transfer_from ON cashbook_transfer(from_cashbook_id, transfer_date) WHERE deleted_at IS NULL;
+CREATE INDEX IF NOT EXISTS idx_cashbook_transfer_to ON cashbook_transfer(to_cashbook_id, transfer_date) WHERE deleted_at IS NULL;
+
+CREATE TRIGGER audit_cashbook_transfer
+    AFTER INSERT OR UPDATE OR DELETE ON cashbook_transfer
+    FOR EACH ROW EXECUTE FUNCTION audit_trigger_function();
+
+-- 4. Physical cash counts, one per cashbook per day, with the notes and
+--    coins counted
+CREATE TABLE IF NOT EXISTS cash_count (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    cashbook_id UUID NOT NULL REFERENCES cashbook(id) ON DELETE CASCADE,
+    parish_id UUID NOT NULL REFERENCES parish(id) ON DELETE CASCADE,
+    count_date DATE NOT NULL,
+    counted_total DECIMAL(15, 2) NOT NULL,
+    book_balance DECIMAL(15, 2) NOT NULL,
+    difference DECIMAL(15, 2) NOT NULL,
+    notes TEXT,
+    counted_by UUID REFERENCES app_user(id) ON DELETE SET NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE(cashbook_id, count_date)
+);
+
+CREATE TRIGGER set_cash_count_updated_at BEFORE UPDATE ON cash_count
+    FOR EACH ROW EXECUTE FUNCTION update_updated_at_column();
+
+CREATE TABLE IF NOT EXISTS cash_count_line (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    cash_count_id UUID NOT NULL REFERENCES cash_count(id) ON DELETE CASCADE,
+    denomination DECIMAL(15, 2) NOT NULL CHECK (denomination > 0),
+    quantity INT NOT NULL CHECK (quantity >= 0),
+    amount DECIMAL(15, 2) NOT NULL,
+    UNIQUE(cash_count_id, denomination)
+);
@@ -0,0 +1,64 @@
+-- ============================================================================
+-- MIGRATION: Fixed asset register with depreciation and disposals
+-- ============================================================================
+
+DO $$
+BEGIN
+    IF NOT EXISTS (SELECT 1 FROM pg_type WHERE typname = 'asset_category') THEN
+        CREATE TYPE asset_category AS ENUM ('LAND', 'BUILDINGS', 'VEHICLES', 'FURNITURE', 'EQUIPMENT', 'OTHER');
+    END IF;
+    IF NOT EXISTS (SELECT 1 FROM pg_type WHERE typname = 'depreciation_method') THEN
+        CREATE TYPE depreciation_method AS ENUM ('NONE', 'STRAIGHT_LINE', 'REDUCING_BALANCE');
+    END IF;
+    IF NOT EXISTS (SELECT 1 FROM pg_type WHERE typname = 'asset_disposal_method') THEN
+        CREATE TYPE asset_disposal_method AS ENUM ('SOLD', 'DONATED', 'SCRAPPED', 'LOST');
+    END IF;
+END$$;
+
+CREATE SEQUENCE IF NOT EXISTS fixed_asset_seq;
+
+-- Land, buildings, vehicles and equipment owned by a parish. Depreciation
+-- is computed from these figures rather than posted, so a corrected cost
+-- or useful life re-states the schedule.
+CREATE TABLE IF NOT EXISTS fixed_asset (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    parish_id UUID NOT NULL REFERENCES parish(id) ON DELETE CASCADE,
+    asset_code VARCHAR(50) NOT NULL DEFAULT 'FA-' || LPAD(nextval('fixed_asset_seq')::TEXT, 6, '0'),
+    asset_name VARCHAR(200) NOT NULL,
+    category asset_category NOT NULL,
+    description TEXT,
+    acquisition_date DATE NOT NULL,
+    acquisition_cost DECIMAL(15, 2) NOT NULL CHECK (acquisition_cost >= 0),
+    location VARCHAR(200),
+    custodian_name VARCHAR(200),
+    fund_id UUID REFERENCES fund(id) ON DELETE SET NULL,
+    funding_source VARCHAR(200),    -- e.g. donor, diocesan grant, parish funds
+    expense_voucher_id UUID REFERENCES expense_voucher(id) ON DELETE SET NULL,
+    depreciation_method depreciation_method NOT NULL DEFAULT 'STRAIGHT_LINE',
+    useful_life_years INT CHECK (useful_life_years > 0),               -- straight line
+    depreciation_rate DECIMAL(5, 2) CHECK (depreciation_rate > 0 AND depreciation_rate <= 100), -- reducing balance, % a year
+    residual_value DECIMAL(15, 2) NOT NULL DEFAULT 0 CHECK (residual_value >= 0),
+    disposal_date DATE,
+    disposal_method asset_disposal_method,
+    disposal_proceeds DECIMAL(15, 2),
+    disposal_gain_loss DECIMAL(15, 2),
+    disposal_notes TEXT,
+    disposal_income_id UUID REFERENCES income_transaction(id) ON DELETE SET NULL,
+    created_by UUID REFERENCES app_user(id) ON DELETE SET NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    deleted_at TIMESTAMPTZ,
+    UNIQUE(parish_id, asset_code),
+    CHECK (depreciation_method <> 'STRAIGHT_LINE' OR useful_life_years IS NOT NULL),
+    CHECK (depreciation_method <> 'REDUCING_BALANCE' OR depreciation_rate IS NOT NULL),
+    CHECK (disposal_date IS NULL OR disposal_date >= acquisition_date)
+);
+
+CREATE INDEX IF NOT EXISTS idx_fixed_asset_parish ON fixed_asset(parish_id, category) WHERE deleted_at IS NULL;
+
+CREATE TRIGGER set_fixed_asset_updated_at BEFORE UPDATE ON fixed_asset
+    FOR EACH ROW EXECUTE FUNCTION update_updated_at_column();
+
+CREATE TRIGGER audit_fixed_asset
+    AFTER INSERT OR UPDATE OR DELETE ON fixed_asset
+    FOR EACH ROW EXECUTE FUNCTION audit_trigger_function();
@@ -0,0 +1,208 @@
+-- ============================================================================
+-- MIGRATION: Parish staff register and monthly payroll
+-- ============================================================================
+
+DO $$
+BEGIN
+    IF NOT EXISTS (SELECT 1 FROM pg_type WHERE typname = 'employment_type') THEN
+        CREATE TYPE employment_type AS ENUM ('FULL_TIME', 'PART_TIME', 'CASUAL');
+    END IF;
+    IF NOT EXISTS (SELECT 1 FROM pg_type WHERE typname = 'deduction_basis') THEN
+        CREATE TYPE deduction_basis AS ENUM ('GROSS', 'TAXABLE');
+    END IF;
+    IF NOT EXISTS (SELECT 1 FROM pg_type WHERE typname = 'payroll_status') THEN
+        CREATE TYPE payroll_status AS ENUM ('DRAFT', 'APPROVED', 'CANCELLED');
+    END IF;
+END$$;
+
+CREATE SEQUENCE IF NOT EXISTS staff_seq;
+
+-- 1. Staff register: catechists, cooks, watchmen, teachers and other
+--    people the parish pays
+CREATE TABLE IF NOT EXISTS staff (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    parish_id UUID NOT NULL REFERENCES parish(id) ON DELETE CASCADE,
+    staff_number VARCHAR(50) NOT NULL,
+    member_id UUID REFERENCES member(id) ON DELETE SET NULL,
+    full_name VARCHAR(200) NOT NULL,
+    position VARCHAR(100) NOT NULL,
+    employment_type employment_type NOT NULL DEFAULT 'FULL_TIME',
+    phone VARCHAR(20),
+    national_id VARCHAR(50),
+    tax_number VARCHAR(50),
+    social_security_number VARCHAR(50),
+    bank_name VARCHAR(100),
+    bank_account VARCHAR(100),
+    basic_salary DECIMAL(15, 2) NOT NULL CHECK (basic_salary >= 0),
+    allowances DECIMAL(15, 2) NOT NULL DEFAULT 0 CHECK (allowances >= 0),
+    statutory_exempt BOOLEAN NOT NULL DEFAULT FALSE,
+    start_date DATE NOT NULL,
+    end_date DATE,
+    is_active BOOLEAN NOT NULL DEFAULT TRUE,
+    created_by UUID REFERENCES app_user(id) ON DELETE SET NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    deleted_at TIMESTAMPTZ,
+    UNIQUE(parish_id, staff_number),
+    CHECK (end_date IS NULL OR end_date >= start_date)
+);
+
+CREATE INDEX IF NOT EXISTS idx_staff_parish ON staff(parish_id) WHERE deleted_at IS NULL;
+
+CREATE TRIGGER set_staff_updated_at BEFORE UPDATE ON staff
+    FOR EACH ROW EXECUTE FUNCTION update_updated_at_column();
+
+CREATE TRIGGER audit_staff
+    AFTER INSERT OR UPDATE OR DELETE ON staff
+    FOR EACH ROW EXECUTE FUNCTION audit_trigger_function();
+
+-- 2. Statutory deductions as data. Rows without a parish apply everywhere;
+--    a parish row with the same code replaces the national one.
+--    A flat percentage is one open band from zero.
+CREATE TABLE IF NOT EXISTS statutory_deduction (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    parish_id UUID REFERENCES parish(id) ON DELETE CASCADE,
+    deduction_code VARCHAR(20) NOT NULL,
+    deduction_name VARCHAR(100) NOT NULL,
+    basis deduction_basis NOT NULL DEFAULT 'GROSS',
+    reduces_taxable BOOLEAN NOT NULL DEFAULT FALSE,  -- e.g. pension contributions before PAYE
+    employer_rate DECIMAL(5, 2) NOT NULL DEFAULT 0,  -- employer share, % of the same base
+    payee_name VARCHAR(200) NOT NULL,                -- who the voucher is paid to
+    is_active BOOLEAN NOT NULL DEFAULT TRUE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    deleted_at TIMESTAMPTZ
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_statutory_deduction_system
+    ON statutory_deduction(deduction_code) WHERE parish_id IS NULL AND deleted_at IS NULL;
+CREATE UNIQUE INDEX IF NOT EXISTS idx_statutory_deduction_parish
+    ON statutory_deduction(parish_id, deduction_code) WHERE parish_id IS NOT NULL AND deleted_at IS NULL;
+
+CREATE TRIGGER set_statutory_deduction_updated_at BEFORE UPDATE ON statutory_deduction
+    FOR EACH ROW EXECUTE FUNCTION update_updated_at_column();
+
+CREATE TRIGGER audit_statutory_deduction
+    AFTER INSERT OR UPDATE OR DELETE ON statutory_deduction
+    FOR EACH ROW EXECUTE FUNCTION audit_trigger_function();
+
+-- Monthly bands: amount = fixed_amount + (base - lower_bound) * rate / 100
+-- for the band the base falls in
+CREATE TABLE IF NOT EXISTS statutory_deduction_band (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    deduction_id UUID NOT NULL REFERENCES statutory_deduction(id) ON DELETE CASCADE,
+    lower_bound DECIMAL(15, 2) NOT NULL CHECK (lower_bound >= 0),
+    upper_bound DECIMAL(15, 2),
+    rate DECIMAL(5, 2) NOT NULL DEFAULT 0,
+    fixed_amount DECIMAL(15, 2) NOT NULL DEFAULT 0,
+    UNIQUE(deduction_id, lower_bound),
+    CHECK (upper_bound IS NULL OR upper_bound > lower_bound)
+);
+
+-- 3. Payroll runs, one live run per parish per month
+CREATE TABLE IF NOT EXISTS payroll_run (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    parish_id UUID NOT NULL REFERENCES parish(id) ON DELETE CASCADE,
+    period_year INT NOT NULL,
+    period_month INT NOT NULL CHECK (period_month BETWEEN 1 AND 12),
+    payment_date DATE NOT NULL,
+    status payroll_status NOT NULL DEFAULT 'DRAFT',
+    total_gross DECIMAL(15, 2) NOT NULL DEFAULT 0,
+    total_deductions DECIMAL(15, 2) NOT NULL DEFAULT 0,
+    total_net DECIMAL(15, 2) NOT NULL DEFAULT 0,
+    total_employer_contributions DECIMAL(15, 2) NOT NULL DEFAULT 0,
+    notes TEXT,
+    created_by UUID REFERENCES app_user(id) ON DELETE SET NULL,
+    approved_by UUID REFERENCES app_user(id) ON DELETE SET NULL,
+    approved_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_payroll_run_period
+    ON payroll_run(parish_id, period_year, period_month) WHERE status <> 'CANCELLED';
+
+CREATE TRIGGER set_payroll_run_updated_at BEFORE UPDATE ON payroll_run
+    FOR EACH ROW EXECUTE FUNCTION update_updated_at_column();
+
+CREATE TRIGGER audit_payroll_run
+    AFTER INSERT OR UPDATE OR DELETE ON payroll_run
+    FOR EACH ROW EXECUTE FUNCTION audit_trigger_function();
+
+CREATE TABLE IF NOT EXISTS payslip (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    payroll_run_id UUID NOT NULL REFERENCES payroll_run(id) ON DELETE CASCADE,
+    staff_id UUID NOT NULL REFERENCES staff(id) ON DELETE RESTRICT,
+    basic_salary DECIMAL(15, 2) NOT NULL,
+    allowances DECIMAL(15, 2) NOT NULL DEFAULT 0,
+    other_earnings DECIMAL(15, 2) NOT NULL DEFAULT 0,
+    gross_pay DECIMAL(15, 2) NOT NULL,
+    taxable_pay DECIMAL(15, 2) NOT NULL,
+    statutory_deductions DECIMAL(15, 2) NOT NULL DEFAULT 0,
+    other_deductions DECIMAL(15, 2) NOT NULL DEFAULT 0,  -- salary advances, loans
+    net_pay DECIMAL(15, 2) NOT NULL,
+    employer_contributions DECIMAL(15, 2) NOT NULL DEFAULT 0,
+    notes TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE(payroll_run_id, staff_id)
+);
+
+CREATE TRIGGER set_payslip_updated_at BEFORE UPDATE ON payslip
+    FOR EACH ROW EXECUTE FUNCTION update_updated_at_column();
+
+CREATE TABLE IF NOT EXISTS payslip_deduction (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    payslip_id UUID NOT NULL REFERENCES payslip(id) ON DELETE CASCADE,
+    deduction_id UUID REFERENCES statutory_deduction(id) ON DELETE SET NULL,
+    deduction_code VARCHAR(20) NOT NULL,
+    deduction_name VARCHAR(100) NOT NULL,
+    base_amount DECIMAL(15, 2) NOT NULL,
+    employee_amount DECIMAL(15, 2) NOT NULL,
+    employer_amount DECIMAL(15, 2) NOT NULL DEFAULT 0
+);
+
+CREATE INDEX IF NOT EXISTS idx_payslip_deduction_payslip ON payslip_deduction(payslip_id);
+
+-- Vouchers raised when a run is approved: net pay and each deduction
+CREATE TABLE IF NOT EXISTS payroll_run_voucher (
+    payroll_run_id UUID NOT NULL REFERENCES payroll_run(id) ON DELETE CASCADE,
+    expense_voucher_id UUID NOT NULL REFERENCES expense_voucher(id) ON DELETE CASCADE,
+    PRIMARY KEY (payroll_run_id, expense_voucher_id)
+);
+
+-- 4. Tanzania mainland defaults: PAYE monthly bands and NSSF 10% + 10%
+WITH paye AS (
+    INSERT INTO statutory_deduction (deduction_code, deduction_name, basis, payee_name)
+    SELECT 'PAYE', 'Pay As You Earn', 'TAXABLE', 'Tanzania Revenue Authority'
+    WHERE NOT EXISTS (SELECT 1 FROM statutory_deduction WHERE parish_id IS NULL AND deduction_code = 'PAYE')
+    RETURNING id
+)
+INSERT INTO statutory_deduction_band (deduction_id, lower_bound, upper_bound, rate, fixed_amount)
+SELECT paye.id, b.lower_bound, b.upper_bound, b.rate, b.fixed_amount
+FROM paye CROSS JOIN (VALUES
+    (0::DECIMAL, 270000::DECIMAL, 0::DECIMAL, 0::DECIMAL),
+    (270000, 520000, 8, 0),
+    (520000, 760000, 20, 20000),
+    (760000, 1000000, 25, 68000),
+    (1000000, NULL, 30, 128000)
+) AS b(lower_bound, upper_bound, rate, fixed_amount);
+
+WITH nssf AS (
+    INSERT INTO statutory_deduction (deduction_code, deduction_name, basis, reduces_taxable, employer_rate, payee_name)
+    SELECT 'NSSF', 'National Social Security Fund', 'GROSS', TRUE, 10, 'National Social Security Fund'
+    WHERE NOT EXISTS (SELECT 1 FROM statutory_deduction WHERE parish_id IS NULL AND deduction_code = 'NSSF')
+    RETURNING id
+)
+INSERT INTO statutory_deduction_band (deduction_id, lower_bound, upper_bound, rate, fixed_amount)
+SELECT nssf.id, 0, NULL, 10, 0 FROM nssf;
+
+-- 5. Permissions
+INSERT INTO permission (permission_key, permission_group, display_name, description) VALUES
+    ('payroll.approve', 'finance', 'Approve Payroll', 'Approve monthly payroll runs and raise the salary vouchers')
+ON CONFLICT (permission_key) DO NOTHING;
+
+INSERT INTO role_permission (role_id, permission_id)
+SELECT cr.id, p.id FROM custom_role cr CROSS JOIN permission p
+WHERE cr.role_name IN ('SUPER_ADMIN', 'PARISH_ADMIN') AND p.permission_key = 'payroll.approve'
+ON CONFLICT DO NOTHING;
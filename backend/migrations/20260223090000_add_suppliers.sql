@@ -0,0 +1,111 @@
+-- ============================================================================
+-- MIGRATION: Supplier register linked to expense vouchers
+-- ============================================================================
+
+CREATE EXTENSION IF NOT EXISTS pg_trgm;
+CREATE EXTENSION IF NOT EXISTS fuzzystrmatch;
+
+-- Payee names as typed vary in case, punctuation and company suffixes.
+-- This key is what "the same supplier" means when matching them.
+CREATE OR REPLACE FUNCTION normalize_payee_name(name TEXT)
+RETURNS TEXT AS $$
+    SELECT btrim(regexp_replace(
+        regexp_replace(
+            regexp_replace(lower(replace(COALESCE(name, ''), '&', ' and ')), '[^a-z0-9]+', ' ', 'g'),
+            '\m(the|ltd|limited|co|company|inc|plc|enterprises?|general|trading)\M', ' ', 'g'
+        ),
+        '\s+', ' ', 'g'
+    ))
+$$ LANGUAGE SQL IMMUTABLE;
+
+-- 1. Suppliers and other regular payees
+CREATE TABLE IF NOT EXISTS supplier (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    parish_id UUID NOT NULL REFERENCES parish(id) ON DELETE CASCADE,
+    supplier_name VARCHAR(200) NOT NULL,
+    name_key TEXT GENERATED ALWAYS AS (normalize_payee_name(supplier_name)) STORED,
+    contact_person VARCHAR(200),
+    phone VARCHAR(20),
+    email VARCHAR(255),
+    address TEXT,
+    tax_number VARCHAR(50),
+    bank_name VARCHAR(100),
+    bank_account VARCHAR(100),
+    mobile_money_number VARCHAR(20),
+    notes TEXT,
+    is_active BOOLEAN NOT NULL DEFAULT TRUE,
+    created_by UUID REFERENCES app_user(id) ON DELETE SET NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    deleted_at TIMESTAMPTZ
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_supplier_name_key ON supplier(parish_id, name_key) WHERE deleted_at IS NULL;
+
+CREATE TRIGGER set_supplier_updated_at BEFORE UPDATE ON supplier
+    FOR EACH ROW EXECUTE FUNCTION update_updated_at_column();
+
+CREATE TRIGGER audit_supplier
+    AFTER INSERT OR UPDATE OR DELETE ON supplier
+    FOR EACH ROW EXECUTE FUNCTION audit_trigger_function();
+
+ALTER TABLE expense_voucher ADD COLUMN IF NOT EXISTS supplier_id UUID REFERENCES supplier(id) ON DELETE SET NULL;
+
+CREATE INDEX IF NOT EXISTS idx_expense_voucher_supplier ON expense_voucher(supplier_id, expense_date) WHERE supplier_id IS NOT NULL;
+CREATE INDEX IF NOT EXISTS idx_expense_voucher_payee_key ON expense_voucher(parish_id, normalize_payee_name(payee_name));
+
+-- 2. One supplier per parish and normalised payee name, named after the
+--    spelling used most often
+WITH spellings AS (
+    SELECT parish_id, normalize_payee_name(payee_name) AS name_key, btrim(payee_name) AS payee_name,
+           COUNT(*) AS uses, MAX(expense_date) AS last_used
+    FROM expense_voucher
+    WHERE deleted_at IS NULL AND normalize_payee_name(payee_name) <> ''
+    GROUP BY 1, 2, 3
+)
+INSERT INTO supplier (parish_id, supplier_name)
+SELECT DISTINCT ON (parish_id, name_key) parish_id, payee_name
+FROM spellings
+ORDER BY parish_id, name_key, uses DESC, last_used DESC
+ON CONFLICT DO NOTHING;
+
+UPDATE expense_voucher v SET supplier_id = s.id
+FROM supplier s
+WHERE v.supplier_id IS NULL AND s.deleted_at IS NULL
+  AND s.parish_id = v.parish_id AND s.name_key = normalize_payee_name(v.payee_name);
+
+-- 3. Fold near-identical names into the supplier with more vouchers:
+--    mostly the same trigrams (an extra or missing word), or one letter
+--    apart in a name long enough for that to be a typo. Remaining
+--    duplicates can be merged by hand.
+DO $$
+DECLARE
+    r RECORD;
+BEGIN
+    FOR r IN
+        WITH counts AS (
+            SELECT s.id, s.parish_id, s.name_key, COUNT(v.id) AS vouchers
+            FROM supplier s LEFT JOIN expense_voucher v ON v.supplier_id = s.id
+            GROUP BY s.id
+        )
+        SELECT a.id AS keep_id, b.id AS drop_id
+        FROM counts a
+        JOIN counts b ON b.parish_id = a.parish_id AND b.id <> a.id
+        WHERE (similarity(a.name_key, b.name_key) >= 0.6
+               OR (LEAST(length(a.name_key), length(b.name_key)) >= 6 AND levenshtein(a.name_key, b.name_key) <= 1))
+          AND (a.vouchers > b.vouchers OR (a.vouchers = b.vouchers AND a.id < b.id))
+        ORDER BY similarity(a.name_key, b.name_key) DESC, a.vouchers DESC
+    LOOP
+        IF EXISTS (SELECT 1 FROM supplier WHERE id = r.keep_id) AND EXISTS (SELECT 1 FROM supplier WHERE id = r.drop_id) THEN
+            UPDATE expense_voucher SET supplier_id = r.keep_id WHERE supplier_id = r.drop_id;
+            DELETE FROM supplier WHERE id = r.drop_id;
+        END IF;
+    END LOOP;
+END$$;
+
+UPDATE supplier s SET phone = (
+    SELECT v.payee_phone FROM expense_voucher v
+    WHERE v.supplier_id = s.id AND v.payee_phone IS NOT NULL
+    ORDER BY v.expense_date DESC LIMIT 1
+)
+WHERE s.phone IS NULL;
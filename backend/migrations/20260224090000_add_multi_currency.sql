@@ -0,0 +1,79 @@
+-- ============================================================================
+-- MIGRATION: Foreign-currency income and expenses
+-- ============================================================================
+-- `amount` on income and vouchers stays in the diocesan base currency, so
+-- every report, fund, budget and cashbook keeps working in one currency.
+-- Foreign-currency entries also keep the amount and rate they came in at.
+
+ALTER TYPE transaction_category ADD VALUE IF NOT EXISTS 'EXCHANGE_GAIN';
+ALTER TYPE transaction_category ADD VALUE IF NOT EXISTS 'EXCHANGE_LOSS';
+
+-- 1. Rates set by the diocese: base currency units per foreign unit
+CREATE TABLE IF NOT EXISTS exchange_rate (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    diocese_id UUID NOT NULL REFERENCES diocese(id) ON DELETE CASCADE,
+    currency_code VARCHAR(3) NOT NULL CHECK (currency_code ~ '^[A-Z]{3}$'),
+    rate_date DATE NOT NULL,
+    rate DECIMAL(18, 6) NOT NULL CHECK (rate > 0),
+    source VARCHAR(100),
+    created_by UUID REFERENCES app_user(id) ON DELETE SET NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (diocese_id, currency_code, rate_date)
+);
+
+CREATE INDEX IF NOT EXISTS idx_exchange_rate_lookup ON exchange_rate(diocese_id, currency_code, rate_date DESC);
+
+CREATE TRIGGER set_exchange_rate_updated_at BEFORE UPDATE ON exchange_rate
+    FOR EACH ROW EXECUTE FUNCTION update_updated_at_column();
+
+CREATE TRIGGER audit_exchange_rate
+    AFTER INSERT OR UPDATE OR DELETE ON exchange_rate
+    FOR EACH ROW EXECUTE FUNCTION audit_trigger_function();
+
+-- 2. Original currency on income and vouchers (NULL = base currency)
+ALTER TABLE income_transaction ADD COLUMN IF NOT EXISTS currency_code VARCHAR(3);
+ALTER TABLE income_transaction ADD COLUMN IF NOT EXISTS original_amount DECIMAL(15, 2);
+ALTER TABLE income_transaction ADD COLUMN IF NOT EXISTS exchange_rate DECIMAL(18, 6);
+ALTER TABLE income_transaction ADD CONSTRAINT income_foreign_currency_complete CHECK (
+    (currency_code IS NULL AND original_amount IS NULL AND exchange_rate IS NULL) OR
+    (currency_code IS NOT NULL AND original_amount > 0 AND exchange_rate > 0)
+);
+
+ALTER TABLE expense_voucher ADD COLUMN IF NOT EXISTS currency_code VARCHAR(3);
+ALTER TABLE expense_voucher ADD COLUMN IF NOT EXISTS original_amount DECIMAL(15, 2);
+ALTER TABLE expense_voucher ADD COLUMN IF NOT EXISTS exchange_rate DECIMAL(18, 6);
+ALTER TABLE expense_voucher ADD CONSTRAINT voucher_foreign_currency_complete CHECK (
+    (currency_code IS NULL AND original_amount IS NULL AND exchange_rate IS NULL) OR
+    (currency_code IS NOT NULL AND original_amount > 0 AND exchange_rate > 0)
+);
+
+CREATE INDEX IF NOT EXISTS idx_income_transaction_currency ON income_transaction(parish_id, currency_code) WHERE currency_code IS NOT NULL;
+CREATE INDEX IF NOT EXISTS idx_expense_voucher_currency ON expense_voucher(parish_id, currency_code) WHERE currency_code IS NOT NULL;
+
+-- 3. Foreign money exchanged into the base currency. The difference from
+--    its book value is the realized gain or loss, posted as EXCHANGE_GAIN
+--    income or an EXCHANGE_LOSS voucher.
+CREATE TABLE IF NOT EXISTS currency_conversion (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    parish_id UUID NOT NULL REFERENCES parish(id) ON DELETE CASCADE,
+    currency_code VARCHAR(3) NOT NULL,
+    conversion_date DATE NOT NULL,
+    foreign_amount DECIMAL(15, 2) NOT NULL CHECK (foreign_amount > 0),
+    base_amount DECIMAL(15, 2) NOT NULL CHECK (base_amount > 0),
+    exchange_rate DECIMAL(18, 6) NOT NULL,
+    book_value DECIMAL(15, 2) NOT NULL,
+    gain_loss DECIMAL(15, 2) NOT NULL,
+    income_transaction_id UUID REFERENCES income_transaction(id) ON DELETE SET NULL,
+    expense_voucher_id UUID REFERENCES expense_voucher(id) ON DELETE SET NULL,
+    reference_number VARCHAR(100),
+    notes TEXT,
+    created_by UUID REFERENCES app_user(id) ON DELETE SET NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_currency_conversion_parish ON currency_conversion(parish_id, currency_code, conversion_date);
+
+CREATE TRIGGER audit_currency_conversion
+    AFTER INSERT OR UPDATE OR DELETE ON currency_conversion
+    FOR EACH ROW EXECUTE FUNCTION audit_trigger_function();
@@ -0,0 +1,80 @@
+-- ============================================================================
+-- MIGRATION: Sacrament certificates
+-- ============================================================================
+
+DO $$
+BEGIN
+    IF NOT EXISTS (SELECT 1 FROM pg_type WHERE typname = 'certificate_language') THEN
+        CREATE TYPE certificate_language AS ENUM ('LATIN', 'ENGLISH', 'SWAHILI');
+    END IF;
+END$$;
+
+-- 1. Parish wording for a sacrament and language; the built-in wording is
+--    used where a parish has none
+CREATE TABLE IF NOT EXISTS certificate_template (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    parish_id UUID NOT NULL REFERENCES parish(id) ON DELETE CASCADE,
+    sacrament_type sacrament_type NOT NULL,
+    language certificate_language NOT NULL,
+    title VARCHAR(200) NOT NULL,
+    body TEXT NOT NULL,
+    signatory_title VARCHAR(100),
+    created_by UUID REFERENCES app_user(id) ON DELETE SET NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    deleted_at TIMESTAMPTZ
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_certificate_template_unique
+    ON certificate_template(parish_id, sacrament_type, language) WHERE deleted_at IS NULL;
+
+CREATE TRIGGER set_certificate_template_updated_at BEFORE UPDATE ON certificate_template
+    FOR EACH ROW EXECUTE FUNCTION update_updated_at_column();
+
+CREATE TRIGGER audit_certificate_template
+    AFTER INSERT OR UPDATE OR DELETE ON certificate_template
+    FOR EACH ROW EXECUTE FUNCTION audit_trigger_function();
+
+-- 2. Certificate numbers run per parish and year of first issue
+CREATE TABLE IF NOT EXISTS certificate_counter (
+    parish_id UUID NOT NULL REFERENCES parish(id) ON DELETE CASCADE,
+    year INTEGER NOT NULL,
+    last_number INTEGER NOT NULL DEFAULT 0,
+    PRIMARY KEY (parish_id, year)
+);
+
+-- 3. Every certificate handed out, with the wording printed on it
+CREATE TABLE IF NOT EXISTS certificate_issuance (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    sacrament_record_id UUID NOT NULL REFERENCES sacrament_record(id) ON DELETE CASCADE,
+    parish_id UUID NOT NULL REFERENCES parish(id) ON DELETE CASCADE,
+    certificate_number VARCHAR(50) NOT NULL,
+    language certificate_language NOT NULL,
+    verification_code VARCHAR(20) NOT NULL UNIQUE,
+    title VARCHAR(200) NOT NULL,
+    body TEXT NOT NULL,
+    signatory_title VARCHAR(100),
+    issued_to VARCHAR(200),
+    purpose VARCHAR(200),
+    issued_by UUID REFERENCES app_user(id) ON DELETE SET NULL,
+    issued_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    revoked_at TIMESTAMPTZ,
+    revoked_by UUID REFERENCES app_user(id) ON DELETE SET NULL,
+    revocation_reason TEXT
+);
+
+CREATE INDEX IF NOT EXISTS idx_certificate_issuance_record ON certificate_issuance(sacrament_record_id);
+CREATE INDEX IF NOT EXISTS idx_certificate_issuance_parish ON certificate_issuance(parish_id, issued_at);
+
+CREATE TRIGGER audit_certificate_issuance
+    AFTER INSERT OR UPDATE OR DELETE ON certificate_issuance
+    FOR EACH ROW EXECUTE FUNCTION audit_trigger_function();
+
+INSERT INTO permission (permission_key, permission_group, display_name, description) VALUES
+    ('sacraments.certificates', 'ministry', 'Issue Certificates', 'Issue and revoke sacrament certificates and edit their templates')
+ON CONFLICT (permission_key) DO NOTHING;
+
+INSERT INTO role_permission (role_id, permission_id)
+SELECT cr.id, p.id FROM custom_role cr CROSS JOIN permission p
+WHERE cr.role_name IN ('SUPER_ADMIN', 'PARISH_ADMIN', 'SECRETARY') AND p.permission_key = 'sacraments.certificates'
+ON CONFLICT DO NOTHING;
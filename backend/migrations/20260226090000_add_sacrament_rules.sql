@@ -0,0 +1,33 @@
+-- ============================================================================
+-- MIGRATION: Canonical checks on sacrament records
+-- ============================================================================
+
+-- 1. Marriage (after widowhood) and the Anointing of the Sick can be
+--    received more than once; removed records no longer block a new one
+ALTER TABLE sacrament_record DROP CONSTRAINT IF EXISTS sacrament_record_member_id_sacrament_type_key;
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_sacrament_record_once
+    ON sacrament_record(member_id, sacrament_type)
+    WHERE deleted_at IS NULL AND sacrament_type NOT IN ('MARRIAGE', 'ANOINTING_OF_SICK');
+
+-- 2. Records saved although a check failed, and why
+ALTER TABLE sacrament_record ADD COLUMN IF NOT EXISTS override_reason TEXT;
+ALTER TABLE sacrament_record ADD COLUMN IF NOT EXISTS overridden_by UUID REFERENCES app_user(id) ON DELETE SET NULL;
+ALTER TABLE sacrament_record ADD COLUMN IF NOT EXISTS overridden_at TIMESTAMPTZ;
+
+DROP TRIGGER IF EXISTS audit_sacrament_record ON sacrament_record;
+CREATE TRIGGER audit_sacrament_record
+    AFTER INSERT OR UPDATE OR DELETE ON sacrament_record
+    FOR EACH ROW EXECUTE FUNCTION audit_trigger_function();
+
+-- 3. WARN saves with warnings; BLOCK refuses unless overridden with a
+--    reason. Minimum ages follow the Code of Canon Law and can be raised
+--    by a parish's own app_setting rows.
+INSERT INTO app_setting (parish_id, setting_key, setting_value, setting_group, description) VALUES
+    (NULL, 'sacraments.rule_policy', 'BLOCK', 'sacraments', 'Failed canonical checks: WARN or BLOCK (override with a reason)'),
+    (NULL, 'sacraments.min_age.first_communion', '7', 'sacraments', 'Minimum age for First Communion'),
+    (NULL, 'sacraments.min_age.confirmation', '7', 'sacraments', 'Minimum age for Confirmation'),
+    (NULL, 'sacraments.min_age.marriage_male', '16', 'sacraments', 'Minimum age for marriage (man), canon 1083'),
+    (NULL, 'sacraments.min_age.marriage_female', '14', 'sacraments', 'Minimum age for marriage (woman), canon 1083'),
+    (NULL, 'sacraments.min_age.holy_orders', '23', 'sacraments', 'Minimum age for ordination to the diaconate, canon 1031')
+ON CONFLICT (setting_key) WHERE parish_id IS NULL DO NOTHING;
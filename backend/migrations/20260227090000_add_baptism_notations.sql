@@ -0,0 +1,74 @@
+-- ============================================================================
+-- MIGRATION: Marginal notations on baptism entries
+-- ============================================================================
+
+DO $$
+BEGIN
+    IF NOT EXISTS (SELECT 1 FROM pg_type WHERE typname = 'notation_type') THEN
+        CREATE TYPE notation_type AS ENUM ('CONFIRMATION', 'MARRIAGE', 'HOLY_ORDERS', 'NULLITY', 'OTHER');
+    END IF;
+    IF NOT EXISTS (SELECT 1 FROM pg_type WHERE typname = 'notation_notice_status') THEN
+        CREATE TYPE notation_notice_status AS ENUM ('PENDING', 'APPLIED', 'REJECTED', 'WITHDRAWN');
+    END IF;
+END$$;
+
+-- 1. Notes in the margin of a baptism entry (canon 535 §2)
+CREATE TABLE IF NOT EXISTS baptism_notation (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    baptism_record_id UUID NOT NULL REFERENCES sacrament_record(id) ON DELETE CASCADE,
+    notation_type notation_type NOT NULL,
+    notation_date DATE NOT NULL,
+    notation_text TEXT NOT NULL,
+    -- The sacrament the note was made from, if it is in the register
+    source_record_id UUID REFERENCES sacrament_record(id) ON DELETE SET NULL,
+    -- The parish where it was celebrated or decided
+    source_parish_id UUID REFERENCES parish(id) ON DELETE SET NULL,
+    created_by UUID REFERENCES app_user(id) ON DELETE SET NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    deleted_at TIMESTAMPTZ
+);
+
+CREATE INDEX IF NOT EXISTS idx_baptism_notation_baptism ON baptism_notation(baptism_record_id) WHERE deleted_at IS NULL;
+CREATE INDEX IF NOT EXISTS idx_baptism_notation_source ON baptism_notation(source_record_id);
+
+CREATE TRIGGER set_baptism_notation_updated_at BEFORE UPDATE ON baptism_notation
+    FOR EACH ROW EXECUTE FUNCTION update_updated_at_column();
+
+CREATE TRIGGER audit_baptism_notation
+    AFTER INSERT OR UPDATE OR DELETE ON baptism_notation
+    FOR EACH ROW EXECUTE FUNCTION audit_trigger_function();
+
+-- 2. Notations sent to the parish that keeps the baptism entry, which
+--    applies them to its register or sends them back
+CREATE TABLE IF NOT EXISTS notation_notice (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    from_parish_id UUID NOT NULL REFERENCES parish(id) ON DELETE CASCADE,
+    to_parish_id UUID NOT NULL REFERENCES parish(id) ON DELETE CASCADE,
+    baptism_record_id UUID NOT NULL REFERENCES sacrament_record(id) ON DELETE CASCADE,
+    member_id UUID NOT NULL REFERENCES member(id) ON DELETE CASCADE,
+    notation_type notation_type NOT NULL,
+    notation_date DATE NOT NULL,
+    notation_text TEXT NOT NULL,
+    source_record_id UUID REFERENCES sacrament_record(id) ON DELETE SET NULL,
+    status notation_notice_status NOT NULL DEFAULT 'PENDING',
+    sent_by UUID REFERENCES app_user(id) ON DELETE SET NULL,
+    sent_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    processed_by UUID REFERENCES app_user(id) ON DELETE SET NULL,
+    processed_at TIMESTAMPTZ,
+    rejection_reason TEXT,
+    notation_id UUID REFERENCES baptism_notation(id) ON DELETE SET NULL,
+    -- Set on a notice asking for a notation it entered to be taken back,
+    -- when the sacrament it was made from is corrected or removed
+    withdraws_notation_id UUID REFERENCES baptism_notation(id) ON DELETE SET NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_notation_notice_to ON notation_notice(to_parish_id, status);
+CREATE INDEX IF NOT EXISTS idx_notation_notice_from ON notation_notice(from_parish_id, status);
+CREATE INDEX IF NOT EXISTS idx_notation_notice_source ON notation_notice(source_record_id);
+CREATE INDEX IF NOT EXISTS idx_notation_notice_withdraws ON notation_notice(withdraws_notation_id)
+    WHERE withdraws_notation_id IS NOT NULL;
+
+CREATE TRIGGER audit_notation_notice
+    AFTER INSERT OR UPDATE OR DELETE ON notation_notice
+    FOR EACH ROW EXECUTE FUNCTION audit_trigger_function();
@@ -0,0 +1,47 @@
+-- ============================================================================
+-- MIGRATION: Paper register books and entry references
+-- ============================================================================
+
+-- 1. The bound registers a parish keeps, one numbered series of volumes
+--    per sacrament
+CREATE TABLE IF NOT EXISTS register_book (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    parish_id UUID NOT NULL REFERENCES parish(id) ON DELETE CASCADE,
+    sacrament_type sacrament_type NOT NULL,
+    volume_number INTEGER NOT NULL CHECK (volume_number > 0),
+    title VARCHAR(200),
+    page_count INTEGER CHECK (page_count > 0),
+    entries_per_page INTEGER CHECK (entries_per_page > 0),
+    -- Entry numbers often run on from the previous volume
+    first_entry_number INTEGER NOT NULL DEFAULT 1 CHECK (first_entry_number > 0),
+    opened_on DATE NOT NULL DEFAULT CURRENT_DATE,
+    closed_on DATE,
+    notes TEXT,
+    created_by UUID REFERENCES app_user(id) ON DELETE SET NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    deleted_at TIMESTAMPTZ
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_register_book_volume
+    ON register_book(parish_id, sacrament_type, volume_number) WHERE deleted_at IS NULL;
+
+-- New entries are allocated in the one open volume
+CREATE UNIQUE INDEX IF NOT EXISTS idx_register_book_open
+    ON register_book(parish_id, sacrament_type) WHERE closed_on IS NULL AND deleted_at IS NULL;
+
+CREATE TRIGGER set_register_book_updated_at BEFORE UPDATE ON register_book
+    FOR EACH ROW EXECUTE FUNCTION update_updated_at_column();
+
+CREATE TRIGGER audit_register_book
+    AFTER INSERT OR UPDATE OR DELETE ON register_book
+    FOR EACH ROW EXECUTE FUNCTION audit_trigger_function();
+
+-- 2. Where each record is written on paper. Not unique: old registers do
+--    contain numbering mistakes, which the book audit reports.
+ALTER TABLE sacrament_record ADD COLUMN IF NOT EXISTS register_book_id UUID REFERENCES register_book(id) ON DELETE SET NULL;
+ALTER TABLE sacrament_record ADD COLUMN IF NOT EXISTS register_page INTEGER CHECK (register_page > 0);
+ALTER TABLE sacrament_record ADD COLUMN IF NOT EXISTS register_entry INTEGER CHECK (register_entry > 0);
+
+CREATE INDEX IF NOT EXISTS idx_sacrament_record_register
+    ON sacrament_record(register_book_id, register_entry) WHERE deleted_at IS NULL;
@@ -0,0 +1,58 @@
+-- ============================================================================
+-- MIGRATION: Godparents, sponsors and witnesses linked to members
+-- ============================================================================
+
+DO $$
+BEGIN
+    IF NOT EXISTS (SELECT 1 FROM pg_type WHERE typname = 'sponsor_role') THEN
+        CREATE TYPE sponsor_role AS ENUM ('GODPARENT', 'SPONSOR', 'WITNESS');
+    END IF;
+END$$;
+
+-- 1. The people who stood for a sacrament. `member_id` is empty for
+--    outsiders, whose name is kept as written.
+CREATE TABLE IF NOT EXISTS sacrament_sponsor (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    sacrament_record_id UUID NOT NULL REFERENCES sacrament_record(id) ON DELETE CASCADE,
+    role sponsor_role NOT NULL,
+    member_id UUID REFERENCES member(id) ON DELETE SET NULL,
+    full_name VARCHAR(200) NOT NULL,
+    sort_order INTEGER NOT NULL DEFAULT 0,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_sacrament_sponsor_record ON sacrament_sponsor(sacrament_record_id);
+CREATE INDEX IF NOT EXISTS idx_sacrament_sponsor_member ON sacrament_sponsor(member_id) WHERE member_id IS NOT NULL;
+
+CREATE TRIGGER audit_sacrament_sponsor
+    AFTER INSERT OR UPDATE OR DELETE ON sacrament_sponsor
+    FOR EACH ROW EXECUTE FUNCTION audit_trigger_function();
+
+-- 2. Existing names become unlinked rows; the text columns stay as the
+--    printed form
+INSERT INTO sacrament_sponsor (sacrament_record_id, role, full_name, sort_order)
+SELECT s.id,
+       CASE WHEN s.sacrament_type = 'CONFIRMATION' THEN 'SPONSOR'::sponsor_role ELSE 'GODPARENT'::sponsor_role END,
+       TRIM(g.name), g.ord
+FROM sacrament_record s
+CROSS JOIN LATERAL (VALUES (s.godparent_1_name, 1), (s.godparent_2_name, 2)) AS g(name, ord)
+WHERE s.sacrament_type IN ('BAPTISM', 'CONFIRMATION')
+  AND NULLIF(TRIM(g.name), '') IS NOT NULL
+  AND NOT EXISTS (SELECT 1 FROM sacrament_sponsor x WHERE x.sacrament_record_id = s.id);
+
+-- Witnesses are split at commas, semicolons, '&' and the word 'and', as
+-- sponsor::from_text does, with runs of spaces collapsed
+INSERT INTO sacrament_sponsor (sacrament_record_id, role, full_name, sort_order)
+SELECT s.id, 'WITNESS', w.name, 10 + ROW_NUMBER() OVER (PARTITION BY s.id ORDER BY p.ord, w.ord)
+FROM sacrament_record s
+CROSS JOIN LATERAL regexp_split_to_table(s.witnesses, '[,;&]') WITH ORDINALITY AS p(part, ord)
+CROSS JOIN LATERAL (
+    SELECT TRIM(n.name) AS name, n.ord
+    FROM regexp_split_to_table(TRIM(regexp_replace(p.part, '\s+', ' ', 'g')), '(^| )and(?= |$)') WITH ORDINALITY AS n(name, ord)
+) AS w
+WHERE w.name <> ''
+  AND NOT EXISTS (SELECT 1 FROM sacrament_sponsor x WHERE x.sacrament_record_id = s.id AND x.role = 'WITNESS');
+
+INSERT INTO app_setting (parish_id, setting_key, setting_value, setting_group, description) VALUES
+    (NULL, 'sacraments.min_age.sponsor', '16', 'sacraments', 'Minimum age for a godparent or confirmation sponsor, canon 874')
+ON CONFLICT (setting_key) WHERE parish_id IS NULL DO NOTHING;
@@ -0,0 +1,49 @@
+-- ============================================================================
+-- MIGRATION: Death and burial register
+-- ============================================================================
+
+-- 1. A member with a date of death is deceased; recording a death also
+--    clears is_active so they drop out of active counts and lists
+ALTER TABLE member ADD COLUMN IF NOT EXISTS date_of_death DATE;
+
+-- 2. The burial register, numbered per parish and year of death as in the
+--    paper book
+CREATE TABLE IF NOT EXISTS burial_record (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    parish_id UUID NOT NULL REFERENCES parish(id) ON DELETE CASCADE,
+    member_id UUID NOT NULL REFERENCES member(id) ON DELETE CASCADE,
+    entry_year INTEGER NOT NULL,
+    entry_number INTEGER NOT NULL CHECK (entry_number > 0),
+    date_of_death DATE NOT NULL,
+    place_of_death VARCHAR(200),
+    cause_of_death VARCHAR(200),
+    burial_date DATE,
+    burial_place VARCHAR(200),
+    cemetery_name VARCHAR(200),
+    plot_reference VARCHAR(50),
+    officiant VARCHAR(200),
+    -- The funeral fee receipt, where one was paid
+    income_transaction_id UUID REFERENCES income_transaction(id) ON DELETE SET NULL,
+    notes TEXT,
+    recorded_by UUID REFERENCES app_user(id) ON DELETE SET NULL,
+    -- Whether the member was active when the death was recorded, so that
+    -- removing an entry made in error restores them as they were
+    member_was_active BOOLEAN,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    deleted_at TIMESTAMPTZ,
+    CONSTRAINT burial_after_death CHECK (burial_date IS NULL OR burial_date >= date_of_death)
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_burial_record_member
+    ON burial_record(member_id) WHERE deleted_at IS NULL;
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_burial_record_entry
+    ON burial_record(parish_id, entry_year, entry_number) WHERE deleted_at IS NULL;
+
+CREATE TRIGGER set_burial_record_updated_at BEFORE UPDATE ON burial_record
+    FOR EACH ROW EXECUTE FUNCTION update_updated_at_column();
+
+CREATE TRIGGER audit_burial_record
+    AFTER INSERT OR UPDATE OR DELETE ON burial_record
+    FOR EACH ROW EXECUTE FUNCTION audit_trigger_function();
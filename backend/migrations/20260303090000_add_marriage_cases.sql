@@ -0,0 +1,128 @@
+-- ============================================================================
+-- MIGRATION: Marriage preparation case files and banns
+-- ============================================================================
+
+DO $$
+BEGIN
+    IF NOT EXISTS (SELECT 1 FROM pg_type WHERE typname = 'marriage_case_status') THEN
+        CREATE TYPE marriage_case_status AS ENUM ('OPEN', 'COMPLETED', 'CANCELLED');
+    END IF;
+    IF NOT EXISTS (SELECT 1 FROM pg_type WHERE typname = 'marriage_party') THEN
+        CREATE TYPE marriage_party AS ENUM ('GROOM', 'BRIDE');
+    END IF;
+    IF NOT EXISTS (SELECT 1 FROM pg_type WHERE typname = 'marriage_document_type') THEN
+        CREATE TYPE marriage_document_type AS ENUM (
+            'BAPTISM_CERTIFICATE', 'CONFIRMATION_CERTIFICATE', 'FREEDOM_TO_MARRY',
+            'CIVIL_NOTICE', 'DISPENSATION', 'OTHER'
+        );
+    END IF;
+END$$;
+
+-- 1. The case file. Each party is a member or an outsider known by name
+--    and parish.
+CREATE TABLE IF NOT EXISTS marriage_case (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    parish_id UUID NOT NULL REFERENCES parish(id) ON DELETE CASCADE,
+    groom_member_id UUID REFERENCES member(id) ON DELETE SET NULL,
+    groom_name VARCHAR(200) NOT NULL,
+    groom_parish_id UUID REFERENCES parish(id) ON DELETE SET NULL,
+    groom_parish_name VARCHAR(200),
+    bride_member_id UUID REFERENCES member(id) ON DELETE SET NULL,
+    bride_name VARCHAR(200) NOT NULL,
+    bride_parish_id UUID REFERENCES parish(id) ON DELETE SET NULL,
+    bride_parish_name VARCHAR(200),
+    proposed_date DATE,
+    church_name VARCHAR(200),
+    officiating_minister VARCHAR(200),
+    pre_cana_course VARCHAR(200),
+    pre_cana_completed_on DATE,
+    status marriage_case_status NOT NULL DEFAULT 'OPEN',
+    groom_record_id UUID REFERENCES sacrament_record(id) ON DELETE SET NULL,
+    bride_record_id UUID REFERENCES sacrament_record(id) ON DELETE SET NULL,
+    notes TEXT,
+    created_by UUID REFERENCES app_user(id) ON DELETE SET NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    deleted_at TIMESTAMPTZ,
+    CONSTRAINT marriage_case_two_parties CHECK (groom_member_id IS NULL OR groom_member_id IS DISTINCT FROM bride_member_id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_marriage_case_parish ON marriage_case(parish_id, status) WHERE deleted_at IS NULL;
+
+CREATE TRIGGER set_marriage_case_updated_at BEFORE UPDATE ON marriage_case
+    FOR EACH ROW EXECUTE FUNCTION update_updated_at_column();
+
+CREATE TRIGGER audit_marriage_case
+    AFTER INSERT OR UPDATE OR DELETE ON marriage_case
+    FOR EACH ROW EXECUTE FUNCTION audit_trigger_function();
+
+-- 2. Documents each party must bring
+CREATE TABLE IF NOT EXISTS marriage_document (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    marriage_case_id UUID NOT NULL REFERENCES marriage_case(id) ON DELETE CASCADE,
+    party marriage_party NOT NULL,
+    document_type marriage_document_type NOT NULL,
+    required BOOLEAN NOT NULL DEFAULT TRUE,
+    -- A baptism certificate must be freshly issued
+    issued_on DATE,
+    received_on DATE,
+    reference VARCHAR(100),
+    notes TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_marriage_document_case ON marriage_document(marriage_case_id);
+
+CREATE TRIGGER set_marriage_document_updated_at BEFORE UPDATE ON marriage_document
+    FOR EACH ROW EXECUTE FUNCTION update_updated_at_column();
+
+CREATE TRIGGER audit_marriage_document
+    AFTER INSERT OR UPDATE OR DELETE ON marriage_document
+    FOR EACH ROW EXECUTE FUNCTION audit_trigger_function();
+
+-- 3. Banns, read on three Sundays in each party's parish. `party` is
+--    empty when both belong to the same parish.
+CREATE TABLE IF NOT EXISTS banns_publication (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    marriage_case_id UUID NOT NULL REFERENCES marriage_case(id) ON DELETE CASCADE,
+    party marriage_party,
+    parish_id UUID REFERENCES parish(id) ON DELETE SET NULL,
+    parish_name VARCHAR(200) NOT NULL,
+    publication_number INTEGER NOT NULL CHECK (publication_number BETWEEN 1 AND 3),
+    publish_on DATE NOT NULL,
+    published_at TIMESTAMPTZ,
+    confirmed_by UUID REFERENCES app_user(id) ON DELETE SET NULL,
+    objection TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_banns_publication_case ON banns_publication(marriage_case_id);
+
+CREATE TRIGGER audit_banns_publication
+    AFTER INSERT OR UPDATE OR DELETE ON banns_publication
+    FOR EACH ROW EXECUTE FUNCTION audit_trigger_function();
+
+-- 4. Impediments found during preparation, resolved by dispensation or
+--    further enquiry
+CREATE TABLE IF NOT EXISTS marriage_impediment (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    marriage_case_id UUID NOT NULL REFERENCES marriage_case(id) ON DELETE CASCADE,
+    description TEXT NOT NULL,
+    resolution TEXT,
+    dispensation_reference VARCHAR(100),
+    resolved_on DATE,
+    resolved_by UUID REFERENCES app_user(id) ON DELETE SET NULL,
+    created_by UUID REFERENCES app_user(id) ON DELETE SET NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_marriage_impediment_case ON marriage_impediment(marriage_case_id);
+
+CREATE TRIGGER audit_marriage_impediment
+    AFTER INSERT OR UPDATE OR DELETE ON marriage_impediment
+    FOR EACH ROW EXECUTE FUNCTION audit_trigger_function();
+
+INSERT INTO app_setting (parish_id, setting_key, setting_value, setting_group, description) VALUES
+    (NULL, 'marriage.baptism_certificate_max_age_days', '180', 'sacraments', 'How recently a baptism certificate for marriage must have been issued')
+ON CONFLICT (setting_key) WHERE parish_id IS NULL DO NOTHING;
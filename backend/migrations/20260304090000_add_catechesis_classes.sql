@@ -0,0 +1,103 @@
+-- ============================================================================
+-- MIGRATION: Catechesis classes and sacrament candidates
+-- ============================================================================
+
+DO $$
+BEGIN
+    IF NOT EXISTS (SELECT 1 FROM pg_type WHERE typname = 'candidate_readiness') THEN
+        CREATE TYPE candidate_readiness AS ENUM (
+            'PENDING', 'READY', 'NOT_READY', 'CELEBRATED', 'WITHDRAWN'
+        );
+    END IF;
+END$$;
+
+-- 1. A class preparing candidates for one sacrament, at the parish or
+--    one of its outstations (clusters)
+CREATE TABLE IF NOT EXISTS catechesis_class (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    parish_id UUID NOT NULL REFERENCES parish(id) ON DELETE CASCADE,
+    cluster_id UUID REFERENCES cluster(id) ON DELETE SET NULL,
+    class_name VARCHAR(200) NOT NULL,
+    sacrament_type sacrament_type NOT NULL,
+    start_date DATE NOT NULL,
+    end_date DATE,
+    planned_celebration_date DATE,
+    -- Share of sessions a candidate must attend to be passed as ready
+    min_attendance_percent INTEGER NOT NULL DEFAULT 75 CHECK (min_attendance_percent BETWEEN 0 AND 100),
+    celebrated_on DATE,
+    notes TEXT,
+    created_by UUID REFERENCES app_user(id) ON DELETE SET NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    deleted_at TIMESTAMPTZ,
+    CONSTRAINT catechesis_class_sacrament CHECK (sacrament_type IN ('BAPTISM', 'FIRST_COMMUNION', 'CONFIRMATION'))
+);
+
+CREATE INDEX IF NOT EXISTS idx_catechesis_class_parish ON catechesis_class(parish_id) WHERE deleted_at IS NULL;
+
+CREATE TRIGGER set_catechesis_class_updated_at BEFORE UPDATE ON catechesis_class
+    FOR EACH ROW EXECUTE FUNCTION update_updated_at_column();
+
+CREATE TRIGGER audit_catechesis_class
+    AFTER INSERT OR UPDATE OR DELETE ON catechesis_class
+    FOR EACH ROW EXECUTE FUNCTION audit_trigger_function();
+
+-- 2. Catechists, members or others known by name
+CREATE TABLE IF NOT EXISTS catechesis_catechist (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    class_id UUID NOT NULL REFERENCES catechesis_class(id) ON DELETE CASCADE,
+    member_id UUID REFERENCES member(id) ON DELETE SET NULL,
+    full_name VARCHAR(200) NOT NULL,
+    is_lead BOOLEAN NOT NULL DEFAULT FALSE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_catechesis_catechist_class ON catechesis_catechist(class_id);
+
+-- 3. Enrolled candidates, with the godparent or sponsor who will stand
+--    for them
+CREATE TABLE IF NOT EXISTS catechesis_candidate (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    class_id UUID NOT NULL REFERENCES catechesis_class(id) ON DELETE CASCADE,
+    member_id UUID NOT NULL REFERENCES member(id) ON DELETE CASCADE,
+    enrolled_on DATE NOT NULL DEFAULT CURRENT_DATE,
+    sponsor_member_id UUID REFERENCES member(id) ON DELETE SET NULL,
+    sponsor_name VARCHAR(200),
+    readiness candidate_readiness NOT NULL DEFAULT 'PENDING',
+    assessment_notes TEXT,
+    assessed_on DATE,
+    assessed_by UUID REFERENCES app_user(id) ON DELETE SET NULL,
+    sacrament_record_id UUID REFERENCES sacrament_record(id) ON DELETE SET NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE(class_id, member_id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_catechesis_candidate_member ON catechesis_candidate(member_id);
+
+CREATE TRIGGER set_catechesis_candidate_updated_at BEFORE UPDATE ON catechesis_candidate
+    FOR EACH ROW EXECUTE FUNCTION update_updated_at_column();
+
+CREATE TRIGGER audit_catechesis_candidate
+    AFTER INSERT OR UPDATE OR DELETE ON catechesis_candidate
+    FOR EACH ROW EXECUTE FUNCTION audit_trigger_function();
+
+-- 4. Lessons and who came to them
+CREATE TABLE IF NOT EXISTS catechesis_session (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    class_id UUID NOT NULL REFERENCES catechesis_class(id) ON DELETE CASCADE,
+    session_date DATE NOT NULL,
+    topic VARCHAR(200),
+    catechist_id UUID REFERENCES catechesis_catechist(id) ON DELETE SET NULL,
+    notes TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_catechesis_session_class ON catechesis_session(class_id, session_date);
+
+CREATE TABLE IF NOT EXISTS catechesis_attendance (
+    session_id UUID NOT NULL REFERENCES catechesis_session(id) ON DELETE CASCADE,
+    candidate_id UUID NOT NULL REFERENCES catechesis_candidate(id) ON DELETE CASCADE,
+    present BOOLEAN NOT NULL,
+    PRIMARY KEY (session_id, candidate_id)
+);
@@ -0,0 +1,91 @@
+-- ============================================================================
+-- MIGRATION: Mass schedule, intentions and stipends
+-- ============================================================================
+
+DO $$
+BEGIN
+    IF NOT EXISTS (SELECT 1 FROM pg_type WHERE typname = 'mass_intention_status') THEN
+        CREATE TYPE mass_intention_status AS ENUM (
+            'BOOKED', 'CELEBRATED', 'TRANSFERRED', 'CANCELLED'
+        );
+    END IF;
+END$$;
+
+-- 1. The parish's regular weekly Masses, at the parish church or an
+--    outstation (cluster)
+CREATE TABLE IF NOT EXISTS mass_schedule (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    parish_id UUID NOT NULL REFERENCES parish(id) ON DELETE CASCADE,
+    cluster_id UUID REFERENCES cluster(id) ON DELETE SET NULL,
+    -- ISO day of the week, Monday = 1 to Sunday = 7
+    day_of_week SMALLINT NOT NULL CHECK (day_of_week BETWEEN 1 AND 7),
+    mass_time TIME NOT NULL,
+    church_name VARCHAR(200),
+    language VARCHAR(50),
+    -- Intentions that may be booked for one celebration of this Mass
+    max_intentions INTEGER NOT NULL DEFAULT 1 CHECK (max_intentions > 0),
+    valid_from DATE,
+    valid_to DATE,
+    is_active BOOLEAN NOT NULL DEFAULT TRUE,
+    notes TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    deleted_at TIMESTAMPTZ
+);
+
+CREATE INDEX IF NOT EXISTS idx_mass_schedule_parish ON mass_schedule(parish_id) WHERE deleted_at IS NULL;
+
+CREATE TRIGGER set_mass_schedule_updated_at BEFORE UPDATE ON mass_schedule
+    FOR EACH ROW EXECUTE FUNCTION update_updated_at_column();
+
+CREATE TRIGGER audit_mass_schedule
+    AFTER INSERT OR UPDATE OR DELETE ON mass_schedule
+    FOR EACH ROW EXECUTE FUNCTION audit_trigger_function();
+
+-- 2. Intentions booked for a Mass on a date, with the stipend received.
+--    An intention passed to another priest leaves the parish schedule and
+--    records who took it
+CREATE TABLE IF NOT EXISTS mass_intention (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    parish_id UUID NOT NULL REFERENCES parish(id) ON DELETE CASCADE,
+    mass_schedule_id UUID REFERENCES mass_schedule(id) ON DELETE SET NULL,
+    mass_date DATE,
+    intention TEXT NOT NULL,
+    -- The member prayed for, where they are one
+    member_id UUID REFERENCES member(id) ON DELETE SET NULL,
+    requested_by_member_id UUID REFERENCES member(id) ON DELETE SET NULL,
+    requested_by_name VARCHAR(200) NOT NULL,
+    requested_by_phone VARCHAR(50),
+    stipend_amount DECIMAL(15, 2) NOT NULL DEFAULT 0 CHECK (stipend_amount >= 0),
+    income_transaction_id UUID REFERENCES income_transaction(id) ON DELETE SET NULL,
+    status mass_intention_status NOT NULL DEFAULT 'BOOKED',
+    celebrated_by VARCHAR(200),
+    transferred_to VARCHAR(200),
+    transferred_on DATE,
+    transfer_reference VARCHAR(100),
+    notes TEXT,
+    booked_by UUID REFERENCES app_user(id) ON DELETE SET NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    deleted_at TIMESTAMPTZ,
+    CONSTRAINT mass_intention_scheduled CHECK (
+        status = 'TRANSFERRED' OR (mass_schedule_id IS NOT NULL AND mass_date IS NOT NULL)
+    ),
+    CONSTRAINT mass_intention_transfer CHECK (
+        status <> 'TRANSFERRED' OR (transferred_to IS NOT NULL AND transferred_on IS NOT NULL)
+    )
+);
+
+CREATE INDEX IF NOT EXISTS idx_mass_intention_mass
+    ON mass_intention(mass_schedule_id, mass_date) WHERE deleted_at IS NULL;
+CREATE INDEX IF NOT EXISTS idx_mass_intention_parish_date
+    ON mass_intention(parish_id, mass_date) WHERE deleted_at IS NULL;
+CREATE INDEX IF NOT EXISTS idx_mass_intention_receipt
+    ON mass_intention(income_transaction_id) WHERE deleted_at IS NULL;
+
+CREATE TRIGGER set_mass_intention_updated_at BEFORE UPDATE ON mass_intention
+    FOR EACH ROW EXECUTE FUNCTION update_updated_at_column();
+
+CREATE TRIGGER audit_mass_intention
+    AFTER INSERT OR UPDATE OR DELETE ON mass_intention
+    FOR EACH ROW EXECUTE FUNCTION audit_trigger_function();
@@ -0,0 +1,54 @@
+-- ============================================================================
+-- MIGRATION: Annual statistical returns
+-- ============================================================================
+
+DO $$
+BEGIN
+    IF NOT EXISTS (SELECT 1 FROM pg_type WHERE typname = 'statistical_return_status') THEN
+        CREATE TYPE statistical_return_status AS ENUM ('DRAFT', 'SUBMITTED');
+    END IF;
+END$$;
+
+-- 1. One return per parish and year, and one for the diocese as a whole
+--    (parish_id NULL). A submitted return is locked.
+CREATE TABLE IF NOT EXISTS statistical_return (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    diocese_id UUID NOT NULL REFERENCES diocese(id) ON DELETE CASCADE,
+    parish_id UUID REFERENCES parish(id) ON DELETE CASCADE,
+    return_year INTEGER NOT NULL,
+    status statistical_return_status NOT NULL DEFAULT 'DRAFT',
+    computed_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    notes TEXT,
+    submitted_by UUID REFERENCES app_user(id) ON DELETE SET NULL,
+    submitted_at TIMESTAMPTZ,
+    created_by UUID REFERENCES app_user(id) ON DELETE SET NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    deleted_at TIMESTAMPTZ
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_statistical_return_parish
+    ON statistical_return(parish_id, return_year) WHERE parish_id IS NOT NULL AND deleted_at IS NULL;
+CREATE UNIQUE INDEX IF NOT EXISTS idx_statistical_return_diocese
+    ON statistical_return(diocese_id, return_year) WHERE parish_id IS NULL AND deleted_at IS NULL;
+
+CREATE TRIGGER set_statistical_return_updated_at BEFORE UPDATE ON statistical_return
+    FOR EACH ROW EXECUTE FUNCTION update_updated_at_column();
+
+CREATE TRIGGER audit_statistical_return
+    AFTER INSERT OR UPDATE OR DELETE ON statistical_return
+    FOR EACH ROW EXECUTE FUNCTION audit_trigger_function();
+
+-- 2. The figures: what the registers give, and any correction made by
+--    hand with the reason for it
+CREATE TABLE IF NOT EXISTS statistical_return_line (
+    return_id UUID NOT NULL REFERENCES statistical_return(id) ON DELETE CASCADE,
+    line_code VARCHAR(50) NOT NULL,
+    computed_value BIGINT NOT NULL DEFAULT 0,
+    adjustment BIGINT NOT NULL DEFAULT 0,
+    adjustment_note TEXT,
+    adjusted_by UUID REFERENCES app_user(id) ON DELETE SET NULL,
+    adjusted_at TIMESTAMPTZ,
+    PRIMARY KEY (return_id, line_code),
+    CONSTRAINT statistical_adjustment_noted CHECK (adjustment = 0 OR adjustment_note IS NOT NULL)
+);
@@ -0,0 +1,65 @@
+-- ============================================================================
+-- MIGRATION: Member transfers between parishes
+-- ============================================================================
+
+DO $$
+BEGIN
+    IF NOT EXISTS (SELECT 1 FROM pg_type WHERE typname = 'member_transfer_status') THEN
+        CREATE TYPE member_transfer_status AS ENUM (
+            'REQUESTED', 'COMPLETED', 'REJECTED', 'CANCELLED'
+        );
+    END IF;
+END$$;
+
+-- 1. A transfer is requested by the receiving parish and approved by the
+--    sending parish, for a whole family or for individual members
+CREATE TABLE IF NOT EXISTS member_transfer (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    from_parish_id UUID NOT NULL REFERENCES parish(id) ON DELETE CASCADE,
+    to_parish_id UUID NOT NULL REFERENCES parish(id) ON DELETE CASCADE,
+    family_id UUID REFERENCES family(id) ON DELETE SET NULL,
+    old_family_code VARCHAR(20),
+    new_family_code VARCHAR(20),
+    -- The receiving parish's small Christian community for the newcomers
+    to_scc_id UUID REFERENCES scc(id) ON DELETE SET NULL,
+    status member_transfer_status NOT NULL DEFAULT 'REQUESTED',
+    reason TEXT,
+    requested_by UUID REFERENCES app_user(id) ON DELETE SET NULL,
+    decided_by UUID REFERENCES app_user(id) ON DELETE SET NULL,
+    decided_at TIMESTAMPTZ,
+    decision_note TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CONSTRAINT member_transfer_parishes CHECK (from_parish_id <> to_parish_id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_member_transfer_from ON member_transfer(from_parish_id, status);
+CREATE INDEX IF NOT EXISTS idx_member_transfer_to ON member_transfer(to_parish_id, status);
+
+CREATE TRIGGER set_member_transfer_updated_at BEFORE UPDATE ON member_transfer
+    FOR EACH ROW EXECUTE FUNCTION update_updated_at_column();
+
+CREATE TRIGGER audit_member_transfer
+    AFTER INSERT OR UPDATE OR DELETE ON member_transfer
+    FOR EACH ROW EXECUTE FUNCTION audit_trigger_function();
+
+-- 2. The members moved, with the code they had and the one they were given
+CREATE TABLE IF NOT EXISTS member_transfer_member (
+    transfer_id UUID NOT NULL REFERENCES member_transfer(id) ON DELETE CASCADE,
+    member_id UUID NOT NULL REFERENCES member(id) ON DELETE CASCADE,
+    old_member_code VARCHAR(20) NOT NULL,
+    new_member_code VARCHAR(20),
+    PRIMARY KEY (transfer_id, member_id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_member_transfer_member ON member_transfer_member(member_id);
+
+-- 3. Moves between parishes, like other changes to members and families,
+--    are kept in the audit log
+CREATE TRIGGER audit_member
+    AFTER INSERT OR UPDATE OR DELETE ON member
+    FOR EACH ROW EXECUTE FUNCTION audit_trigger_function();
+
+CREATE TRIGGER audit_family
+    AFTER INSERT OR UPDATE OR DELETE ON family
+    FOR EACH ROW EXECUTE FUNCTION audit_trigger_function();
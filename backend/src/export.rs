@@ -0,0 +1,1180 @@
+use axum::{
+    async_trait,
+    extract::{FromRequestParts, Query},
+    http::{header, request::Parts, StatusCode},
+    response::{IntoResponse, Response},
+    Json,
+};
+use chrono::NaiveDate;
+use rust_decimal::{prelude::ToPrimitive, Decimal};
+use serde::{Deserialize, Serialize};
+use sqlx::postgres::PgPool;
+use std::path::{Component, Path, PathBuf};
+use uuid::Uuid;
+use crate::handlers::{auth::AuthUser, rbac};
+use crate::models::report::{
+    TrialBalance, IncomeExpenditureStatement, BudgetVsActualReport, BalanceSheet, BalanceSheetSection,
+    CashFlowStatement, FundBalanceReport, ConsolidatedStatement, ConsolidatedLine, ComparativeReport,
+    ComparativeEntry, MonthlyReport, MonthlyLine,
+};
+use crate::models::cashbook::CashbookStatement;
+use crate::models::asset::{AssetRegister, DepreciationSchedule};
+use crate::models::payroll::{PayrollRunDetail, PayslipDetail};
+use crate::models::supplier::{SupplierStatement, SpendBySupplierReport};
+use crate::models::register::RegisterBookAudit;
+
+const XLSX_MIME: &str = "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet";
+
+// ============================================================================
+// Format negotiation
+// ============================================================================
+
+/// Stored as `export_format` on report schedules, which has no JSON value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "export_format", rename_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ExportFormat {
+    Json,
+    Csv,
+    Xlsx,
+    Pdf,
+}
+
+impl ExportFormat {
+    fn parse(value: &str) -> Option<Self> {
+        match value.to_ascii_lowercase().as_str() {
+            "json" => Some(Self::Json),
+            "csv" => Some(Self::Csv),
+            "xlsx" | "excel" => Some(Self::Xlsx),
+            "pdf" => Some(Self::Pdf),
+            _ => None,
+        }
+    }
+
+    fn from_accept(accept: &str) -> Self {
+        if accept.contains("application/pdf") {
+            Self::Pdf
+        } else if accept.contains(XLSX_MIME) {
+            Self::Xlsx
+        } else if accept.contains("text/csv") {
+            Self::Csv
+        } else {
+            Self::Json
+        }
+    }
+
+    pub fn content_type(self) -> &'static str {
+        match self {
+            Self::Json => "application/json",
+            Self::Csv => "text/csv; charset=utf-8",
+            Self::Xlsx => XLSX_MIME,
+            Self::Pdf => "application/pdf",
+        }
+    }
+
+    pub fn extension(self) -> &'static str {
+        match self {
+            Self::Json => "json",
+            Self::Csv => "csv",
+            Self::Xlsx => "xlsx",
+            Self::Pdf => "pdf",
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct FormatParam {
+    format: Option<String>,
+}
+
+/// Picks the response format from the `format` query parameter, falling back
+/// to the `Accept` header. JSON unless something else is asked for.
+#[async_trait]
+impl<S> FromRequestParts<S> for ExportFormat
+where
+    S: Send + Sync,
+{
+    type Rejection = (StatusCode, String);
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        let param = Query::<FormatParam>::try_from_uri(&parts.uri)
+            .ok()
+            .and_then(|Query(p)| p.format);
+
+        if let Some(value) = param {
+            return Self::parse(&value).ok_or((
+                StatusCode::BAD_REQUEST,
+                format!("Unsupported format '{}' (use json, csv, xlsx or pdf)", value),
+            ));
+        }
+
+        let accept = parts.headers
+            .get(header::ACCEPT)
+            .and_then(|v| v.to_str().ok())
+            .unwrap_or_default();
+        Ok(Self::from_accept(accept))
+    }
+}
+
+// ============================================================================
+// Tabular representation shared by all renderers
+// ============================================================================
+
+#[derive(Debug, Clone)]
+pub enum Cell {
+    Text(String),
+    Amount(Decimal),
+    Count(i64),
+    Percent(Option<Decimal>),
+    Empty,
+}
+
+impl Cell {
+    pub fn text(value: impl Into<String>) -> Self {
+        Cell::Text(value.into())
+    }
+
+    pub fn opt(value: Option<impl ToString>) -> Self {
+        value.map_or(Cell::Empty, |v| Cell::Text(v.to_string()))
+    }
+
+    fn is_numeric(&self) -> bool {
+        matches!(self, Cell::Amount(_) | Cell::Count(_) | Cell::Percent(_))
+    }
+
+    fn display(&self) -> String {
+        match self {
+            Cell::Text(s) => s.clone(),
+            Cell::Amount(d) => format_amount(*d),
+            Cell::Count(n) => n.to_string(),
+            Cell::Percent(Some(p)) => format!("{}%", p.round_dp(2)),
+            Cell::Percent(None) | Cell::Empty => String::new(),
+        }
+    }
+
+    /// Plain value for CSV: no thousands separators, so spreadsheets parse it.
+    fn raw(&self) -> String {
+        match self {
+            Cell::Amount(d) => format!("{:.2}", d.round_dp(2)),
+            Cell::Percent(Some(p)) => p.round_dp(2).to_string(),
+            other => other.display(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RowKind {
+    Detail,
+    Heading,
+    Total,
+}
+
+#[derive(Debug, Clone)]
+pub struct Row {
+    pub kind: RowKind,
+    pub cells: Vec<Cell>,
+}
+
+impl Row {
+    pub fn detail(cells: Vec<Cell>) -> Self {
+        Row { kind: RowKind::Detail, cells }
+    }
+
+    pub fn total(cells: Vec<Cell>) -> Self {
+        Row { kind: RowKind::Total, cells }
+    }
+
+    pub fn heading(label: impl Into<String>) -> Self {
+        Row { kind: RowKind::Heading, cells: vec![Cell::text(label)] }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Table {
+    pub columns: Vec<String>,
+    pub rows: Vec<Row>,
+}
+
+impl Table {
+    pub fn new(columns: &[&str]) -> Self {
+        Table { columns: columns.iter().map(|c| c.to_string()).collect(), rows: Vec::new() }
+    }
+}
+
+/// Implemented by every report (and register) that can be exported.
+pub trait Tabular {
+    fn to_table(&self) -> Table;
+}
+
+/// What goes above the table: report title, period and whose books these are.
+#[derive(Debug, Clone)]
+pub struct ReportHeader {
+    pub title: String,
+    pub period: Option<String>,
+    pub parish_id: Option<Uuid>,
+    pub diocese_id: Option<Uuid>,
+}
+
+impl ReportHeader {
+    pub fn parish(parish_id: Uuid, title: &str, period: Option<String>) -> Self {
+        ReportHeader { title: title.to_string(), period, parish_id: Some(parish_id), diocese_id: None }
+    }
+
+    pub fn diocese(diocese_id: Uuid, title: &str, period: Option<String>) -> Self {
+        ReportHeader { title: title.to_string(), period, parish_id: None, diocese_id: Some(diocese_id) }
+    }
+
+    fn file_name(&self, format: ExportFormat) -> String {
+        let stem: String = self.title
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c.to_ascii_lowercase() } else { '-' })
+            .collect::<String>()
+            .split('-')
+            .filter(|s| !s.is_empty())
+            .collect::<Vec<_>>()
+            .join("-");
+        format!("{}.{}", stem, format.extension())
+    }
+}
+
+pub fn period_label(start: NaiveDate, end: NaiveDate) -> String {
+    format!("{} to {}", start.format("%d %b %Y"), end.format("%d %b %Y"))
+}
+
+/// Name of a SCREAMING_SNAKE_CASE enum value as stored, for table cells.
+pub fn enum_label<T: Serialize>(value: &T) -> String {
+    serde_json::to_value(value)
+        .ok()
+        .and_then(|v| v.as_str().map(str::to_string))
+        .unwrap_or_default()
+}
+
+// ============================================================================
+// Responses
+// ============================================================================
+
+/// JSON as before, or the report rendered in the requested format.
+pub async fn respond<T: Serialize + Tabular>(
+    db: &PgPool,
+    auth: &AuthUser,
+    format: ExportFormat,
+    header: ReportHeader,
+    data: T,
+) -> Result<Response, (StatusCode, String)> {
+    if format == ExportFormat::Json {
+        return Ok(Json(data).into_response());
+    }
+    respond_table(db, auth, format, header, data.to_table()).await
+}
+
+pub async fn respond_table(
+    db: &PgPool,
+    auth: &AuthUser,
+    format: ExportFormat,
+    header: ReportHeader,
+    table: Table,
+) -> Result<Response, (StatusCode, String)> {
+    rbac::require_permission(db, auth, "reports.export").await?;
+
+    let file_name = header.file_name(format);
+    let bytes = render(db, format, &header, &table)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e))?;
+
+    Ok((
+        [
+            (header::CONTENT_TYPE, format.content_type().to_string()),
+            (header::CONTENT_DISPOSITION, format!("attachment; filename=\"{}\"", file_name)),
+        ],
+        bytes,
+    ).into_response())
+}
+
+/// Renders a table to file contents. Also used by the report scheduler.
+pub async fn render(
+    db: &PgPool,
+    format: ExportFormat,
+    header: &ReportHeader,
+    table: &Table,
+) -> Result<Vec<u8>, String> {
+    match format {
+        ExportFormat::Json => Err("JSON is not a file export format".to_string()),
+        ExportFormat::Csv => render_csv(table),
+        ExportFormat::Xlsx => {
+            let letterhead = Letterhead::load(db, header).await?;
+            render_xlsx(&letterhead, header, table)
+        }
+        ExportFormat::Pdf => {
+            let letterhead = Letterhead::load(db, header).await?;
+            render_pdf(&letterhead, header, table)
+        }
+    }
+}
+
+// ============================================================================
+// Letterhead
+// ============================================================================
+
+#[derive(sqlx::FromRow)]
+struct LetterheadRow {
+    name: String,
+    address: Option<String>,
+    phone: Option<String>,
+    email: Option<String>,
+    logo_url: Option<String>,
+    currency_code: Option<String>,
+}
+
+struct Letterhead {
+    name: String,
+    lines: Vec<String>,
+    logo: Option<PathBuf>,
+    /// Amounts are in the diocesan base currency.
+    currency: Option<String>,
+}
+
+impl Letterhead {
+    async fn load(db: &PgPool, header: &ReportHeader) -> Result<Self, String> {
+        let row: Option<LetterheadRow> =
+            if let Some(parish_id) = header.parish_id {
+                sqlx::query_as(
+                    r#"SELECT p.parish_name AS name, p.postal_address AS address, p.contact_phone AS phone,
+                              p.contact_email AS email, COALESCE(p.logo_url, d.logo_url) AS logo_url,
+                              d.currency_code
+                       FROM parish p JOIN diocese d ON d.id = p.diocese_id WHERE p.id = $1"#
+                )
+                .bind(parish_id)
+                .fetch_optional(db)
+                .await
+                .map_err(|e| e.to_string())?
+            } else if let Some(diocese_id) = header.diocese_id {
+                sqlx::query_as(
+                    r#"SELECT diocese_name AS name, headquarters_address AS address, contact_phone AS phone,
+                              contact_email AS email, logo_url, currency_code
+                       FROM diocese WHERE id = $1"#
+                )
+                .bind(diocese_id)
+                .fetch_optional(db)
+                .await
+                .map_err(|e| e.to_string())?
+            } else {
+                None
+            };
+
+        let Some(LetterheadRow { name, address, phone, email, logo_url, currency_code }) = row else {
+            return Ok(Letterhead { name: String::new(), lines: Vec::new(), logo: None, currency: None });
+        };
+
+        let contact = [phone, email].into_iter().flatten().collect::<Vec<_>>().join("  |  ");
+        let lines = [address, Some(contact)].into_iter()
+            .flatten()
+            .filter(|l| !l.trim().is_empty())
+            .collect();
+
+        Ok(Letterhead { name, lines, logo: logo_url.as_deref().and_then(logo_path), currency: currency_code })
+    }
+}
+
+/// Maps an uploaded logo URL (`/uploads/...`) to its file on disk.
+fn logo_path(url: &str) -> Option<PathBuf> {
+    let relative = Path::new(url.strip_prefix('/')?);
+    let safe = relative.starts_with("uploads")
+        && relative.components().all(|c| matches!(c, Component::Normal(_)));
+    (safe && relative.is_file()).then(|| relative.to_path_buf())
+}
+
+// ============================================================================
+// Renderers
+// ============================================================================
+
+pub fn format_amount(value: Decimal) -> String {
+    let rounded = value.round_dp(2);
+    let negative = rounded.is_sign_negative() && !rounded.is_zero();
+    let text = format!("{:.2}", rounded.abs());
+    let (int_part, frac_part) = text.split_once('.').unwrap_or((&text, "00"));
+
+    let mut grouped = String::new();
+    for (i, ch) in int_part.chars().enumerate() {
+        if i > 0 && (int_part.len() - i) % 3 == 0 {
+            grouped.push(',');
+        }
+        grouped.push(ch);
+    }
+
+    if negative {
+        format!("({}.{})", grouped, frac_part)
+    } else {
+        format!("{}.{}", grouped, frac_part)
+    }
+}
+
+fn render_csv(table: &Table) -> Result<Vec<u8>, String> {
+    let mut writer = csv::Writer::from_writer(Vec::new());
+    writer.write_record(&table.columns).map_err(|e| e.to_string())?;
+    for row in &table.rows {
+        let mut record: Vec<String> = row.cells.iter().map(Cell::raw).collect();
+        record.resize(table.columns.len(), String::new());
+        writer.write_record(&record).map_err(|e| e.to_string())?;
+    }
+    writer.into_inner().map_err(|e| e.to_string())
+}
+
+fn render_xlsx(letterhead: &Letterhead, header: &ReportHeader, table: &Table) -> Result<Vec<u8>, String> {
+    use rust_xlsxwriter::{Format, FormatBorder, Workbook};
+
+    let mut workbook = Workbook::new();
+    let sheet = workbook.add_worksheet();
+    let sheet_name: String = header.title.chars().filter(|c| !"[]:*?/\\".contains(*c)).take(31).collect();
+    sheet.set_name(sheet_name).map_err(|e| e.to_string())?;
+
+    let title = Format::new().set_bold().set_font_size(14);
+    let subtitle = Format::new().set_italic();
+    let column_header = Format::new().set_bold().set_border_bottom(FormatBorder::Thin);
+    let heading = Format::new().set_bold();
+    let money = Format::new().set_num_format("#,##0.00;(#,##0.00)");
+    let count = Format::new().set_num_format("#,##0");
+    let percent = Format::new().set_num_format("0.00\"%\"");
+    let total_text = Format::new().set_bold().set_border_top(FormatBorder::Thin);
+    let total_money = money.clone().set_bold().set_border_top(FormatBorder::Thin).set_border_bottom(FormatBorder::Double);
+    let total_count = count.clone().set_bold().set_border_top(FormatBorder::Thin);
+    let total_percent = percent.clone().set_bold().set_border_top(FormatBorder::Thin);
+
+    let err = |e: rust_xlsxwriter::XlsxError| e.to_string();
+    let mut r: u32 = 0;
+    if !letterhead.name.is_empty() {
+        sheet.write_string_with_format(r, 0, &letterhead.name, &title).map_err(err)?;
+        r += 1;
+    }
+    sheet.write_string_with_format(r, 0, &header.title, &heading).map_err(err)?;
+    r += 1;
+    if let Some(period) = &header.period {
+        sheet.write_string_with_format(r, 0, period, &subtitle).map_err(err)?;
+        r += 1;
+    }
+    if let Some(currency) = &letterhead.currency {
+        sheet.write_string_with_format(r, 0, format!("Amounts in {}", currency), &subtitle).map_err(err)?;
+        r += 1;
+    }
+    r += 1;
+
+    for (c, name) in table.columns.iter().enumerate() {
+        sheet.write_string_with_format(r, c as u16, name, &column_header).map_err(err)?;
+    }
+    r += 1;
+
+    for row in &table.rows {
+        let is_total = row.kind == RowKind::Total;
+        for (c, cell) in row.cells.iter().enumerate() {
+            let c = c as u16;
+            match cell {
+                Cell::Text(s) => {
+                    let fmt = match row.kind {
+                        RowKind::Heading => &heading,
+                        RowKind::Total => &total_text,
+                        RowKind::Detail => &Format::new(),
+                    };
+                    sheet.write_string_with_format(r, c, s, fmt).map_err(err)?;
+                }
+                Cell::Amount(d) => {
+                    let fmt = if is_total { &total_money } else { &money };
+                    sheet.write_number_with_format(r, c, d.to_f64().unwrap_or_default(), fmt).map_err(err)?;
+                }
+                Cell::Count(n) => {
+                    let fmt = if is_total { &total_count } else { &count };
+                    sheet.write_number_with_format(r, c, *n as f64, fmt).map_err(err)?;
+                }
+                Cell::Percent(Some(p)) => {
+                    let fmt = if is_total { &total_percent } else { &percent };
+                    sheet.write_number_with_format(r, c, p.to_f64().unwrap_or_default(), fmt).map_err(err)?;
+                }
+                Cell::Percent(None) | Cell::Empty => {
+                    if is_total {
+                        sheet.write_blank(r, c, &total_text).map_err(err)?;
+                    }
+                }
+            }
+        }
+        r += 1;
+    }
+
+    sheet.autofit();
+    // Autofit measures the raw numbers, not the formatted "1,234.00" text
+    for c in 1..table.columns.len() {
+        if table.rows.iter().any(|row| row.cells.get(c).is_some_and(Cell::is_numeric)) {
+            sheet.set_column_width(c as u16, 15).map_err(err)?;
+        }
+    }
+    workbook.save_to_buffer().map_err(err)
+}
+
+/// Approximate Helvetica advance width in millimetres; the built-in PDF
+/// fonts carry no metrics we can query, and this is close enough to
+/// right-align figures and clip long labels.
+fn text_width_mm(text: &str, size_pt: f32) -> f32 {
+    let units: u32 = text.chars().map(|c| match c {
+        '0'..='9' => 556,
+        '.' | ',' | ' ' | ':' | ';' | '\'' | 'i' | 'j' | 'l' | 'I' | '/' => 278,
+        '-' | '(' | ')' | 'f' | 't' | 'r' => 333,
+        'm' | 'M' | 'W' | '%' => 889,
+        'w' => 722,
+        'A'..='Z' => 667,
+        _ => 556,
+    }).sum();
+    units as f32 / 1000.0 * size_pt * 0.3528
+}
+
+fn clip(text: &str, width_mm: f32, size_pt: f32) -> String {
+    if text_width_mm(text, size_pt) <= width_mm {
+        return text.to_string();
+    }
+    let mut out: String = text.to_string();
+    while !out.is_empty() && text_width_mm(&format!("{}...", out), size_pt) > width_mm {
+        out.pop();
+    }
+    format!("{}...", out)
+}
+
+fn render_pdf(letterhead: &Letterhead, header: &ReportHeader, table: &Table) -> Result<Vec<u8>, String> {
+    use printpdf::{BuiltinFont, Image, ImageTransform, Line, Mm, PdfDocument, Point};
+
+    const MARGIN: f32 = 15.0;
+    const BODY_PT: f32 = 8.5;
+    const ROW_MM: f32 = 5.0;
+
+    // Natural column widths from the content, first column capped
+    let mut widths: Vec<f32> = table.columns.iter().map(|c| text_width_mm(c, BODY_PT) + 4.0).collect();
+    for row in &table.rows {
+        if row.kind == RowKind::Heading {
+            continue;
+        }
+        for (i, cell) in row.cells.iter().enumerate().take(widths.len()) {
+            let w = text_width_mm(&cell.display(), BODY_PT) + 4.0;
+            widths[i] = widths[i].max(w.min(if i == 0 { 70.0 } else { 45.0 }));
+        }
+    }
+
+    let natural: f32 = widths.iter().sum();
+    let landscape = natural > 210.0 - 2.0 * MARGIN;
+    let (page_w, page_h) = if landscape { (297.0, 210.0) } else { (210.0, 297.0) };
+    let usable = page_w - 2.0 * MARGIN;
+    let scale = usable / natural;
+    // Narrow tables stretch the label column; wide ones shrink everything
+    if scale > 1.0 {
+        widths[0] += usable - natural;
+    } else {
+        widths.iter_mut().for_each(|w| *w *= scale);
+    }
+
+    let (doc, page, layer) = PdfDocument::new(&header.title, Mm(page_w), Mm(page_h), "Report");
+    let regular = doc.add_builtin_font(BuiltinFont::Helvetica).map_err(|e| e.to_string())?;
+    let bold = doc.add_builtin_font(BuiltinFont::HelveticaBold).map_err(|e| e.to_string())?;
+    let mut layer = doc.get_page(page).get_layer(layer);
+
+    let rule = |layer: &printpdf::PdfLayerReference, y: f32, thickness: f32| {
+        layer.set_outline_thickness(thickness);
+        layer.add_line(Line {
+            points: vec![
+                (Point::new(Mm(MARGIN), Mm(y)), false),
+                (Point::new(Mm(page_w - MARGIN), Mm(y)), false),
+            ],
+            is_closed: false,
+        });
+    };
+
+    // Letterhead
+    let mut y = page_h - MARGIN;
+    let mut text_x = MARGIN;
+    if let Some(img) = letterhead.logo.as_ref()
+        .and_then(|p| std::fs::read(p).ok())
+        .and_then(|bytes| printpdf::image_crate::load_from_memory(&bytes).ok())
+    {
+        const LOGO_MM: f32 = 20.0;
+        let rgb = printpdf::image_crate::DynamicImage::ImageRgb8(img.to_rgb8());
+        let dpi = rgb.height() as f32 * 25.4 / LOGO_MM;
+        Image::from_dynamic_image(&rgb).add_to_layer(layer.clone(), ImageTransform {
+            translate_x: Some(Mm(MARGIN)),
+            translate_y: Some(Mm(y - LOGO_MM)),
+            dpi: Some(dpi),
+            ..Default::default()
+        });
+        text_x += rgb.width() as f32 * 25.4 / dpi + 5.0;
+    }
+    let letterhead_top = y;
+    if !letterhead.name.is_empty() {
+        y -= 6.0;
+        layer.use_text(&letterhead.name, 15.0, Mm(text_x), Mm(y), &bold);
+        for line in &letterhead.lines {
+            y -= 4.5;
+            layer.use_text(line, 8.5, Mm(text_x), Mm(y), &regular);
+        }
+    }
+    y = y.min(letterhead_top - if letterhead.logo.is_some() { 22.0 } else { 0.0 }) - 8.0;
+    layer.use_text(&header.title, 12.0, Mm(MARGIN), Mm(y), &bold);
+    if let Some(period) = &header.period {
+        y -= 5.0;
+        layer.use_text(period, 9.0, Mm(MARGIN), Mm(y), &regular);
+    }
+    if let Some(currency) = &letterhead.currency {
+        y -= 4.5;
+        layer.use_text(format!("Amounts in {}", currency), 8.5, Mm(MARGIN), Mm(y), &regular);
+    }
+    y -= 4.0;
+
+    let draw_columns = |layer: &printpdf::PdfLayerReference, y: &mut f32| {
+        *y -= ROW_MM;
+        let mut x = MARGIN;
+        for (i, name) in table.columns.iter().enumerate() {
+            let w = widths[i];
+            let label = clip(name, w - 2.0, BODY_PT);
+            let tx = if i == 0 { x + 1.0 } else { x + w - 1.0 - text_width_mm(&label, BODY_PT) };
+            layer.use_text(label, BODY_PT, Mm(tx), Mm(*y), &bold);
+            x += w;
+        }
+        rule(layer, *y - 1.5, 0.6);
+        *y -= 1.5;
+    };
+    draw_columns(&layer, &mut y);
+
+    let mut page_no = 1;
+    let footer = |layer: &printpdf::PdfLayerReference, page_no: u32| {
+        let text = format!("{}  -  Page {}", header.title, page_no);
+        layer.use_text(text, 7.0, Mm(MARGIN), Mm(MARGIN - 7.0), &regular);
+    };
+
+    for row in &table.rows {
+        if y - ROW_MM < MARGIN {
+            footer(&layer, page_no);
+            page_no += 1;
+            let (page, new_layer) = doc.add_page(Mm(page_w), Mm(page_h), "Report");
+            layer = doc.get_page(page).get_layer(new_layer);
+            y = page_h - MARGIN;
+            draw_columns(&layer, &mut y);
+        }
+        y -= ROW_MM;
+
+        let font = if row.kind == RowKind::Detail { &regular } else { &bold };
+        if row.kind == RowKind::Heading {
+            layer.use_text(row.cells.first().map(Cell::display).unwrap_or_default(), BODY_PT + 0.5, Mm(MARGIN + 1.0), Mm(y), font);
+            continue;
+        }
+        if row.kind == RowKind::Total {
+            rule(&layer, y + ROW_MM - 1.2, 0.3);
+        }
+
+        let mut x = MARGIN;
+        for (i, cell) in row.cells.iter().enumerate().take(widths.len()) {
+            let w = widths[i];
+            let text = clip(&cell.display(), w - 2.0, BODY_PT);
+            let tx = if cell.is_numeric() { x + w - 1.0 - text_width_mm(&text, BODY_PT) } else { x + 1.0 };
+            layer.use_text(text, BODY_PT, Mm(tx), Mm(y), font);
+            x += w;
+        }
+    }
+    footer(&layer, page_no);
+
+    doc.save_to_bytes().map_err(|e| e.to_string())
+}
+
+// ============================================================================
+// Certificates
+// ============================================================================
+
+/// A sacrament certificate as printed: the parish letterhead, the wording
+/// and a QR code linking to the public verification page.
+pub struct CertificateDocument {
+    pub parish_id: Uuid,
+    pub title: String,
+    pub body: String,
+    pub certificate_number: String,
+    pub signatory_title: Option<String>,
+    pub issued_on: String,
+    pub verification_code: String,
+    pub verification_url: String,
+}
+
+pub async fn render_certificate(db: &PgPool, doc: &CertificateDocument) -> Result<Vec<u8>, String> {
+    use printpdf::{path::PaintMode, BuiltinFont, Image, ImageTransform, Line, Mm, PdfDocument, Point, Rect};
+
+    const PAGE_W: f32 = 210.0;
+    const PAGE_H: f32 = 297.0;
+    const MARGIN: f32 = 20.0;
+    const BODY_PT: f32 = 12.0;
+
+    let header = ReportHeader { title: doc.title.clone(), period: None, parish_id: Some(doc.parish_id), diocese_id: None };
+    let letterhead = Letterhead::load(db, &header).await?;
+
+    let (pdf, page, layer) = PdfDocument::new(&doc.title, Mm(PAGE_W), Mm(PAGE_H), "Certificate");
+    let regular = pdf.add_builtin_font(BuiltinFont::Helvetica).map_err(|e| e.to_string())?;
+    let bold = pdf.add_builtin_font(BuiltinFont::HelveticaBold).map_err(|e| e.to_string())?;
+    let italic = pdf.add_builtin_font(BuiltinFont::HelveticaOblique).map_err(|e| e.to_string())?;
+    let layer = pdf.get_page(page).get_layer(layer);
+
+    let centered = |text: &str, size: f32, y: f32, font: &printpdf::IndirectFontRef| {
+        let x = (PAGE_W - text_width_mm(text, size)) / 2.0;
+        layer.use_text(text, size, Mm(x), Mm(y), font);
+    };
+
+    // Double border
+    for (inset, thickness) in [(10.0, 1.5), (12.5, 0.5)] {
+        layer.set_outline_thickness(thickness);
+        layer.add_rect(Rect::new(Mm(inset), Mm(inset), Mm(PAGE_W - inset), Mm(PAGE_H - inset)).with_mode(PaintMode::Stroke));
+    }
+
+    let number = format!("No. {}", doc.certificate_number);
+    layer.use_text(&number, 9.0, Mm(PAGE_W - MARGIN - text_width_mm(&number, 9.0)), Mm(PAGE_H - MARGIN), &regular);
+
+    // Letterhead, centred
+    let mut y = PAGE_H - MARGIN - 2.0;
+    if let Some(img) = letterhead.logo.as_ref()
+        .and_then(|p| std::fs::read(p).ok())
+        .and_then(|bytes| printpdf::image_crate::load_from_memory(&bytes).ok())
+    {
+        const LOGO_MM: f32 = 25.0;
+        let rgb = printpdf::image_crate::DynamicImage::ImageRgb8(img.to_rgb8());
+        let dpi = rgb.height() as f32 * 25.4 / LOGO_MM;
+        let width = rgb.width() as f32 * 25.4 / dpi;
+        Image::from_dynamic_image(&rgb).add_to_layer(layer.clone(), ImageTransform {
+            translate_x: Some(Mm((PAGE_W - width) / 2.0)),
+            translate_y: Some(Mm(y - LOGO_MM)),
+            dpi: Some(dpi),
+            ..Default::default()
+        });
+        y -= LOGO_MM + 4.0;
+    }
+    if !letterhead.name.is_empty() {
+        y -= 6.0;
+        centered(&letterhead.name, 16.0, y, &bold);
+        for line in &letterhead.lines {
+            y -= 5.0;
+            centered(line, 9.0, y, &regular);
+        }
+    }
+
+    y -= 22.0;
+    centered(&doc.title, 22.0, y, &bold);
+    y -= 16.0;
+
+    // Body, each line centred and wrapped to the page
+    for paragraph in doc.body.lines() {
+        let mut line = String::new();
+        for word in paragraph.split_whitespace() {
+            let candidate = if line.is_empty() { word.to_string() } else { format!("{} {}", line, word) };
+            if text_width_mm(&candidate, BODY_PT) > PAGE_W - 2.0 * MARGIN - 10.0 && !line.is_empty() {
+                centered(&line, BODY_PT, y, &regular);
+                y -= 8.0;
+                line = word.to_string();
+            } else {
+                line = candidate;
+            }
+        }
+        centered(&line, BODY_PT, y, &regular);
+        y -= 8.0;
+    }
+
+    // Signature
+    let sign_x = PAGE_W - MARGIN - 70.0;
+    let sign_y = 62.0;
+    layer.set_outline_thickness(0.5);
+    layer.add_line(Line {
+        points: vec![
+            (Point::new(Mm(sign_x), Mm(sign_y)), false),
+            (Point::new(Mm(PAGE_W - MARGIN), Mm(sign_y)), false),
+        ],
+        is_closed: false,
+    });
+    if let Some(signatory) = &doc.signatory_title {
+        layer.use_text(signatory, 10.0, Mm(sign_x), Mm(sign_y - 5.0), &regular);
+    }
+    layer.use_text(&doc.issued_on, 10.0, Mm(sign_x), Mm(sign_y - 10.0), &regular);
+    layer.use_text("(Parish seal)", 9.0, Mm(sign_x), Mm(sign_y + 18.0), &italic);
+
+    // Verification QR code
+    const QR_MM: f32 = 30.0;
+    let qr = qrcode::QrCode::new(doc.verification_url.as_bytes()).map_err(|e| e.to_string())?;
+    let modules = qr.width();
+    let cell = QR_MM / modules as f32;
+    let (qr_x, qr_y) = (MARGIN, 30.0);
+    for (i, color) in qr.to_colors().into_iter().enumerate() {
+        if color == qrcode::Color::Dark {
+            let (col, row) = ((i % modules) as f32, (i / modules) as f32);
+            let x = qr_x + col * cell;
+            let top = qr_y + QR_MM - row * cell;
+            layer.add_rect(Rect::new(Mm(x), Mm(top - cell), Mm(x + cell), Mm(top)));
+        }
+    }
+    layer.use_text(format!("Verification code: {}", doc.verification_code), 8.0, Mm(qr_x), Mm(qr_y - 5.0), &regular);
+    layer.use_text(&doc.verification_url, 7.0, Mm(qr_x), Mm(qr_y - 9.0), &regular);
+
+    pdf.save_to_bytes().map_err(|e| e.to_string())
+}
+
+// ============================================================================
+// Report tables
+// ============================================================================
+
+impl Tabular for TrialBalance {
+    fn to_table(&self) -> Table {
+        let mut t = Table::new(&["Category", "Debit", "Credit"]);
+        for e in &self.entries {
+            t.rows.push(Row::detail(vec![Cell::text(&e.category), Cell::Amount(e.debit), Cell::Amount(e.credit)]));
+        }
+        t.rows.push(Row::total(vec![Cell::text("Total"), Cell::Amount(self.total_debit), Cell::Amount(self.total_credit)]));
+        t
+    }
+}
+
+impl Tabular for IncomeExpenditureStatement {
+    fn to_table(&self) -> Table {
+        let mut t = Table::new(&["Category", "Amount"]);
+        t.rows.push(Row::heading("Income"));
+        for e in &self.income_entries {
+            t.rows.push(Row::detail(vec![Cell::text(&e.category), Cell::Amount(e.amount)]));
+        }
+        t.rows.push(Row::total(vec![Cell::text("Total Income"), Cell::Amount(self.total_income)]));
+        t.rows.push(Row::heading("Expenditure"));
+        for e in &self.expenditure_entries {
+            t.rows.push(Row::detail(vec![Cell::text(&e.category), Cell::Amount(e.amount)]));
+        }
+        t.rows.push(Row::total(vec![Cell::text("Total Expenditure"), Cell::Amount(self.total_expenditure)]));
+        t.rows.push(Row::total(vec![Cell::text("Net Surplus/(Deficit)"), Cell::Amount(self.net_surplus_deficit)]));
+        t
+    }
+}
+
+impl Tabular for BudgetVsActualReport {
+    fn to_table(&self) -> Table {
+        let mut t = Table::new(&["Category", "Budget", "Actual", "Variance"]);
+        let mut entries: Vec<_> = self.entries.iter().collect();
+        entries.sort_by(|a, b| a.category.cmp(&b.category));
+        for e in entries {
+            t.rows.push(Row::detail(vec![
+                Cell::text(&e.category), Cell::Amount(e.budget), Cell::Amount(e.actual), Cell::Amount(e.variance),
+            ]));
+        }
+        t.rows.push(Row::total(vec![
+            Cell::text("Total"), Cell::Amount(self.total_budget), Cell::Amount(self.total_actual), Cell::Amount(self.total_variance),
+        ]));
+        t
+    }
+}
+
+fn balance_sheet_section(t: &mut Table, section: &BalanceSheetSection) {
+    t.rows.push(Row::heading(&section.section_name));
+    for e in &section.entries {
+        t.rows.push(Row::detail(vec![Cell::text(&e.name), Cell::Amount(e.amount)]));
+    }
+    t.rows.push(Row::total(vec![Cell::text(format!("Total {}", section.section_name)), Cell::Amount(section.total)]));
+}
+
+impl Tabular for BalanceSheet {
+    fn to_table(&self) -> Table {
+        let mut t = Table::new(&["Item", "Amount"]);
+        balance_sheet_section(&mut t, &self.assets);
+        balance_sheet_section(&mut t, &self.liabilities);
+        balance_sheet_section(&mut t, &self.equity);
+        t
+    }
+}
+
+impl Tabular for CashFlowStatement {
+    fn to_table(&self) -> Table {
+        let mut t = Table::new(&["Description", "Amount"]);
+        t.rows.push(Row::detail(vec![Cell::text("Opening Balance"), Cell::Amount(self.opening_balance)]));
+        for section in &self.sections {
+            t.rows.push(Row::heading(&section.section_name));
+            for e in &section.entries {
+                t.rows.push(Row::detail(vec![Cell::text(&e.description), Cell::Amount(e.amount)]));
+            }
+            t.rows.push(Row::total(vec![Cell::text(format!("Net {}", section.section_name)), Cell::Amount(section.total)]));
+        }
+        t.rows.push(Row::total(vec![Cell::text("Net Cash Flow"), Cell::Amount(self.net_cash_flow)]));
+        t.rows.push(Row::total(vec![Cell::text("Closing Balance"), Cell::Amount(self.closing_balance)]));
+        t
+    }
+}
+
+impl Tabular for FundBalanceReport {
+    fn to_table(&self) -> Table {
+        let mut t = Table::new(&["Code", "Fund", "Type", "Opening", "Income", "Expenditure", "Closing", "Pending"]);
+        for e in &self.entries {
+            t.rows.push(Row::detail(vec![
+                Cell::text(&e.fund_code), Cell::text(&e.fund_name), Cell::text(&e.fund_type),
+                Cell::Amount(e.opening_balance), Cell::Amount(e.income), Cell::Amount(e.expenditure),
+                Cell::Amount(e.closing_balance), Cell::Amount(e.pending_commitments),
+            ]));
+        }
+        let total = |label: &str, amount: Decimal| {
+            let mut cells = vec![Cell::text(label), Cell::Empty, Cell::Empty, Cell::Empty, Cell::Empty, Cell::Empty];
+            cells.push(Cell::Amount(amount));
+            Row::total(cells)
+        };
+        t.rows.push(total("Total Restricted", self.total_restricted));
+        t.rows.push(total("Total Unrestricted", self.total_unrestricted));
+        t.rows.push(total("Total", self.total_closing_balance));
+        t
+    }
+}
+
+fn consolidated_row(line: &ConsolidatedLine, total: bool) -> Row {
+    let mut cells = vec![Cell::text(&line.label)];
+    cells.extend(line.by_parish.iter().map(|a| Cell::Amount(*a)));
+    cells.push(Cell::Amount(line.total));
+    if total { Row::total(cells) } else { Row::detail(cells) }
+}
+
+impl Tabular for ConsolidatedStatement {
+    fn to_table(&self) -> Table {
+        let mut columns = vec!["Line".to_string()];
+        columns.extend(self.parishes.iter().map(|p| p.parish_name.clone()));
+        columns.push("Total".to_string());
+        let mut t = Table { columns, rows: Vec::new() };
+
+        for section in &self.sections {
+            t.rows.push(Row::heading(&section.section_name));
+            t.rows.extend(section.lines.iter().map(|l| consolidated_row(l, false)));
+            t.rows.push(consolidated_row(&section.totals, true));
+        }
+        if let Some(net) = &self.net {
+            t.rows.push(consolidated_row(net, true));
+        }
+        t
+    }
+}
+
+fn comparative_row(e: &ComparativeEntry, total: bool) -> Row {
+    let cells = vec![
+        Cell::text(&e.category), Cell::Amount(e.current), Cell::Amount(e.previous),
+        Cell::Amount(e.change), Cell::Percent(e.change_percent),
+    ];
+    if total { Row::total(cells) } else { Row::detail(cells) }
+}
+
+impl Tabular for ComparativeReport {
+    fn to_table(&self) -> Table {
+        let current = format!("{} - {}", self.start_date.format("%d %b %Y"), self.end_date.format("%d %b %Y"));
+        let previous = format!("{} - {}", self.previous_start_date.format("%d %b %Y"), self.previous_end_date.format("%d %b %Y"));
+        let mut t = Table::new(&["Category", &current, &previous, "Change", "Change %"]);
+        for section in &self.sections {
+            t.rows.push(Row::heading(&section.section_name));
+            t.rows.extend(section.entries.iter().map(|e| comparative_row(e, false)));
+            t.rows.push(comparative_row(&section.totals, true));
+        }
+        if let Some(net) = &self.net {
+            t.rows.push(comparative_row(net, true));
+        }
+        t
+    }
+}
+
+fn monthly_row(line: &MonthlyLine, total: bool) -> Row {
+    let mut cells = vec![Cell::text(&line.category)];
+    cells.extend(line.by_month.iter().map(|a| Cell::Amount(*a)));
+    cells.push(Cell::Amount(line.total));
+    cells.push(Cell::Amount(line.previous_year_total));
+    cells.push(Cell::Percent(line.change_percent));
+    if total { Row::total(cells) } else { Row::detail(cells) }
+}
+
+impl Tabular for MonthlyReport {
+    fn to_table(&self) -> Table {
+        const MONTHS: [&str; 12] = ["Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec"];
+        let mut columns = vec!["Category".to_string()];
+        columns.extend(MONTHS.iter().map(|m| m.to_string()));
+        columns.push(self.fiscal_year.to_string());
+        columns.push((self.fiscal_year - 1).to_string());
+        columns.push("Change %".to_string());
+        let mut t = Table { columns, rows: Vec::new() };
+
+        for section in &self.sections {
+            t.rows.push(Row::heading(&section.section_name));
+            t.rows.extend(section.lines.iter().map(|l| monthly_row(l, false)));
+            t.rows.push(monthly_row(&section.totals, true));
+        }
+        if let Some(net) = &self.net {
+            t.rows.push(monthly_row(net, true));
+        }
+        t
+    }
+}
+
+impl Tabular for CashbookStatement {
+    fn to_table(&self) -> Table {
+        let mut t = Table::new(&["Date", "Type", "Reference", "Description", "Receipts", "Payments", "Balance"]);
+        t.rows.push(Row::total(vec![
+            Cell::text(self.start_date.to_string()), Cell::text("Opening balance"), Cell::Empty, Cell::Empty,
+            Cell::Empty, Cell::Empty, Cell::Amount(self.opening_balance),
+        ]));
+        for l in &self.lines {
+            t.rows.push(Row::detail(vec![
+                Cell::text(l.entry_date.to_string()), Cell::text(&l.entry_type), Cell::opt(l.reference.as_ref()),
+                Cell::opt(l.description.as_ref()), Cell::Amount(l.receipts), Cell::Amount(l.payments), Cell::Amount(l.balance),
+            ]));
+        }
+        t.rows.push(Row::total(vec![
+            Cell::text(self.end_date.to_string()), Cell::text("Closing balance"), Cell::Empty, Cell::Empty,
+            Cell::Amount(self.total_receipts), Cell::Amount(self.total_payments), Cell::Amount(self.closing_balance),
+        ]));
+        t
+    }
+}
+
+impl Tabular for AssetRegister {
+    fn to_table(&self) -> Table {
+        let mut t = Table::new(&[
+            "Code", "Asset", "Category", "Acquired", "Location", "Custodian", "Cost", "Accumulated Depreciation", "Net Book Value",
+        ]);
+        for l in &self.lines {
+            t.rows.push(Row::detail(vec![
+                Cell::text(&l.asset_code), Cell::text(&l.asset_name), Cell::text(enum_label(&l.category)),
+                Cell::text(l.acquisition_date.to_string()), Cell::opt(l.location.as_ref()), Cell::opt(l.custodian_name.as_ref()),
+                Cell::Amount(l.acquisition_cost), Cell::Amount(l.accumulated_depreciation), Cell::Amount(l.net_book_value),
+            ]));
+        }
+        t.rows.push(Row::total(vec![
+            Cell::text("Total"), Cell::Empty, Cell::Empty, Cell::Empty, Cell::Empty, Cell::Empty,
+            Cell::Amount(self.total_cost), Cell::Amount(self.total_accumulated_depreciation), Cell::Amount(self.total_net_book_value),
+        ]));
+        t
+    }
+}
+
+impl Tabular for DepreciationSchedule {
+    fn to_table(&self) -> Table {
+        let mut t = Table::new(&["Year", "Months", "Opening Value", "Depreciation", "Accumulated Depreciation", "Closing Value"]);
+        for y in &self.years {
+            t.rows.push(Row::detail(vec![
+                Cell::text(y.year.to_string()), Cell::Count(y.months.into()), Cell::Amount(y.opening_value),
+                Cell::Amount(y.depreciation), Cell::Amount(y.accumulated_depreciation), Cell::Amount(y.closing_value),
+            ]));
+        }
+        t
+    }
+}
+
+impl Tabular for PayrollRunDetail {
+    fn to_table(&self) -> Table {
+        let mut t = Table::new(&[
+            "Staff No", "Name", "Position", "Basic", "Allowances", "Other Earnings", "Gross Pay",
+            "Statutory Deductions", "Other Deductions", "Net Pay", "Employer Contributions",
+        ]);
+        for p in &self.payslips {
+            t.rows.push(Row::detail(vec![
+                Cell::text(&p.staff_number), Cell::text(&p.full_name), Cell::text(&p.position),
+                Cell::Amount(p.payslip.basic_salary), Cell::Amount(p.payslip.allowances), Cell::Amount(p.payslip.other_earnings),
+                Cell::Amount(p.payslip.gross_pay), Cell::Amount(p.payslip.statutory_deductions), Cell::Amount(p.payslip.other_deductions),
+                Cell::Amount(p.payslip.net_pay), Cell::Amount(p.payslip.employer_contributions),
+            ]));
+        }
+        let sum = |f: fn(&PayslipDetail) -> Decimal| self.payslips.iter().map(f).sum::<Decimal>();
+        t.rows.push(Row::total(vec![
+            Cell::text("Total"), Cell::Empty, Cell::Empty,
+            Cell::Amount(sum(|p| p.payslip.basic_salary)), Cell::Amount(sum(|p| p.payslip.allowances)),
+            Cell::Amount(sum(|p| p.payslip.other_earnings)), Cell::Amount(self.run.total_gross),
+            Cell::Amount(sum(|p| p.payslip.statutory_deductions)), Cell::Amount(sum(|p| p.payslip.other_deductions)),
+            Cell::Amount(self.run.total_net), Cell::Amount(self.run.total_employer_contributions),
+        ]));
+        t
+    }
+}
+
+impl Tabular for PayslipDetail {
+    fn to_table(&self) -> Table {
+        let p = &self.payslip;
+        let line = |label: &str, amount: Decimal| Row::detail(vec![Cell::text(label), Cell::Amount(amount)]);
+        let mut t = Table::new(&["Item", "Amount"]);
+        t.rows.push(Row::detail(vec![Cell::text("Staff No"), Cell::text(&self.staff_number)]));
+        t.rows.push(Row::detail(vec![Cell::text("Name"), Cell::text(&self.full_name)]));
+        t.rows.push(Row::detail(vec![Cell::text("Position"), Cell::text(&self.position)]));
+        t.rows.push(Row::detail(vec![Cell::text("Payment date"), Cell::text(self.payment_date.to_string())]));
+
+        t.rows.push(Row::heading("Earnings"));
+        t.rows.push(line("Basic salary", p.basic_salary));
+        t.rows.push(line("Allowances", p.allowances));
+        if !p.other_earnings.is_zero() {
+            t.rows.push(line("Other earnings", p.other_earnings));
+        }
+        t.rows.push(Row::total(vec![Cell::text("Gross pay"), Cell::Amount(p.gross_pay)]));
+
+        t.rows.push(Row::heading("Deductions"));
+        for d in &self.deductions {
+            t.rows.push(line(&d.deduction_name, d.employee_amount));
+        }
+        if !p.other_deductions.is_zero() {
+            t.rows.push(line("Other deductions", p.other_deductions));
+        }
+        t.rows.push(Row::total(vec![Cell::text("Total deductions"), Cell::Amount(p.statutory_deductions + p.other_deductions)]));
+        t.rows.push(Row::total(vec![Cell::text("Net pay"), Cell::Amount(p.net_pay)]));
+
+        if self.deductions.iter().any(|d| !d.employer_amount.is_zero()) {
+            t.rows.push(Row::heading("Employer contributions"));
+            for d in self.deductions.iter().filter(|d| !d.employer_amount.is_zero()) {
+                t.rows.push(line(&d.deduction_name, d.employer_amount));
+            }
+        }
+        t
+    }
+}
+
+impl Tabular for SupplierStatement {
+    fn to_table(&self) -> Table {
+        let mut t = Table::new(&["Date", "Voucher", "Description", "Status", "Amount", "Paid"]);
+        for l in &self.lines {
+            t.rows.push(Row::detail(vec![
+                Cell::text(l.expense_date.to_string()), Cell::text(&l.voucher_number), Cell::text(&l.description),
+                Cell::text(&l.approval_status), Cell::Amount(l.amount), Cell::text(if l.paid { "Yes" } else { "No" }),
+            ]));
+        }
+        t.rows.push(Row::total(vec![
+            Cell::text("Approved in period"), Cell::Empty, Cell::Empty, Cell::Empty, Cell::Amount(self.total_approved), Cell::Empty,
+        ]));
+        t.rows.push(Row::total(vec![
+            Cell::text("Paid in period"), Cell::Empty, Cell::Empty, Cell::Empty, Cell::Amount(self.total_paid), Cell::Empty,
+        ]));
+        t.rows.push(Row::total(vec![
+            Cell::text("Outstanding"), Cell::Empty, Cell::Empty, Cell::Empty, Cell::Amount(self.outstanding), Cell::Empty,
+        ]));
+        t
+    }
+}
+
+impl Tabular for SpendBySupplierReport {
+    fn to_table(&self) -> Table {
+        let mut t = Table::new(&["Supplier", "Vouchers", "Amount", "Paid", "% of Total"]);
+        for l in &self.lines {
+            let name = if l.supplier_id.is_some() { l.supplier_name.clone() } else { format!("{} (unregistered)", l.supplier_name) };
+            t.rows.push(Row::detail(vec![
+                Cell::text(name), Cell::Count(l.voucher_count), Cell::Amount(l.total_amount),
+                Cell::Amount(l.paid_amount), Cell::Percent(l.share_percent),
+            ]));
+        }
+        t.rows.push(Row::total(vec![
+            Cell::text("Total"), Cell::Count(self.lines.iter().map(|l| l.voucher_count).sum()),
+            Cell::Amount(self.total_amount), Cell::Amount(self.total_paid), Cell::Empty,
+        ]));
+        t
+    }
+}
+
+impl Tabular for RegisterBookAudit {
+    fn to_table(&self) -> Table {
+        let mut t = Table::new(&["Entry", "Page", "Name", "Date"]);
+        t.rows.push(Row::heading("Missing entries"));
+        for g in &self.gaps {
+            let entries = if g.from_entry == g.to_entry { g.from_entry.to_string() } else { format!("{}-{}", g.from_entry, g.to_entry) };
+            t.rows.push(Row::detail(vec![Cell::text(entries), Cell::Empty, Cell::Empty, Cell::Empty]));
+        }
+        for (title, lines) in [("Entry numbers cited more than once", &self.duplicates), ("Page does not match entry number", &self.page_mismatches)] {
+            t.rows.push(Row::heading(title));
+            for l in lines {
+                t.rows.push(Row::detail(vec![
+                    Cell::text(l.register_entry.to_string()), Cell::opt(l.register_page),
+                    Cell::text(&l.member_name), Cell::text(l.sacrament_date.to_string()),
+                ]));
+            }
+        }
+        t.rows.push(Row::total(vec![
+            Cell::text("Records citing no register"), Cell::Empty, Cell::Empty, Cell::Count(self.uncited_records),
+        ]));
+        t
+    }
+}
@@ -0,0 +1,540 @@
+use axum::{
+    extract::{Path, State, Query},
+    http::StatusCode,
+    response::Response,
+    Json,
+};
+use uuid::Uuid;
+use serde::Deserialize;
+use chrono::{Datelike, Months, NaiveDate, Utc};
+use rust_decimal::Decimal;
+use sqlx::postgres::PgPool;
+use std::collections::BTreeMap;
+use crate::{
+    AppState,
+    models::asset::{
+        FixedAsset, FixedAssetWithValue, AssetCategory, DepreciationMethod, AssetDisposalMethod,
+        CreateFixedAssetRequest, UpdateFixedAssetRequest, DisposeAssetRequest,
+        DepreciationYear, DepreciationSchedule, AssetRegister, AssetRegisterLine,
+    },
+    models::transaction::{PaymentMethod, TransactionCategory},
+    handlers::auth::AuthUser,
+    handlers::{rbac, fund, transaction},
+    handlers::transaction::CreateIncomeRequest,
+    export::{self, ExportFormat, ReportHeader},
+};
+
+#[derive(Debug, Deserialize)]
+pub struct AssetQuery {
+    pub parish_id: Option<Uuid>,
+    pub category: Option<AssetCategory>,
+    pub include_disposed: Option<bool>,
+}
+
+pub async fn list_fixed_assets(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Query(query): Query<AssetQuery>,
+) -> Result<Json<Vec<FixedAssetWithValue>>, (StatusCode, String)> {
+    rbac::require_finance(&auth)?;
+    let parish_id = rbac::resolve_parish_id(&auth, query.parish_id)?;
+
+    let assets = sqlx::query_as::<_, FixedAsset>(
+        r#"
+        SELECT * FROM fixed_asset
+        WHERE parish_id = $1 AND deleted_at IS NULL
+          AND ($2::asset_category IS NULL OR category = $2)
+          AND ($3 OR disposal_date IS NULL)
+        ORDER BY category, asset_code
+        "#
+    )
+    .bind(parish_id)
+    .bind(query.category)
+    .bind(query.include_disposed.unwrap_or(false))
+    .fetch_all(&state.db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    let today = Utc::now().date_naive();
+    Ok(Json(assets.into_iter().map(|asset| with_value(asset, today)).collect()))
+}
+
+pub async fn get_fixed_asset(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<FixedAssetWithValue>, (StatusCode, String)> {
+    rbac::require_finance(&auth)?;
+    let asset = fetch_asset(&state.db, id).await?;
+    rbac::resolve_parish_id(&auth, Some(asset.parish_id))?;
+
+    Ok(Json(with_value(asset, Utc::now().date_naive())))
+}
+
+pub async fn create_fixed_asset(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Json(payload): Json<CreateFixedAssetRequest>,
+) -> Result<Json<FixedAsset>, (StatusCode, String)> {
+    rbac::require_finance(&auth)?;
+    let parish_id = rbac::resolve_parish_id(&auth, Some(payload.parish_id))?;
+
+    let residual_value = payload.residual_value.unwrap_or(Decimal::ZERO);
+    validate_depreciation(
+        payload.depreciation_method,
+        payload.useful_life_years,
+        payload.depreciation_rate,
+        payload.acquisition_cost,
+        residual_value,
+    )?;
+    if let Some(fund_id) = payload.fund_id {
+        fund::fund_for_parish(&state.db, fund_id, parish_id).await?;
+    }
+    if let Some(voucher_id) = payload.expense_voucher_id {
+        let voucher_parish: Option<Uuid> = sqlx::query_scalar(
+            "SELECT parish_id FROM expense_voucher WHERE id = $1 AND deleted_at IS NULL"
+        )
+        .bind(voucher_id)
+        .fetch_optional(&state.db)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+        if voucher_parish != Some(parish_id) {
+            return Err((StatusCode::BAD_REQUEST, "Expense voucher not found in this parish".to_string()));
+        }
+    }
+
+    let asset = sqlx::query_as::<_, FixedAsset>(
+        r#"
+        INSERT INTO fixed_asset (
+            parish_id, asset_code, asset_name, category, description, acquisition_date,
+            acquisition_cost, location, custodian_name, fund_id, funding_source,
+            expense_voucher_id, depreciation_method, useful_life_years, depreciation_rate,
+            residual_value, created_by
+        )
+        VALUES (
+            $1, COALESCE($2, 'FA-' || LPAD(nextval('fixed_asset_seq')::TEXT, 6, '0')), $3, $4, $5, $6,
+            $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17
+        )
+        RETURNING *
+        "#
+    )
+    .bind(parish_id)
+    .bind(payload.asset_code)
+    .bind(payload.asset_name)
+    .bind(payload.category)
+    .bind(payload.description)
+    .bind(payload.acquisition_date)
+    .bind(payload.acquisition_cost)
+    .bind(payload.location)
+    .bind(payload.custodian_name)
+    .bind(payload.fund_id)
+    .bind(payload.funding_source)
+    .bind(payload.expense_voucher_id)
+    .bind(payload.depreciation_method)
+    .bind(payload.useful_life_years)
+    .bind(payload.depreciation_rate)
+    .bind(residual_value)
+    .bind(auth.user_id)
+    .fetch_one(&state.db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(asset))
+}
+
+pub async fn update_fixed_asset(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+    Json(payload): Json<UpdateFixedAssetRequest>,
+) -> Result<Json<FixedAsset>, (StatusCode, String)> {
+    rbac::require_finance(&auth)?;
+    let existing = fetch_asset(&state.db, id).await?;
+    rbac::resolve_parish_id(&auth, Some(existing.parish_id))?;
+
+    if existing.disposal_date.is_some() {
+        return Err((StatusCode::BAD_REQUEST, "Disposed assets cannot be changed".to_string()));
+    }
+
+    let method = payload.depreciation_method.unwrap_or(existing.depreciation_method);
+    let useful_life_years = payload.useful_life_years.or(existing.useful_life_years);
+    let depreciation_rate = payload.depreciation_rate.or(existing.depreciation_rate);
+    let acquisition_cost = payload.acquisition_cost.unwrap_or(existing.acquisition_cost);
+    let residual_value = payload.residual_value.unwrap_or(existing.residual_value);
+    validate_depreciation(method, useful_life_years, depreciation_rate, acquisition_cost, residual_value)?;
+
+    if let Some(fund_id) = payload.fund_id {
+        fund::fund_for_parish(&state.db, fund_id, existing.parish_id).await?;
+    }
+
+    let asset = sqlx::query_as::<_, FixedAsset>(
+        r#"
+        UPDATE fixed_asset SET
+            asset_name = $1,
+            category = $2,
+            description = $3,
+            acquisition_date = $4,
+            acquisition_cost = $5,
+            location = $6,
+            custodian_name = $7,
+            fund_id = $8,
+            funding_source = $9,
+            depreciation_method = $10,
+            useful_life_years = $11,
+            depreciation_rate = $12,
+            residual_value = $13,
+            updated_at = NOW()
+        WHERE id = $14
+        RETURNING *
+        "#
+    )
+    .bind(payload.asset_name.unwrap_or(existing.asset_name))
+    .bind(payload.category.unwrap_or(existing.category))
+    .bind(payload.description.or(existing.description))
+    .bind(payload.acquisition_date.unwrap_or(existing.acquisition_date))
+    .bind(acquisition_cost)
+    .bind(payload.location.or(existing.location))
+    .bind(payload.custodian_name.or(existing.custodian_name))
+    .bind(payload.fund_id.or(existing.fund_id))
+    .bind(payload.funding_source.or(existing.funding_source))
+    .bind(method)
+    .bind(useful_life_years)
+    .bind(depreciation_rate)
+    .bind(residual_value)
+    .bind(id)
+    .fetch_one(&state.db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(asset))
+}
+
+pub async fn delete_fixed_asset(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> Result<StatusCode, (StatusCode, String)> {
+    rbac::require_finance(&auth)?;
+    let asset = fetch_asset(&state.db, id).await?;
+    rbac::resolve_parish_id(&auth, Some(asset.parish_id))?;
+
+    sqlx::query("UPDATE fixed_asset SET deleted_at = NOW() WHERE id = $1")
+        .bind(id)
+        .execute(&state.db)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Takes an asset off the register. Sale proceeds are receipted as other
+/// income and the gain or loss against net book value is kept on the asset.
+pub async fn dispose_fixed_asset(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+    Json(payload): Json<DisposeAssetRequest>,
+) -> Result<Json<FixedAssetWithValue>, (StatusCode, String)> {
+    rbac::require_finance(&auth)?;
+    let asset = fetch_asset(&state.db, id).await?;
+    rbac::resolve_parish_id(&auth, Some(asset.parish_id))?;
+
+    if asset.disposal_date.is_some() {
+        return Err((StatusCode::BAD_REQUEST, "Asset has already been disposed of".to_string()));
+    }
+    if payload.disposal_date < asset.acquisition_date {
+        return Err((StatusCode::BAD_REQUEST, "Disposal date is before the acquisition date".to_string()));
+    }
+    let proceeds = payload.proceeds.unwrap_or(Decimal::ZERO);
+    if proceeds < Decimal::ZERO {
+        return Err((StatusCode::BAD_REQUEST, "Proceeds cannot be negative".to_string()));
+    }
+    if proceeds > Decimal::ZERO && payload.disposal_method != AssetDisposalMethod::Sold {
+        return Err((StatusCode::BAD_REQUEST, "Only sold assets have disposal proceeds".to_string()));
+    }
+
+    let (_, net_book_value) = value_at(&asset, payload.disposal_date);
+
+    // Claimed first, so a second disposal of the same asset cannot receipt
+    // the proceeds again.
+    let mut tx = state.db.begin().await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+    let mut disposed = sqlx::query_as::<_, FixedAsset>(
+        r#"
+        UPDATE fixed_asset SET
+            disposal_date = $1,
+            disposal_method = $2,
+            disposal_proceeds = $3,
+            disposal_gain_loss = $4,
+            disposal_notes = $5,
+            updated_at = NOW()
+        WHERE id = $6 AND disposal_date IS NULL AND deleted_at IS NULL
+        RETURNING *
+        "#
+    )
+    .bind(payload.disposal_date)
+    .bind(payload.disposal_method)
+    .bind(proceeds)
+    .bind(proceeds - net_book_value)
+    .bind(payload.notes)
+    .bind(id)
+    .fetch_optional(&mut *tx)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+    .ok_or((StatusCode::CONFLICT, "Asset has already been disposed of".to_string()))?;
+
+    if proceeds > Decimal::ZERO {
+        let income = transaction::record_income(&mut tx, asset.parish_id, CreateIncomeRequest {
+            parish_id: asset.parish_id,
+            member_id: None,
+            family_id: None,
+            fund_id: asset.fund_id,
+            cashbook_id: payload.cashbook_id,
+            category: TransactionCategory::OtherIncome,
+            amount: proceeds,
+            currency_code: None,
+            exchange_rate: None,
+            payment_method: payload.payment_method.unwrap_or(PaymentMethod::Cash),
+            transaction_date: payload.disposal_date,
+            description: Some(format!("Sale of {} {}", asset.asset_code, asset.asset_name)),
+            reference_number: Some(asset.asset_code.clone()),
+            received_by: Some(auth.user_id),
+        }).await?;
+
+        disposed = sqlx::query_as::<_, FixedAsset>(
+            "UPDATE fixed_asset SET disposal_income_id = $1 WHERE id = $2 RETURNING *"
+        )
+        .bind(income.id)
+        .bind(id)
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+    }
+
+    tx.commit().await.map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(with_value(disposed, payload.disposal_date)))
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ScheduleQuery {
+    /// Defaults to the end of the asset's useful life, or today for
+    /// reducing balance assets.
+    pub through: Option<NaiveDate>,
+}
+
+pub async fn get_depreciation_schedule(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+    Query(query): Query<ScheduleQuery>,
+    format: ExportFormat,
+) -> Result<Response, (StatusCode, String)> {
+    rbac::require_finance(&auth)?;
+    let asset = fetch_asset(&state.db, id).await?;
+    rbac::resolve_parish_id(&auth, Some(asset.parish_id))?;
+
+    let through = query.through.unwrap_or_else(|| match (asset.depreciation_method, asset.useful_life_years) {
+        // Adding months clamps 29 Feb to 28 Feb in a non-leap year
+        (DepreciationMethod::StraightLine, Some(years)) => asset.acquisition_date
+            .checked_add_months(Months::new(12 * years.max(0) as u32))
+            .unwrap_or(asset.acquisition_date),
+        _ => Utc::now().date_naive(),
+    });
+
+    let schedule = DepreciationSchedule {
+        asset_id: asset.id,
+        asset_code: asset.asset_code.clone(),
+        asset_name: asset.asset_name.clone(),
+        depreciation_method: asset.depreciation_method,
+        acquisition_cost: asset.acquisition_cost,
+        residual_value: asset.residual_value,
+        years: depreciation_schedule(&asset, through),
+    };
+
+    let header = ReportHeader::parish(
+        asset.parish_id,
+        &format!("Depreciation Schedule - {} {}", asset.asset_code, asset.asset_name),
+        Some(format!("Through {}", through.format("%d %b %Y"))),
+    );
+    export::respond(&state.db, &auth, format, header, schedule).await
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RegisterQuery {
+    pub parish_id: Option<Uuid>,
+    pub as_of: Option<NaiveDate>,
+}
+
+/// The assets held on a date at cost, accumulated depreciation and net
+/// book value.
+pub async fn get_asset_register(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Query(query): Query<RegisterQuery>,
+    format: ExportFormat,
+) -> Result<Response, (StatusCode, String)> {
+    rbac::require_finance(&auth)?;
+    let parish_id = rbac::resolve_parish_id(&auth, query.parish_id)?;
+    let as_of = query.as_of.unwrap_or_else(|| Utc::now().date_naive());
+
+    let lines: Vec<AssetRegisterLine> = assets_held(&state.db, parish_id, as_of)
+        .await?
+        .into_iter()
+        .map(|asset| {
+            let (accumulated_depreciation, net_book_value) = value_at(&asset, as_of);
+            AssetRegisterLine {
+                asset_code: asset.asset_code,
+                asset_name: asset.asset_name,
+                category: asset.category,
+                acquisition_date: asset.acquisition_date,
+                location: asset.location,
+                custodian_name: asset.custodian_name,
+                acquisition_cost: asset.acquisition_cost,
+                accumulated_depreciation,
+                net_book_value,
+            }
+        })
+        .collect();
+
+    let register = AssetRegister {
+        as_of,
+        total_cost: lines.iter().map(|l| l.acquisition_cost).sum(),
+        total_accumulated_depreciation: lines.iter().map(|l| l.accumulated_depreciation).sum(),
+        total_net_book_value: lines.iter().map(|l| l.net_book_value).sum(),
+        lines,
+    };
+
+    let header = ReportHeader::parish(parish_id, "Fixed Asset Register", Some(format!("As at {}", as_of.format("%d %b %Y"))));
+    export::respond(&state.db, &auth, format, header, register).await
+}
+
+// ============================================================================
+// Valuation
+// ============================================================================
+
+/// Net book value of the assets held on `as_of`, per category, for the
+/// balance sheet.
+pub async fn book_values_by_category(
+    db: &PgPool,
+    parish_id: Uuid,
+    as_of: NaiveDate,
+) -> Result<Vec<(AssetCategory, Decimal)>, (StatusCode, String)> {
+    let mut totals: BTreeMap<AssetCategory, Decimal> = BTreeMap::new();
+    for asset in assets_held(db, parish_id, as_of).await? {
+        *totals.entry(asset.category).or_default() += value_at(&asset, as_of).1;
+    }
+    Ok(totals.into_iter().collect())
+}
+
+/// Year-by-year depreciation up to `through` (or the disposal date).
+/// Depreciation runs from the month of acquisition, counting whole months,
+/// and never takes the asset below its residual value.
+pub fn depreciation_schedule(asset: &FixedAsset, through: NaiveDate) -> Vec<DepreciationYear> {
+    let end = asset.disposal_date.map_or(through, |d| d.min(through));
+    if end < asset.acquisition_date {
+        return Vec::new();
+    }
+
+    let depreciable = (asset.acquisition_cost - asset.residual_value).max(Decimal::ZERO);
+    let hundred = Decimal::from(100);
+    let twelve = Decimal::from(12);
+
+    let mut years = Vec::new();
+    let mut value = asset.acquisition_cost;
+    let mut accumulated = Decimal::ZERO;
+    for year in asset.acquisition_date.year()..=end.year() {
+        let first_month = if year == asset.acquisition_date.year() { asset.acquisition_date.month() } else { 1 };
+        let last_month = if year == end.year() { end.month() } else { 12 };
+        let months = last_month - first_month + 1;
+
+        let charge = match (asset.depreciation_method, asset.useful_life_years, asset.depreciation_rate) {
+            (DepreciationMethod::StraightLine, Some(life), _) => {
+                depreciable * Decimal::from(months) / (Decimal::from(life) * twelve)
+            }
+            (DepreciationMethod::ReducingBalance, _, Some(rate)) => {
+                value * rate / hundred * Decimal::from(months) / twelve
+            }
+            _ => Decimal::ZERO,
+        };
+        let charge = charge.round_dp(2).min(value - asset.residual_value).max(Decimal::ZERO);
+
+        let opening_value = value;
+        value -= charge;
+        accumulated += charge;
+        years.push(DepreciationYear {
+            year,
+            months,
+            opening_value,
+            depreciation: charge,
+            accumulated_depreciation: accumulated,
+            closing_value: value,
+        });
+    }
+    years
+}
+
+/// Accumulated depreciation and net book value on `as_of`.
+fn value_at(asset: &FixedAsset, as_of: NaiveDate) -> (Decimal, Decimal) {
+    let accumulated = depreciation_schedule(asset, as_of)
+        .last()
+        .map_or(Decimal::ZERO, |y| y.accumulated_depreciation);
+    (accumulated, asset.acquisition_cost - accumulated)
+}
+
+fn with_value(asset: FixedAsset, as_of: NaiveDate) -> FixedAssetWithValue {
+    let (accumulated_depreciation, net_book_value) = value_at(&asset, as_of);
+    FixedAssetWithValue { asset, accumulated_depreciation, net_book_value }
+}
+
+async fn assets_held(db: &PgPool, parish_id: Uuid, as_of: NaiveDate) -> Result<Vec<FixedAsset>, (StatusCode, String)> {
+    sqlx::query_as::<_, FixedAsset>(
+        r#"
+        SELECT * FROM fixed_asset
+        WHERE parish_id = $1 AND deleted_at IS NULL
+          AND acquisition_date <= $2
+          AND (disposal_date IS NULL OR disposal_date > $2)
+        ORDER BY category, asset_code
+        "#
+    )
+    .bind(parish_id)
+    .bind(as_of)
+    .fetch_all(db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+}
+
+async fn fetch_asset(db: &PgPool, id: Uuid) -> Result<FixedAsset, (StatusCode, String)> {
+    sqlx::query_as::<_, FixedAsset>("SELECT * FROM fixed_asset WHERE id = $1 AND deleted_at IS NULL")
+        .bind(id)
+        .fetch_optional(db)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+        .ok_or((StatusCode::NOT_FOUND, "Fixed asset not found".to_string()))
+}
+
+fn validate_depreciation(
+    method: DepreciationMethod,
+    useful_life_years: Option<i32>,
+    depreciation_rate: Option<Decimal>,
+    acquisition_cost: Decimal,
+    residual_value: Decimal,
+) -> Result<(), (StatusCode, String)> {
+    if acquisition_cost < Decimal::ZERO || residual_value < Decimal::ZERO {
+        return Err((StatusCode::BAD_REQUEST, "Cost and residual value cannot be negative".to_string()));
+    }
+    if residual_value > acquisition_cost {
+        return Err((StatusCode::BAD_REQUEST, "Residual value cannot exceed the acquisition cost".to_string()));
+    }
+    match method {
+        DepreciationMethod::StraightLine if useful_life_years.is_none_or(|y| y <= 0) => {
+            Err((StatusCode::BAD_REQUEST, "Straight-line depreciation needs a useful life in years".to_string()))
+        }
+        DepreciationMethod::ReducingBalance
+            if depreciation_rate.is_none_or(|r| r <= Decimal::ZERO || r > Decimal::from(100)) =>
+        {
+            Err((StatusCode::BAD_REQUEST, "Reducing-balance depreciation needs an annual rate between 0 and 100%".to_string()))
+        }
+        _ => Ok(()),
+    }
+}
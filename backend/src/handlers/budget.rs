@@ -4,13 +4,31 @@ use axum::{
     Json,
 };
 use uuid::Uuid;
-use crate::{AppState, models::budget::{Budget, CreateBudgetRequest, UpdateBudgetRequest}, handlers::auth::AuthUser, handlers::rbac};
+use rust_decimal::Decimal;
+use chrono::{Datelike, Months, NaiveDate, Utc};
+use sqlx::{postgres::PgPool, Acquire, PgConnection, Postgres, Transaction};
+use crate::{
+    AppState,
+    models::budget::{
+        Budget, BudgetStatus, BudgetVersion, BudgetVersionDetail, BudgetRevision,
+        CreateBudgetRequest, UpdateBudgetRequest, CreateBudgetVersionRequest,
+        ReviseBudgetRequest, RejectBudgetRequest, CopyForwardBudgetRequest,
+        BudgetOverrunPolicy, BudgetCheck, ExpenseBudgetCheck, BudgetAlert,
+    },
+    models::transaction::TransactionCategory,
+    handlers::auth::AuthUser,
+    handlers::rbac,
+    export,
+};
 use serde::Deserialize;
 
 #[derive(Debug, Deserialize)]
 pub struct ListBudgetQuery {
     pub parish_id: Uuid,
     pub fiscal_year: Option<i32>,
+    /// Lines of a specific version; defaults to the approved version, or
+    /// the latest draft if the year has not been approved yet.
+    pub version_id: Option<Uuid>,
 }
 
 pub async fn list_budgets(
@@ -23,11 +41,28 @@ pub async fn list_budgets(
 
     let year = query.fiscal_year.unwrap_or(chrono::Utc::now().format("%Y").to_string().parse().unwrap());
 
+    let version_id = match query.version_id {
+        Some(id) => Some(id),
+        None => sqlx::query_scalar::<_, Uuid>(
+            r#"
+            SELECT id FROM budget_version
+            WHERE parish_id = $1 AND fiscal_year = $2 AND deleted_at IS NULL
+            ORDER BY (status = 'APPROVED') DESC, version_number DESC
+            LIMIT 1
+            "#
+        )
+        .bind(parish_id)
+        .bind(year)
+        .fetch_optional(&state.db)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?,
+    };
+
     let budgets = sqlx::query_as::<_, Budget>(
-        "SELECT * FROM budget WHERE parish_id = $1 AND fiscal_year = $2 AND deleted_at IS NULL ORDER BY category"
+        "SELECT * FROM budget WHERE parish_id = $1 AND version_id = $2 AND deleted_at IS NULL ORDER BY category, fiscal_month"
     )
     .bind(parish_id)
-    .bind(year)
+    .bind(version_id)
     .fetch_all(&state.db)
     .await
     .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
@@ -43,26 +78,46 @@ pub async fn create_budget(
     rbac::require_finance(&auth)?;
     let parish_id = rbac::resolve_parish_id(&auth, Some(payload.parish_id))?;
 
+    let mut tx = state.db.begin().await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    let version = match payload.version_id {
+        Some(id) => {
+            let version = lock_version(&mut tx, id).await?;
+            if version.parish_id != parish_id || version.fiscal_year != payload.fiscal_year {
+                return Err((StatusCode::BAD_REQUEST, "Budget version belongs to another parish or fiscal year".to_string()));
+            }
+            version
+        }
+        None => open_draft(&mut tx, parish_id, payload.fiscal_year, auth.user_id).await?,
+    };
+    ensure_editable(&version)?;
+
     let budget = sqlx::query_as::<_, Budget>(
         r#"
         INSERT INTO budget (
-            parish_id, category, amount, fiscal_year, fiscal_month, description, created_by
+            parish_id, version_id, category, amount, fiscal_year, fiscal_month, description, created_by
         )
-        VALUES ($1, $2, $3, $4, $5, $6, $7)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
         RETURNING *
         "#
     )
     .bind(parish_id)
+    .bind(version.id)
     .bind(payload.category)
     .bind(payload.amount)
     .bind(payload.fiscal_year)
     .bind(payload.fiscal_month)
     .bind(payload.description)
     .bind(auth.user_id)
-    .fetch_one(&state.db)
+    .fetch_one(&mut *tx)
     .await
     .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
 
+    record_revision(&mut tx, &budget, None, Some(budget.amount), None, auth.user_id).await?;
+
+    tx.commit().await.map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
     Ok(Json(budget))
 }
 
@@ -82,10 +137,22 @@ pub async fn update_budget(
     .await
     .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
     .ok_or((StatusCode::NOT_FOUND, "Budget not found".to_string()))?;
+    rbac::resolve_parish_id(&auth, Some(budget.parish_id))?;
+
+    let mut tx = state.db.begin().await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
 
+    let version = lock_version(&mut tx, budget.version_id).await?;
+    ensure_editable(&version)?;
+
+    let old_amount = budget.amount;
     if let Some(amt) = payload.amount { budget.amount = amt; }
     if let Some(desc) = payload.description { budget.description = Some(desc); }
 
+    if budget.amount != old_amount {
+        require_reason(&version, payload.reason.as_deref())?;
+    }
+
     let updated = sqlx::query_as::<_, Budget>(
         r#"
         UPDATE budget
@@ -97,9 +164,874 @@ pub async fn update_budget(
     .bind(budget.amount)
     .bind(budget.description)
     .bind(id)
-    .fetch_one(&state.db)
+    .fetch_one(&mut *tx)
     .await
     .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
 
+    if updated.amount != old_amount {
+        record_revision(&mut tx, &updated, Some(old_amount), Some(updated.amount), payload.reason, auth.user_id).await?;
+    }
+
+    tx.commit().await.map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
     Ok(Json(updated))
 }
+
+#[derive(Debug, Deserialize)]
+pub struct DeleteBudgetQuery {
+    pub reason: Option<String>,
+}
+
+pub async fn delete_budget(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+    Query(query): Query<DeleteBudgetQuery>,
+) -> Result<StatusCode, (StatusCode, String)> {
+    rbac::require_finance(&auth)?;
+
+    let budget = sqlx::query_as::<_, Budget>("SELECT * FROM budget WHERE id = $1 AND deleted_at IS NULL")
+        .bind(id)
+        .fetch_optional(&state.db)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+        .ok_or((StatusCode::NOT_FOUND, "Budget not found".to_string()))?;
+    rbac::resolve_parish_id(&auth, Some(budget.parish_id))?;
+
+    let mut tx = state.db.begin().await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    let version = lock_version(&mut tx, budget.version_id).await?;
+    ensure_editable(&version)?;
+    require_reason(&version, query.reason.as_deref())?;
+
+    sqlx::query("UPDATE budget SET deleted_at = NOW() WHERE id = $1")
+        .bind(id)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    record_revision(&mut tx, &budget, Some(budget.amount), None, query.reason, auth.user_id).await?;
+
+    tx.commit().await.map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+// ============================================================================
+// Versions
+// ============================================================================
+
+#[derive(Debug, Deserialize)]
+pub struct ListBudgetVersionQuery {
+    pub parish_id: Uuid,
+    pub fiscal_year: Option<i32>,
+}
+
+pub async fn list_budget_versions(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Query(query): Query<ListBudgetVersionQuery>,
+) -> Result<Json<Vec<BudgetVersion>>, (StatusCode, String)> {
+    rbac::require_finance(&auth)?;
+    let parish_id = rbac::resolve_parish_id(&auth, Some(query.parish_id))?;
+
+    let versions = sqlx::query_as::<_, BudgetVersion>(
+        r#"
+        SELECT * FROM budget_version
+        WHERE parish_id = $1 AND ($2::int IS NULL OR fiscal_year = $2) AND deleted_at IS NULL
+        ORDER BY fiscal_year DESC, version_number DESC
+        "#
+    )
+    .bind(parish_id)
+    .bind(query.fiscal_year)
+    .fetch_all(&state.db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(versions))
+}
+
+pub async fn get_budget_version(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<BudgetVersionDetail>, (StatusCode, String)> {
+    rbac::require_finance(&auth)?;
+    let version = fetch_version(&state.db, id).await?;
+    rbac::resolve_parish_id(&auth, Some(version.parish_id))?;
+
+    Ok(Json(version_detail(&state.db, version).await?))
+}
+
+/// Creates an empty draft for a year that has no open draft.
+pub async fn create_budget_version(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Json(payload): Json<CreateBudgetVersionRequest>,
+) -> Result<Json<BudgetVersion>, (StatusCode, String)> {
+    rbac::require_finance(&auth)?;
+    let parish_id = rbac::resolve_parish_id(&auth, Some(payload.parish_id))?;
+
+    let mut tx = state.db.begin().await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    ensure_no_open_version(&mut tx, parish_id, payload.fiscal_year).await?;
+    let version = insert_version(
+        &mut tx, parish_id, payload.fiscal_year, payload.version_name, None, None, payload.notes, auth.user_id,
+    ).await?;
+
+    tx.commit().await.map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(version))
+}
+
+pub async fn delete_budget_version(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> Result<StatusCode, (StatusCode, String)> {
+    rbac::require_finance(&auth)?;
+    let version = fetch_version(&state.db, id).await?;
+    rbac::resolve_parish_id(&auth, Some(version.parish_id))?;
+
+    if version.status != BudgetStatus::Draft {
+        return Err((StatusCode::BAD_REQUEST, "Only draft budget versions can be deleted".to_string()));
+    }
+
+    let deleted = sqlx::query("UPDATE budget_version SET deleted_at = NOW() WHERE id = $1 AND status = 'DRAFT' AND deleted_at IS NULL")
+        .bind(id)
+        .execute(&state.db)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+    if deleted.rows_affected() == 0 {
+        return Err((StatusCode::CONFLICT, "The budget version changed while it was being deleted".to_string()));
+    }
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Sends a draft to the parish council for approval.
+pub async fn submit_budget_version(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<BudgetVersion>, (StatusCode, String)> {
+    rbac::require_finance(&auth)?;
+    let version = fetch_version(&state.db, id).await?;
+    rbac::resolve_parish_id(&auth, Some(version.parish_id))?;
+
+    if version.status != BudgetStatus::Draft {
+        return Err((StatusCode::BAD_REQUEST, "Only draft budget versions can be submitted".to_string()));
+    }
+
+    let mut tx = state.db.begin().await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    let version = sqlx::query_as::<_, BudgetVersion>(
+        r#"
+        UPDATE budget_version
+        SET status = 'SUBMITTED', submitted_by = $1, submitted_at = NOW(), rejection_reason = NULL
+        WHERE id = $2 AND status = 'DRAFT' AND deleted_at IS NULL
+        RETURNING *
+        "#
+    )
+    .bind(auth.user_id)
+    .bind(id)
+    .fetch_optional(&mut *tx)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+    .ok_or((StatusCode::CONFLICT, "The budget version changed while it was being submitted".to_string()))?;
+
+    let lines: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM budget WHERE version_id = $1 AND deleted_at IS NULL")
+        .bind(id)
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+    if lines == 0 {
+        return Err((StatusCode::BAD_REQUEST, "Cannot submit a budget with no lines".to_string()));
+    }
+
+    tx.commit().await.map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(version))
+}
+
+/// Approves a submitted version. Any previously approved version of the same
+/// year is superseded, so budget-vs-actual switches to the new figures.
+pub async fn approve_budget_version(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<BudgetVersion>, (StatusCode, String)> {
+    rbac::require_permission(&state.db, &auth, "budgets.approve").await?;
+    let version = fetch_version(&state.db, id).await?;
+    rbac::resolve_parish_id(&auth, Some(version.parish_id))?;
+
+    if version.status != BudgetStatus::Submitted {
+        return Err((StatusCode::BAD_REQUEST, "Only submitted budget versions can be approved".to_string()));
+    }
+
+    let mut tx = state.db.begin().await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    sqlx::query(
+        r#"
+        UPDATE budget_version SET status = 'SUPERSEDED'
+        WHERE parish_id = $1 AND fiscal_year = $2 AND status = 'APPROVED' AND deleted_at IS NULL
+        "#
+    )
+    .bind(version.parish_id)
+    .bind(version.fiscal_year)
+    .execute(&mut *tx)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    let version = sqlx::query_as::<_, BudgetVersion>(
+        r#"
+        UPDATE budget_version SET status = 'APPROVED', approved_by = $1, approved_at = NOW()
+        WHERE id = $2 AND status = 'SUBMITTED' AND deleted_at IS NULL
+        RETURNING *
+        "#
+    )
+    .bind(auth.user_id)
+    .bind(id)
+    .fetch_optional(&mut *tx)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+    .ok_or((StatusCode::CONFLICT, "The budget version was decided while it was being approved".to_string()))?;
+
+    tx.commit().await.map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(version))
+}
+
+/// Returns a submitted version to draft with the council's reason.
+pub async fn reject_budget_version(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+    Json(payload): Json<RejectBudgetRequest>,
+) -> Result<Json<BudgetVersion>, (StatusCode, String)> {
+    rbac::require_permission(&state.db, &auth, "budgets.approve").await?;
+    let version = fetch_version(&state.db, id).await?;
+    rbac::resolve_parish_id(&auth, Some(version.parish_id))?;
+
+    if version.status != BudgetStatus::Submitted {
+        return Err((StatusCode::BAD_REQUEST, "Only submitted budget versions can be rejected".to_string()));
+    }
+    if payload.reason.trim().is_empty() {
+        return Err((StatusCode::BAD_REQUEST, "A reason is required to reject a budget".to_string()));
+    }
+
+    let version = sqlx::query_as::<_, BudgetVersion>(
+        r#"
+        UPDATE budget_version
+        SET status = 'DRAFT', rejection_reason = $1, submitted_by = NULL, submitted_at = NULL
+        WHERE id = $2 AND status = 'SUBMITTED' AND deleted_at IS NULL
+        RETURNING *
+        "#
+    )
+    .bind(payload.reason.trim())
+    .bind(id)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+    .ok_or((StatusCode::CONFLICT, "The budget version was decided while it was being rejected".to_string()))?;
+
+    Ok(Json(version))
+}
+
+/// Opens a mid-year revision: a new draft holding a copy of the approved
+/// lines. The approved version stays in force until the revision is approved.
+pub async fn revise_budget_version(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+    Json(payload): Json<ReviseBudgetRequest>,
+) -> Result<Json<BudgetVersionDetail>, (StatusCode, String)> {
+    rbac::require_finance(&auth)?;
+    let source = fetch_version(&state.db, id).await?;
+    rbac::resolve_parish_id(&auth, Some(source.parish_id))?;
+
+    if source.status != BudgetStatus::Approved {
+        return Err((StatusCode::BAD_REQUEST, "Only the approved budget can be revised".to_string()));
+    }
+    if payload.reason.trim().is_empty() {
+        return Err((StatusCode::BAD_REQUEST, "A reason is required to revise an approved budget".to_string()));
+    }
+
+    let mut tx = state.db.begin().await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    ensure_no_open_version(&mut tx, source.parish_id, source.fiscal_year).await?;
+    let version = insert_version(
+        &mut tx,
+        source.parish_id,
+        source.fiscal_year,
+        payload.version_name,
+        Some(source.id),
+        Some(payload.reason.trim().to_string()),
+        None,
+        auth.user_id,
+    ).await?;
+    copy_lines(&mut tx, source.id, &version, Decimal::ONE, auth.user_id).await?;
+
+    tx.commit().await.map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(version_detail(&state.db, version).await?))
+}
+
+/// Drafts a new year's budget from the approved budget of an earlier year,
+/// with every line increased by `increase_percent`.
+pub async fn copy_forward_budget(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Json(payload): Json<CopyForwardBudgetRequest>,
+) -> Result<Json<BudgetVersionDetail>, (StatusCode, String)> {
+    rbac::require_finance(&auth)?;
+    let parish_id = rbac::resolve_parish_id(&auth, Some(payload.parish_id))?;
+
+    let to_year = payload.to_fiscal_year.unwrap_or(payload.from_fiscal_year + 1);
+    let percent = payload.increase_percent.unwrap_or(Decimal::ZERO);
+    if percent <= Decimal::from(-100) {
+        return Err((StatusCode::BAD_REQUEST, "Increase percent must be greater than -100".to_string()));
+    }
+
+    let source = sqlx::query_as::<_, BudgetVersion>(
+        r#"
+        SELECT * FROM budget_version
+        WHERE parish_id = $1 AND fiscal_year = $2 AND status = 'APPROVED' AND deleted_at IS NULL
+        "#
+    )
+    .bind(parish_id)
+    .bind(payload.from_fiscal_year)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+    .ok_or((StatusCode::NOT_FOUND, format!("No approved budget for {}", payload.from_fiscal_year)))?;
+
+    let mut tx = state.db.begin().await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    ensure_no_open_version(&mut tx, parish_id, to_year).await?;
+    let version = insert_version(
+        &mut tx,
+        parish_id,
+        to_year,
+        Some(format!("{} budget", to_year)),
+        Some(source.id),
+        None,
+        Some(format!("Copied from the approved {} budget with a {}% increase", payload.from_fiscal_year, percent.normalize())),
+        auth.user_id,
+    ).await?;
+    let factor = (Decimal::from(100) + percent) / Decimal::from(100);
+    copy_lines(&mut tx, source.id, &version, factor, auth.user_id).await?;
+
+    tx.commit().await.map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(version_detail(&state.db, version).await?))
+}
+
+pub async fn list_budget_revisions(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<Vec<BudgetRevision>>, (StatusCode, String)> {
+    rbac::require_finance(&auth)?;
+    let version = fetch_version(&state.db, id).await?;
+    rbac::resolve_parish_id(&auth, Some(version.parish_id))?;
+
+    let revisions = sqlx::query_as::<_, BudgetRevision>(
+        "SELECT * FROM budget_revision WHERE version_id = $1 ORDER BY revised_at"
+    )
+    .bind(id)
+    .fetch_all(&state.db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(revisions))
+}
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+async fn fetch_version<'e, E>(db: E, id: Uuid) -> Result<BudgetVersion, (StatusCode, String)>
+where
+    E: sqlx::Executor<'e, Database = Postgres>,
+{
+    sqlx::query_as::<_, BudgetVersion>("SELECT * FROM budget_version WHERE id = $1 AND deleted_at IS NULL")
+        .bind(id)
+        .fetch_optional(db)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+        .ok_or((StatusCode::NOT_FOUND, "Budget version not found".to_string()))
+}
+
+/// Loads a version and locks it until the transaction ends, so its status
+/// cannot change while its lines are edited.
+async fn lock_version(tx: &mut Transaction<'_, Postgres>, id: Uuid) -> Result<BudgetVersion, (StatusCode, String)> {
+    sqlx::query_as::<_, BudgetVersion>("SELECT * FROM budget_version WHERE id = $1 AND deleted_at IS NULL FOR UPDATE")
+        .bind(id)
+        .fetch_optional(&mut **tx)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+        .ok_or((StatusCode::NOT_FOUND, "Budget version not found".to_string()))
+}
+
+async fn version_detail(db: &PgPool, version: BudgetVersion) -> Result<BudgetVersionDetail, (StatusCode, String)> {
+    let lines = sqlx::query_as::<_, Budget>(
+        "SELECT * FROM budget WHERE version_id = $1 AND deleted_at IS NULL ORDER BY category, fiscal_month"
+    )
+    .bind(version.id)
+    .fetch_all(db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    let total = lines.iter().map(|l| l.amount).sum();
+    Ok(BudgetVersionDetail { version, total, lines })
+}
+
+fn ensure_editable(version: &BudgetVersion) -> Result<(), (StatusCode, String)> {
+    if version.status != BudgetStatus::Draft {
+        let state = match version.status {
+            BudgetStatus::Submitted => "awaiting approval",
+            BudgetStatus::Approved => "approved",
+            _ => "superseded",
+        };
+        return Err((
+            StatusCode::CONFLICT,
+            format!("Version {} of the {} budget is {}; start a revision to change it", version.version_number, version.fiscal_year, state),
+        ));
+    }
+    Ok(())
+}
+
+/// Changes to a revision of an approved budget must say why.
+fn require_reason(version: &BudgetVersion, reason: Option<&str>) -> Result<(), (StatusCode, String)> {
+    if version.revision_reason.is_some()
+        && reason.is_none_or(|r| r.trim().is_empty())
+    {
+        return Err((StatusCode::BAD_REQUEST, "A reason is required when revising an approved budget".to_string()));
+    }
+    Ok(())
+}
+
+/// The year's open draft, created as the first version when the year has
+/// no budget at all.
+async fn open_draft(
+    tx: &mut Transaction<'_, Postgres>,
+    parish_id: Uuid,
+    fiscal_year: i32,
+    user_id: Uuid,
+) -> Result<BudgetVersion, (StatusCode, String)> {
+    let latest = sqlx::query_as::<_, BudgetVersion>(
+        r#"
+        SELECT * FROM budget_version
+        WHERE parish_id = $1 AND fiscal_year = $2 AND deleted_at IS NULL
+        ORDER BY (status IN ('DRAFT', 'SUBMITTED')) DESC, version_number DESC
+        LIMIT 1
+        FOR UPDATE
+        "#
+    )
+    .bind(parish_id)
+    .bind(fiscal_year)
+    .fetch_optional(&mut **tx)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    match latest {
+        Some(version) => Ok(version),
+        None => insert_version(tx, parish_id, fiscal_year, None, None, None, None, user_id).await,
+    }
+}
+
+async fn ensure_no_open_version(
+    tx: &mut Transaction<'_, Postgres>,
+    parish_id: Uuid,
+    fiscal_year: i32,
+) -> Result<(), (StatusCode, String)> {
+    let open: Option<i32> = sqlx::query_scalar(
+        r#"
+        SELECT version_number FROM budget_version
+        WHERE parish_id = $1 AND fiscal_year = $2 AND status IN ('DRAFT', 'SUBMITTED') AND deleted_at IS NULL
+        LIMIT 1
+        "#
+    )
+    .bind(parish_id)
+    .bind(fiscal_year)
+    .fetch_optional(&mut **tx)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    match open {
+        Some(number) => Err((
+            StatusCode::CONFLICT,
+            format!("Version {} of the {} budget is still open; approve or delete it first", number, fiscal_year),
+        )),
+        None => Ok(()),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn insert_version(
+    tx: &mut Transaction<'_, Postgres>,
+    parish_id: Uuid,
+    fiscal_year: i32,
+    version_name: Option<String>,
+    based_on_version_id: Option<Uuid>,
+    revision_reason: Option<String>,
+    notes: Option<String>,
+    user_id: Uuid,
+) -> Result<BudgetVersion, (StatusCode, String)> {
+    sqlx::query_as::<_, BudgetVersion>(
+        r#"
+        INSERT INTO budget_version (
+            parish_id, fiscal_year, version_number, version_name, based_on_version_id,
+            revision_reason, notes, created_by
+        )
+        VALUES (
+            $1, $2,
+            (SELECT COALESCE(MAX(version_number), 0) + 1 FROM budget_version WHERE parish_id = $1 AND fiscal_year = $2),
+            $3, $4, $5, $6, $7
+        )
+        RETURNING *
+        "#
+    )
+    .bind(parish_id)
+    .bind(fiscal_year)
+    .bind(version_name)
+    .bind(based_on_version_id)
+    .bind(revision_reason)
+    .bind(notes)
+    .bind(user_id)
+    .fetch_one(&mut **tx)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+}
+
+/// Copies the lines of `source_id` into `target`, scaling every amount.
+async fn copy_lines(
+    tx: &mut Transaction<'_, Postgres>,
+    source_id: Uuid,
+    target: &BudgetVersion,
+    factor: Decimal,
+    user_id: Uuid,
+) -> Result<(), (StatusCode, String)> {
+    let lines = sqlx::query_as::<_, Budget>("SELECT * FROM budget WHERE version_id = $1 AND deleted_at IS NULL")
+        .bind(source_id)
+        .fetch_all(&mut **tx)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    for line in lines {
+        sqlx::query(
+            r#"
+            INSERT INTO budget (
+                parish_id, version_id, category, amount, fiscal_year, fiscal_month, description, created_by
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            "#
+        )
+        .bind(target.parish_id)
+        .bind(target.id)
+        .bind(line.category)
+        .bind((line.amount * factor).round_dp(2))
+        .bind(target.fiscal_year)
+        .bind(line.fiscal_month)
+        .bind(line.description)
+        .bind(user_id)
+        .execute(&mut **tx)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+    }
+
+    Ok(())
+}
+
+async fn record_revision(
+    tx: &mut Transaction<'_, Postgres>,
+    budget: &Budget,
+    old_amount: Option<Decimal>,
+    new_amount: Option<Decimal>,
+    reason: Option<String>,
+    user_id: Uuid,
+) -> Result<(), (StatusCode, String)> {
+    sqlx::query(
+        r#"
+        INSERT INTO budget_revision (
+            version_id, budget_id, category, fiscal_month, old_amount, new_amount, reason, revised_by
+        )
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+        "#
+    )
+    .bind(budget.version_id)
+    .bind(budget.id)
+    .bind(budget.category)
+    .bind(budget.fiscal_month)
+    .bind(old_amount)
+    .bind(new_amount)
+    .bind(reason)
+    .bind(user_id)
+    .execute(&mut **tx)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(())
+}
+
+// ============================================================================
+// Spending controls
+// ============================================================================
+
+/// Alerts are raised once per budget line and period at each of these.
+const ALERT_THRESHOLDS: [i32; 2] = [80, 100];
+
+#[derive(Debug, Deserialize)]
+pub struct BudgetCheckQuery {
+    pub parish_id: Uuid,
+    pub category: TransactionCategory,
+    pub amount: Decimal,
+    pub expense_date: Option<NaiveDate>,
+}
+
+/// Previews the budget check a voucher would get, so the form can warn
+/// before it is submitted.
+pub async fn check_expense_budget(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Query(query): Query<BudgetCheckQuery>,
+) -> Result<Json<ExpenseBudgetCheck>, (StatusCode, String)> {
+    rbac::require_finance(&auth)?;
+    let parish_id = rbac::resolve_parish_id(&auth, Some(query.parish_id))?;
+    let date = query.expense_date.unwrap_or_else(|| Utc::now().date_naive());
+
+    let mut conn = state.db.acquire().await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+    Ok(Json(ExpenseBudgetCheck {
+        policy: overrun_policy(&state.db, parish_id).await?,
+        check: check_expense(&mut conn, parish_id, query.category, date, query.amount).await?,
+    }))
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ListBudgetAlertQuery {
+    pub parish_id: Uuid,
+    pub fiscal_year: Option<i32>,
+}
+
+pub async fn list_budget_alerts(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Query(query): Query<ListBudgetAlertQuery>,
+) -> Result<Json<Vec<BudgetAlert>>, (StatusCode, String)> {
+    rbac::require_finance(&auth)?;
+    let parish_id = rbac::resolve_parish_id(&auth, Some(query.parish_id))?;
+
+    let alerts = sqlx::query_as::<_, BudgetAlert>(
+        r#"
+        SELECT * FROM budget_alert
+        WHERE parish_id = $1 AND ($2::int IS NULL OR EXTRACT(YEAR FROM period_start)::int = $2)
+        ORDER BY triggered_at DESC
+        "#
+    )
+    .bind(parish_id)
+    .bind(query.fiscal_year)
+    .fetch_all(&state.db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(alerts))
+}
+
+/// The parish's overrun policy, falling back to the system setting and
+/// then to `Warn`.
+pub async fn overrun_policy<'e, E>(db: E, parish_id: Uuid) -> Result<BudgetOverrunPolicy, (StatusCode, String)>
+where
+    E: sqlx::Executor<'e, Database = Postgres>,
+{
+    let value: Option<String> = sqlx::query_scalar(
+        r#"
+        SELECT setting_value FROM app_setting
+        WHERE setting_key = 'finance.budget_overrun_policy' AND (parish_id = $1 OR parish_id IS NULL)
+        ORDER BY parish_id NULLS LAST
+        LIMIT 1
+        "#
+    )
+    .bind(parish_id)
+    .fetch_optional(db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(match value.as_deref().map(str::trim) {
+        Some("BLOCK") => BudgetOverrunPolicy::Block,
+        Some("REQUIRE_APPROVAL") => BudgetOverrunPolicy::RequireApproval,
+        _ => BudgetOverrunPolicy::Warn,
+    })
+}
+
+/// Checks an expense against the approved budget line for its category.
+/// A monthly line for the expense month takes precedence over the annual
+/// line; vouchers count as committed unless rejected or cancelled, the same
+/// rule the fund guard uses. The budget line stays locked until the caller's
+/// transaction ends, so concurrent vouchers against it are checked in turn.
+pub async fn check_expense(
+    conn: &mut PgConnection,
+    parish_id: Uuid,
+    category: TransactionCategory,
+    date: NaiveDate,
+    amount: Decimal,
+) -> Result<Option<BudgetCheck>, (StatusCode, String)> {
+    let line: Option<(Uuid, Option<i32>, Decimal)> = sqlx::query_as(
+        r#"
+        SELECT b.version_id, b.fiscal_month, b.amount
+        FROM budget b
+        JOIN budget_version v ON v.id = b.version_id
+        WHERE v.parish_id = $1 AND v.fiscal_year = $2 AND v.status = 'APPROVED' AND v.deleted_at IS NULL
+          AND b.category = $3 AND (b.fiscal_month = $4 OR b.fiscal_month IS NULL) AND b.deleted_at IS NULL
+        ORDER BY b.fiscal_month NULLS LAST
+        LIMIT 1
+        FOR UPDATE OF b
+        "#
+    )
+    .bind(parish_id)
+    .bind(date.year())
+    .bind(category)
+    .bind(date.month() as i32)
+    .fetch_optional(&mut *conn)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    let Some((version_id, fiscal_month, budget)) = line else {
+        return Ok(None);
+    };
+
+    let (period_start, period_end) = match fiscal_month {
+        Some(month) => {
+            let start = NaiveDate::from_ymd_opt(date.year(), month as u32, 1).expect("valid month");
+            (start, (start + Months::new(1)).pred_opt().expect("date in range"))
+        }
+        None => (
+            NaiveDate::from_ymd_opt(date.year(), 1, 1).expect("valid year"),
+            NaiveDate::from_ymd_opt(date.year(), 12, 31).expect("valid year"),
+        ),
+    };
+
+    let committed: Decimal = sqlx::query_scalar(
+        r#"
+        SELECT COALESCE(SUM(amount), 0) FROM expense_voucher
+        WHERE parish_id = $1 AND category = $2 AND expense_date BETWEEN $3 AND $4 AND deleted_at IS NULL
+          AND COALESCE(approval_status, 'PENDING') NOT IN ('REJECTED', 'CANCELLED')
+        "#
+    )
+    .bind(parish_id)
+    .bind(category)
+    .bind(period_start)
+    .bind(period_end)
+    .fetch_one(&mut *conn)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    let used = committed + amount;
+    let percent_used = if budget > Decimal::ZERO {
+        (used * Decimal::from(100) / budget).round_dp(1)
+    } else if used > Decimal::ZERO {
+        Decimal::from(100)
+    } else {
+        Decimal::ZERO
+    };
+
+    Ok(Some(BudgetCheck {
+        version_id,
+        category,
+        period_start,
+        period_end,
+        budget,
+        committed,
+        amount,
+        remaining: budget - used,
+        percent_used,
+        exceeds_budget: used > budget,
+    }))
+}
+
+/// Records each threshold the category has now reached and emails the
+/// parish admins through the outbox. Thresholds already alerted for the
+/// same line and period are skipped.
+pub async fn raise_alerts(conn: &mut PgConnection, parish_id: Uuid, check: &BudgetCheck, voucher_id: Uuid) -> Result<(), (StatusCode, String)> {
+    for threshold in ALERT_THRESHOLDS {
+        if check.percent_used < Decimal::from(threshold) {
+            continue;
+        }
+
+        let mut tx = conn.begin().await
+            .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+        let alert_id: Option<Uuid> = sqlx::query_scalar(
+            r#"
+            INSERT INTO budget_alert (
+                parish_id, version_id, category, period_start, period_end, threshold_percent,
+                budget_amount, committed_amount, voucher_id
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+            ON CONFLICT (version_id, category, period_start, threshold_percent) DO NOTHING
+            RETURNING id
+            "#
+        )
+        .bind(parish_id)
+        .bind(check.version_id)
+        .bind(check.category)
+        .bind(check.period_start)
+        .bind(check.period_end)
+        .bind(threshold)
+        .bind(check.budget)
+        .bind(check.committed + check.amount)
+        .bind(voucher_id)
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+        let Some(alert_id) = alert_id else { continue };
+
+        let category = export::enum_label(&check.category).replace('_', " ");
+        let subject = format!("Budget alert: {} has reached {}% of budget", category, threshold);
+        let body = format!(
+            "{} spending for {} to {} now stands at {} against a budget of {} ({}%).\n\nThis message was generated automatically.",
+            category,
+            check.period_start.format("%d %b %Y"),
+            check.period_end.format("%d %b %Y"),
+            check.committed + check.amount,
+            check.budget,
+            check.percent_used,
+        );
+
+        let queued = sqlx::query(
+            r#"
+            INSERT INTO email_log (recipient_email, subject, body_text, email_type, user_id, status)
+            SELECT email, $1, $2, 'BUDGET_ALERT', id, 'PENDING'
+            FROM app_user
+            WHERE parish_id = $3 AND role = 'PARISH_ADMIN' AND email IS NOT NULL
+              AND COALESCE(is_active, TRUE) AND deleted_at IS NULL
+            "#
+        )
+        .bind(&subject)
+        .bind(&body)
+        .bind(parish_id)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+        .rows_affected();
+
+        sqlx::query("UPDATE budget_alert SET emails_queued = $1 WHERE id = $2")
+            .bind(queued as i32)
+            .bind(alert_id)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+        tx.commit().await.map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,401 @@
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use sqlx::PgConnection;
+use uuid::Uuid;
+use chrono::{Datelike, NaiveDate, Utc};
+use serde::Deserialize;
+use crate::{AppState, handlers::auth::AuthUser, handlers::rbac};
+use crate::export::{self, Cell, ExportFormat, ReportHeader, Row, Table};
+use crate::models::burial::{
+    BurialRecord, CreateBurialRequest, FamilyHeadVacancy, HeadCandidate, SavedBurial, UpdateBurialRequest,
+};
+use crate::models::member::Member;
+use crate::models::transaction::TransactionCategory;
+
+const BURIAL_WITH_MEMBER: &str = r#"
+    SELECT b.*, m.member_code, CONCAT_WS(' ', m.first_name, m.middle_name, m.last_name) AS member_name,
+           EXTRACT(YEAR FROM AGE(b.date_of_death, m.date_of_birth))::int AS age_at_death,
+           (SELECT MAX(s.sacrament_date) FROM sacrament_record s
+            WHERE s.member_id = b.member_id AND s.sacrament_type = 'ANOINTING_OF_SICK' AND s.deleted_at IS NULL) AS last_anointed_on
+    FROM burial_record b
+    JOIN member m ON m.id = b.member_id
+"#;
+
+#[derive(Debug, Deserialize)]
+pub struct BurialQuery {
+    pub parish_id: Option<Uuid>,
+    /// Year of death.
+    pub year: Option<i32>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct HeadVacancyQuery {
+    pub parish_id: Option<Uuid>,
+}
+
+pub async fn list_burials(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Query(query): Query<BurialQuery>,
+    format: ExportFormat,
+) -> Result<Response, (StatusCode, String)> {
+    let parish_id = rbac::resolve_parish_id(&auth, query.parish_id)?;
+
+    let burials = sqlx::query_as::<_, BurialRecord>(&format!(
+        "{} WHERE b.parish_id = $1 AND b.deleted_at IS NULL AND ($2::int IS NULL OR b.entry_year = $2) ORDER BY b.entry_year, b.entry_number",
+        BURIAL_WITH_MEMBER
+    ))
+    .bind(parish_id)
+    .bind(query.year)
+    .fetch_all(&state.db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    if format == ExportFormat::Json {
+        return Ok(Json(burials).into_response());
+    }
+
+    let mut table = Table::new(&[
+        "Entry", "Member Code", "Name", "Age", "Date of Death", "Cause of Death", "Burial Date",
+        "Cemetery", "Plot", "Officiant",
+    ]);
+    for b in &burials {
+        table.rows.push(Row::detail(vec![
+            Cell::text(format!("{}/{}", b.entry_year, b.entry_number)),
+            Cell::opt(b.member_code.as_ref()),
+            Cell::opt(b.member_name.as_ref()),
+            b.age_at_death.map(|a| Cell::Count(a as i64)).unwrap_or(Cell::Empty),
+            Cell::opt(Some(b.date_of_death)),
+            Cell::opt(b.cause_of_death.as_ref()),
+            Cell::opt(b.burial_date),
+            Cell::opt(b.cemetery_name.as_ref().or(b.burial_place.as_ref())),
+            Cell::opt(b.plot_reference.as_ref()),
+            Cell::opt(b.officiant.as_ref()),
+        ]));
+    }
+    table.rows.push(Row::total(vec![Cell::text("Total Burials"), Cell::Count(burials.len() as i64)]));
+
+    let period = query.year.map(|y| y.to_string());
+    let header = ReportHeader::parish(parish_id, "Burial Register", period);
+    export::respond_table(&state.db, &auth, format, header, table).await
+}
+
+pub async fn get_burial(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<BurialRecord>, (StatusCode, String)> {
+    let burial = fetch_burial(&mut *acquire(&state).await?, id).await?;
+    rbac::resolve_parish_id(&auth, Some(burial.parish_id))?;
+    Ok(Json(burial))
+}
+
+/// Records a death. The member is marked deceased and inactive, and where
+/// they headed a family the response says which family needs a new head.
+pub async fn create_burial(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Json(payload): Json<CreateBurialRequest>,
+) -> Result<Json<SavedBurial>, (StatusCode, String)> {
+    rbac::require_write(&auth)?;
+    let member = sqlx::query_as::<_, Member>("SELECT * FROM member WHERE id = $1 AND deleted_at IS NULL")
+        .bind(payload.member_id)
+        .fetch_optional(&state.db)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+        .ok_or((StatusCode::NOT_FOUND, "Member not found".to_string()))?;
+    let parish_id = rbac::resolve_parish_id(&auth, Some(member.parish_id))?;
+    if let Some(died) = member.date_of_death {
+        return Err((StatusCode::CONFLICT, format!("The member is already recorded as deceased on {}", died)));
+    }
+    validate_dates(member.date_of_birth, payload.date_of_death, payload.burial_date)?;
+
+    let mut tx = state.db.begin().await.map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+    check_funeral_fee(&mut tx, parish_id, payload.income_transaction_id).await?;
+
+    // Entries are numbered per year of death; lock the parish so two
+    // deaths recorded at once do not take the same number
+    sqlx::query("SELECT id FROM parish WHERE id = $1 FOR UPDATE")
+        .bind(parish_id)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+    let entry_year = payload.date_of_death.year();
+    let entry_number: i32 = sqlx::query_scalar(
+        "SELECT COALESCE(MAX(entry_number), 0) + 1 FROM burial_record WHERE parish_id = $1 AND entry_year = $2 AND deleted_at IS NULL"
+    )
+    .bind(parish_id)
+    .bind(entry_year)
+    .fetch_one(&mut *tx)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    let id: Uuid = sqlx::query_scalar(
+        r#"
+        INSERT INTO burial_record (
+            parish_id, member_id, entry_year, entry_number, date_of_death, place_of_death, cause_of_death,
+            burial_date, burial_place, cemetery_name, plot_reference, officiant, income_transaction_id, notes, recorded_by,
+            member_was_active
+        )
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16)
+        RETURNING id
+        "#
+    )
+    .bind(parish_id)
+    .bind(member.id)
+    .bind(entry_year)
+    .bind(entry_number)
+    .bind(payload.date_of_death)
+    .bind(payload.place_of_death)
+    .bind(payload.cause_of_death)
+    .bind(payload.burial_date)
+    .bind(payload.burial_place)
+    .bind(payload.cemetery_name)
+    .bind(payload.plot_reference)
+    .bind(payload.officiant)
+    .bind(payload.income_transaction_id)
+    .bind(payload.notes)
+    .bind(auth.user_id)
+    .bind(member.is_active.unwrap_or(true))
+    .fetch_one(&mut *tx)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    sqlx::query("UPDATE member SET date_of_death = $1, is_active = FALSE, updated_at = NOW() WHERE id = $2")
+        .bind(payload.date_of_death)
+        .bind(member.id)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    let head_vacancy = match member.family_id {
+        Some(family_id) => vacancies(&mut tx, parish_id, Some(family_id)).await?.pop(),
+        None => None,
+    };
+    let record = fetch_burial(&mut tx, id).await?;
+
+    tx.commit().await.map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(SavedBurial { record, head_vacancy }))
+}
+
+pub async fn update_burial(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+    Json(payload): Json<UpdateBurialRequest>,
+) -> Result<Json<BurialRecord>, (StatusCode, String)> {
+    rbac::require_write(&auth)?;
+    let mut tx = state.db.begin().await.map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+    let mut burial = fetch_burial(&mut tx, id).await?;
+    rbac::resolve_parish_id(&auth, Some(burial.parish_id))?;
+
+    if let Some(val) = payload.date_of_death { burial.date_of_death = val; }
+    if let Some(val) = payload.place_of_death { burial.place_of_death = Some(val); }
+    if let Some(val) = payload.cause_of_death { burial.cause_of_death = Some(val); }
+    if let Some(val) = payload.burial_date { burial.burial_date = Some(val); }
+    if let Some(val) = payload.burial_place { burial.burial_place = Some(val); }
+    if let Some(val) = payload.cemetery_name { burial.cemetery_name = Some(val); }
+    if let Some(val) = payload.plot_reference { burial.plot_reference = Some(val); }
+    if let Some(val) = payload.officiant { burial.officiant = Some(val); }
+    if let Some(val) = payload.income_transaction_id { burial.income_transaction_id = Some(val); }
+    if let Some(val) = payload.notes { burial.notes = Some(val); }
+
+    let date_of_birth: Option<NaiveDate> = sqlx::query_scalar("SELECT date_of_birth FROM member WHERE id = $1")
+        .bind(burial.member_id)
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+    validate_dates(date_of_birth, burial.date_of_death, burial.burial_date)?;
+    if payload.income_transaction_id.is_some() {
+        check_funeral_fee(&mut tx, burial.parish_id, burial.income_transaction_id).await?;
+    }
+
+    // The entry keeps its number even if the date of death is corrected
+    sqlx::query(
+        r#"
+        UPDATE burial_record SET
+            date_of_death = $1, place_of_death = $2, cause_of_death = $3, burial_date = $4, burial_place = $5,
+            cemetery_name = $6, plot_reference = $7, officiant = $8, income_transaction_id = $9, notes = $10
+        WHERE id = $11
+        "#
+    )
+    .bind(burial.date_of_death)
+    .bind(&burial.place_of_death)
+    .bind(&burial.cause_of_death)
+    .bind(burial.burial_date)
+    .bind(&burial.burial_place)
+    .bind(&burial.cemetery_name)
+    .bind(&burial.plot_reference)
+    .bind(&burial.officiant)
+    .bind(burial.income_transaction_id)
+    .bind(&burial.notes)
+    .bind(id)
+    .execute(&mut *tx)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    if payload.date_of_death.is_some() {
+        sqlx::query("UPDATE member SET date_of_death = $1, updated_at = NOW() WHERE id = $2")
+            .bind(burial.date_of_death)
+            .bind(burial.member_id)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+    }
+
+    let burial = fetch_burial(&mut tx, id).await?;
+    tx.commit().await.map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(burial))
+}
+
+/// Removes a death entered in error; the member goes back to being active
+/// or inactive as before.
+pub async fn delete_burial(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> Result<StatusCode, (StatusCode, String)> {
+    rbac::require_write(&auth)?;
+    let mut tx = state.db.begin().await.map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+    let burial = fetch_burial(&mut tx, id).await?;
+    rbac::resolve_parish_id(&auth, Some(burial.parish_id))?;
+
+    sqlx::query("UPDATE burial_record SET deleted_at = NOW() WHERE id = $1")
+        .bind(id)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    sqlx::query("UPDATE member SET date_of_death = NULL, is_active = $1, updated_at = NOW() WHERE id = $2")
+        .bind(burial.member_was_active.unwrap_or(true))
+        .bind(burial.member_id)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    tx.commit().await.map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Families whose head has died and who have no living head yet.
+pub async fn list_head_vacancies(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Query(query): Query<HeadVacancyQuery>,
+) -> Result<Json<Vec<FamilyHeadVacancy>>, (StatusCode, String)> {
+    let parish_id = rbac::resolve_parish_id(&auth, query.parish_id)?;
+    Ok(Json(vacancies(&mut *acquire(&state).await?, parish_id, None).await?))
+}
+
+async fn acquire(state: &AppState) -> Result<sqlx::pool::PoolConnection<sqlx::Postgres>, (StatusCode, String)> {
+    state.db.acquire().await.map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+}
+
+async fn fetch_burial(conn: &mut PgConnection, id: Uuid) -> Result<BurialRecord, (StatusCode, String)> {
+    sqlx::query_as::<_, BurialRecord>(&format!("{} WHERE b.id = $1 AND b.deleted_at IS NULL", BURIAL_WITH_MEMBER))
+        .bind(id)
+        .fetch_optional(conn)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+        .ok_or((StatusCode::NOT_FOUND, "Burial record not found".to_string()))
+}
+
+fn validate_dates(
+    date_of_birth: Option<NaiveDate>,
+    date_of_death: NaiveDate,
+    burial_date: Option<NaiveDate>,
+) -> Result<(), (StatusCode, String)> {
+    if date_of_death > Utc::now().date_naive() {
+        return Err((StatusCode::BAD_REQUEST, "The date of death cannot be in the future".to_string()));
+    }
+    if date_of_birth.is_some_and(|dob| date_of_death < dob) {
+        return Err((StatusCode::BAD_REQUEST, "The date of death is before the date of birth".to_string()));
+    }
+    if burial_date.is_some_and(|b| b < date_of_death) {
+        return Err((StatusCode::BAD_REQUEST, "The burial date is before the date of death".to_string()));
+    }
+    Ok(())
+}
+
+/// A linked receipt must be a funeral fee taken by the same parish.
+async fn check_funeral_fee(
+    conn: &mut PgConnection,
+    parish_id: Uuid,
+    income_transaction_id: Option<Uuid>,
+) -> Result<(), (StatusCode, String)> {
+    let Some(transaction_id) = income_transaction_id else { return Ok(()) };
+    let category: TransactionCategory = sqlx::query_scalar(
+        "SELECT category FROM income_transaction WHERE id = $1 AND parish_id = $2 AND deleted_at IS NULL"
+    )
+    .bind(transaction_id)
+    .bind(parish_id)
+    .fetch_optional(conn)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+    .ok_or((StatusCode::NOT_FOUND, "Income transaction not found".to_string()))?;
+
+    if category != TransactionCategory::FuneralFee {
+        return Err((StatusCode::BAD_REQUEST, "The linked income transaction is not a funeral fee".to_string()));
+    }
+    Ok(())
+}
+
+/// Families with no living head where the named head, or a member with
+/// the HEAD role, has died. Candidates are the living members, spouse
+/// first, then the eldest.
+async fn vacancies(
+    conn: &mut PgConnection,
+    parish_id: Uuid,
+    family_id: Option<Uuid>,
+) -> Result<Vec<FamilyHeadVacancy>, (StatusCode, String)> {
+    let families = sqlx::query_as::<_, (Uuid, String, String, Option<Uuid>)>(
+        r#"
+        SELECT f.id, f.family_code, f.family_name, f.head_of_family_id
+        FROM family f
+        WHERE f.parish_id = $1 AND f.deleted_at IS NULL AND ($2::uuid IS NULL OR f.id = $2)
+          AND NOT EXISTS (
+              SELECT 1 FROM member m
+              WHERE m.deleted_at IS NULL AND m.date_of_death IS NULL
+                AND (m.id = f.head_of_family_id OR (m.family_id = f.id AND m.family_role = 'HEAD'))
+          )
+          AND EXISTS (
+              SELECT 1 FROM member m
+              WHERE m.deleted_at IS NULL AND m.date_of_death IS NOT NULL
+                AND (m.id = f.head_of_family_id OR (m.family_id = f.id AND m.family_role = 'HEAD'))
+          )
+        ORDER BY f.family_name
+        "#
+    )
+    .bind(parish_id)
+    .bind(family_id)
+    .fetch_all(&mut *conn)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    let mut vacancies = Vec::with_capacity(families.len());
+    for (family_id, family_code, family_name, head_of_family_id) in families {
+        let candidates = sqlx::query_as::<_, HeadCandidate>(
+            r#"
+            SELECT id AS member_id, CONCAT_WS(' ', first_name, middle_name, last_name) AS member_name,
+                   family_role, date_of_birth
+            FROM member
+            WHERE family_id = $1 AND deleted_at IS NULL AND date_of_death IS NULL
+            ORDER BY family_role = 'SPOUSE' DESC, date_of_birth NULLS LAST, last_name, first_name
+            "#
+        )
+        .bind(family_id)
+        .fetch_all(&mut *conn)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+        vacancies.push(FamilyHeadVacancy { family_id, family_code, family_name, head_of_family_id, candidates });
+    }
+    Ok(vacancies)
+}
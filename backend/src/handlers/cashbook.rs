@@ -0,0 +1,645 @@
+use axum::{
+    extract::{Path, State, Query},
+    http::StatusCode,
+    response::Response,
+    Json,
+};
+use uuid::Uuid;
+use serde::Deserialize;
+use chrono::{NaiveDate, Utc};
+use rust_decimal::Decimal;
+use sqlx::{postgres::PgPool, Postgres};
+use std::collections::BTreeMap;
+use crate::{
+    AppState,
+    models::cashbook::{
+        Cashbook, CashbookType, CashbookTransferType, CashbookWithBalance, CashbookTransfer,
+        CashCount, CashCountLine, CashCountDetail, CashbookStatement, CashbookStatementLine,
+        CreateCashbookRequest, UpdateCashbookRequest, CreateTransferRequest, ImprestTopupRequest,
+        RecordCashCountRequest,
+    },
+    handlers::auth::AuthUser,
+    handlers::rbac,
+    export::{self, ExportFormat, ReportHeader},
+};
+
+#[derive(Debug, Deserialize)]
+pub struct CashbookQuery {
+    pub parish_id: Option<Uuid>,
+}
+
+pub async fn list_cashbooks(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Query(query): Query<CashbookQuery>,
+) -> Result<Json<Vec<CashbookWithBalance>>, (StatusCode, String)> {
+    rbac::require_finance(&auth)?;
+    let parish_id = rbac::resolve_parish_id(&auth, query.parish_id)?;
+
+    let cashbooks = sqlx::query_as::<_, Cashbook>(
+        "SELECT * FROM cashbook WHERE parish_id = $1 AND deleted_at IS NULL ORDER BY cashbook_type, cashbook_name"
+    )
+    .bind(parish_id)
+    .fetch_all(&state.db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    let today = Utc::now().date_naive();
+    let mut result = Vec::with_capacity(cashbooks.len());
+    for cashbook in cashbooks {
+        let balance = balance_as_of(&state.db, &cashbook, today).await?;
+        result.push(CashbookWithBalance { cashbook, balance });
+    }
+
+    Ok(Json(result))
+}
+
+pub async fn get_cashbook(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<CashbookWithBalance>, (StatusCode, String)> {
+    rbac::require_finance(&auth)?;
+    let cashbook = fetch_cashbook(&state.db, id).await?;
+    rbac::resolve_parish_id(&auth, Some(cashbook.parish_id))?;
+
+    let balance = balance_as_of(&state.db, &cashbook, Utc::now().date_naive()).await?;
+    Ok(Json(CashbookWithBalance { cashbook, balance }))
+}
+
+pub async fn create_cashbook(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Json(payload): Json<CreateCashbookRequest>,
+) -> Result<Json<Cashbook>, (StatusCode, String)> {
+    rbac::require_finance(&auth)?;
+    let parish_id = rbac::resolve_parish_id(&auth, Some(payload.parish_id))?;
+
+    if payload.imprest_amount.is_some() && payload.cashbook_type != CashbookType::PettyCash {
+        return Err((StatusCode::BAD_REQUEST, "Only petty cash books have an imprest amount".to_string()));
+    }
+
+    let cashbook = sqlx::query_as::<_, Cashbook>(
+        r#"
+        INSERT INTO cashbook (
+            parish_id, cashbook_name, cashbook_type, provider, account_number,
+            imprest_amount, opening_balance, opening_date, custodian_id, created_by
+        )
+        VALUES ($1, $2, $3, $4, $5, $6, $7, COALESCE($8, CURRENT_DATE), $9, $10)
+        RETURNING *
+        "#
+    )
+    .bind(parish_id)
+    .bind(payload.cashbook_name)
+    .bind(payload.cashbook_type)
+    .bind(payload.provider)
+    .bind(payload.account_number)
+    .bind(payload.imprest_amount)
+    .bind(payload.opening_balance.unwrap_or(Decimal::ZERO))
+    .bind(payload.opening_date)
+    .bind(payload.custodian_id)
+    .bind(auth.user_id)
+    .fetch_one(&state.db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(cashbook))
+}
+
+pub async fn update_cashbook(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+    Json(payload): Json<UpdateCashbookRequest>,
+) -> Result<Json<Cashbook>, (StatusCode, String)> {
+    rbac::require_finance(&auth)?;
+    let existing = fetch_cashbook(&state.db, id).await?;
+    rbac::resolve_parish_id(&auth, Some(existing.parish_id))?;
+
+    if payload.imprest_amount.is_some() && existing.cashbook_type != CashbookType::PettyCash {
+        return Err((StatusCode::BAD_REQUEST, "Only petty cash books have an imprest amount".to_string()));
+    }
+
+    let cashbook = sqlx::query_as::<_, Cashbook>(
+        r#"
+        UPDATE cashbook SET
+            cashbook_name = $1,
+            provider = $2,
+            account_number = $3,
+            imprest_amount = $4,
+            custodian_id = $5,
+            is_active = $6,
+            updated_at = NOW()
+        WHERE id = $7
+        RETURNING *
+        "#
+    )
+    .bind(payload.cashbook_name.unwrap_or(existing.cashbook_name))
+    .bind(payload.provider.or(existing.provider))
+    .bind(payload.account_number.or(existing.account_number))
+    .bind(payload.imprest_amount.or(existing.imprest_amount))
+    .bind(payload.custodian_id.or(existing.custodian_id))
+    .bind(payload.is_active.unwrap_or(existing.is_active))
+    .bind(id)
+    .fetch_one(&state.db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(cashbook))
+}
+
+pub async fn delete_cashbook(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> Result<StatusCode, (StatusCode, String)> {
+    rbac::require_finance(&auth)?;
+    let cashbook = fetch_cashbook(&state.db, id).await?;
+    rbac::resolve_parish_id(&auth, Some(cashbook.parish_id))?;
+
+    let balance = balance_as_of(&state.db, &cashbook, Utc::now().date_naive()).await?;
+    if !balance.is_zero() {
+        return Err((StatusCode::BAD_REQUEST, format!("Cashbook still holds {}; transfer it out first", balance)));
+    }
+
+    sqlx::query("UPDATE cashbook SET deleted_at = NOW(), is_active = FALSE WHERE id = $1")
+        .bind(id)
+        .execute(&state.db)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+// ============================================================================
+// Transfers
+// ============================================================================
+
+#[derive(Debug, Deserialize)]
+pub struct TransferQuery {
+    pub parish_id: Option<Uuid>,
+    pub cashbook_id: Option<Uuid>,
+}
+
+pub async fn list_transfers(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Query(query): Query<TransferQuery>,
+) -> Result<Json<Vec<CashbookTransfer>>, (StatusCode, String)> {
+    rbac::require_finance(&auth)?;
+    let parish_id = rbac::resolve_parish_id(&auth, query.parish_id)?;
+
+    let transfers = sqlx::query_as::<_, CashbookTransfer>(
+        r#"
+        SELECT * FROM cashbook_transfer
+        WHERE parish_id = $1 AND deleted_at IS NULL
+          AND ($2::uuid IS NULL OR from_cashbook_id = $2 OR to_cashbook_id = $2)
+        ORDER BY transfer_date DESC, created_at DESC
+        "#
+    )
+    .bind(parish_id)
+    .bind(query.cashbook_id)
+    .fetch_all(&state.db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(transfers))
+}
+
+pub async fn create_transfer(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Json(payload): Json<CreateTransferRequest>,
+) -> Result<Json<CashbookTransfer>, (StatusCode, String)> {
+    rbac::require_finance(&auth)?;
+    let from = fetch_cashbook(&state.db, payload.from_cashbook_id).await?;
+    rbac::resolve_parish_id(&auth, Some(from.parish_id))?;
+    let to = cashbook_for_parish(&state.db, payload.to_cashbook_id, from.parish_id).await?;
+
+    let transfer = insert_transfer(
+        &state.db,
+        &from,
+        &to,
+        CashbookTransferType::Transfer,
+        payload.amount,
+        payload.transfer_date.unwrap_or_else(|| Utc::now().date_naive()),
+        payload.reference_number,
+        payload.description,
+        auth.user_id,
+    ).await?;
+
+    Ok(Json(transfer))
+}
+
+pub async fn delete_transfer(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> Result<StatusCode, (StatusCode, String)> {
+    rbac::require_finance(&auth)?;
+
+    let parish_id: Uuid = sqlx::query_scalar("SELECT parish_id FROM cashbook_transfer WHERE id = $1 AND deleted_at IS NULL")
+        .bind(id)
+        .fetch_optional(&state.db)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+        .ok_or((StatusCode::NOT_FOUND, "Transfer not found".to_string()))?;
+    rbac::resolve_parish_id(&auth, Some(parish_id))?;
+
+    sqlx::query("UPDATE cashbook_transfer SET deleted_at = NOW() WHERE id = $1")
+        .bind(id)
+        .execute(&state.db)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Tops a petty cash float back up from another cashbook.
+pub async fn imprest_topup(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+    Json(payload): Json<ImprestTopupRequest>,
+) -> Result<Json<CashbookTransfer>, (StatusCode, String)> {
+    rbac::require_finance(&auth)?;
+    let petty = fetch_cashbook(&state.db, id).await?;
+    rbac::resolve_parish_id(&auth, Some(petty.parish_id))?;
+    let from = cashbook_for_parish(&state.db, payload.from_cashbook_id, petty.parish_id).await?;
+
+    if petty.cashbook_type != CashbookType::PettyCash {
+        return Err((StatusCode::BAD_REQUEST, "Imprest top-ups are only for petty cash books".to_string()));
+    }
+
+    let transfer_date = payload.transfer_date.unwrap_or_else(|| Utc::now().date_naive());
+    let amount = match payload.amount {
+        Some(amount) => amount,
+        None => {
+            let imprest = petty.imprest_amount
+                .ok_or((StatusCode::BAD_REQUEST, "Cashbook has no imprest amount; give the top-up amount".to_string()))?;
+            let balance = balance_as_of(&state.db, &petty, transfer_date).await?;
+            if balance >= imprest {
+                return Err((StatusCode::BAD_REQUEST, "Float is already at its imprest level".to_string()));
+            }
+            imprest - balance
+        }
+    };
+
+    let transfer = insert_transfer(
+        &state.db,
+        &from,
+        &petty,
+        CashbookTransferType::ImprestTopup,
+        amount,
+        transfer_date,
+        payload.reference_number,
+        Some(format!("Imprest top-up of {}", petty.cashbook_name)),
+        auth.user_id,
+    ).await?;
+
+    Ok(Json(transfer))
+}
+
+// ============================================================================
+// Cash counts
+// ============================================================================
+
+pub async fn list_cash_counts(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<Vec<CashCount>>, (StatusCode, String)> {
+    rbac::require_finance(&auth)?;
+    let cashbook = fetch_cashbook(&state.db, id).await?;
+    rbac::resolve_parish_id(&auth, Some(cashbook.parish_id))?;
+
+    let counts = sqlx::query_as::<_, CashCount>(
+        "SELECT * FROM cash_count WHERE cashbook_id = $1 ORDER BY count_date DESC"
+    )
+    .bind(id)
+    .fetch_all(&state.db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(counts))
+}
+
+pub async fn get_cash_count(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<CashCountDetail>, (StatusCode, String)> {
+    rbac::require_finance(&auth)?;
+
+    let count = sqlx::query_as::<_, CashCount>("SELECT * FROM cash_count WHERE id = $1")
+        .bind(id)
+        .fetch_optional(&state.db)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+        .ok_or((StatusCode::NOT_FOUND, "Cash count not found".to_string()))?;
+    rbac::resolve_parish_id(&auth, Some(count.parish_id))?;
+
+    let lines = sqlx::query_as::<_, CashCountLine>(
+        "SELECT * FROM cash_count_line WHERE cash_count_id = $1 ORDER BY denomination DESC"
+    )
+    .bind(id)
+    .fetch_all(&state.db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(CashCountDetail { count, lines }))
+}
+
+/// Records the day's physical count of a cash book, replacing an earlier
+/// count for the same day, and compares it with the book balance.
+pub async fn record_cash_count(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+    Json(payload): Json<RecordCashCountRequest>,
+) -> Result<Json<CashCountDetail>, (StatusCode, String)> {
+    rbac::require_finance(&auth)?;
+    let cashbook = fetch_cashbook(&state.db, id).await?;
+    rbac::resolve_parish_id(&auth, Some(cashbook.parish_id))?;
+
+    if !cashbook.cashbook_type.is_cash() {
+        return Err((StatusCode::BAD_REQUEST, "Cash counts are only recorded for cash books".to_string()));
+    }
+    if payload.denominations.iter().any(|d| d.denomination <= Decimal::ZERO || d.quantity < 0) {
+        return Err((StatusCode::BAD_REQUEST, "Denominations must be positive and quantities not negative".to_string()));
+    }
+
+    let count_date = payload.count_date.unwrap_or_else(|| Utc::now().date_naive());
+    let counted_total: Decimal = payload.denominations.iter().map(|d| d.denomination * Decimal::from(d.quantity)).sum();
+    let book_balance = balance_as_of(&state.db, &cashbook, count_date).await?;
+
+    let mut tx = state.db.begin().await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    let count = sqlx::query_as::<_, CashCount>(
+        r#"
+        INSERT INTO cash_count (cashbook_id, parish_id, count_date, counted_total, book_balance, difference, notes, counted_by)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+        ON CONFLICT (cashbook_id, count_date) DO UPDATE SET
+            counted_total = EXCLUDED.counted_total,
+            book_balance = EXCLUDED.book_balance,
+            difference = EXCLUDED.difference,
+            notes = EXCLUDED.notes,
+            counted_by = EXCLUDED.counted_by
+        RETURNING *
+        "#
+    )
+    .bind(id)
+    .bind(cashbook.parish_id)
+    .bind(count_date)
+    .bind(counted_total)
+    .bind(book_balance)
+    .bind(counted_total - book_balance)
+    .bind(payload.notes)
+    .bind(auth.user_id)
+    .fetch_one(&mut *tx)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    sqlx::query("DELETE FROM cash_count_line WHERE cash_count_id = $1")
+        .bind(count.id)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    let mut quantities: BTreeMap<Decimal, i32> = BTreeMap::new();
+    for d in payload.denominations.iter().filter(|d| d.quantity > 0) {
+        *quantities.entry(d.denomination).or_default() += d.quantity;
+    }
+
+    let mut lines = Vec::with_capacity(quantities.len());
+    for (denomination, quantity) in quantities.into_iter().rev() {
+        let line = sqlx::query_as::<_, CashCountLine>(
+            r#"
+            INSERT INTO cash_count_line (cash_count_id, denomination, quantity, amount)
+            VALUES ($1, $2, $3, $4)
+            RETURNING *
+            "#
+        )
+        .bind(count.id)
+        .bind(denomination)
+        .bind(quantity)
+        .bind(denomination * Decimal::from(quantity))
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+        lines.push(line);
+    }
+
+    tx.commit().await.map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(CashCountDetail { count, lines }))
+}
+
+// ============================================================================
+// Statement
+// ============================================================================
+
+#[derive(Debug, Deserialize)]
+pub struct StatementQuery {
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+}
+
+/// Receipts, payments and transfers of one cashbook with a running balance.
+/// Vouchers count once approved, as in the financial reports.
+pub async fn get_cashbook_statement(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+    Query(query): Query<StatementQuery>,
+    format: ExportFormat,
+) -> Result<Response, (StatusCode, String)> {
+    rbac::require_finance(&auth)?;
+    let cashbook = fetch_cashbook(&state.db, id).await?;
+    rbac::resolve_parish_id(&auth, Some(cashbook.parish_id))?;
+
+    // A statement starting on or before the opening date starts from the
+    // opening balance itself.
+    let opening_balance = match query.start_date.pred_opt() {
+        Some(day_before) if query.start_date > cashbook.opening_date => {
+            balance_as_of(&state.db, &cashbook, day_before).await?
+        }
+        _ => cashbook.opening_balance,
+    };
+
+    let mut lines = sqlx::query_as::<_, CashbookStatementLine>(
+        r#"
+        SELECT entry_date, entry_type, reference, description, receipts, payments
+        FROM (
+            SELECT transaction_date AS entry_date, 'RECEIPT' AS entry_type, transaction_number AS reference,
+                   COALESCE(description, category::text) AS description, amount AS receipts, 0::numeric AS payments, created_at
+            FROM income_transaction
+            WHERE cashbook_id = $1 AND deleted_at IS NULL
+            UNION ALL
+            SELECT expense_date, 'PAYMENT', voucher_number, payee_name || ' - ' || description, 0, amount, created_at
+            FROM expense_voucher
+            WHERE cashbook_id = $1 AND deleted_at IS NULL AND approval_status = 'APPROVED'
+            UNION ALL
+            SELECT t.transfer_date, CASE WHEN t.transfer_type = 'IMPREST_TOPUP' THEN 'IMPREST_TOPUP' ELSE 'TRANSFER_IN' END,
+                   t.reference_number, COALESCE(t.description, 'From ' || c.cashbook_name), t.amount, 0, t.created_at
+            FROM cashbook_transfer t JOIN cashbook c ON c.id = t.from_cashbook_id
+            WHERE t.to_cashbook_id = $1 AND t.deleted_at IS NULL
+            UNION ALL
+            SELECT t.transfer_date, 'TRANSFER_OUT', t.reference_number, COALESCE(t.description, 'To ' || c.cashbook_name), 0, t.amount, t.created_at
+            FROM cashbook_transfer t JOIN cashbook c ON c.id = t.to_cashbook_id
+            WHERE t.from_cashbook_id = $1 AND t.deleted_at IS NULL
+        ) entries
+        WHERE entry_date BETWEEN GREATEST($2, $4) AND $3
+        ORDER BY entry_date, created_at
+        "#
+    )
+    .bind(id)
+    .bind(query.start_date)
+    .bind(query.end_date)
+    .bind(cashbook.opening_date)
+    .fetch_all(&state.db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    let mut balance = opening_balance;
+    for line in &mut lines {
+        balance += line.receipts - line.payments;
+        line.balance = balance;
+    }
+
+    let statement = CashbookStatement {
+        cashbook_id: cashbook.id,
+        cashbook_name: cashbook.cashbook_name.clone(),
+        start_date: query.start_date,
+        end_date: query.end_date,
+        opening_balance,
+        total_receipts: lines.iter().map(|l| l.receipts).sum(),
+        total_payments: lines.iter().map(|l| l.payments).sum(),
+        closing_balance: balance,
+        lines,
+    };
+
+    let header = ReportHeader::parish(
+        cashbook.parish_id,
+        &format!("Cashbook Statement - {}", cashbook.cashbook_name),
+        Some(export::period_label(query.start_date, query.end_date)),
+    );
+    export::respond(&state.db, &auth, format, header, statement).await
+}
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+async fn fetch_cashbook<'e, E>(db: E, id: Uuid) -> Result<Cashbook, (StatusCode, String)>
+where
+    E: sqlx::Executor<'e, Database = Postgres>,
+{
+    sqlx::query_as::<_, Cashbook>("SELECT * FROM cashbook WHERE id = $1 AND deleted_at IS NULL")
+        .bind(id)
+        .fetch_optional(db)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+        .ok_or((StatusCode::NOT_FOUND, "Cashbook not found".to_string()))
+}
+
+/// Loads an active cashbook and checks that it belongs to the given parish.
+pub async fn cashbook_for_parish<'e, E>(db: E, cashbook_id: Uuid, parish_id: Uuid) -> Result<Cashbook, (StatusCode, String)>
+where
+    E: sqlx::Executor<'e, Database = Postgres>,
+{
+    let cashbook = fetch_cashbook(db, cashbook_id).await?;
+    if cashbook.parish_id != parish_id {
+        return Err((StatusCode::BAD_REQUEST, "Cashbook does not belong to this parish".to_string()));
+    }
+    if !cashbook.is_active {
+        return Err((StatusCode::BAD_REQUEST, format!("Cashbook '{}' is closed", cashbook.cashbook_name)));
+    }
+    Ok(cashbook)
+}
+
+/// Book balance at the end of `as_of`: opening balance plus receipts and
+/// transfers in, less approved vouchers and transfers out since the
+/// opening date.
+pub async fn balance_as_of(db: &PgPool, cashbook: &Cashbook, as_of: NaiveDate) -> Result<Decimal, (StatusCode, String)> {
+    let movement: Decimal = sqlx::query_scalar(
+        r#"
+        SELECT
+            (SELECT COALESCE(SUM(amount), 0) FROM income_transaction
+             WHERE cashbook_id = $1 AND deleted_at IS NULL AND transaction_date BETWEEN $2 AND $3)
+          - (SELECT COALESCE(SUM(amount), 0) FROM expense_voucher
+             WHERE cashbook_id = $1 AND deleted_at IS NULL AND approval_status = 'APPROVED' AND expense_date BETWEEN $2 AND $3)
+          + (SELECT COALESCE(SUM(amount), 0) FROM cashbook_transfer
+             WHERE to_cashbook_id = $1 AND deleted_at IS NULL AND transfer_date BETWEEN $2 AND $3)
+          - (SELECT COALESCE(SUM(amount), 0) FROM cashbook_transfer
+             WHERE from_cashbook_id = $1 AND deleted_at IS NULL AND transfer_date BETWEEN $2 AND $3)
+        "#
+    )
+    .bind(cashbook.id)
+    .bind(cashbook.opening_date)
+    .bind(as_of)
+    .fetch_one(db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    if as_of < cashbook.opening_date {
+        return Ok(Decimal::ZERO);
+    }
+    Ok(cashbook.opening_balance + movement)
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn insert_transfer(
+    db: &PgPool,
+    from: &Cashbook,
+    to: &Cashbook,
+    transfer_type: CashbookTransferType,
+    amount: Decimal,
+    transfer_date: NaiveDate,
+    reference_number: Option<String>,
+    description: Option<String>,
+    user_id: Uuid,
+) -> Result<CashbookTransfer, (StatusCode, String)> {
+    if from.id == to.id {
+        return Err((StatusCode::BAD_REQUEST, "Cannot transfer to the same cashbook".to_string()));
+    }
+    if amount <= Decimal::ZERO {
+        return Err((StatusCode::BAD_REQUEST, "Amount must be greater than zero".to_string()));
+    }
+    if !from.is_active {
+        return Err((StatusCode::BAD_REQUEST, format!("Cashbook '{}' is closed", from.cashbook_name)));
+    }
+    if from.cashbook_type.is_cash() {
+        let available = balance_as_of(db, from, transfer_date).await?;
+        if amount > available {
+            return Err((
+                StatusCode::BAD_REQUEST,
+                format!("Only {} is available in '{}'", available, from.cashbook_name),
+            ));
+        }
+    }
+
+    sqlx::query_as::<_, CashbookTransfer>(
+        r#"
+        INSERT INTO cashbook_transfer (
+            parish_id, from_cashbook_id, to_cashbook_id, transfer_type, amount,
+            transfer_date, reference_number, description, created_by
+        )
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+        RETURNING *
+        "#
+    )
+    .bind(from.parish_id)
+    .bind(from.id)
+    .bind(to.id)
+    .bind(transfer_type)
+    .bind(amount)
+    .bind(transfer_date)
+    .bind(reference_number)
+    .bind(description)
+    .bind(user_id)
+    .fetch_one(db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+}
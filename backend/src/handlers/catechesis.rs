@@ -0,0 +1,683 @@
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    Json,
+};
+use sqlx::{PgConnection, PgPool};
+use uuid::Uuid;
+use chrono::Utc;
+use serde::Deserialize;
+use crate::{AppState, handlers::auth::AuthUser, handlers::rbac};
+use crate::handlers::{sacrament, sacrament_rule};
+use crate::handlers::sacrament::CreateSacramentRequest;
+use crate::models::catechesis::{
+    AddCatechistRequest, Candidate, CandidateReadiness, CatechesisClass, CatechesisClassDetail, CatechesisSession,
+    Catechist, CelebrateRequest, CelebrationResult, CreateCatechesisClassRequest, EnrolCandidatesRequest,
+    RecordSessionRequest, SkippedCandidate, UpdateAttendanceRequest, UpdateCandidateRequest,
+    UpdateCatechesisClassRequest,
+};
+use crate::models::member::SacramentType;
+use crate::models::sacrament_rule::CheckSacramentRequest;
+use crate::models::sponsor::{SponsorInput, SponsorRole};
+
+const CLASS_WITH_COUNTS: &str = r#"
+    SELECT c.*, cl.cluster_name, n.candidate_count, n.ready_count
+    FROM catechesis_class c
+    LEFT JOIN cluster cl ON cl.id = c.cluster_id
+    LEFT JOIN LATERAL (
+        SELECT COUNT(*) FILTER (WHERE readiness <> 'WITHDRAWN') AS candidate_count,
+               COUNT(*) FILTER (WHERE readiness = 'READY') AS ready_count
+        FROM catechesis_candidate WHERE class_id = c.id
+    ) n ON TRUE
+"#;
+
+const CANDIDATE_WITH_ATTENDANCE: &str = r#"
+    SELECT d.*, m.member_code, CONCAT_WS(' ', m.first_name, m.middle_name, m.last_name) AS member_name,
+           a.sessions_attended, a.sessions_held
+    FROM catechesis_candidate d
+    JOIN member m ON m.id = d.member_id
+    LEFT JOIN LATERAL (
+        SELECT COUNT(*) FILTER (WHERE present) AS sessions_attended, COUNT(*) AS sessions_held
+        FROM catechesis_attendance WHERE candidate_id = d.id
+    ) a ON TRUE
+"#;
+
+#[derive(Debug, Deserialize)]
+pub struct CatechesisClassQuery {
+    pub parish_id: Option<Uuid>,
+    pub cluster_id: Option<Uuid>,
+    pub sacrament_type: Option<SacramentType>,
+}
+
+pub async fn list_catechesis_classes(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Query(query): Query<CatechesisClassQuery>,
+) -> Result<Json<Vec<CatechesisClass>>, (StatusCode, String)> {
+    let parish_id = rbac::resolve_parish_id(&auth, query.parish_id)?;
+
+    let classes = sqlx::query_as::<_, CatechesisClass>(&format!(
+        r#"{}
+        WHERE c.parish_id = $1 AND c.deleted_at IS NULL
+          AND ($2::uuid IS NULL OR c.cluster_id = $2)
+          AND ($3::sacrament_type IS NULL OR c.sacrament_type = $3)
+        ORDER BY c.start_date DESC, c.class_name"#,
+        CLASS_WITH_COUNTS
+    ))
+    .bind(parish_id)
+    .bind(query.cluster_id)
+    .bind(query.sacrament_type)
+    .fetch_all(&state.db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(classes))
+}
+
+pub async fn get_catechesis_class(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<CatechesisClassDetail>, (StatusCode, String)> {
+    let class = fetch_class(&state.db, &auth, id).await?;
+    Ok(Json(detail(&state.db, class).await?))
+}
+
+pub async fn create_catechesis_class(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Json(payload): Json<CreateCatechesisClassRequest>,
+) -> Result<Json<CatechesisClassDetail>, (StatusCode, String)> {
+    rbac::require_write(&auth)?;
+    let parish_id = rbac::resolve_parish_id(&auth, Some(payload.parish_id))?;
+    if !matches!(payload.sacrament_type, SacramentType::Baptism | SacramentType::FirstCommunion | SacramentType::Confirmation) {
+        return Err((StatusCode::BAD_REQUEST, "Classes prepare for Baptism, First Communion or Confirmation".to_string()));
+    }
+    validate_class(payload.class_name.as_str(), payload.min_attendance_percent)?;
+
+    let id: Uuid = sqlx::query_scalar(
+        r#"
+        INSERT INTO catechesis_class (
+            parish_id, cluster_id, class_name, sacrament_type, start_date, end_date,
+            planned_celebration_date, min_attendance_percent, notes, created_by
+        )
+        VALUES ($1, $2, $3, $4, $5, $6, $7, COALESCE($8, 75), $9, $10)
+        RETURNING id
+        "#
+    )
+    .bind(parish_id)
+    .bind(payload.cluster_id)
+    .bind(payload.class_name.trim())
+    .bind(payload.sacrament_type)
+    .bind(payload.start_date)
+    .bind(payload.end_date)
+    .bind(payload.planned_celebration_date)
+    .bind(payload.min_attendance_percent)
+    .bind(payload.notes)
+    .bind(auth.user_id)
+    .fetch_one(&state.db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    let class = fetch_class(&state.db, &auth, id).await?;
+    Ok(Json(detail(&state.db, class).await?))
+}
+
+pub async fn update_catechesis_class(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+    Json(payload): Json<UpdateCatechesisClassRequest>,
+) -> Result<Json<CatechesisClassDetail>, (StatusCode, String)> {
+    rbac::require_write(&auth)?;
+    let mut class = fetch_class(&state.db, &auth, id).await?;
+
+    if let Some(val) = payload.cluster_id { class.cluster_id = Some(val); }
+    if let Some(val) = payload.class_name { class.class_name = val; }
+    if let Some(val) = payload.start_date { class.start_date = val; }
+    if let Some(val) = payload.end_date { class.end_date = Some(val); }
+    if let Some(val) = payload.planned_celebration_date { class.planned_celebration_date = Some(val); }
+    if let Some(val) = payload.min_attendance_percent { class.min_attendance_percent = val; }
+    if let Some(val) = payload.notes { class.notes = Some(val); }
+    validate_class(&class.class_name, Some(class.min_attendance_percent))?;
+
+    sqlx::query(
+        r#"
+        UPDATE catechesis_class SET
+            cluster_id = $1, class_name = $2, start_date = $3, end_date = $4,
+            planned_celebration_date = $5, min_attendance_percent = $6, notes = $7
+        WHERE id = $8
+        "#
+    )
+    .bind(class.cluster_id)
+    .bind(class.class_name.trim())
+    .bind(class.start_date)
+    .bind(class.end_date)
+    .bind(class.planned_celebration_date)
+    .bind(class.min_attendance_percent)
+    .bind(&class.notes)
+    .bind(id)
+    .execute(&state.db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    let class = fetch_class(&state.db, &auth, id).await?;
+    Ok(Json(detail(&state.db, class).await?))
+}
+
+pub async fn delete_catechesis_class(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> Result<StatusCode, (StatusCode, String)> {
+    rbac::require_write(&auth)?;
+    let class = fetch_class(&state.db, &auth, id).await?;
+    if class.celebrated_on.is_some() {
+        return Err((StatusCode::CONFLICT, "The class has celebrated its sacrament and is kept with the records".to_string()));
+    }
+
+    sqlx::query("UPDATE catechesis_class SET deleted_at = NOW() WHERE id = $1")
+        .bind(id)
+        .execute(&state.db)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+// ============================================================================
+// CATECHISTS
+// ============================================================================
+
+pub async fn add_catechist(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Path(class_id): Path<Uuid>,
+    Json(payload): Json<AddCatechistRequest>,
+) -> Result<Json<Catechist>, (StatusCode, String)> {
+    rbac::require_write(&auth)?;
+    fetch_class(&state.db, &auth, class_id).await?;
+
+    let given = payload.full_name.as_deref().map(str::trim).filter(|n| !n.is_empty());
+    let full_name = match (given, payload.member_id) {
+        (Some(name), _) => name.to_string(),
+        (None, Some(member_id)) => sqlx::query_scalar::<_, String>(
+            "SELECT CONCAT_WS(' ', first_name, middle_name, last_name) FROM member WHERE id = $1 AND deleted_at IS NULL"
+        )
+        .bind(member_id)
+        .fetch_optional(&state.db)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+        .ok_or((StatusCode::NOT_FOUND, "Member not found".to_string()))?,
+        (None, None) => return Err((StatusCode::BAD_REQUEST, "Give the catechist's member_id or full_name".to_string())),
+    };
+
+    let catechist = sqlx::query_as::<_, Catechist>(
+        "INSERT INTO catechesis_catechist (class_id, member_id, full_name, is_lead) VALUES ($1, $2, $3, $4) RETURNING *"
+    )
+    .bind(class_id)
+    .bind(payload.member_id)
+    .bind(full_name)
+    .bind(payload.is_lead)
+    .fetch_one(&state.db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(catechist))
+}
+
+pub async fn remove_catechist(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> Result<StatusCode, (StatusCode, String)> {
+    rbac::require_write(&auth)?;
+    let class_id: Uuid = sqlx::query_scalar("SELECT class_id FROM catechesis_catechist WHERE id = $1")
+        .bind(id)
+        .fetch_optional(&state.db)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+        .ok_or((StatusCode::NOT_FOUND, "Catechist not found".to_string()))?;
+    fetch_class(&state.db, &auth, class_id).await?;
+
+    sqlx::query("DELETE FROM catechesis_catechist WHERE id = $1")
+        .bind(id)
+        .execute(&state.db)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+// ============================================================================
+// CANDIDATES
+// ============================================================================
+
+/// Enrols living members of the class's parish who have not yet received
+/// the sacrament. Members already enrolled are left as they are.
+pub async fn enrol_candidates(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Path(class_id): Path<Uuid>,
+    Json(payload): Json<EnrolCandidatesRequest>,
+) -> Result<Json<Vec<Candidate>>, (StatusCode, String)> {
+    rbac::require_write(&auth)?;
+    let class = fetch_class(&state.db, &auth, class_id).await?;
+    if payload.member_ids.is_empty() {
+        return Err((StatusCode::BAD_REQUEST, "No members to enrol".to_string()));
+    }
+
+    let found: Vec<(Uuid, String, bool)> = sqlx::query_as(
+        r#"
+        SELECT m.id, CONCAT_WS(' ', m.first_name, m.middle_name, m.last_name),
+               EXISTS (SELECT 1 FROM sacrament_record s WHERE s.member_id = m.id AND s.sacrament_type = $3 AND s.deleted_at IS NULL)
+        FROM member m
+        WHERE m.id = ANY($1) AND m.parish_id = $2 AND m.deleted_at IS NULL AND m.date_of_death IS NULL
+        "#
+    )
+    .bind(&payload.member_ids)
+    .bind(class.parish_id)
+    .bind(class.sacrament_type)
+    .fetch_all(&state.db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    if found.len() != payload.member_ids.iter().collect::<std::collections::HashSet<_>>().len() {
+        return Err((StatusCode::BAD_REQUEST, "Candidates must be living members of the class's parish".to_string()));
+    }
+    let received: Vec<&str> = found.iter().filter(|(_, _, r)| *r).map(|(_, name, _)| name.as_str()).collect();
+    if !received.is_empty() {
+        return Err((StatusCode::CONFLICT, format!("Already received the sacrament: {}", received.join(", "))));
+    }
+
+    let enrolled_on = payload.enrolled_on.unwrap_or_else(|| Utc::now().date_naive());
+    sqlx::query(
+        r#"
+        INSERT INTO catechesis_candidate (class_id, member_id, enrolled_on)
+        SELECT $1, UNNEST($2::uuid[]), $3
+        ON CONFLICT (class_id, member_id) DO NOTHING
+        "#
+    )
+    .bind(class_id)
+    .bind(&payload.member_ids)
+    .bind(enrolled_on)
+    .execute(&state.db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(fetch_candidates(&state.db, class_id).await?))
+}
+
+/// Records a candidate's assessment or sponsor. A candidate is passed as
+/// ready only with the class's minimum attendance.
+pub async fn update_candidate(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+    Json(payload): Json<UpdateCandidateRequest>,
+) -> Result<Json<Candidate>, (StatusCode, String)> {
+    rbac::require_write(&auth)?;
+    let mut candidate = fetch_candidate(&state.db, id).await?;
+    let class = fetch_class(&state.db, &auth, candidate.class_id).await?;
+    let name = candidate.member_name.clone().unwrap_or_default();
+
+    if candidate.readiness == CandidateReadiness::Celebrated {
+        return Err((StatusCode::CONFLICT, format!("{} has already received the sacrament", name)));
+    }
+    if payload.readiness == Some(CandidateReadiness::Celebrated) {
+        return Err((StatusCode::BAD_REQUEST, "Candidates are marked celebrated when the sacrament is recorded".to_string()));
+    }
+    if payload.readiness == Some(CandidateReadiness::Ready) {
+        let attended = candidate.sessions_attended.unwrap_or(0);
+        let held = candidate.sessions_held.unwrap_or(0);
+        if held > 0 && attended * 100 < class.min_attendance_percent as i64 * held {
+            return Err((StatusCode::CONFLICT, format!(
+                "{} attended {} of {} sessions, below the class minimum of {}%",
+                name, attended, held, class.min_attendance_percent
+            )));
+        }
+    }
+    if (payload.sponsor_member_id.is_some() || payload.sponsor_name.is_some()) && sponsor_role(class.sacrament_type).is_none() {
+        return Err((StatusCode::BAD_REQUEST, "No godparent or sponsor stands at First Communion".to_string()));
+    }
+
+    let assessed = payload.readiness.is_some();
+    if let Some(val) = payload.readiness { candidate.readiness = val; }
+    if let Some(val) = payload.assessment_notes { candidate.assessment_notes = Some(val); }
+    if let Some(val) = payload.sponsor_member_id { candidate.sponsor_member_id = Some(val); }
+    if let Some(val) = payload.sponsor_name { candidate.sponsor_name = Some(val); }
+
+    sqlx::query(
+        r#"
+        UPDATE catechesis_candidate SET
+            readiness = $1, assessment_notes = $2, sponsor_member_id = $3, sponsor_name = $4,
+            assessed_on = CASE WHEN $5 THEN CURRENT_DATE ELSE assessed_on END,
+            assessed_by = CASE WHEN $5 THEN $6 ELSE assessed_by END
+        WHERE id = $7
+        "#
+    )
+    .bind(candidate.readiness)
+    .bind(&candidate.assessment_notes)
+    .bind(candidate.sponsor_member_id)
+    .bind(&candidate.sponsor_name)
+    .bind(assessed)
+    .bind(auth.user_id)
+    .bind(id)
+    .execute(&state.db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(fetch_candidate(&state.db, id).await?))
+}
+
+// ============================================================================
+// SESSIONS
+// ============================================================================
+
+/// Records a lesson with who attended. Candidates enrolled by that date
+/// and not withdrawn are marked present or absent.
+pub async fn record_session(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Path(class_id): Path<Uuid>,
+    Json(payload): Json<RecordSessionRequest>,
+) -> Result<Json<CatechesisSession>, (StatusCode, String)> {
+    rbac::require_write(&auth)?;
+    fetch_class(&state.db, &auth, class_id).await?;
+
+    let mut tx = state.db.begin().await.map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    let session_id: Uuid = sqlx::query_scalar(
+        r#"
+        INSERT INTO catechesis_session (class_id, session_date, topic, catechist_id, notes)
+        VALUES ($1, $2, $3, $4, $5)
+        RETURNING id
+        "#
+    )
+    .bind(class_id)
+    .bind(payload.session_date)
+    .bind(payload.topic)
+    .bind(payload.catechist_id)
+    .bind(payload.notes)
+    .fetch_one(&mut *tx)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    mark_attendance(&mut tx, class_id, session_id, &payload.present).await?;
+    let session = fetch_session(&mut tx, session_id).await?;
+
+    tx.commit().await.map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(session))
+}
+
+pub async fn update_attendance(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Path(session_id): Path<Uuid>,
+    Json(payload): Json<UpdateAttendanceRequest>,
+) -> Result<Json<CatechesisSession>, (StatusCode, String)> {
+    rbac::require_write(&auth)?;
+    let mut tx = state.db.begin().await.map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+    let session = fetch_session(&mut tx, session_id).await?;
+    fetch_class(&state.db, &auth, session.class_id).await?;
+
+    mark_attendance(&mut tx, session.class_id, session_id, &payload.present).await?;
+    let session = fetch_session(&mut tx, session_id).await?;
+
+    tx.commit().await.map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(session))
+}
+
+// ============================================================================
+// CELEBRATION
+// ============================================================================
+
+/// Records the sacrament for every ready candidate on the ceremony date.
+/// Each record is checked, registered and noted on the baptism as if it
+/// were entered alone; candidates refused by the checks are listed as
+/// skipped and stay ready for a later ceremony.
+pub async fn celebrate(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Path(class_id): Path<Uuid>,
+    Json(payload): Json<CelebrateRequest>,
+) -> Result<Json<CelebrationResult>, (StatusCode, String)> {
+    rbac::require_write(&auth)?;
+    if payload.override_reason.is_some() {
+        rbac::require_admin(&auth)?;
+    }
+    let class = fetch_class(&state.db, &auth, class_id).await?;
+    let ready: Vec<Candidate> = fetch_candidates(&state.db, class_id)
+        .await?
+        .into_iter()
+        .filter(|c| c.readiness == CandidateReadiness::Ready && c.sacrament_record_id.is_none())
+        .collect();
+    if ready.is_empty() {
+        return Err((StatusCode::BAD_REQUEST, "No candidates are marked ready".to_string()));
+    }
+
+    let mut accepted = Vec::new();
+    let mut skipped = Vec::new();
+    for candidate in ready {
+        let sponsors: Vec<SponsorInput> = sponsor_role(class.sacrament_type)
+            .filter(|_| candidate.sponsor_member_id.is_some() || candidate.sponsor_name.is_some())
+            .map(|role| SponsorInput { role, member_id: candidate.sponsor_member_id, full_name: candidate.sponsor_name.clone() })
+            .into_iter()
+            .collect();
+
+        let check = match sacrament_rule::check(&state.db, class.parish_id, &CheckSacramentRequest {
+            member_id: candidate.member_id,
+            sacrament_type: class.sacrament_type,
+            sacrament_date: payload.sacrament_date,
+            spouse_id: None,
+            record_id: None,
+            sponsors: sponsors.clone(),
+        })
+        .await
+        {
+            Ok(check) => check,
+            Err((StatusCode::CONFLICT | StatusCode::BAD_REQUEST, reason)) => {
+                skipped.push(SkippedCandidate {
+                    candidate_id: candidate.id, member_id: candidate.member_id, member_name: candidate.member_name,
+                    reason, violations: Vec::new(),
+                });
+                continue;
+            }
+            Err(e) => return Err(e),
+        };
+        match sacrament_rule::override_reason(&auth, &check, payload.override_reason.as_deref()) {
+            Ok(override_reason) => accepted.push((candidate, sponsors, check, override_reason)),
+            Err(_) => skipped.push(SkippedCandidate {
+                candidate_id: candidate.id, member_id: candidate.member_id, member_name: candidate.member_name,
+                reason: "Refused by the canonical checks".to_string(), violations: check.violations,
+            }),
+        }
+    }
+
+    let mut tx = state.db.begin().await.map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    let mut records = Vec::with_capacity(accepted.len());
+    for (candidate, sponsors, check, override_reason) in accepted {
+        let saved = sacrament::insert_record(&mut tx, &auth, class.parish_id, CreateSacramentRequest {
+            member_id: candidate.member_id,
+            sacrament_type: class.sacrament_type,
+            sacrament_date: payload.sacrament_date,
+            officiating_minister: payload.officiating_minister.clone(),
+            parish_id: class.parish_id,
+            church_name: payload.church_name.clone(),
+            certificate_number: None,
+            godparent_1_name: None,
+            godparent_2_name: None,
+            spouse_id: None,
+            spouse_name: None,
+            witnesses: None,
+            notes: None,
+            sponsors: Some(sponsors),
+            override_reason: None,
+            register_book_id: None,
+            register_page: None,
+            register_entry: None,
+            unregistered: false,
+        }, &check.violations, override_reason)
+        .await?;
+
+        sqlx::query("UPDATE catechesis_candidate SET readiness = 'CELEBRATED', sacrament_record_id = $1 WHERE id = $2")
+            .bind(saved.record.id)
+            .bind(candidate.id)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+        records.push(saved);
+    }
+
+    if !records.is_empty() {
+        sqlx::query("UPDATE catechesis_class SET celebrated_on = $1 WHERE id = $2")
+            .bind(payload.sacrament_date)
+            .bind(class_id)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+    }
+
+    tx.commit().await.map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(CelebrationResult { records, skipped }))
+}
+
+// ============================================================================
+// HELPERS
+// ============================================================================
+
+fn validate_class(class_name: &str, min_attendance_percent: Option<i32>) -> Result<(), (StatusCode, String)> {
+    if class_name.trim().is_empty() {
+        return Err((StatusCode::BAD_REQUEST, "The class needs a name".to_string()));
+    }
+    if min_attendance_percent.is_some_and(|p| !(0..=100).contains(&p)) {
+        return Err((StatusCode::BAD_REQUEST, "Minimum attendance is a percentage from 0 to 100".to_string()));
+    }
+    Ok(())
+}
+
+/// Who stands for a candidate: a godparent at Baptism, a sponsor at
+/// Confirmation, nobody at First Communion.
+fn sponsor_role(sacrament_type: SacramentType) -> Option<SponsorRole> {
+    match sacrament_type {
+        SacramentType::Baptism => Some(SponsorRole::Godparent),
+        SacramentType::Confirmation => Some(SponsorRole::Sponsor),
+        _ => None,
+    }
+}
+
+async fn fetch_class(db: &PgPool, auth: &AuthUser, id: Uuid) -> Result<CatechesisClass, (StatusCode, String)> {
+    let class = sqlx::query_as::<_, CatechesisClass>(&format!(
+        "{} WHERE c.id = $1 AND c.deleted_at IS NULL", CLASS_WITH_COUNTS
+    ))
+    .bind(id)
+    .fetch_optional(db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+    .ok_or((StatusCode::NOT_FOUND, "Catechesis class not found".to_string()))?;
+    rbac::resolve_parish_id(auth, Some(class.parish_id))?;
+    Ok(class)
+}
+
+async fn fetch_candidates(db: &PgPool, class_id: Uuid) -> Result<Vec<Candidate>, (StatusCode, String)> {
+    sqlx::query_as::<_, Candidate>(&format!(
+        "{} WHERE d.class_id = $1 ORDER BY m.last_name, m.first_name", CANDIDATE_WITH_ATTENDANCE
+    ))
+    .bind(class_id)
+    .fetch_all(db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+}
+
+async fn fetch_candidate(db: &PgPool, id: Uuid) -> Result<Candidate, (StatusCode, String)> {
+    sqlx::query_as::<_, Candidate>(&format!("{} WHERE d.id = $1", CANDIDATE_WITH_ATTENDANCE))
+        .bind(id)
+        .fetch_optional(db)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+        .ok_or((StatusCode::NOT_FOUND, "Candidate not found".to_string()))
+}
+
+async fn fetch_session(conn: &mut PgConnection, id: Uuid) -> Result<CatechesisSession, (StatusCode, String)> {
+    sqlx::query_as::<_, CatechesisSession>(
+        r#"
+        SELECT s.*, (SELECT COUNT(*) FROM catechesis_attendance a WHERE a.session_id = s.id AND a.present) AS present_count
+        FROM catechesis_session s WHERE s.id = $1
+        "#
+    )
+    .bind(id)
+    .fetch_optional(conn)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+    .ok_or((StatusCode::NOT_FOUND, "Session not found".to_string()))
+}
+
+async fn mark_attendance(
+    conn: &mut PgConnection,
+    class_id: Uuid,
+    session_id: Uuid,
+    present: &[Uuid],
+) -> Result<(), (StatusCode, String)> {
+    let unknown: i64 = sqlx::query_scalar(
+        r#"
+        SELECT COUNT(*) FROM UNNEST($2::uuid[]) AS p(id)
+        WHERE NOT EXISTS (SELECT 1 FROM catechesis_candidate c WHERE c.id = p.id AND c.class_id = $1)
+        "#
+    )
+    .bind(class_id)
+    .bind(present)
+    .fetch_one(&mut *conn)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+    if unknown > 0 {
+        return Err((StatusCode::BAD_REQUEST, "Attendance lists a candidate not enrolled in the class".to_string()));
+    }
+
+    sqlx::query(
+        r#"
+        INSERT INTO catechesis_attendance (session_id, candidate_id, present)
+        SELECT s.id, c.id, c.id = ANY($3)
+        FROM catechesis_session s
+        JOIN catechesis_candidate c ON c.class_id = s.class_id
+        WHERE s.id = $2 AND c.class_id = $1 AND c.readiness <> 'WITHDRAWN' AND c.enrolled_on <= s.session_date
+        ON CONFLICT (session_id, candidate_id) DO UPDATE SET present = EXCLUDED.present
+        "#
+    )
+    .bind(class_id)
+    .bind(session_id)
+    .bind(present)
+    .execute(&mut *conn)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+    Ok(())
+}
+
+async fn detail(db: &PgPool, class: CatechesisClass) -> Result<CatechesisClassDetail, (StatusCode, String)> {
+    let catechists = sqlx::query_as::<_, Catechist>(
+        "SELECT * FROM catechesis_catechist WHERE class_id = $1 ORDER BY is_lead DESC, full_name"
+    )
+    .bind(class.id)
+    .fetch_all(db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    let candidates = fetch_candidates(db, class.id).await?;
+
+    let sessions = sqlx::query_as::<_, CatechesisSession>(
+        r#"
+        SELECT s.*, (SELECT COUNT(*) FROM catechesis_attendance a WHERE a.session_id = s.id AND a.present) AS present_count
+        FROM catechesis_session s WHERE s.class_id = $1
+        ORDER BY s.session_date, s.created_at
+        "#
+    )
+    .bind(class.id)
+    .fetch_all(db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(CatechesisClassDetail { class, catechists, candidates, sessions })
+}
@@ -0,0 +1,675 @@
+use axum::{
+    extract::{Path, State, Query},
+    http::{header, StatusCode},
+    response::{IntoResponse, Response},
+    Json,
+};
+use uuid::Uuid;
+use serde::Deserialize;
+use chrono::{Datelike, NaiveDate, Utc};
+use sqlx::postgres::PgPool;
+use crate::{
+    AppState,
+    models::certificate::{
+        CertificateLanguage, CertificateTemplate, SaveCertificateTemplateRequest, DefaultCertificateTemplate,
+        CertificateIssuance, IssueCertificateRequest, RevokeCertificateRequest, CertificateVerification,
+    },
+    models::member::{SacramentRecord, SacramentType},
+    handlers::auth::AuthUser,
+    handlers::rbac,
+    handlers::register,
+    export::{self, CertificateDocument, ExportFormat},
+};
+
+/// Placeholders a template body may use. A line whose placeholders are
+/// all blank for a record (no godparents, unknown birth date) is left out.
+pub const PLACEHOLDERS: &[&str] = &[
+    "full_name", "first_name", "last_name", "date_of_birth", "sacrament", "sacrament_date",
+    "minister", "church", "parish", "diocese", "godparents", "spouse", "witnesses",
+    "notations", "register", "certificate_number", "issue_date",
+];
+
+const SACRAMENTS: [SacramentType; 6] = [
+    SacramentType::Baptism,
+    SacramentType::FirstCommunion,
+    SacramentType::Confirmation,
+    SacramentType::Marriage,
+    SacramentType::HolyOrders,
+    SacramentType::AnointingOfSick,
+];
+
+const LANGUAGES: [CertificateLanguage; 3] = [CertificateLanguage::English, CertificateLanguage::Latin, CertificateLanguage::Swahili];
+
+// ============================================================================
+// Templates
+// ============================================================================
+
+#[derive(Debug, Deserialize)]
+pub struct TemplateQuery {
+    pub parish_id: Option<Uuid>,
+}
+
+pub async fn list_certificate_templates(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Query(query): Query<TemplateQuery>,
+) -> Result<Json<Vec<CertificateTemplate>>, (StatusCode, String)> {
+    let parish_id = rbac::resolve_parish_id(&auth, query.parish_id)?;
+
+    let templates = sqlx::query_as::<_, CertificateTemplate>(
+        "SELECT * FROM certificate_template WHERE parish_id = $1 AND deleted_at IS NULL ORDER BY sacrament_type, language"
+    )
+    .bind(parish_id)
+    .fetch_all(&state.db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(templates))
+}
+
+/// The built-in wording for every sacrament and language, as a starting
+/// point for a parish's own.
+pub async fn list_default_certificate_templates(
+    _auth: AuthUser,
+) -> Result<Json<Vec<DefaultCertificateTemplate>>, (StatusCode, String)> {
+    let templates = SACRAMENTS.iter()
+        .flat_map(|s| LANGUAGES.iter().map(move |l| default_template(*s, *l)))
+        .collect();
+    Ok(Json(templates))
+}
+
+pub async fn save_certificate_template(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Json(payload): Json<SaveCertificateTemplateRequest>,
+) -> Result<Json<CertificateTemplate>, (StatusCode, String)> {
+    rbac::require_permission(&state.db, &auth, "sacraments.certificates").await?;
+    let parish_id = rbac::resolve_parish_id(&auth, Some(payload.parish_id))?;
+
+    if payload.title.trim().is_empty() || payload.body.trim().is_empty() {
+        return Err((StatusCode::BAD_REQUEST, "Title and body are required".to_string()));
+    }
+    if let Some(unknown) = unknown_placeholder(&payload.body) {
+        return Err((StatusCode::BAD_REQUEST, format!("Unknown placeholder {{{}}}", unknown)));
+    }
+
+    let template = sqlx::query_as::<_, CertificateTemplate>(
+        r#"
+        INSERT INTO certificate_template (parish_id, sacrament_type, language, title, body, signatory_title, created_by)
+        VALUES ($1, $2, $3, $4, $5, $6, $7)
+        ON CONFLICT (parish_id, sacrament_type, language) WHERE deleted_at IS NULL
+        DO UPDATE SET title = EXCLUDED.title, body = EXCLUDED.body, signatory_title = EXCLUDED.signatory_title
+        RETURNING *
+        "#
+    )
+    .bind(parish_id)
+    .bind(payload.sacrament_type)
+    .bind(payload.language)
+    .bind(payload.title.trim())
+    .bind(payload.body)
+    .bind(payload.signatory_title)
+    .bind(auth.user_id)
+    .fetch_one(&state.db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(template))
+}
+
+/// Removing a parish template falls back to the built-in wording.
+pub async fn delete_certificate_template(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> Result<StatusCode, (StatusCode, String)> {
+    rbac::require_permission(&state.db, &auth, "sacraments.certificates").await?;
+
+    let parish_id: Uuid = sqlx::query_scalar("SELECT parish_id FROM certificate_template WHERE id = $1 AND deleted_at IS NULL")
+        .bind(id)
+        .fetch_optional(&state.db)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+        .ok_or((StatusCode::NOT_FOUND, "Certificate template not found".to_string()))?;
+    rbac::resolve_parish_id(&auth, Some(parish_id))?;
+
+    sqlx::query("UPDATE certificate_template SET deleted_at = NOW() WHERE id = $1")
+        .bind(id)
+        .execute(&state.db)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+// ============================================================================
+// Issuance
+// ============================================================================
+
+/// Issues a certificate for a sacrament record. The record gets the next
+/// certificate number for its parish and year on its first certificate;
+/// reissues keep that number but each gets its own verification code.
+pub async fn issue_certificate(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+    Json(payload): Json<IssueCertificateRequest>,
+) -> Result<Json<CertificateIssuance>, (StatusCode, String)> {
+    rbac::require_permission(&state.db, &auth, "sacraments.certificates").await?;
+    let record = sqlx::query_as::<_, SacramentRecord>(
+        "SELECT * FROM sacrament_record WHERE id = $1 AND deleted_at IS NULL"
+    )
+    .bind(id)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+    .ok_or((StatusCode::NOT_FOUND, "Sacrament record not found".to_string()))?;
+    rbac::resolve_parish_id(&auth, Some(record.parish_id))?;
+
+    let language = payload.language.unwrap_or(CertificateLanguage::English);
+    let template = effective_template(&state.db, record.parish_id, record.sacrament_type, language).await?;
+    let today = Utc::now().date_naive();
+
+    let mut tx = state.db.begin().await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    // Read the record again under lock so two first issues at once do not
+    // each allocate a number
+    let record = sqlx::query_as::<_, SacramentRecord>(
+        "SELECT * FROM sacrament_record WHERE id = $1 AND deleted_at IS NULL FOR UPDATE"
+    )
+    .bind(id)
+    .fetch_optional(&mut *tx)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+    .ok_or((StatusCode::NOT_FOUND, "Sacrament record not found".to_string()))?;
+
+    let certificate_number = match record.certificate_number.as_deref().map(str::trim).filter(|n| !n.is_empty()) {
+        Some(number) => number.to_string(),
+        None => {
+            let (parish_code, next): (String, i32) = sqlx::query_as(
+                r#"
+                WITH counter AS (
+                    INSERT INTO certificate_counter (parish_id, year, last_number)
+                    VALUES ($1, $2, 1)
+                    ON CONFLICT (parish_id, year) DO UPDATE SET last_number = certificate_counter.last_number + 1
+                    RETURNING last_number
+                )
+                SELECT p.parish_code, counter.last_number FROM parish p, counter WHERE p.id = $1
+                "#
+            )
+            .bind(record.parish_id)
+            .bind(today.year())
+            .fetch_one(&mut *tx)
+            .await
+            .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+            let number = format!("{}/{}/{:04}", parish_code, today.year(), next);
+            sqlx::query("UPDATE sacrament_record SET certificate_number = $1, updated_at = NOW() WHERE id = $2")
+                .bind(&number)
+                .bind(id)
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+            number
+        }
+    };
+
+    let values = placeholder_values(&state.db, &record, language, &certificate_number, today).await?;
+    let body = fill_template(&template.body, &values);
+
+    let issuance = sqlx::query_as::<_, CertificateIssuance>(
+        r#"
+        INSERT INTO certificate_issuance (
+            sacrament_record_id, parish_id, certificate_number, language, verification_code,
+            title, body, signatory_title, issued_to, purpose, issued_by
+        )
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
+        RETURNING *
+        "#
+    )
+    .bind(id)
+    .bind(record.parish_id)
+    .bind(&certificate_number)
+    .bind(language)
+    .bind(verification_code())
+    .bind(&template.title)
+    .bind(body)
+    .bind(&template.signatory_title)
+    .bind(payload.issued_to)
+    .bind(payload.purpose)
+    .bind(auth.user_id)
+    .fetch_one(&mut *tx)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    tx.commit().await.map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(issuance))
+}
+
+#[derive(Debug, Deserialize)]
+pub struct IssuanceQuery {
+    pub parish_id: Option<Uuid>,
+    pub sacrament_record_id: Option<Uuid>,
+    pub start_date: Option<NaiveDate>,
+    pub end_date: Option<NaiveDate>,
+}
+
+pub async fn list_certificates(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Query(query): Query<IssuanceQuery>,
+) -> Result<Json<Vec<CertificateIssuance>>, (StatusCode, String)> {
+    let parish_id = rbac::resolve_parish_id(&auth, query.parish_id)?;
+
+    let issuances = sqlx::query_as::<_, CertificateIssuance>(
+        r#"
+        SELECT * FROM certificate_issuance
+        WHERE parish_id = $1
+          AND ($2::uuid IS NULL OR sacrament_record_id = $2)
+          AND ($3::date IS NULL OR issued_at::date >= $3)
+          AND ($4::date IS NULL OR issued_at::date <= $4)
+        ORDER BY issued_at DESC
+        "#
+    )
+    .bind(parish_id)
+    .bind(query.sacrament_record_id)
+    .bind(query.start_date)
+    .bind(query.end_date)
+    .fetch_all(&state.db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(issuances))
+}
+
+/// The issuance record, or with `format=pdf` the certificate itself as
+/// worded when it was issued.
+pub async fn get_certificate(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+    format: ExportFormat,
+) -> Result<Response, (StatusCode, String)> {
+    let issuance = fetch_issuance(&state.db, id).await?;
+    rbac::resolve_parish_id(&auth, Some(issuance.parish_id))?;
+
+    match format {
+        ExportFormat::Json => Ok(Json(issuance).into_response()),
+        ExportFormat::Pdf => {
+            if issuance.revoked_at.is_some() {
+                return Err((StatusCode::BAD_REQUEST, "Certificate has been revoked".to_string()));
+            }
+            let document = CertificateDocument {
+                parish_id: issuance.parish_id,
+                title: issuance.title,
+                body: issuance.body,
+                certificate_number: issuance.certificate_number.clone(),
+                signatory_title: issuance.signatory_title,
+                issued_on: format_date(issuance.issued_at.date_naive(), issuance.language),
+                verification_url: verification_url(&issuance.verification_code),
+                verification_code: issuance.verification_code,
+            };
+            let bytes = export::render_certificate(&state.db, &document)
+                .await
+                .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e))?;
+            let file_name = format!("certificate-{}.pdf", issuance.certificate_number.replace(['/', ' '], "-"));
+            Ok((
+                [
+                    (header::CONTENT_TYPE, format.content_type().to_string()),
+                    (header::CONTENT_DISPOSITION, format!("attachment; filename=\"{}\"", file_name)),
+                ],
+                bytes,
+            ).into_response())
+        }
+        _ => Err((StatusCode::BAD_REQUEST, "Certificates are available as PDF only".to_string())),
+    }
+}
+
+pub async fn revoke_certificate(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+    Json(payload): Json<RevokeCertificateRequest>,
+) -> Result<Json<CertificateIssuance>, (StatusCode, String)> {
+    rbac::require_permission(&state.db, &auth, "sacraments.certificates").await?;
+    let issuance = fetch_issuance(&state.db, id).await?;
+    rbac::resolve_parish_id(&auth, Some(issuance.parish_id))?;
+
+    if issuance.revoked_at.is_some() {
+        return Err((StatusCode::BAD_REQUEST, "Certificate is already revoked".to_string()));
+    }
+    if payload.reason.trim().is_empty() {
+        return Err((StatusCode::BAD_REQUEST, "A reason is required".to_string()));
+    }
+
+    let issuance = sqlx::query_as::<_, CertificateIssuance>(
+        r#"
+        UPDATE certificate_issuance
+        SET revoked_at = NOW(), revoked_by = $1, revocation_reason = $2
+        WHERE id = $3
+        RETURNING *
+        "#
+    )
+    .bind(auth.user_id)
+    .bind(payload.reason.trim())
+    .bind(id)
+    .fetch_one(&state.db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(issuance))
+}
+
+/// Public: what the QR code on a certificate points to.
+pub async fn verify_certificate(
+    State(state): State<AppState>,
+    Path(code): Path<String>,
+) -> Result<Json<CertificateVerification>, (StatusCode, String)> {
+    let mut verification = sqlx::query_as::<_, CertificateVerification>(
+        r#"
+        SELECT ci.certificate_number, sr.sacrament_type,
+               CONCAT_WS(' ', m.first_name, m.middle_name, m.last_name) AS holder_name,
+               sr.sacrament_date, p.parish_name, ci.issued_at, ci.revoked_at
+        FROM certificate_issuance ci
+        JOIN sacrament_record sr ON sr.id = ci.sacrament_record_id
+        JOIN member m ON m.id = sr.member_id
+        JOIN parish p ON p.id = ci.parish_id
+        WHERE ci.verification_code = $1
+        "#
+    )
+    .bind(code.trim().to_ascii_uppercase())
+    .fetch_optional(&state.db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+    .ok_or((StatusCode::NOT_FOUND, "No certificate with this verification code".to_string()))?;
+
+    verification.valid = verification.revoked_at.is_none();
+    Ok(Json(verification))
+}
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+async fn fetch_issuance(db: &PgPool, id: Uuid) -> Result<CertificateIssuance, (StatusCode, String)> {
+    sqlx::query_as::<_, CertificateIssuance>("SELECT * FROM certificate_issuance WHERE id = $1")
+        .bind(id)
+        .fetch_optional(db)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+        .ok_or((StatusCode::NOT_FOUND, "Certificate not found".to_string()))
+}
+
+/// The parish's own template, or the built-in one.
+async fn effective_template(
+    db: &PgPool,
+    parish_id: Uuid,
+    sacrament_type: SacramentType,
+    language: CertificateLanguage,
+) -> Result<DefaultCertificateTemplate, (StatusCode, String)> {
+    let saved = sqlx::query_as::<_, CertificateTemplate>(
+        r#"
+        SELECT * FROM certificate_template
+        WHERE parish_id = $1 AND sacrament_type = $2 AND language = $3 AND deleted_at IS NULL
+        "#
+    )
+    .bind(parish_id)
+    .bind(sacrament_type)
+    .bind(language)
+    .fetch_optional(db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    let default = default_template(sacrament_type, language);
+    Ok(match saved {
+        Some(t) => DefaultCertificateTemplate {
+            sacrament_type,
+            language,
+            title: t.title,
+            body: t.body,
+            signatory_title: t.signatory_title.unwrap_or(default.signatory_title),
+        },
+        None => default,
+    })
+}
+
+#[derive(sqlx::FromRow)]
+struct CertificateSubject {
+    first_name: String,
+    last_name: String,
+    full_name: String,
+    date_of_birth: Option<NaiveDate>,
+    parish_name: String,
+    diocese_name: String,
+    spouse_name: Option<String>,
+}
+
+async fn placeholder_values(
+    db: &PgPool,
+    record: &SacramentRecord,
+    language: CertificateLanguage,
+    certificate_number: &str,
+    issue_date: NaiveDate,
+) -> Result<Vec<(&'static str, String)>, (StatusCode, String)> {
+    let subject = sqlx::query_as::<_, CertificateSubject>(
+        r#"
+        SELECT m.first_name, m.last_name, CONCAT_WS(' ', m.first_name, m.middle_name, m.last_name) AS full_name,
+               m.date_of_birth, p.parish_name, d.diocese_name,
+               CONCAT_WS(' ', s.first_name, s.middle_name, s.last_name) AS spouse_name
+        FROM member m
+        JOIN parish p ON p.id = $2
+        JOIN diocese d ON d.id = p.diocese_id
+        LEFT JOIN member s ON s.id = $3
+        WHERE m.id = $1
+        "#
+    )
+    .bind(record.member_id)
+    .bind(record.parish_id)
+    .bind(record.spouse_id)
+    .fetch_optional(db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+    .ok_or((StatusCode::NOT_FOUND, "Member not found".to_string()))?;
+
+    let godparents = [record.godparent_1_name.as_deref(), record.godparent_2_name.as_deref()]
+        .into_iter()
+        .flatten()
+        .filter(|n| !n.trim().is_empty())
+        .collect::<Vec<_>>()
+        .join(" & ");
+    let spouse = record.spouse_name.clone()
+        .or(subject.spouse_name.filter(|n| !n.is_empty()))
+        .unwrap_or_default();
+    let notations: Vec<String> = sqlx::query_scalar(
+        "SELECT notation_text FROM baptism_notation WHERE baptism_record_id = $1 AND deleted_at IS NULL ORDER BY notation_date, created_at"
+    )
+    .bind(record.id)
+    .fetch_all(db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(vec![
+        ("full_name", subject.full_name),
+        ("first_name", subject.first_name),
+        ("last_name", subject.last_name),
+        ("date_of_birth", subject.date_of_birth.map(|d| format_date(d, language)).unwrap_or_default()),
+        ("sacrament", sacrament_name(record.sacrament_type, language).to_string()),
+        ("sacrament_date", format_date(record.sacrament_date, language)),
+        ("minister", record.officiating_minister.clone().unwrap_or_default()),
+        ("church", record.church_name.clone().unwrap_or_else(|| subject.parish_name.clone())),
+        ("parish", subject.parish_name),
+        ("diocese", subject.diocese_name),
+        ("godparents", godparents),
+        ("spouse", spouse),
+        ("witnesses", record.witnesses.clone().unwrap_or_default()),
+        ("notations", notations.join("; ")),
+        ("register", register::reference(db, record).await?.unwrap_or_default()),
+        ("certificate_number", certificate_number.to_string()),
+        ("issue_date", format_date(issue_date, language)),
+    ])
+}
+
+/// Substitutes placeholders line by line, dropping lines that only had
+/// blank values to show.
+fn fill_template(body: &str, values: &[(&str, String)]) -> String {
+    body.lines()
+        .filter_map(|line| {
+            let mut out = line.to_string();
+            let mut used = 0;
+            let mut filled = 0;
+            for (key, value) in values {
+                let token = format!("{{{}}}", key);
+                if out.contains(&token) {
+                    used += 1;
+                    if !value.trim().is_empty() {
+                        filled += 1;
+                    }
+                    out = out.replace(&token, value.trim());
+                }
+            }
+            (used == 0 || filled > 0).then_some(out)
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn unknown_placeholder(body: &str) -> Option<String> {
+    body.split('{')
+        .skip(1)
+        .filter_map(|rest| rest.split_once('}').map(|(key, _)| key))
+        .find(|key| !PLACEHOLDERS.contains(key))
+        .map(str::to_string)
+}
+
+/// Twelve characters from an alphabet without look-alikes (0/O, 1/I),
+/// grouped in fours.
+fn verification_code() -> String {
+    const ALPHABET: &[u8] = b"ABCDEFGHJKLMNPQRSTUVWXYZ23456789";
+    let chars: Vec<char> = Uuid::new_v4().as_bytes()
+        .iter()
+        .take(12)
+        .map(|b| ALPHABET[(*b as usize) % ALPHABET.len()] as char)
+        .collect();
+    chars.chunks(4).map(|c| c.iter().collect::<String>()).collect::<Vec<_>>().join("-")
+}
+
+/// Where the QR code points. `PUBLIC_BASE_URL` is the address the API is
+/// reachable at from outside.
+fn verification_url(code: &str) -> String {
+    let base = std::env::var("PUBLIC_BASE_URL").unwrap_or_else(|_| "http://localhost:3000".to_string());
+    format!("{}/certificates/verify/{}", base.trim_end_matches('/'), code)
+}
+
+fn format_date(date: NaiveDate, language: CertificateLanguage) -> String {
+    const ENGLISH: [&str; 12] = [
+        "January", "February", "March", "April", "May", "June",
+        "July", "August", "September", "October", "November", "December",
+    ];
+    // Genitive, as in "die 5 Martii 2026"
+    const LATIN: [&str; 12] = [
+        "Ianuarii", "Februarii", "Martii", "Aprilis", "Maii", "Iunii",
+        "Iulii", "Augusti", "Septembris", "Octobris", "Novembris", "Decembris",
+    ];
+    const SWAHILI: [&str; 12] = [
+        "Januari", "Februari", "Machi", "Aprili", "Mei", "Juni",
+        "Julai", "Agosti", "Septemba", "Oktoba", "Novemba", "Desemba",
+    ];
+    let months = match language {
+        CertificateLanguage::English => ENGLISH,
+        CertificateLanguage::Latin => LATIN,
+        CertificateLanguage::Swahili => SWAHILI,
+    };
+    format!("{} {} {}", date.day(), months[date.month0() as usize], date.year())
+}
+
+fn sacrament_name(sacrament: SacramentType, language: CertificateLanguage) -> &'static str {
+    use CertificateLanguage::*;
+    use SacramentType::*;
+    match (sacrament, language) {
+        (Baptism, English) => "Baptism",
+        (Baptism, Latin) => "Baptismi",
+        (Baptism, Swahili) => "Ubatizo",
+        (FirstCommunion, English) => "First Holy Communion",
+        (FirstCommunion, Latin) => "Primae Communionis",
+        (FirstCommunion, Swahili) => "Komunio ya Kwanza",
+        (Confirmation, English) => "Confirmation",
+        (Confirmation, Latin) => "Confirmationis",
+        (Confirmation, Swahili) => "Kipaimara",
+        (Marriage, English) => "Matrimony",
+        (Marriage, Latin) => "Matrimonii",
+        (Marriage, Swahili) => "Ndoa",
+        (HolyOrders, English) => "Holy Orders",
+        (HolyOrders, Latin) => "Ordinis",
+        (HolyOrders, Swahili) => "Daraja Takatifu",
+        (AnointingOfSick, English) => "Anointing of the Sick",
+        (AnointingOfSick, Latin) => "Unctionis Infirmorum",
+        (AnointingOfSick, Swahili) => "Mpako wa Wagonjwa",
+    }
+}
+
+fn default_template(sacrament: SacramentType, language: CertificateLanguage) -> DefaultCertificateTemplate {
+    let (title_prefix, signatory, intro, sponsors, marriage, notes, entry, closing) = match language {
+        CertificateLanguage::English => (
+            "Certificate of",
+            "Parish Priest",
+            "This is to certify that\n{full_name}\nborn on {date_of_birth}\nreceived the Sacrament of {sacrament}\non {sacrament_date}\nat {church}\nin the Diocese of {diocese}\nthe minister being {minister}",
+            "Sponsors: {godparents}",
+            "Spouse: {spouse}\nWitnesses: {witnesses}",
+            "Notes: {notations}",
+            "Register: {register}",
+            "as appears in the register of this parish.",
+        ),
+        CertificateLanguage::Latin => (
+            "Testimonium",
+            "Parochus",
+            "Testor\n{full_name}\ndie natali {date_of_birth}\nsacramentum {sacrament} recepisse\ndie {sacrament_date}\nin ecclesia {church}\ndioecesis {diocese}\nministrante {minister}",
+            "Patrini: {godparents}",
+            "Coniux: {spouse}\nTestes: {witnesses}",
+            "Adnotationes: {notations}",
+            "Liber: {register}",
+            "prout in libro huius paroeciae adnotatum est.",
+        ),
+        CertificateLanguage::Swahili => (
+            "Cheti cha",
+            "Paroko",
+            "Hii ni kuthibitisha kwamba\n{full_name}\naliyezaliwa tarehe {date_of_birth}\nalipokea Sakramenti ya {sacrament}\ntarehe {sacrament_date}\nkatika {church}\nJimbo la {diocese}\nkwa mhudumu {minister}",
+            "Wasimamizi: {godparents}",
+            "Mwenzi: {spouse}\nMashahidi: {witnesses}",
+            "Maelezo ya pembeni: {notations}",
+            "Kitabu: {register}",
+            "kama ilivyoandikwa katika kitabu cha parokia hii.",
+        ),
+    };
+
+    let mut body = intro.to_string();
+    match sacrament {
+        SacramentType::Baptism => {
+            body.push('\n');
+            body.push_str(sponsors);
+            body.push('\n');
+            body.push_str(notes);
+        }
+        SacramentType::Confirmation => {
+            body.push('\n');
+            body.push_str(sponsors);
+        }
+        SacramentType::Marriage => {
+            body.push('\n');
+            body.push_str(marriage);
+        }
+        _ => {}
+    }
+    body.push('\n');
+    body.push_str(entry);
+    body.push('\n');
+    body.push_str(closing);
+
+    DefaultCertificateTemplate {
+        sacrament_type: sacrament,
+        language,
+        title: format!("{} {}", title_prefix, sacrament_name(sacrament, language)),
+        body,
+        signatory_title: signatory.to_string(),
+    }
+}
@@ -0,0 +1,458 @@
+use axum::{
+    extract::{Path, State, Query},
+    http::StatusCode,
+    Json,
+};
+use uuid::Uuid;
+use serde::Deserialize;
+use chrono::{NaiveDate, Utc};
+use rust_decimal::Decimal;
+use sqlx::{postgres::PgPool, PgConnection, Postgres};
+use crate::{
+    AppState,
+    models::currency::{
+        ExchangeRate, SaveExchangeRateRequest, CurrencyConversion, CreateConversionRequest,
+        CurrencyHolding, CurrencyHoldings,
+    },
+    models::user::UserRole,
+    handlers::auth::AuthUser,
+    handlers::rbac,
+};
+
+// ============================================================================
+// Exchange Rates
+// ============================================================================
+
+#[derive(Debug, Deserialize)]
+pub struct ExchangeRateQuery {
+    pub diocese_id: Option<Uuid>,
+    pub currency_code: Option<String>,
+    pub start_date: Option<NaiveDate>,
+    pub end_date: Option<NaiveDate>,
+}
+
+pub async fn list_exchange_rates(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Query(query): Query<ExchangeRateQuery>,
+) -> Result<Json<Vec<ExchangeRate>>, (StatusCode, String)> {
+    // Parish users see the rates of the diocese their parish belongs to
+    let diocese_id = match auth.parish_id {
+        Some(parish_id) if auth.role != UserRole::SuperAdmin => parish_diocese_id(&state.db, parish_id).await?,
+        _ => rbac::resolve_diocese_id(&state.db, &auth, query.diocese_id).await?,
+    };
+
+    let rates = sqlx::query_as::<_, ExchangeRate>(
+        r#"
+        SELECT * FROM exchange_rate
+        WHERE diocese_id = $1
+          AND ($2::text IS NULL OR currency_code = upper($2))
+          AND ($3::date IS NULL OR rate_date >= $3)
+          AND ($4::date IS NULL OR rate_date <= $4)
+        ORDER BY currency_code, rate_date DESC
+        "#
+    )
+    .bind(diocese_id)
+    .bind(query.currency_code)
+    .bind(query.start_date)
+    .bind(query.end_date)
+    .fetch_all(&state.db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(rates))
+}
+
+pub async fn save_exchange_rate(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Json(payload): Json<SaveExchangeRateRequest>,
+) -> Result<Json<ExchangeRate>, (StatusCode, String)> {
+    rbac::require_role(&auth, &[UserRole::SuperAdmin])?;
+    let diocese_id = rbac::resolve_diocese_id(&state.db, &auth, payload.diocese_id).await?;
+
+    let currency_code = currency_code(&payload.currency_code)?;
+    if currency_code == diocese_currency(&state.db, diocese_id).await? {
+        return Err((StatusCode::BAD_REQUEST, "The base currency does not need an exchange rate".to_string()));
+    }
+    if payload.rate <= Decimal::ZERO {
+        return Err((StatusCode::BAD_REQUEST, "Exchange rate must be positive".to_string()));
+    }
+
+    let rate = sqlx::query_as::<_, ExchangeRate>(
+        r#"
+        INSERT INTO exchange_rate (diocese_id, currency_code, rate_date, rate, source, created_by)
+        VALUES ($1, $2, $3, $4, $5, $6)
+        ON CONFLICT (diocese_id, currency_code, rate_date)
+        DO UPDATE SET rate = EXCLUDED.rate, source = EXCLUDED.source, created_by = EXCLUDED.created_by
+        RETURNING *
+        "#
+    )
+    .bind(diocese_id)
+    .bind(currency_code)
+    .bind(payload.rate_date)
+    .bind(payload.rate)
+    .bind(payload.source)
+    .bind(auth.user_id)
+    .fetch_one(&state.db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(rate))
+}
+
+pub async fn delete_exchange_rate(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> Result<StatusCode, (StatusCode, String)> {
+    rbac::require_role(&auth, &[UserRole::SuperAdmin])?;
+
+    let diocese_id: Uuid = sqlx::query_scalar("SELECT diocese_id FROM exchange_rate WHERE id = $1")
+        .bind(id)
+        .fetch_optional(&state.db)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+        .ok_or((StatusCode::NOT_FOUND, "Exchange rate not found".to_string()))?;
+    rbac::resolve_diocese_id(&state.db, &auth, Some(diocese_id)).await?;
+
+    sqlx::query("DELETE FROM exchange_rate WHERE id = $1")
+        .bind(id)
+        .execute(&state.db)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+// ============================================================================
+// Foreign Currency Holdings
+// ============================================================================
+
+#[derive(Debug, Deserialize)]
+pub struct HoldingsQuery {
+    pub parish_id: Option<Uuid>,
+    pub as_of: Option<NaiveDate>,
+}
+
+/// Foreign money on hand at its book value and revalued at the latest rate.
+pub async fn get_currency_holdings(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Query(query): Query<HoldingsQuery>,
+) -> Result<Json<CurrencyHoldings>, (StatusCode, String)> {
+    let parish_id = rbac::resolve_parish_id(&auth, query.parish_id)?;
+    let as_of = query.as_of.unwrap_or_else(|| Utc::now().date_naive());
+
+    let mut holdings = holdings(&state.db, parish_id, as_of).await?;
+    for holding in &mut holdings {
+        holding.current_rate = rate_on(&state.db, parish_id, &holding.currency_code, as_of).await?;
+        if let Some(rate) = holding.current_rate {
+            let value = (holding.foreign_balance * rate).round_dp(2);
+            holding.current_value = Some(value);
+            holding.unrealized_gain_loss = Some(value - holding.book_value);
+        }
+    }
+
+    Ok(Json(CurrencyHoldings {
+        base_currency: base_currency(&state.db, parish_id).await?,
+        as_of,
+        holdings,
+    }))
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ConversionQuery {
+    pub parish_id: Option<Uuid>,
+    pub currency_code: Option<String>,
+}
+
+pub async fn list_conversions(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Query(query): Query<ConversionQuery>,
+) -> Result<Json<Vec<CurrencyConversion>>, (StatusCode, String)> {
+    let parish_id = rbac::resolve_parish_id(&auth, query.parish_id)?;
+
+    let conversions = sqlx::query_as::<_, CurrencyConversion>(
+        r#"
+        SELECT * FROM currency_conversion
+        WHERE parish_id = $1 AND ($2::text IS NULL OR currency_code = upper($2))
+        ORDER BY conversion_date DESC, created_at DESC
+        "#
+    )
+    .bind(parish_id)
+    .bind(query.currency_code)
+    .fetch_all(&state.db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(conversions))
+}
+
+/// Records foreign money exchanged into the base currency. The portion
+/// converted leaves the books at its average book value; the difference
+/// from what was received is posted as exchange gain income or an
+/// approved, paid exchange loss voucher.
+pub async fn create_conversion(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Json(payload): Json<CreateConversionRequest>,
+) -> Result<Json<CurrencyConversion>, (StatusCode, String)> {
+    rbac::require_finance(&auth)?;
+    let parish_id = rbac::resolve_parish_id(&auth, Some(payload.parish_id))?;
+    let code = currency_code(&payload.currency_code)?;
+
+    if payload.foreign_amount <= Decimal::ZERO || payload.base_amount <= Decimal::ZERO {
+        return Err((StatusCode::BAD_REQUEST, "Amounts must be positive".to_string()));
+    }
+
+    let mut tx = state.db.begin().await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    // Lock the parish so two conversions cannot both spend the same
+    // foreign balance
+    sqlx::query("SELECT id FROM parish WHERE id = $1 FOR UPDATE")
+        .bind(parish_id)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+    let holding = holdings(&mut *tx, parish_id, payload.conversion_date).await?
+        .into_iter()
+        .find(|h| h.currency_code == code)
+        .filter(|h| h.foreign_balance >= payload.foreign_amount)
+        .ok_or((StatusCode::BAD_REQUEST, format!("Not enough {} on hand on {}", code, payload.conversion_date)))?;
+
+    let book_value = if payload.foreign_amount == holding.foreign_balance {
+        holding.book_value
+    } else {
+        (holding.book_value * payload.foreign_amount / holding.foreign_balance).round_dp(2)
+    };
+    let gain_loss = payload.base_amount - book_value;
+    let description = format!("Realized exchange difference on {} {} converted", code, payload.foreign_amount);
+
+    let mut income_transaction_id = None;
+    let mut expense_voucher_id = None;
+    if gain_loss > Decimal::ZERO {
+        income_transaction_id = Some(sqlx::query_scalar::<_, Uuid>(
+            r#"
+            INSERT INTO income_transaction (
+                parish_id, category, amount, payment_method, transaction_date, description,
+                reference_number, received_by
+            )
+            VALUES ($1, 'EXCHANGE_GAIN', $2, 'BANK_TRANSFER', $3, $4, $5, $6)
+            RETURNING id
+            "#
+        )
+        .bind(parish_id)
+        .bind(gain_loss)
+        .bind(payload.conversion_date)
+        .bind(&description)
+        .bind(&payload.reference_number)
+        .bind(auth.user_id)
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?);
+    } else if gain_loss < Decimal::ZERO {
+        expense_voucher_id = Some(sqlx::query_scalar::<_, Uuid>(
+            r#"
+            INSERT INTO expense_voucher (
+                parish_id, category, amount, payment_method, payee_name, expense_date, description,
+                reference_number, requested_by, approval_status, approved_by, approved_at, paid, paid_at
+            )
+            VALUES ($1, 'EXCHANGE_LOSS', $2, 'BANK_TRANSFER', 'Currency exchange', $3, $4, $5, $6, 'APPROVED', $6, NOW(), TRUE, NOW())
+            RETURNING id
+            "#
+        )
+        .bind(parish_id)
+        .bind(-gain_loss)
+        .bind(payload.conversion_date)
+        .bind(&description)
+        .bind(&payload.reference_number)
+        .bind(auth.user_id)
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?);
+    }
+
+    let conversion = sqlx::query_as::<_, CurrencyConversion>(
+        r#"
+        INSERT INTO currency_conversion (
+            parish_id, currency_code, conversion_date, foreign_amount, base_amount, exchange_rate,
+            book_value, gain_loss, income_transaction_id, expense_voucher_id, reference_number, notes, created_by
+        )
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13)
+        RETURNING *
+        "#
+    )
+    .bind(parish_id)
+    .bind(&code)
+    .bind(payload.conversion_date)
+    .bind(payload.foreign_amount)
+    .bind(payload.base_amount)
+    .bind((payload.base_amount / payload.foreign_amount).round_dp(6))
+    .bind(book_value)
+    .bind(gain_loss)
+    .bind(income_transaction_id)
+    .bind(expense_voucher_id)
+    .bind(payload.reference_number)
+    .bind(payload.notes)
+    .bind(auth.user_id)
+    .fetch_one(&mut *tx)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    tx.commit().await.map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(conversion))
+}
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+/// An amount as entered, with the base currency amount that is booked.
+/// The foreign fields are all `None` for base currency entries.
+pub struct BaseAmount {
+    pub currency_code: Option<String>,
+    pub original_amount: Option<Decimal>,
+    pub exchange_rate: Option<Decimal>,
+    pub amount: Decimal,
+}
+
+/// Converts an amount in `currency_code` to the parish's base currency,
+/// using the given rate or else the diocese's latest rate on or before
+/// `date`.
+pub async fn to_base(
+    conn: &mut PgConnection,
+    parish_id: Uuid,
+    currency: Option<&str>,
+    exchange_rate: Option<Decimal>,
+    amount: Decimal,
+    date: NaiveDate,
+) -> Result<BaseAmount, (StatusCode, String)> {
+    let base = BaseAmount { currency_code: None, original_amount: None, exchange_rate: None, amount };
+    let Some(currency) = currency.filter(|c| !c.trim().is_empty()) else {
+        return Ok(base);
+    };
+    let code = currency_code(currency)?;
+    if code == base_currency(&mut *conn, parish_id).await? {
+        return Ok(base);
+    }
+
+    let rate = match exchange_rate {
+        Some(rate) if rate <= Decimal::ZERO => {
+            return Err((StatusCode::BAD_REQUEST, "Exchange rate must be positive".to_string()));
+        }
+        Some(rate) => rate,
+        None => rate_on(&mut *conn, parish_id, &code, date).await?.ok_or((
+            StatusCode::BAD_REQUEST,
+            format!("No {} exchange rate on or before {}; give the rate used", code, date),
+        ))?,
+    };
+
+    Ok(BaseAmount {
+        currency_code: Some(code),
+        original_amount: Some(amount),
+        exchange_rate: Some(rate),
+        amount: (amount * rate).round_dp(2),
+    })
+}
+
+/// The diocesan currency all of a parish's books are kept in.
+pub async fn base_currency<'e, E>(db: E, parish_id: Uuid) -> Result<String, (StatusCode, String)>
+where
+    E: sqlx::Executor<'e, Database = Postgres>,
+{
+    sqlx::query_scalar(
+        "SELECT COALESCE(d.currency_code, 'TZS') FROM parish p JOIN diocese d ON d.id = p.diocese_id WHERE p.id = $1"
+    )
+    .bind(parish_id)
+    .fetch_optional(db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+    .ok_or((StatusCode::NOT_FOUND, "Parish not found".to_string()))
+}
+
+async fn diocese_currency(db: &PgPool, diocese_id: Uuid) -> Result<String, (StatusCode, String)> {
+    sqlx::query_scalar("SELECT COALESCE(currency_code, 'TZS') FROM diocese WHERE id = $1")
+        .bind(diocese_id)
+        .fetch_optional(db)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+        .ok_or((StatusCode::NOT_FOUND, "Diocese not found".to_string()))
+}
+
+async fn rate_on<'e, E>(db: E, parish_id: Uuid, currency_code: &str, date: NaiveDate) -> Result<Option<Decimal>, (StatusCode, String)>
+where
+    E: sqlx::Executor<'e, Database = Postgres>,
+{
+    sqlx::query_scalar(
+        r#"
+        SELECT r.rate FROM exchange_rate r
+        JOIN parish p ON p.diocese_id = r.diocese_id
+        WHERE p.id = $1 AND r.currency_code = $2 AND r.rate_date <= $3
+        ORDER BY r.rate_date DESC
+        LIMIT 1
+        "#
+    )
+    .bind(parish_id)
+    .bind(currency_code)
+    .bind(date)
+    .fetch_optional(db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+}
+
+/// Foreign receipts less paid foreign vouchers and conversions, per
+/// currency, with the base amounts they were booked at.
+async fn holdings<'e, E>(db: E, parish_id: Uuid, as_of: NaiveDate) -> Result<Vec<CurrencyHolding>, (StatusCode, String)>
+where
+    E: sqlx::Executor<'e, Database = Postgres>,
+{
+    sqlx::query_as::<_, CurrencyHolding>(
+        r#"
+        WITH flows AS (
+            SELECT currency_code, original_amount AS foreign_amount, amount AS book
+            FROM income_transaction
+            WHERE parish_id = $1 AND currency_code IS NOT NULL AND deleted_at IS NULL AND transaction_date <= $2
+            UNION ALL
+            SELECT currency_code, -original_amount, -amount
+            FROM expense_voucher
+            WHERE parish_id = $1 AND currency_code IS NOT NULL AND deleted_at IS NULL AND paid = TRUE AND expense_date <= $2
+            UNION ALL
+            SELECT currency_code, -foreign_amount, -book_value
+            FROM currency_conversion
+            WHERE parish_id = $1 AND conversion_date <= $2
+        )
+        SELECT currency_code, SUM(foreign_amount) AS foreign_balance, SUM(book) AS book_value
+        FROM flows
+        GROUP BY currency_code
+        HAVING SUM(foreign_amount) <> 0
+        ORDER BY currency_code
+        "#
+    )
+    .bind(parish_id)
+    .bind(as_of)
+    .fetch_all(db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+}
+
+fn currency_code(code: &str) -> Result<String, (StatusCode, String)> {
+    let code = code.trim().to_ascii_uppercase();
+    if code.len() == 3 && code.chars().all(|c| c.is_ascii_uppercase()) {
+        Ok(code)
+    } else {
+        Err((StatusCode::BAD_REQUEST, format!("'{}' is not a three-letter currency code", code)))
+    }
+}
+
+async fn parish_diocese_id(db: &PgPool, parish_id: Uuid) -> Result<Uuid, (StatusCode, String)> {
+    sqlx::query_scalar("SELECT diocese_id FROM parish WHERE id = $1 AND deleted_at IS NULL")
+        .bind(parish_id)
+        .fetch_optional(db)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+        .ok_or((StatusCode::NOT_FOUND, "Parish not found".to_string()))
+}
@@ -5,7 +5,7 @@ use axum::{
 };
 use uuid::Uuid;
 use serde::Deserialize;
-use crate::{AppState, models::family::{Family, CreateFamilyRequest, UpdateFamilyRequest}, handlers::auth::AuthUser, handlers::rbac};
+use crate::{AppState, models::family::{Family, CreateFamilyRequest, UpdateFamilyRequest, AssignFamilyHeadRequest}, handlers::auth::AuthUser, handlers::rbac};
 
 #[derive(Debug, Deserialize)]
 pub struct FamilyQuery {
@@ -138,6 +138,64 @@ pub async fn update_family(
     Ok(Json(family))
 }
 
+/// Makes a living member the family's head. Any other living head is
+/// recorded as an ordinary member; a deceased head keeps their role.
+pub async fn assign_family_head(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+    Json(payload): Json<AssignFamilyHeadRequest>,
+) -> Result<Json<Family>, (StatusCode, String)> {
+    rbac::require_write(&auth)?;
+    let parish_id: Uuid = sqlx::query_scalar("SELECT parish_id FROM family WHERE id = $1 AND deleted_at IS NULL")
+        .bind(id)
+        .fetch_optional(&state.db)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+        .ok_or((StatusCode::NOT_FOUND, "Family not found".to_string()))?;
+    rbac::resolve_parish_id(&auth, Some(parish_id))?;
+
+    let eligible: bool = sqlx::query_scalar(
+        "SELECT EXISTS (SELECT 1 FROM member WHERE id = $1 AND family_id = $2 AND deleted_at IS NULL AND date_of_death IS NULL)"
+    )
+    .bind(payload.member_id)
+    .bind(id)
+    .fetch_one(&state.db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+    if !eligible {
+        return Err((StatusCode::BAD_REQUEST, "The head must be a living member of the family".to_string()));
+    }
+
+    let mut tx = state.db.begin().await.map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    sqlx::query(
+        r#"
+        UPDATE member SET family_role = CASE WHEN id = $2 THEN 'HEAD'::family_role ELSE 'MEMBER'::family_role END, updated_at = NOW()
+        WHERE family_id = $1 AND deleted_at IS NULL AND date_of_death IS NULL
+          AND (id = $2 OR family_role = 'HEAD')
+        "#
+    )
+    .bind(id)
+    .bind(payload.member_id)
+    .execute(&mut *tx)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    let family = sqlx::query_as::<_, Family>(
+        "UPDATE family SET head_of_family_id = $1, updated_at = NOW() WHERE id = $2 RETURNING *"
+    )
+    .bind(payload.member_id)
+    .bind(id)
+    .fetch_one(&mut *tx)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    tx.commit().await.map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(family))
+}
+
 pub async fn delete_family(
     auth: AuthUser,
     State(state): State<AppState>,
@@ -0,0 +1,211 @@
+use axum::{
+    extract::{Path, State, Query},
+    http::StatusCode,
+    Json,
+};
+use uuid::Uuid;
+use serde::Deserialize;
+use rust_decimal::Decimal;
+use sqlx::{PgConnection, Postgres};
+use crate::{AppState, models::fund::{Fund, FundType, CreateFundRequest, UpdateFundRequest}, handlers::auth::AuthUser, handlers::rbac};
+
+#[derive(Debug, Deserialize)]
+pub struct FundQuery {
+    pub parish_id: Option<Uuid>,
+}
+
+pub async fn list_funds(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Query(query): Query<FundQuery>,
+) -> Result<Json<Vec<Fund>>, (StatusCode, String)> {
+    let parish_id = rbac::resolve_parish_id(&auth, query.parish_id)?;
+
+    let funds = sqlx::query_as::<_, Fund>(
+        "SELECT * FROM fund WHERE parish_id = $1 AND deleted_at IS NULL ORDER BY fund_type, fund_name"
+    )
+    .bind(parish_id)
+    .fetch_all(&state.db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(funds))
+}
+
+pub async fn get_fund(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<Fund>, (StatusCode, String)> {
+    let fund = fetch_fund(&state.db, id).await?;
+    rbac::resolve_parish_id(&auth, Some(fund.parish_id))?;
+
+    Ok(Json(fund))
+}
+
+pub async fn create_fund(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Json(payload): Json<CreateFundRequest>,
+) -> Result<Json<Fund>, (StatusCode, String)> {
+    rbac::require_finance(&auth)?;
+    let parish_id = rbac::resolve_parish_id(&auth, Some(payload.parish_id))?;
+
+    let fund = sqlx::query_as::<_, Fund>(
+        r#"
+        INSERT INTO fund (parish_id, fund_code, fund_name, fund_type, purpose, opening_balance, created_by)
+        VALUES ($1, $2, $3, $4, $5, $6, $7)
+        RETURNING *
+        "#
+    )
+    .bind(parish_id)
+    .bind(payload.fund_code)
+    .bind(payload.fund_name)
+    .bind(payload.fund_type)
+    .bind(payload.purpose)
+    .bind(payload.opening_balance.unwrap_or(Decimal::ZERO))
+    .bind(auth.user_id)
+    .fetch_one(&state.db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(fund))
+}
+
+pub async fn update_fund(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+    Json(payload): Json<UpdateFundRequest>,
+) -> Result<Json<Fund>, (StatusCode, String)> {
+    rbac::require_finance(&auth)?;
+    let existing = fetch_fund(&state.db, id).await?;
+    rbac::resolve_parish_id(&auth, Some(existing.parish_id))?;
+
+    let fund = sqlx::query_as::<_, Fund>(
+        r#"
+        UPDATE fund SET
+            fund_name = $1,
+            fund_type = $2,
+            purpose = $3,
+            opening_balance = $4,
+            is_active = $5,
+            updated_at = NOW()
+        WHERE id = $6
+        RETURNING *
+        "#
+    )
+    .bind(payload.fund_name.unwrap_or(existing.fund_name))
+    .bind(payload.fund_type.unwrap_or(existing.fund_type))
+    .bind(payload.purpose.or(existing.purpose))
+    .bind(payload.opening_balance.unwrap_or(existing.opening_balance))
+    .bind(payload.is_active.unwrap_or(existing.is_active))
+    .bind(id)
+    .fetch_one(&state.db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(fund))
+}
+
+pub async fn delete_fund(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> Result<StatusCode, (StatusCode, String)> {
+    rbac::require_admin(&auth)?;
+    let fund = fetch_fund(&state.db, id).await?;
+    rbac::resolve_parish_id(&auth, Some(fund.parish_id))?;
+
+    sqlx::query("UPDATE fund SET deleted_at = NOW() WHERE id = $1 AND deleted_at IS NULL")
+        .bind(id)
+        .execute(&state.db)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+async fn fetch_fund<'e, E>(db: E, id: Uuid) -> Result<Fund, (StatusCode, String)>
+where
+    E: sqlx::Executor<'e, Database = Postgres>,
+{
+    sqlx::query_as::<_, Fund>("SELECT * FROM fund WHERE id = $1 AND deleted_at IS NULL")
+        .bind(id)
+        .fetch_optional(db)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+        .ok_or((StatusCode::NOT_FOUND, "Fund not found".to_string()))
+}
+
+/// Loads an active fund and checks that it belongs to the given parish.
+pub async fn fund_for_parish<'e, E>(db: E, fund_id: Uuid, parish_id: Uuid) -> Result<Fund, (StatusCode, String)>
+where
+    E: sqlx::Executor<'e, Database = Postgres>,
+{
+    let fund = fetch_fund(db, fund_id).await?;
+    check_fund_parish(&fund, parish_id)?;
+    Ok(fund)
+}
+
+fn check_fund_parish(fund: &Fund, parish_id: Uuid) -> Result<(), (StatusCode, String)> {
+    if fund.parish_id != parish_id {
+        return Err((StatusCode::BAD_REQUEST, "Fund does not belong to this parish".to_string()));
+    }
+    if !fund.is_active {
+        return Err((StatusCode::BAD_REQUEST, format!("Fund '{}' is closed", fund.fund_name)));
+    }
+    Ok(())
+}
+
+/// Money still available in a fund: opening balance plus all income tagged to it,
+/// less every voucher that has not been rejected or cancelled (pending vouchers
+/// are treated as committed so two requests cannot spend the same shillings).
+pub async fn available_balance(conn: &mut PgConnection, fund: &Fund) -> Result<Decimal, (StatusCode, String)> {
+    let (income, committed): (Decimal, Decimal) = sqlx::query_as(
+        r#"
+        SELECT
+            (SELECT COALESCE(SUM(amount), 0) FROM income_transaction
+             WHERE fund_id = $1 AND deleted_at IS NULL),
+            (SELECT COALESCE(SUM(amount), 0) FROM expense_voucher
+             WHERE fund_id = $1 AND deleted_at IS NULL
+               AND COALESCE(approval_status, 'PENDING') NOT IN ('REJECTED', 'CANCELLED'))
+        "#
+    )
+    .bind(fund.id)
+    .fetch_one(conn)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(fund.opening_balance + income - committed)
+}
+
+/// Refuses an expense that would take a restricted fund below zero.
+/// Unrestricted funds may go negative; the parish council deals with that.
+/// The fund row stays locked until the caller's transaction ends, so run
+/// this in the transaction that inserts the voucher: concurrent vouchers
+/// on the fund are then checked one after another.
+pub async fn guard_expense(conn: &mut PgConnection, parish_id: Uuid, fund_id: Uuid, amount: Decimal) -> Result<(), (StatusCode, String)> {
+    let fund = sqlx::query_as::<_, Fund>("SELECT * FROM fund WHERE id = $1 AND deleted_at IS NULL FOR UPDATE")
+        .bind(fund_id)
+        .fetch_optional(&mut *conn)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+        .ok_or((StatusCode::NOT_FOUND, "Fund not found".to_string()))?;
+    check_fund_parish(&fund, parish_id)?;
+    if fund.fund_type != FundType::Restricted {
+        return Ok(());
+    }
+
+    let available = available_balance(conn, &fund).await?;
+    if amount > available {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            format!(
+                "Expense of {} would overdraw restricted fund '{}' (available: {})",
+                amount, fund.fund_name, available
+            ),
+        ));
+    }
+    Ok(())
+}
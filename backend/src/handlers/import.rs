@@ -107,7 +107,7 @@ pub async fn import_members(
                     }
                 };
 
-                let member_code = row.get(0).map(get_str).unwrap_or_default();
+                let member_code = row.first().map(get_str).unwrap_or_default();
                 let first_name = row.get(1).map(get_str).unwrap_or_default();
                 let last_name = row.get(2).map(get_str).unwrap_or_default();
 
@@ -182,7 +182,7 @@ pub async fn import_clusters(
     let rows = parse_file_rows(&data, &file_name)?;
 
     for (i, row) in rows.iter().enumerate() {
-        let cluster_code = row.get(0).cloned().unwrap_or_default();
+        let cluster_code = row.first().cloned().unwrap_or_default();
         let cluster_name = row.get(1).cloned().unwrap_or_default();
         let location_description = row.get(2).cloned().unwrap_or_default();
         let leader_name = row.get(3).cloned().unwrap_or_default();
@@ -249,7 +249,7 @@ pub async fn import_sccs(
     let rows = parse_file_rows(&data, &file_name)?;
 
     for (i, row) in rows.iter().enumerate() {
-        let scc_code = row.get(0).cloned().unwrap_or_default();
+        let scc_code = row.first().cloned().unwrap_or_default();
         let scc_name = row.get(1).cloned().unwrap_or_default();
         let cluster_code = row.get(2).cloned().unwrap_or_default();
         let patron_saint = row.get(3).cloned().unwrap_or_default();
@@ -332,7 +332,7 @@ pub async fn import_families(
     let rows = parse_file_rows(&data, &file_name)?;
 
     for (i, row) in rows.iter().enumerate() {
-        let family_code = row.get(0).cloned().unwrap_or_default();
+        let family_code = row.first().cloned().unwrap_or_default();
         let family_name = row.get(1).cloned().unwrap_or_default();
         let scc_code = row.get(2).cloned().unwrap_or_default();
         let physical_address = row.get(3).cloned().unwrap_or_default();
@@ -520,7 +520,7 @@ pub async fn import_transactions(
                     }
                 };
 
-                let category = row.get(0).map(get_str).unwrap_or_default();
+                let category = row.first().map(get_str).unwrap_or_default();
                 let amount_str = row.get(1).map(get_str).unwrap_or_default();
                 let payment_method = row.get(2).map(get_str).unwrap_or_default();
                 let date_str = row.get(3).map(get_str).unwrap_or_default();
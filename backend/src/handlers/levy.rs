@@ -0,0 +1,611 @@
+use axum::{
+    extract::{Path, State, Query},
+    http::StatusCode,
+    Json,
+};
+use uuid::Uuid;
+use serde::Deserialize;
+use chrono::{NaiveDate, Datelike, Months, Duration, Utc};
+use rust_decimal::Decimal;
+use sqlx::postgres::PgPool;
+use std::collections::HashSet;
+use crate::{
+    AppState,
+    models::levy::{
+        LevyRule, LevyAssessment, LevyLedgerEntry, LevyPeriod, LevyStatus, LevyEntryType,
+        CreateLevyRuleRequest, UpdateLevyRuleRequest, RecordLevyPaymentRequest, WaiveLevyRequest,
+        LevyRunResponse, LevyLedgerLine, LevyLedgerStatement, ParishLevyArrears,
+    },
+    models::user::UserRole,
+    handlers::auth::AuthUser,
+    handlers::rbac,
+};
+
+// ============================================================================
+// Levy Rules
+// ============================================================================
+
+#[derive(Debug, Deserialize)]
+pub struct LevyRuleQuery {
+    pub diocese_id: Option<Uuid>,
+}
+
+pub async fn list_levy_rules(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Query(query): Query<LevyRuleQuery>,
+) -> Result<Json<Vec<LevyRule>>, (StatusCode, String)> {
+    // Parish users see the rules of the diocese their parish belongs to
+    let diocese_id = match auth.parish_id {
+        Some(parish_id) if auth.role != UserRole::SuperAdmin => parish_diocese_id(&state.db, parish_id).await?,
+        _ => rbac::resolve_diocese_id(&state.db, &auth, query.diocese_id).await?,
+    };
+
+    let rules = sqlx::query_as::<_, LevyRule>(
+        "SELECT * FROM levy_rule WHERE diocese_id = $1 AND deleted_at IS NULL ORDER BY effective_from DESC, rule_name"
+    )
+    .bind(diocese_id)
+    .fetch_all(&state.db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(rules))
+}
+
+pub async fn create_levy_rule(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Json(payload): Json<CreateLevyRuleRequest>,
+) -> Result<Json<LevyRule>, (StatusCode, String)> {
+    rbac::require_role(&auth, &[UserRole::SuperAdmin])?;
+    let diocese_id = rbac::resolve_diocese_id(&state.db, &auth, payload.diocese_id).await?;
+
+    if payload.income_categories.is_empty() {
+        return Err((StatusCode::BAD_REQUEST, "At least one income category is required".to_string()));
+    }
+
+    let rule = sqlx::query_as::<_, LevyRule>(
+        r#"
+        INSERT INTO levy_rule (
+            diocese_id, rule_name, rate, income_categories, period,
+            effective_from, effective_to, due_days, created_by
+        )
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+        RETURNING *
+        "#
+    )
+    .bind(diocese_id)
+    .bind(payload.rule_name)
+    .bind(payload.rate)
+    .bind(payload.income_categories)
+    .bind(payload.period)
+    .bind(payload.effective_from)
+    .bind(payload.effective_to)
+    .bind(payload.due_days.unwrap_or(30))
+    .bind(auth.user_id)
+    .fetch_one(&state.db)
+    .await
+    .map_err(|e| (StatusCode::BAD_REQUEST, e.to_string()))?;
+
+    Ok(Json(rule))
+}
+
+pub async fn update_levy_rule(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+    Json(payload): Json<UpdateLevyRuleRequest>,
+) -> Result<Json<LevyRule>, (StatusCode, String)> {
+    rbac::require_role(&auth, &[UserRole::SuperAdmin])?;
+    let existing = fetch_rule(&state.db, id).await?;
+    rbac::resolve_diocese_id(&state.db, &auth, Some(existing.diocese_id)).await?;
+
+    if matches!(&payload.income_categories, Some(c) if c.is_empty()) {
+        return Err((StatusCode::BAD_REQUEST, "At least one income category is required".to_string()));
+    }
+
+    // Rate and categories only affect assessments made from now on;
+    // existing assessments keep the rate they were raised with.
+    let rule = sqlx::query_as::<_, LevyRule>(
+        r#"
+        UPDATE levy_rule SET
+            rule_name = $1,
+            rate = $2,
+            income_categories = $3,
+            effective_to = $4,
+            due_days = $5,
+            is_active = $6,
+            updated_at = NOW()
+        WHERE id = $7
+        RETURNING *
+        "#
+    )
+    .bind(payload.rule_name.unwrap_or(existing.rule_name))
+    .bind(payload.rate.unwrap_or(existing.rate))
+    .bind(payload.income_categories.unwrap_or(existing.income_categories))
+    .bind(payload.effective_to.unwrap_or(existing.effective_to))
+    .bind(payload.due_days.unwrap_or(existing.due_days))
+    .bind(payload.is_active.unwrap_or(existing.is_active))
+    .bind(id)
+    .fetch_one(&state.db)
+    .await
+    .map_err(|e| (StatusCode::BAD_REQUEST, e.to_string()))?;
+
+    Ok(Json(rule))
+}
+
+pub async fn delete_levy_rule(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> Result<StatusCode, (StatusCode, String)> {
+    rbac::require_role(&auth, &[UserRole::SuperAdmin])?;
+    let rule = fetch_rule(&state.db, id).await?;
+    rbac::resolve_diocese_id(&state.db, &auth, Some(rule.diocese_id)).await?;
+
+    sqlx::query("UPDATE levy_rule SET deleted_at = NOW(), is_active = FALSE WHERE id = $1")
+        .bind(id)
+        .execute(&state.db)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+// ============================================================================
+// Assessments
+// ============================================================================
+
+#[derive(Debug, Deserialize)]
+pub struct RunAssessmentQuery {
+    pub as_of: Option<NaiveDate>,
+}
+
+pub async fn run_levy_assessments(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Query(query): Query<RunAssessmentQuery>,
+) -> Result<Json<LevyRunResponse>, (StatusCode, String)> {
+    rbac::require_role(&auth, &[UserRole::SuperAdmin])?;
+    let as_of = query.as_of.unwrap_or_else(|| Utc::now().date_naive());
+
+    let assessments_created = assess_due_levies(&state.db, as_of)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(LevyRunResponse { assessments_created }))
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AssessmentQuery {
+    pub parish_id: Option<Uuid>,
+    pub status: Option<LevyStatus>,
+}
+
+pub async fn list_levy_assessments(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Query(query): Query<AssessmentQuery>,
+) -> Result<Json<Vec<LevyAssessment>>, (StatusCode, String)> {
+    let parish_id = rbac::resolve_parish_id(&auth, query.parish_id)?;
+
+    let assessments = sqlx::query_as::<_, LevyAssessment>(
+        r#"
+        SELECT * FROM levy_assessment
+        WHERE parish_id = $1 AND ($2::levy_status IS NULL OR status = $2)
+        ORDER BY period_start DESC
+        "#
+    )
+    .bind(parish_id)
+    .bind(query.status)
+    .fetch_all(&state.db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(assessments))
+}
+
+pub async fn waive_levy_assessment(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+    Json(payload): Json<WaiveLevyRequest>,
+) -> Result<Json<LevyAssessment>, (StatusCode, String)> {
+    rbac::require_role(&auth, &[UserRole::SuperAdmin])?;
+
+    let mut tx = state.db.begin().await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    let assessment = sqlx::query_as::<_, LevyAssessment>(
+        "SELECT * FROM levy_assessment WHERE id = $1 FOR UPDATE"
+    )
+    .bind(id)
+    .fetch_optional(&mut *tx)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+    .ok_or((StatusCode::NOT_FOUND, "Levy assessment not found".to_string()))?;
+
+    rbac::resolve_diocese_id(&state.db, &auth, Some(assessment.diocese_id)).await?;
+
+    if matches!(assessment.status, LevyStatus::Paid | LevyStatus::Waived) {
+        return Err((StatusCode::BAD_REQUEST, "Only open assessments can be waived".to_string()));
+    }
+
+    let remaining = assessment.amount - assessment.amount_paid;
+    sqlx::query(
+        r#"
+        INSERT INTO levy_ledger_entry (diocese_id, parish_id, assessment_id, entry_type, entry_date, amount, description, recorded_by)
+        VALUES ($1, $2, $3, 'WAIVER', CURRENT_DATE, $4, $5, $6)
+        "#
+    )
+    .bind(assessment.diocese_id)
+    .bind(assessment.parish_id)
+    .bind(assessment.id)
+    .bind(-remaining)
+    .bind(payload.reason)
+    .bind(auth.user_id)
+    .execute(&mut *tx)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    let updated = sqlx::query_as::<_, LevyAssessment>(
+        "UPDATE levy_assessment SET status = 'WAIVED', updated_at = NOW() WHERE id = $1 RETURNING *"
+    )
+    .bind(id)
+    .fetch_one(&mut *tx)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    tx.commit().await.map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(updated))
+}
+
+// ============================================================================
+// Payments & Ledger
+// ============================================================================
+
+pub async fn record_levy_payment(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Json(payload): Json<RecordLevyPaymentRequest>,
+) -> Result<Json<LevyLedgerEntry>, (StatusCode, String)> {
+    rbac::require_finance(&auth)?;
+    let parish_id = rbac::resolve_parish_id(&auth, Some(payload.parish_id))?;
+
+    if payload.amount <= Decimal::ZERO {
+        return Err((StatusCode::BAD_REQUEST, "Payment amount must be positive".to_string()));
+    }
+
+    let diocese_id = parish_diocese_id(&state.db, parish_id).await?;
+
+    if let Some(voucher_id) = payload.expense_voucher_id {
+        let voucher_parish: Option<Uuid> = sqlx::query_scalar(
+            "SELECT parish_id FROM expense_voucher WHERE id = $1 AND deleted_at IS NULL"
+        )
+        .bind(voucher_id)
+        .fetch_optional(&state.db)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+        if voucher_parish != Some(parish_id) {
+            return Err((StatusCode::BAD_REQUEST, "Expense voucher not found in this parish".to_string()));
+        }
+    }
+
+    let mut tx = state.db.begin().await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    // Settle the chosen assessment first, then the oldest open ones.
+    // Anything left over stays on the ledger as a credit for the parish.
+    let open = sqlx::query_as::<_, LevyAssessment>(
+        r#"
+        SELECT * FROM levy_assessment
+        WHERE parish_id = $1 AND status IN ('OUTSTANDING', 'PARTIALLY_PAID')
+        ORDER BY (id = $2) DESC, due_date, period_start
+        FOR UPDATE
+        "#
+    )
+    .bind(parish_id)
+    .bind(payload.assessment_id)
+    .fetch_all(&mut *tx)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    if let Some(assessment_id) = payload.assessment_id {
+        if open.first().map(|a| a.id) != Some(assessment_id) {
+            return Err((StatusCode::BAD_REQUEST, "Assessment is not open for this parish".to_string()));
+        }
+    }
+
+    let mut remaining = payload.amount;
+    for a in &open {
+        if remaining <= Decimal::ZERO {
+            break;
+        }
+        let applied = remaining.min(a.amount - a.amount_paid);
+        let new_paid = a.amount_paid + applied;
+        let status = if new_paid >= a.amount { LevyStatus::Paid } else { LevyStatus::PartiallyPaid };
+
+        sqlx::query("UPDATE levy_assessment SET amount_paid = $1, status = $2, updated_at = NOW() WHERE id = $3")
+            .bind(new_paid)
+            .bind(status)
+            .bind(a.id)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+        remaining -= applied;
+    }
+
+    let entry = sqlx::query_as::<_, LevyLedgerEntry>(
+        r#"
+        INSERT INTO levy_ledger_entry (
+            diocese_id, parish_id, assessment_id, entry_type, entry_date, amount,
+            reference_number, expense_voucher_id, description, recorded_by
+        )
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+        RETURNING *
+        "#
+    )
+    .bind(diocese_id)
+    .bind(parish_id)
+    .bind(payload.assessment_id.or(open.first().map(|a| a.id)))
+    .bind(LevyEntryType::Payment)
+    .bind(payload.payment_date)
+    .bind(-payload.amount)
+    .bind(payload.reference_number)
+    .bind(payload.expense_voucher_id)
+    .bind(payload.description)
+    .bind(auth.user_id)
+    .fetch_one(&mut *tx)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    tx.commit().await.map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(entry))
+}
+
+#[derive(Debug, Deserialize)]
+pub struct LedgerQuery {
+    pub parish_id: Option<Uuid>,
+}
+
+pub async fn get_levy_ledger(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Query(query): Query<LedgerQuery>,
+) -> Result<Json<LevyLedgerStatement>, (StatusCode, String)> {
+    let parish_id = rbac::resolve_parish_id(&auth, query.parish_id)?;
+
+    let rows = sqlx::query_as::<_, LevyLedgerEntry>(
+        "SELECT * FROM levy_ledger_entry WHERE parish_id = $1 ORDER BY entry_date, created_at"
+    )
+    .bind(parish_id)
+    .fetch_all(&state.db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    let mut balance = Decimal::ZERO;
+    let entries = rows.into_iter().map(|entry| {
+        balance += entry.amount;
+        LevyLedgerLine { entry, running_balance: balance }
+    }).collect();
+
+    Ok(Json(LevyLedgerStatement { parish_id, entries, balance }))
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ArrearsQuery {
+    pub diocese_id: Option<Uuid>,
+    pub as_of: Option<NaiveDate>,
+    pub include_settled: Option<bool>,
+}
+
+pub async fn get_levy_arrears(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Query(query): Query<ArrearsQuery>,
+) -> Result<Json<Vec<ParishLevyArrears>>, (StatusCode, String)> {
+    let diocese_id = rbac::resolve_diocese_id(&state.db, &auth, query.diocese_id).await?;
+    let as_of = query.as_of.unwrap_or_else(|| Utc::now().date_naive());
+
+    let rows = sqlx::query_as::<_, ParishLevyArrears>(
+        r#"
+        SELECT
+            p.id AS parish_id, p.parish_code, p.parish_name,
+            COALESCE(SUM(a.amount) FILTER (WHERE a.status <> 'WAIVED'), 0) AS total_assessed,
+            COALESCE(SUM(a.amount_paid), 0) AS total_paid,
+            COALESCE(SUM(a.amount - a.amount_paid)
+                FILTER (WHERE a.status IN ('OUTSTANDING', 'PARTIALLY_PAID')), 0) AS outstanding,
+            COALESCE(SUM(a.amount - a.amount_paid)
+                FILTER (WHERE a.status IN ('OUTSTANDING', 'PARTIALLY_PAID') AND a.due_date < $2), 0) AS overdue_amount,
+            COUNT(a.id) FILTER (WHERE a.status IN ('OUTSTANDING', 'PARTIALLY_PAID') AND a.due_date < $2) AS overdue_assessments,
+            MIN(a.due_date) FILTER (WHERE a.status IN ('OUTSTANDING', 'PARTIALLY_PAID') AND a.due_date < $2) AS oldest_overdue_date
+        FROM parish p
+        LEFT JOIN levy_assessment a ON a.parish_id = p.id
+        WHERE p.diocese_id = $1 AND p.deleted_at IS NULL
+        GROUP BY p.id, p.parish_code, p.parish_name
+        ORDER BY overdue_amount DESC, p.parish_name
+        "#
+    )
+    .bind(diocese_id)
+    .bind(as_of)
+    .fetch_all(&state.db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    let rows = if query.include_settled.unwrap_or(false) {
+        rows
+    } else {
+        rows.into_iter().filter(|r| r.overdue_amount > Decimal::ZERO).collect()
+    };
+
+    Ok(Json(rows))
+}
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+async fn fetch_rule(db: &PgPool, id: Uuid) -> Result<LevyRule, (StatusCode, String)> {
+    sqlx::query_as::<_, LevyRule>("SELECT * FROM levy_rule WHERE id = $1 AND deleted_at IS NULL")
+        .bind(id)
+        .fetch_optional(db)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+        .ok_or((StatusCode::NOT_FOUND, "Levy rule not found".to_string()))
+}
+
+async fn parish_diocese_id(db: &PgPool, parish_id: Uuid) -> Result<Uuid, (StatusCode, String)> {
+    sqlx::query_scalar("SELECT diocese_id FROM parish WHERE id = $1 AND deleted_at IS NULL")
+        .bind(parish_id)
+        .fetch_optional(db)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+        .ok_or((StatusCode::NOT_FOUND, "Parish not found".to_string()))
+}
+
+/// First day of the levy period containing `date`.
+fn period_start(period: LevyPeriod, date: NaiveDate) -> NaiveDate {
+    let month = match period {
+        LevyPeriod::Monthly => date.month(),
+        LevyPeriod::Quarterly => date.month0() / 3 * 3 + 1,
+        LevyPeriod::Annual => 1,
+    };
+    NaiveDate::from_ymd_opt(date.year(), month, 1).expect("valid period start")
+}
+
+fn next_period_start(period: LevyPeriod, start: NaiveDate) -> NaiveDate {
+    let months = match period {
+        LevyPeriod::Monthly => 1,
+        LevyPeriod::Quarterly => 3,
+        LevyPeriod::Annual => 12,
+    };
+    start + Months::new(months)
+}
+
+/// Raises an assessment for every active rule, parish and completed period
+/// (period end before `as_of`) that has not been assessed yet. Safe to run
+/// repeatedly; used by the background job and the manual trigger.
+pub async fn assess_due_levies(db: &PgPool, as_of: NaiveDate) -> Result<usize, sqlx::Error> {
+    let rules = sqlx::query_as::<_, LevyRule>(
+        "SELECT * FROM levy_rule WHERE is_active = TRUE AND deleted_at IS NULL AND effective_from < $1"
+    )
+    .bind(as_of)
+    .fetch_all(db)
+    .await?;
+
+    let mut created = 0;
+    for rule in rules {
+        let parishes: Vec<Uuid> = sqlx::query_scalar(
+            "SELECT id FROM parish WHERE diocese_id = $1 AND deleted_at IS NULL AND COALESCE(is_active, TRUE)"
+        )
+        .bind(rule.diocese_id)
+        .fetch_all(db)
+        .await?;
+
+        for parish_id in parishes {
+            let assessed: HashSet<NaiveDate> = sqlx::query_scalar(
+                "SELECT period_start FROM levy_assessment WHERE levy_rule_id = $1 AND parish_id = $2"
+            )
+            .bind(rule.id)
+            .bind(parish_id)
+            .fetch_all(db)
+            .await?
+            .into_iter()
+            .collect();
+
+            let mut start = period_start(rule.period, rule.effective_from);
+            loop {
+                let next = next_period_start(rule.period, start);
+                let end = next - Duration::days(1);
+                if end >= as_of || rule.effective_to.is_some_and(|to| start > to) {
+                    break;
+                }
+
+                // A rule that starts or stops mid-period only covers its own days
+                let window_start = start.max(rule.effective_from);
+                let window_end = rule.effective_to.map_or(end, |to| end.min(to));
+
+                if !assessed.contains(&window_start) {
+                    assess_period(db, &rule, parish_id, window_start, window_end).await?;
+                    created += 1;
+                }
+                start = next;
+            }
+        }
+    }
+
+    Ok(created)
+}
+
+async fn assess_period(
+    db: &PgPool,
+    rule: &LevyRule,
+    parish_id: Uuid,
+    start: NaiveDate,
+    end: NaiveDate,
+) -> Result<(), sqlx::Error> {
+    let assessable_income: Decimal = sqlx::query_scalar(
+        r#"
+        SELECT COALESCE(SUM(amount), 0) FROM income_transaction
+        WHERE parish_id = $1 AND category = ANY($2) AND transaction_date BETWEEN $3 AND $4 AND deleted_at IS NULL
+        "#
+    )
+    .bind(parish_id)
+    .bind(&rule.income_categories)
+    .bind(start)
+    .bind(end)
+    .fetch_one(db)
+    .await?;
+
+    let amount = (assessable_income * rule.rate / Decimal::ONE_HUNDRED).round_dp(2);
+    let status = if amount.is_zero() { LevyStatus::Paid } else { LevyStatus::Outstanding };
+    let due_date = end + Duration::days(rule.due_days.into());
+
+    let mut tx = db.begin().await?;
+
+    let assessment_id: Option<Uuid> = sqlx::query_scalar(
+        r#"
+        INSERT INTO levy_assessment (
+            levy_rule_id, diocese_id, parish_id, period_start, period_end,
+            assessable_income, rate, amount, due_date, status
+        )
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+        ON CONFLICT (levy_rule_id, parish_id, period_start) DO NOTHING
+        RETURNING id
+        "#
+    )
+    .bind(rule.id)
+    .bind(rule.diocese_id)
+    .bind(parish_id)
+    .bind(start)
+    .bind(end)
+    .bind(assessable_income)
+    .bind(rule.rate)
+    .bind(amount)
+    .bind(due_date)
+    .bind(status)
+    .fetch_optional(&mut *tx)
+    .await?;
+
+    if let Some(assessment_id) = assessment_id {
+        sqlx::query(
+            r#"
+            INSERT INTO levy_ledger_entry (diocese_id, parish_id, assessment_id, entry_type, entry_date, amount, description)
+            VALUES ($1, $2, $3, 'ASSESSMENT', $4, $5, $6)
+            "#
+        )
+        .bind(rule.diocese_id)
+        .bind(parish_id)
+        .bind(assessment_id)
+        .bind(end)
+        .bind(amount)
+        .bind(format!("{} ({} to {}) at {}%", rule.rule_name, start, end, rule.rate.normalize()))
+        .execute(&mut *tx)
+        .await?;
+    }
+
+    tx.commit().await
+}
@@ -0,0 +1,828 @@
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use sqlx::{PgConnection, PgPool};
+use uuid::Uuid;
+use chrono::{Datelike, Duration, Utc, Weekday};
+use serde::Deserialize;
+use crate::{AppState, handlers::auth::AuthUser, handlers::rbac};
+use crate::handlers::{sacrament, sacrament_rule};
+use crate::handlers::sacrament::CreateSacramentRequest;
+use crate::models::member::SacramentType;
+use crate::models::marriage::{
+    BannsPublication, CompleteMarriageRequest, CreateImpedimentRequest, CreateMarriageCaseRequest,
+    CreateMarriageDocumentRequest, MarriageCase, MarriageCaseDetail, MarriageCaseStatus, MarriageCompletion,
+    MarriageDocument, MarriageDocumentType, MarriageImpediment, MarriageParty, ResolveImpedimentRequest,
+    ScheduleBannsRequest, UpdateBannsRequest, UpdateMarriageCaseRequest, UpdateMarriageDocumentRequest,
+};
+use crate::models::register::RegisterEntryRef;
+use crate::models::sacrament_rule::CheckSacramentRequest;
+
+#[derive(Debug, Deserialize)]
+pub struct MarriageCaseQuery {
+    pub parish_id: Option<Uuid>,
+    pub status: Option<MarriageCaseStatus>,
+}
+
+/// A party as entered on the case: a member, or an outsider by name.
+struct Party {
+    member_id: Option<Uuid>,
+    name: String,
+    parish_id: Option<Uuid>,
+    parish_name: Option<String>,
+}
+
+pub async fn list_marriage_cases(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Query(query): Query<MarriageCaseQuery>,
+) -> Result<Json<Vec<MarriageCase>>, (StatusCode, String)> {
+    let parish_id = rbac::resolve_parish_id(&auth, query.parish_id)?;
+
+    let cases = sqlx::query_as::<_, MarriageCase>(
+        r#"
+        SELECT * FROM marriage_case
+        WHERE parish_id = $1 AND deleted_at IS NULL AND ($2::marriage_case_status IS NULL OR status = $2)
+        ORDER BY proposed_date NULLS LAST, created_at
+        "#
+    )
+    .bind(parish_id)
+    .bind(query.status)
+    .fetch_all(&state.db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(cases))
+}
+
+pub async fn get_marriage_case(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<MarriageCaseDetail>, (StatusCode, String)> {
+    let case = fetch_case(&state.db, id).await?;
+    rbac::resolve_parish_id(&auth, Some(case.parish_id))?;
+    Ok(Json(detail(&state.db, case).await?))
+}
+
+/// Opens a case file with the usual documents to collect from each party:
+/// a fresh baptism certificate and a freedom-to-marry statement, and the
+/// confirmation certificate where there is one.
+pub async fn create_marriage_case(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Json(payload): Json<CreateMarriageCaseRequest>,
+) -> Result<Json<MarriageCaseDetail>, (StatusCode, String)> {
+    rbac::require_write(&auth)?;
+    let parish_id = rbac::resolve_parish_id(&auth, Some(payload.parish_id))?;
+
+    let groom = party(
+        &state.db, "groom", payload.groom_member_id, payload.groom_name, payload.groom_parish_id, payload.groom_parish_name,
+    )
+    .await?;
+    let bride = party(
+        &state.db, "bride", payload.bride_member_id, payload.bride_name, payload.bride_parish_id, payload.bride_parish_name,
+    )
+    .await?;
+    if groom.member_id.is_some() && groom.member_id == bride.member_id {
+        return Err((StatusCode::BAD_REQUEST, "The groom and bride must be different people".to_string()));
+    }
+
+    let mut tx = state.db.begin().await.map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    let case = sqlx::query_as::<_, MarriageCase>(
+        r#"
+        INSERT INTO marriage_case (
+            parish_id, groom_member_id, groom_name, groom_parish_id, groom_parish_name,
+            bride_member_id, bride_name, bride_parish_id, bride_parish_name,
+            proposed_date, church_name, officiating_minister, pre_cana_course, notes, created_by
+        )
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15)
+        RETURNING *
+        "#
+    )
+    .bind(parish_id)
+    .bind(groom.member_id)
+    .bind(&groom.name)
+    .bind(groom.parish_id)
+    .bind(&groom.parish_name)
+    .bind(bride.member_id)
+    .bind(&bride.name)
+    .bind(bride.parish_id)
+    .bind(&bride.parish_name)
+    .bind(payload.proposed_date)
+    .bind(payload.church_name)
+    .bind(payload.officiating_minister)
+    .bind(payload.pre_cana_course)
+    .bind(payload.notes)
+    .bind(auth.user_id)
+    .fetch_one(&mut *tx)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    let checklist = [
+        (MarriageDocumentType::BaptismCertificate, true),
+        (MarriageDocumentType::ConfirmationCertificate, false),
+        (MarriageDocumentType::FreedomToMarry, true),
+    ];
+    for party in [MarriageParty::Groom, MarriageParty::Bride] {
+        for (document_type, required) in checklist {
+            sqlx::query(
+                "INSERT INTO marriage_document (marriage_case_id, party, document_type, required) VALUES ($1, $2, $3, $4)"
+            )
+            .bind(case.id)
+            .bind(party)
+            .bind(document_type)
+            .bind(required)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+        }
+    }
+
+    tx.commit().await.map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(detail(&state.db, case).await?))
+}
+
+pub async fn update_marriage_case(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+    Json(payload): Json<UpdateMarriageCaseRequest>,
+) -> Result<Json<MarriageCaseDetail>, (StatusCode, String)> {
+    rbac::require_write(&auth)?;
+    // A cancelled case may be reopened
+    let mut case = fetch_case(&state.db, id).await?;
+    rbac::resolve_parish_id(&auth, Some(case.parish_id))?;
+    if case.status == MarriageCaseStatus::Completed {
+        return Err((StatusCode::CONFLICT, "The marriage case is closed".to_string()));
+    }
+    if payload.status == Some(MarriageCaseStatus::Completed) {
+        return Err((StatusCode::BAD_REQUEST, "A case is completed by recording the marriage".to_string()));
+    }
+    if let Some(val) = payload.groom_parish_name { case.groom_parish_name = Some(val); }
+    if let Some(val) = payload.bride_parish_name { case.bride_parish_name = Some(val); }
+    if let Some(val) = payload.proposed_date { case.proposed_date = Some(val); }
+    if let Some(val) = payload.church_name { case.church_name = Some(val); }
+    if let Some(val) = payload.officiating_minister { case.officiating_minister = Some(val); }
+    if let Some(val) = payload.pre_cana_course { case.pre_cana_course = Some(val); }
+    if let Some(val) = payload.pre_cana_completed_on { case.pre_cana_completed_on = Some(val); }
+    if let Some(val) = payload.status { case.status = val; }
+    if let Some(val) = payload.notes { case.notes = Some(val); }
+
+    let case = sqlx::query_as::<_, MarriageCase>(
+        r#"
+        UPDATE marriage_case SET
+            groom_parish_name = $1, bride_parish_name = $2, proposed_date = $3, church_name = $4,
+            officiating_minister = $5, pre_cana_course = $6, pre_cana_completed_on = $7, status = $8, notes = $9
+        WHERE id = $10
+        RETURNING *
+        "#
+    )
+    .bind(case.groom_parish_name)
+    .bind(case.bride_parish_name)
+    .bind(case.proposed_date)
+    .bind(case.church_name)
+    .bind(case.officiating_minister)
+    .bind(case.pre_cana_course)
+    .bind(case.pre_cana_completed_on)
+    .bind(case.status)
+    .bind(case.notes)
+    .bind(id)
+    .fetch_one(&state.db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(detail(&state.db, case).await?))
+}
+
+pub async fn delete_marriage_case(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> Result<StatusCode, (StatusCode, String)> {
+    rbac::require_write(&auth)?;
+    let case = fetch_case(&state.db, id).await?;
+    rbac::resolve_parish_id(&auth, Some(case.parish_id))?;
+    if case.status == MarriageCaseStatus::Completed {
+        return Err((StatusCode::CONFLICT, "A completed case is kept with the marriage records".to_string()));
+    }
+
+    sqlx::query("UPDATE marriage_case SET deleted_at = NOW() WHERE id = $1")
+        .bind(id)
+        .execute(&state.db)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+// ============================================================================
+// DOCUMENTS
+// ============================================================================
+
+pub async fn create_marriage_document(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Path(case_id): Path<Uuid>,
+    Json(payload): Json<CreateMarriageDocumentRequest>,
+) -> Result<Json<MarriageDocument>, (StatusCode, String)> {
+    rbac::require_write(&auth)?;
+    fetch_open_case(&state.db, &auth, case_id).await?;
+
+    let document = sqlx::query_as::<_, MarriageDocument>(
+        r#"
+        INSERT INTO marriage_document (marriage_case_id, party, document_type, required, issued_on, received_on, reference, notes)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+        RETURNING *
+        "#
+    )
+    .bind(case_id)
+    .bind(payload.party)
+    .bind(payload.document_type)
+    .bind(payload.required.unwrap_or(true))
+    .bind(payload.issued_on)
+    .bind(payload.received_on)
+    .bind(payload.reference)
+    .bind(payload.notes)
+    .fetch_one(&state.db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(document))
+}
+
+pub async fn update_marriage_document(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+    Json(payload): Json<UpdateMarriageDocumentRequest>,
+) -> Result<Json<MarriageDocument>, (StatusCode, String)> {
+    rbac::require_write(&auth)?;
+    let mut document = sqlx::query_as::<_, MarriageDocument>("SELECT * FROM marriage_document WHERE id = $1")
+        .bind(id)
+        .fetch_optional(&state.db)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+        .ok_or((StatusCode::NOT_FOUND, "Document not found".to_string()))?;
+    fetch_open_case(&state.db, &auth, document.marriage_case_id).await?;
+
+    if let Some(val) = payload.required { document.required = val; }
+    if let Some(val) = payload.issued_on { document.issued_on = Some(val); }
+    if let Some(val) = payload.received_on { document.received_on = Some(val); }
+    if let Some(val) = payload.reference { document.reference = Some(val); }
+    if let Some(val) = payload.notes { document.notes = Some(val); }
+
+    let document = sqlx::query_as::<_, MarriageDocument>(
+        r#"
+        UPDATE marriage_document SET required = $1, issued_on = $2, received_on = $3, reference = $4, notes = $5
+        WHERE id = $6
+        RETURNING *
+        "#
+    )
+    .bind(document.required)
+    .bind(document.issued_on)
+    .bind(document.received_on)
+    .bind(document.reference)
+    .bind(document.notes)
+    .bind(id)
+    .fetch_one(&state.db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(document))
+}
+
+pub async fn delete_marriage_document(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> Result<StatusCode, (StatusCode, String)> {
+    rbac::require_write(&auth)?;
+    let case_id: Uuid = sqlx::query_scalar("SELECT marriage_case_id FROM marriage_document WHERE id = $1")
+        .bind(id)
+        .fetch_optional(&state.db)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+        .ok_or((StatusCode::NOT_FOUND, "Document not found".to_string()))?;
+    fetch_open_case(&state.db, &auth, case_id).await?;
+
+    sqlx::query("DELETE FROM marriage_document WHERE id = $1")
+        .bind(id)
+        .execute(&state.db)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+// ============================================================================
+// BANNS
+// ============================================================================
+
+/// Schedules the three readings in the parish of each party, or once where
+/// both belong to the same parish. The last reading must fall before the
+/// proposed wedding date.
+pub async fn schedule_banns(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Path(case_id): Path<Uuid>,
+    Json(payload): Json<ScheduleBannsRequest>,
+) -> Result<Json<Vec<BannsPublication>>, (StatusCode, String)> {
+    rbac::require_write(&auth)?;
+    let case = fetch_open_case(&state.db, &auth, case_id).await?;
+
+    if payload.first_sunday.weekday() != Weekday::Sun {
+        return Err((StatusCode::BAD_REQUEST, "Banns are read on a Sunday".to_string()));
+    }
+    let last_sunday = payload.first_sunday + Duration::weeks(2);
+    if case.proposed_date.is_some_and(|d| last_sunday >= d) {
+        return Err((StatusCode::BAD_REQUEST, format!(
+            "The third reading on {} would not fall before the wedding", last_sunday
+        )));
+    }
+
+    let groom_parish = case.groom_parish_name.clone()
+        .ok_or((StatusCode::BAD_REQUEST, "The groom's parish is not known".to_string()))?;
+    let bride_parish = case.bride_parish_name.clone()
+        .ok_or((StatusCode::BAD_REQUEST, "The bride's parish is not known".to_string()))?;
+    let same_parish = match (case.groom_parish_id, case.bride_parish_id) {
+        (Some(g), Some(b)) => g == b,
+        _ => groom_parish.trim().eq_ignore_ascii_case(bride_parish.trim()),
+    };
+    let parishes = if same_parish {
+        vec![(None, case.groom_parish_id, groom_parish)]
+    } else {
+        vec![
+            (Some(MarriageParty::Groom), case.groom_parish_id, groom_parish),
+            (Some(MarriageParty::Bride), case.bride_parish_id, bride_parish),
+        ]
+    };
+
+    let mut tx = state.db.begin().await.map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    let read: bool = sqlx::query_scalar(
+        "SELECT EXISTS (SELECT 1 FROM banns_publication WHERE marriage_case_id = $1 AND published_at IS NOT NULL)"
+    )
+    .bind(case_id)
+    .fetch_one(&mut *tx)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+    if read {
+        return Err((StatusCode::CONFLICT, "Banns already read cannot be rescheduled".to_string()));
+    }
+
+    sqlx::query("DELETE FROM banns_publication WHERE marriage_case_id = $1")
+        .bind(case_id)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    for (party, parish_id, parish_name) in &parishes {
+        for n in 0..3 {
+            sqlx::query(
+                r#"
+                INSERT INTO banns_publication (marriage_case_id, party, parish_id, parish_name, publication_number, publish_on)
+                VALUES ($1, $2, $3, $4, $5, $6)
+                "#
+            )
+            .bind(case_id)
+            .bind(party)
+            .bind(parish_id)
+            .bind(parish_name)
+            .bind(n + 1)
+            .bind(payload.first_sunday + Duration::weeks(n as i64))
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+        }
+    }
+
+    let banns = fetch_banns(&mut tx, case_id).await?;
+    tx.commit().await.map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(banns))
+}
+
+/// Confirms a reading, or records an objection raised at it. An objection
+/// is entered as an impediment to be resolved.
+pub async fn update_banns(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+    Json(payload): Json<UpdateBannsRequest>,
+) -> Result<Json<BannsPublication>, (StatusCode, String)> {
+    rbac::require_write(&auth)?;
+    let banns = sqlx::query_as::<_, BannsPublication>("SELECT * FROM banns_publication WHERE id = $1")
+        .bind(id)
+        .fetch_optional(&state.db)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+        .ok_or((StatusCode::NOT_FOUND, "Banns publication not found".to_string()))?;
+    fetch_open_case(&state.db, &auth, banns.marriage_case_id).await?;
+    if payload.published == Some(true) && banns.publish_on > Utc::now().date_naive() {
+        return Err((StatusCode::BAD_REQUEST, format!("These banns are not due to be read until {}", banns.publish_on)));
+    }
+
+    let mut tx = state.db.begin().await.map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    let objection = payload.objection.as_deref().map(str::trim).filter(|o| !o.is_empty());
+    let updated = sqlx::query_as::<_, BannsPublication>(
+        r#"
+        UPDATE banns_publication SET
+            published_at = CASE WHEN $1::boolean IS NULL THEN published_at WHEN $1 THEN COALESCE(published_at, NOW()) END,
+            confirmed_by = CASE WHEN $1::boolean IS NULL THEN confirmed_by WHEN $1 THEN $2 END,
+            objection = COALESCE($3, objection)
+        WHERE id = $4
+        RETURNING *
+        "#
+    )
+    .bind(payload.published)
+    .bind(auth.user_id)
+    .bind(objection)
+    .bind(id)
+    .fetch_one(&mut *tx)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    if let Some(objection) = objection {
+        sqlx::query("INSERT INTO marriage_impediment (marriage_case_id, description, created_by) VALUES ($1, $2, $3)")
+            .bind(banns.marriage_case_id)
+            .bind(format!(
+                "Objection at reading {} of the banns in {} on {}: {}",
+                banns.publication_number, banns.parish_name, banns.publish_on, objection
+            ))
+            .bind(auth.user_id)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+    }
+
+    tx.commit().await.map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(updated))
+}
+
+// ============================================================================
+// IMPEDIMENTS
+// ============================================================================
+
+pub async fn create_impediment(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Path(case_id): Path<Uuid>,
+    Json(payload): Json<CreateImpedimentRequest>,
+) -> Result<Json<MarriageImpediment>, (StatusCode, String)> {
+    rbac::require_write(&auth)?;
+    fetch_open_case(&state.db, &auth, case_id).await?;
+    if payload.description.trim().is_empty() {
+        return Err((StatusCode::BAD_REQUEST, "Describe the impediment".to_string()));
+    }
+
+    let impediment = sqlx::query_as::<_, MarriageImpediment>(
+        "INSERT INTO marriage_impediment (marriage_case_id, description, created_by) VALUES ($1, $2, $3) RETURNING *"
+    )
+    .bind(case_id)
+    .bind(payload.description.trim())
+    .bind(auth.user_id)
+    .fetch_one(&state.db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(impediment))
+}
+
+/// Resolves an impediment, by dispensation or because it proved unfounded.
+pub async fn resolve_impediment(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+    Json(payload): Json<ResolveImpedimentRequest>,
+) -> Result<Json<MarriageImpediment>, (StatusCode, String)> {
+    rbac::require_admin(&auth)?;
+    let case_id: Uuid = sqlx::query_scalar("SELECT marriage_case_id FROM marriage_impediment WHERE id = $1")
+        .bind(id)
+        .fetch_optional(&state.db)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+        .ok_or((StatusCode::NOT_FOUND, "Impediment not found".to_string()))?;
+    fetch_open_case(&state.db, &auth, case_id).await?;
+    if payload.resolution.trim().is_empty() {
+        return Err((StatusCode::BAD_REQUEST, "Say how the impediment was resolved".to_string()));
+    }
+
+    let impediment = sqlx::query_as::<_, MarriageImpediment>(
+        r#"
+        UPDATE marriage_impediment SET resolution = $1, dispensation_reference = $2, resolved_on = $3, resolved_by = $4
+        WHERE id = $5
+        RETURNING *
+        "#
+    )
+    .bind(payload.resolution.trim())
+    .bind(payload.dispensation_reference)
+    .bind(payload.resolved_on.unwrap_or_else(|| Utc::now().date_naive()))
+    .bind(auth.user_id)
+    .bind(id)
+    .fetch_one(&state.db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(impediment))
+}
+
+// ============================================================================
+// COMPLETION
+// ============================================================================
+
+/// Records the marriage once nothing is outstanding on the case: one
+/// Marriage record for each party who is a member, each naming the other
+/// as spouse and citing the same register entry. The records are checked
+/// like any other sacrament and may be overridden with a reason.
+pub async fn complete_marriage_case(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+    Json(payload): Json<CompleteMarriageRequest>,
+) -> Result<Response, (StatusCode, String)> {
+    rbac::require_write(&auth)?;
+    let case = fetch_open_case(&state.db, &auth, id).await?;
+    let current = detail(&state.db, case).await?;
+    if !current.ready {
+        return Err((StatusCode::CONFLICT, format!("The case is not ready: {}", current.outstanding.join("; "))));
+    }
+    let case = current.case;
+
+    let parties = [
+        (case.groom_member_id, case.bride_member_id, &case.bride_name),
+        (case.bride_member_id, case.groom_member_id, &case.groom_name),
+    ];
+    let mut checked = Vec::new();
+    for (member_id, spouse_id, spouse_name) in parties {
+        let Some(member_id) = member_id else { continue };
+        let check = sacrament_rule::check(&state.db, case.parish_id, &CheckSacramentRequest {
+            member_id,
+            sacrament_type: SacramentType::Marriage,
+            sacrament_date: payload.sacrament_date,
+            spouse_id,
+            record_id: None,
+            sponsors: payload.witnesses.clone(),
+        })
+        .await?;
+        let override_reason = match sacrament_rule::override_reason(&auth, &check, payload.override_reason.as_deref()) {
+            Ok(reason) => reason,
+            Err(refused) => return Ok(*refused),
+        };
+        checked.push((member_id, spouse_id, spouse_name.clone(), check, override_reason));
+    }
+    if checked.is_empty() {
+        return Err((StatusCode::BAD_REQUEST, "Neither party is a member, so there is no record to make".to_string()));
+    }
+
+    let mut tx = state.db.begin().await.map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    // Lock the case so a second completion waits here and then finds it
+    // closed, instead of recording the marriage twice
+    let status: MarriageCaseStatus = sqlx::query_scalar("SELECT status FROM marriage_case WHERE id = $1 FOR UPDATE")
+        .bind(id)
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+    if status != MarriageCaseStatus::Open {
+        return Err((StatusCode::CONFLICT, "The marriage case is closed".to_string()));
+    }
+
+    let mut records = Vec::with_capacity(checked.len());
+    let mut entry = RegisterEntryRef::default();
+    for (member_id, spouse_id, spouse_name, check, override_reason) in checked {
+        let saved = sacrament::insert_record(&mut tx, &auth, case.parish_id, CreateSacramentRequest {
+            member_id,
+            sacrament_type: SacramentType::Marriage,
+            sacrament_date: payload.sacrament_date,
+            officiating_minister: payload.officiating_minister.clone().or(case.officiating_minister.clone()),
+            parish_id: case.parish_id,
+            church_name: payload.church_name.clone().or(case.church_name.clone()),
+            certificate_number: None,
+            godparent_1_name: None,
+            godparent_2_name: None,
+            spouse_id,
+            spouse_name: Some(spouse_name),
+            witnesses: None,
+            notes: payload.notes.clone(),
+            sponsors: Some(payload.witnesses.clone()),
+            override_reason: None,
+            register_book_id: entry.register_book_id,
+            register_page: entry.register_page,
+            register_entry: entry.register_entry,
+            unregistered: false,
+        }, &check.violations, override_reason)
+        .await?;
+        // The second spouse cites the entry made for the first
+        entry = RegisterEntryRef {
+            register_book_id: saved.record.register_book_id,
+            register_page: saved.record.register_page,
+            register_entry: saved.record.register_entry,
+        };
+        records.push(saved);
+    }
+
+    let record_of = |member_id: Option<Uuid>| {
+        records.iter().find(|r| Some(r.record.member_id) == member_id).map(|r| r.record.id)
+    };
+    let case = sqlx::query_as::<_, MarriageCase>(
+        r#"
+        UPDATE marriage_case SET status = 'COMPLETED', groom_record_id = $1, bride_record_id = $2
+        WHERE id = $3 AND status = 'OPEN'
+        RETURNING *
+        "#
+    )
+    .bind(record_of(case.groom_member_id))
+    .bind(record_of(case.bride_member_id))
+    .bind(id)
+    .fetch_optional(&mut *tx)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+    .ok_or((StatusCode::CONFLICT, "The marriage case is closed".to_string()))?;
+
+    tx.commit().await.map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    let case = detail(&state.db, case).await?;
+    Ok(Json(MarriageCompletion { case, records }).into_response())
+}
+
+// ============================================================================
+// HELPERS
+// ============================================================================
+
+async fn fetch_case(db: &PgPool, id: Uuid) -> Result<MarriageCase, (StatusCode, String)> {
+    sqlx::query_as::<_, MarriageCase>("SELECT * FROM marriage_case WHERE id = $1 AND deleted_at IS NULL")
+        .bind(id)
+        .fetch_optional(db)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+        .ok_or((StatusCode::NOT_FOUND, "Marriage case not found".to_string()))
+}
+
+/// A case that can still be worked on, in one of the user's parishes.
+async fn fetch_open_case(db: &PgPool, auth: &AuthUser, id: Uuid) -> Result<MarriageCase, (StatusCode, String)> {
+    let case = fetch_case(db, id).await?;
+    rbac::resolve_parish_id(auth, Some(case.parish_id))?;
+    if case.status != MarriageCaseStatus::Open {
+        return Err((StatusCode::CONFLICT, "The marriage case is closed".to_string()));
+    }
+    Ok(case)
+}
+
+async fn fetch_banns(conn: &mut PgConnection, case_id: Uuid) -> Result<Vec<BannsPublication>, (StatusCode, String)> {
+    sqlx::query_as::<_, BannsPublication>(
+        "SELECT * FROM banns_publication WHERE marriage_case_id = $1 ORDER BY party NULLS FIRST, publication_number"
+    )
+    .bind(case_id)
+    .fetch_all(conn)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+}
+
+/// Fills in a party from their member record, or takes the name given.
+async fn party(
+    db: &PgPool,
+    label: &str,
+    member_id: Option<Uuid>,
+    name: Option<String>,
+    parish_id: Option<Uuid>,
+    parish_name: Option<String>,
+) -> Result<Party, (StatusCode, String)> {
+    let (name, parish_id) = match member_id {
+        Some(member_id) => {
+            let (full_name, member_parish): (String, Uuid) = sqlx::query_as(
+                "SELECT CONCAT_WS(' ', first_name, middle_name, last_name), parish_id FROM member WHERE id = $1 AND deleted_at IS NULL"
+            )
+            .bind(member_id)
+            .fetch_optional(db)
+            .await
+            .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+            .ok_or((StatusCode::NOT_FOUND, format!("The {} is not a member", label)))?;
+            (full_name, parish_id.or(Some(member_parish)))
+        }
+        None => {
+            let name = name
+                .map(|n| n.trim().to_string())
+                .filter(|n| !n.is_empty())
+                .ok_or((StatusCode::BAD_REQUEST, format!("Give the {}'s member_id or name", label)))?;
+            (name, parish_id)
+        }
+    };
+
+    let parish_name = match (parish_name.filter(|p| !p.trim().is_empty()), parish_id) {
+        (Some(given), _) => Some(given),
+        (None, Some(parish_id)) => sqlx::query_scalar("SELECT parish_name FROM parish WHERE id = $1")
+            .bind(parish_id)
+            .fetch_optional(db)
+            .await
+            .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?,
+        (None, None) => None,
+    };
+
+    Ok(Party { member_id, name, parish_id, parish_name })
+}
+
+async fn detail(db: &PgPool, case: MarriageCase) -> Result<MarriageCaseDetail, (StatusCode, String)> {
+    let documents = sqlx::query_as::<_, MarriageDocument>(
+        "SELECT * FROM marriage_document WHERE marriage_case_id = $1 ORDER BY party, document_type, created_at"
+    )
+    .bind(case.id)
+    .fetch_all(db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    let banns = fetch_banns(&mut *db.acquire().await.map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?, case.id).await?;
+
+    let impediments = sqlx::query_as::<_, MarriageImpediment>(
+        "SELECT * FROM marriage_impediment WHERE marriage_case_id = $1 ORDER BY created_at"
+    )
+    .bind(case.id)
+    .fetch_all(db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    let max_age_days: i64 = sacrament_rule::setting(db, case.parish_id, "marriage.baptism_certificate_max_age_days")
+        .await?
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(180);
+
+    let outstanding = if case.status == MarriageCaseStatus::Open {
+        outstanding(&case, &documents, &banns, &impediments, max_age_days)
+    } else {
+        Vec::new()
+    };
+    let ready = case.status == MarriageCaseStatus::Open && outstanding.is_empty();
+
+    Ok(MarriageCaseDetail { case, documents, banns, impediments, outstanding, ready })
+}
+
+/// What must still be done before the marriage can be recorded.
+fn outstanding(
+    case: &MarriageCase,
+    documents: &[MarriageDocument],
+    banns: &[BannsPublication],
+    impediments: &[MarriageImpediment],
+    max_age_days: i64,
+) -> Vec<String> {
+    let mut missing = Vec::new();
+    let name_of = |party: MarriageParty| match party {
+        MarriageParty::Groom => &case.groom_name,
+        MarriageParty::Bride => &case.bride_name,
+    };
+    // Certificates are fresh relative to the wedding, or today if no date is set
+    let wedding = case.proposed_date.unwrap_or_else(|| Utc::now().date_naive());
+
+    for d in documents.iter().filter(|d| d.required) {
+        let label = document_label(d.document_type);
+        if d.received_on.is_none() {
+            missing.push(format!("{}: {} not received", name_of(d.party), label));
+        } else if d.document_type == MarriageDocumentType::BaptismCertificate {
+            match d.issued_on {
+                None => missing.push(format!("{}: issue date of the {} not recorded", name_of(d.party), label)),
+                Some(issued) if (wedding - issued).num_days() > max_age_days => missing.push(format!(
+                    "{}: {} issued on {} is more than {} days old", name_of(d.party), label, issued, max_age_days
+                )),
+                _ => {}
+            }
+        }
+    }
+
+    if banns.is_empty() {
+        missing.push("Banns not scheduled".to_string());
+    }
+    let mut parishes: Vec<&str> = banns.iter().map(|b| b.parish_name.as_str()).collect();
+    parishes.dedup();
+    for parish in parishes {
+        let read = banns.iter().filter(|b| b.parish_name == parish && b.published_at.is_some()).count();
+        if read < 3 {
+            missing.push(format!("Banns read {} of 3 times in {}", read, parish));
+        }
+    }
+
+    for i in impediments.iter().filter(|i| i.resolved_on.is_none()) {
+        missing.push(format!("Impediment not resolved: {}", i.description));
+    }
+
+    if case.pre_cana_completed_on.is_none() {
+        missing.push("Pre-Cana course not completed".to_string());
+    }
+    missing
+}
+
+fn document_label(document_type: MarriageDocumentType) -> &'static str {
+    match document_type {
+        MarriageDocumentType::BaptismCertificate => "baptism certificate",
+        MarriageDocumentType::ConfirmationCertificate => "confirmation certificate",
+        MarriageDocumentType::FreedomToMarry => "freedom-to-marry statement",
+        MarriageDocumentType::CivilNotice => "civil notice",
+        MarriageDocumentType::Dispensation => "dispensation",
+        MarriageDocumentType::Other => "document",
+    }
+}
@@ -0,0 +1,834 @@
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use sqlx::{PgConnection, PgPool, Postgres};
+use uuid::Uuid;
+use chrono::{Datelike, Duration, NaiveDate, Utc};
+use rust_decimal::Decimal;
+use serde::Deserialize;
+use crate::{AppState, handlers::auth::AuthUser, handlers::rbac};
+use crate::export::{self, Cell, ExportFormat, ReportHeader, Row, Table};
+use crate::models::mass::{
+    CreateMassIntentionRequest, CreateMassScheduleRequest, MassIntention, MassIntentionStatus, MassSchedule, MassSlot,
+    TransferMassIntentionRequest, UpdateMassIntentionRequest, UpdateMassScheduleRequest, WeeklyMass,
+};
+use crate::models::transaction::TransactionCategory;
+
+const INTENTION_WITH_MASS: &str = r#"
+    SELECT i.*, s.mass_time, COALESCE(s.church_name, cl.cluster_name) AS church_name,
+           t.transaction_number AS receipt_number
+    FROM mass_intention i
+    LEFT JOIN mass_schedule s ON s.id = i.mass_schedule_id
+    LEFT JOIN cluster cl ON cl.id = s.cluster_id
+    LEFT JOIN income_transaction t ON t.id = i.income_transaction_id
+"#;
+
+/// Longest range of Masses listed at once.
+const MAX_SLOT_DAYS: i64 = 62;
+
+#[derive(Debug, Deserialize)]
+pub struct MassScheduleQuery {
+    pub parish_id: Option<Uuid>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MassSlotQuery {
+    pub parish_id: Option<Uuid>,
+    /// Defaults to today.
+    pub from: Option<NaiveDate>,
+    /// Defaults to a week from `from`.
+    pub to: Option<NaiveDate>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MassIntentionQuery {
+    pub parish_id: Option<Uuid>,
+    /// Mass date, or the transfer date for transferred intentions.
+    pub from: Option<NaiveDate>,
+    pub to: Option<NaiveDate>,
+    pub status: Option<MassIntentionStatus>,
+    pub member_id: Option<Uuid>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct WeeklyIntentionsQuery {
+    pub parish_id: Option<Uuid>,
+    /// Any day of the week wanted; defaults to this week.
+    pub week_of: Option<NaiveDate>,
+}
+
+// ============================================================================
+// MASS SCHEDULE
+// ============================================================================
+
+pub async fn list_mass_schedules(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Query(query): Query<MassScheduleQuery>,
+) -> Result<Json<Vec<MassSchedule>>, (StatusCode, String)> {
+    let parish_id = rbac::resolve_parish_id(&auth, query.parish_id)?;
+
+    let schedules = sqlx::query_as::<_, MassSchedule>(
+        r#"
+        SELECT s.*, cl.cluster_name
+        FROM mass_schedule s
+        LEFT JOIN cluster cl ON cl.id = s.cluster_id
+        WHERE s.parish_id = $1 AND s.deleted_at IS NULL
+        ORDER BY s.day_of_week, s.mass_time
+        "#
+    )
+    .bind(parish_id)
+    .fetch_all(&state.db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(schedules))
+}
+
+pub async fn create_mass_schedule(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Json(payload): Json<CreateMassScheduleRequest>,
+) -> Result<Json<MassSchedule>, (StatusCode, String)> {
+    rbac::require_write(&auth)?;
+    let parish_id = rbac::resolve_parish_id(&auth, Some(payload.parish_id))?;
+    if !(1..=7).contains(&payload.day_of_week) {
+        return Err((StatusCode::BAD_REQUEST, "day_of_week runs from 1 (Monday) to 7 (Sunday)".to_string()));
+    }
+    validate_schedule(payload.max_intentions, payload.valid_from, payload.valid_to)?;
+    check_cluster(&state.db, parish_id, payload.cluster_id).await?;
+
+    let id: Uuid = sqlx::query_scalar(
+        r#"
+        INSERT INTO mass_schedule (
+            parish_id, cluster_id, day_of_week, mass_time, church_name, language,
+            max_intentions, valid_from, valid_to, notes
+        )
+        VALUES ($1, $2, $3, $4, $5, $6, COALESCE($7, 1), $8, $9, $10)
+        RETURNING id
+        "#
+    )
+    .bind(parish_id)
+    .bind(payload.cluster_id)
+    .bind(payload.day_of_week)
+    .bind(payload.mass_time)
+    .bind(payload.church_name)
+    .bind(payload.language)
+    .bind(payload.max_intentions)
+    .bind(payload.valid_from)
+    .bind(payload.valid_to)
+    .bind(payload.notes)
+    .fetch_one(&state.db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(fetch_schedule(&state.db, &auth, id).await?))
+}
+
+pub async fn update_mass_schedule(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+    Json(payload): Json<UpdateMassScheduleRequest>,
+) -> Result<Json<MassSchedule>, (StatusCode, String)> {
+    rbac::require_write(&auth)?;
+
+    // Lock the schedule so no intention is booked against the old dates
+    // while the change is checked
+    let mut tx = state.db.begin().await.map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+    lock_schedule(&mut tx, id).await?;
+    let mut schedule = fetch_schedule(&mut *tx, &auth, id).await?;
+
+    if let Some(val) = payload.cluster_id { schedule.cluster_id = Some(val); }
+    if let Some(val) = payload.mass_time { schedule.mass_time = val; }
+    if let Some(val) = payload.church_name { schedule.church_name = Some(val); }
+    if let Some(val) = payload.language { schedule.language = Some(val); }
+    if let Some(val) = payload.max_intentions { schedule.max_intentions = val; }
+    if let Some(val) = payload.valid_from { schedule.valid_from = Some(val); }
+    if let Some(val) = payload.valid_to { schedule.valid_to = Some(val); }
+    if let Some(val) = payload.is_active { schedule.is_active = val; }
+    if let Some(val) = payload.notes { schedule.notes = Some(val); }
+    validate_schedule(Some(schedule.max_intentions), schedule.valid_from, schedule.valid_to)?;
+    check_cluster(&mut *tx, schedule.parish_id, schedule.cluster_id).await?;
+
+    // Deactivating or shortening the schedule would drop Masses that
+    // already have intentions booked
+    if payload.is_active == Some(false) || payload.valid_from.is_some() || payload.valid_to.is_some() {
+        let stranded = stranded_intentions(&mut tx, id, schedule.is_active, schedule.valid_from, schedule.valid_to).await?;
+        if stranded > 0 {
+            return Err((StatusCode::CONFLICT, format!("{} intention(s) are booked for coming celebrations this change would remove", stranded)));
+        }
+    }
+
+    sqlx::query(
+        r#"
+        UPDATE mass_schedule SET
+            cluster_id = $1, mass_time = $2, church_name = $3, language = $4, max_intentions = $5,
+            valid_from = $6, valid_to = $7, is_active = $8, notes = $9
+        WHERE id = $10
+        "#
+    )
+    .bind(schedule.cluster_id)
+    .bind(schedule.mass_time)
+    .bind(&schedule.church_name)
+    .bind(&schedule.language)
+    .bind(schedule.max_intentions)
+    .bind(schedule.valid_from)
+    .bind(schedule.valid_to)
+    .bind(schedule.is_active)
+    .bind(&schedule.notes)
+    .bind(id)
+    .execute(&mut *tx)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    let schedule = fetch_schedule(&mut *tx, &auth, id).await?;
+    tx.commit().await.map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(schedule))
+}
+
+/// Removes a Mass from the schedule. Intentions still booked for a future
+/// celebration must be moved or cancelled first.
+pub async fn delete_mass_schedule(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> Result<StatusCode, (StatusCode, String)> {
+    rbac::require_write(&auth)?;
+
+    let mut tx = state.db.begin().await.map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+    lock_schedule(&mut tx, id).await?;
+    fetch_schedule(&mut *tx, &auth, id).await?;
+
+    let booked = stranded_intentions(&mut tx, id, false, None, None).await?;
+    if booked > 0 {
+        return Err((StatusCode::CONFLICT, format!("{} intention(s) are booked for coming celebrations of this Mass", booked)));
+    }
+
+    sqlx::query("UPDATE mass_schedule SET deleted_at = NOW() WHERE id = $1")
+        .bind(id)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    tx.commit().await.map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Each celebration of the scheduled Masses over a range of dates, with
+/// how many more intentions it can take.
+pub async fn list_masses(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Query(query): Query<MassSlotQuery>,
+) -> Result<Json<Vec<MassSlot>>, (StatusCode, String)> {
+    let parish_id = rbac::resolve_parish_id(&auth, query.parish_id)?;
+    let from = query.from.unwrap_or_else(|| Utc::now().date_naive());
+    let to = query.to.unwrap_or(from + Duration::days(6));
+    if to < from || (to - from).num_days() >= MAX_SLOT_DAYS {
+        return Err((StatusCode::BAD_REQUEST, format!("List up to {} days of Masses at a time", MAX_SLOT_DAYS)));
+    }
+
+    Ok(Json(slots(&state.db, parish_id, from, to).await?))
+}
+
+// ============================================================================
+// MASS INTENTIONS
+// ============================================================================
+
+pub async fn list_mass_intentions(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Query(query): Query<MassIntentionQuery>,
+    format: ExportFormat,
+) -> Result<Response, (StatusCode, String)> {
+    let parish_id = rbac::resolve_parish_id(&auth, query.parish_id)?;
+
+    let intentions = sqlx::query_as::<_, MassIntention>(&format!(
+        r#"{}
+        WHERE i.parish_id = $1 AND i.deleted_at IS NULL
+          AND ($2::date IS NULL OR COALESCE(i.transferred_on, i.mass_date) >= $2)
+          AND ($3::date IS NULL OR COALESCE(i.transferred_on, i.mass_date) <= $3)
+          AND ($4::mass_intention_status IS NULL OR i.status = $4)
+          AND ($5::uuid IS NULL OR i.member_id = $5 OR i.requested_by_member_id = $5)
+        ORDER BY COALESCE(i.transferred_on, i.mass_date), s.mass_time, i.created_at"#,
+        INTENTION_WITH_MASS
+    ))
+    .bind(parish_id)
+    .bind(query.from)
+    .bind(query.to)
+    .bind(query.status)
+    .bind(query.member_id)
+    .fetch_all(&state.db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    if format == ExportFormat::Json {
+        return Ok(Json(intentions).into_response());
+    }
+
+    let mut table = Table::new(&[
+        "Mass Date", "Time", "Church", "Intention", "Requested By", "Stipend", "Receipt", "Status", "Transferred To",
+        "Transferred On",
+    ]);
+    for i in &intentions {
+        table.rows.push(Row::detail(vec![
+            Cell::opt(i.mass_date),
+            Cell::opt(i.mass_time.map(|t| t.format("%H:%M"))),
+            Cell::opt(i.church_name.as_ref()),
+            Cell::text(i.intention.as_str()),
+            Cell::text(i.requested_by_name.as_str()),
+            Cell::Amount(i.stipend_amount),
+            Cell::opt(i.receipt_number.as_ref()),
+            Cell::text(export::enum_label(&i.status)),
+            Cell::opt(i.transferred_to.as_ref()),
+            Cell::opt(i.transferred_on),
+        ]));
+    }
+    let stipends: Decimal = intentions.iter().map(|i| i.stipend_amount).sum();
+    table.rows.push(Row::total(vec![
+        Cell::text("Total Intentions"), Cell::Empty, Cell::Empty, Cell::Count(intentions.len() as i64), Cell::Empty,
+        Cell::Amount(stipends),
+    ]));
+
+    let period = match (query.from, query.to) {
+        (Some(from), Some(to)) => Some(export::period_label(from, to)),
+        _ => None,
+    };
+    let title = match query.status {
+        Some(MassIntentionStatus::Transferred) => "Transferred Mass Intentions",
+        _ => "Mass Intentions",
+    };
+    let header = ReportHeader::parish(parish_id, title, period);
+    export::respond_table(&state.db, &auth, format, header, table).await
+}
+
+/// The week's Masses, Monday to Sunday, each with the intentions the
+/// celebrant is to offer.
+pub async fn weekly_mass_intentions(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Query(query): Query<WeeklyIntentionsQuery>,
+    format: ExportFormat,
+) -> Result<Response, (StatusCode, String)> {
+    let parish_id = rbac::resolve_parish_id(&auth, query.parish_id)?;
+    let day = query.week_of.unwrap_or_else(|| Utc::now().date_naive());
+    let monday = day - Duration::days(day.weekday().num_days_from_monday() as i64);
+    let sunday = monday + Duration::days(6);
+
+    let masses = slots(&state.db, parish_id, monday, sunday).await?;
+    let mut intentions = sqlx::query_as::<_, MassIntention>(&format!(
+        r#"{}
+        WHERE i.parish_id = $1 AND i.deleted_at IS NULL AND i.mass_date BETWEEN $2 AND $3
+          AND i.status IN ('BOOKED', 'CELEBRATED')
+        ORDER BY i.created_at"#,
+        INTENTION_WITH_MASS
+    ))
+    .bind(parish_id)
+    .bind(monday)
+    .bind(sunday)
+    .fetch_all(&state.db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    let week: Vec<WeeklyMass> = masses
+        .into_iter()
+        .map(|mass| {
+            let (own, rest) = intentions.drain(..).partition(|i: &MassIntention| {
+                i.mass_schedule_id == Some(mass.mass_schedule_id) && i.mass_date == Some(mass.mass_date)
+            });
+            intentions = rest;
+            WeeklyMass { mass, intentions: own }
+        })
+        .collect();
+
+    if format == ExportFormat::Json {
+        return Ok(Json(week).into_response());
+    }
+
+    let mut table = Table::new(&["Intention", "Requested By", "Notes"]);
+    for w in &week {
+        let place = w.mass.church_name.as_ref().or(w.mass.cluster_name.as_ref());
+        table.rows.push(Row::heading(format!(
+            "{} {}{}",
+            w.mass.mass_date.format("%A %d %b %Y"),
+            w.mass.mass_time.format("%H:%M"),
+            place.map(|p| format!(" - {}", p)).unwrap_or_default(),
+        )));
+        if w.intentions.is_empty() {
+            table.rows.push(Row::detail(vec![Cell::text("No intention booked")]));
+        }
+        for i in &w.intentions {
+            table.rows.push(Row::detail(vec![
+                Cell::text(i.intention.as_str()),
+                Cell::text(i.requested_by_name.as_str()),
+                Cell::opt(i.notes.as_ref()),
+            ]));
+        }
+    }
+
+    let header = ReportHeader::parish(parish_id, "Weekly Mass Intentions", Some(export::period_label(monday, sunday)));
+    export::respond_table(&state.db, &auth, format, header, table).await
+}
+
+pub async fn get_mass_intention(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<MassIntention>, (StatusCode, String)> {
+    let intention = fetch_intention(&mut *acquire(&state).await?, id).await?;
+    rbac::resolve_parish_id(&auth, Some(intention.parish_id))?;
+    Ok(Json(intention))
+}
+
+/// Books an intention for a celebration of a scheduled Mass, within the
+/// number of intentions that Mass takes.
+pub async fn create_mass_intention(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Json(payload): Json<CreateMassIntentionRequest>,
+) -> Result<Json<MassIntention>, (StatusCode, String)> {
+    rbac::require_write(&auth)?;
+    if payload.intention.trim().is_empty() {
+        return Err((StatusCode::BAD_REQUEST, "The intention is required".to_string()));
+    }
+    let stipend_amount = payload.stipend_amount.unwrap_or(Decimal::ZERO);
+    if stipend_amount < Decimal::ZERO {
+        return Err((StatusCode::BAD_REQUEST, "The stipend cannot be negative".to_string()));
+    }
+
+    let mut tx = state.db.begin().await.map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+    let parish_id = book_slot(&mut tx, payload.mass_schedule_id, payload.mass_date, None).await?;
+    rbac::resolve_parish_id(&auth, Some(parish_id))?;
+    check_stipend(&mut tx, parish_id, payload.income_transaction_id, stipend_amount, None).await?;
+
+    let requested_by_name = match (payload.requested_by_name.as_deref().map(str::trim).filter(|n| !n.is_empty()), payload.requested_by_member_id) {
+        (Some(name), _) => name.to_string(),
+        (None, Some(member_id)) => member_name(&mut tx, member_id).await?,
+        (None, None) => return Err((StatusCode::BAD_REQUEST, "Give who requested the intention".to_string())),
+    };
+    if let Some(member_id) = payload.member_id {
+        member_name(&mut tx, member_id).await?;
+    }
+
+    let id: Uuid = sqlx::query_scalar(
+        r#"
+        INSERT INTO mass_intention (
+            parish_id, mass_schedule_id, mass_date, intention, member_id, requested_by_member_id,
+            requested_by_name, requested_by_phone, stipend_amount, income_transaction_id, notes, booked_by
+        )
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)
+        RETURNING id
+        "#
+    )
+    .bind(parish_id)
+    .bind(payload.mass_schedule_id)
+    .bind(payload.mass_date)
+    .bind(payload.intention.trim())
+    .bind(payload.member_id)
+    .bind(payload.requested_by_member_id)
+    .bind(requested_by_name)
+    .bind(payload.requested_by_phone)
+    .bind(stipend_amount)
+    .bind(payload.income_transaction_id)
+    .bind(payload.notes)
+    .bind(auth.user_id)
+    .fetch_one(&mut *tx)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    let intention = fetch_intention(&mut tx, id).await?;
+    tx.commit().await.map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(intention))
+}
+
+pub async fn update_mass_intention(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+    Json(payload): Json<UpdateMassIntentionRequest>,
+) -> Result<Json<MassIntention>, (StatusCode, String)> {
+    rbac::require_write(&auth)?;
+    let mut tx = state.db.begin().await.map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+    let mut intention = fetch_intention(&mut tx, id).await?;
+    rbac::resolve_parish_id(&auth, Some(intention.parish_id))?;
+
+    if payload.status == Some(MassIntentionStatus::Transferred) {
+        return Err((StatusCode::BAD_REQUEST, "Transfer an intention to another priest with its transfer endpoint".to_string()));
+    }
+    let was_scheduled = matches!(intention.status, MassIntentionStatus::Booked | MassIntentionStatus::Celebrated);
+    let moved = payload.mass_schedule_id.is_some_and(|s| intention.mass_schedule_id != Some(s))
+        || payload.mass_date.is_some_and(|d| intention.mass_date != Some(d));
+
+    if let Some(val) = payload.mass_schedule_id { intention.mass_schedule_id = Some(val); }
+    if let Some(val) = payload.mass_date { intention.mass_date = Some(val); }
+    if let Some(val) = payload.intention { intention.intention = val; }
+    if let Some(val) = payload.requested_by_phone { intention.requested_by_phone = Some(val); }
+    if let Some(val) = payload.stipend_amount { intention.stipend_amount = val; }
+    if let Some(val) = payload.income_transaction_id { intention.income_transaction_id = Some(val); }
+    if let Some(val) = payload.status { intention.status = val; }
+    if let Some(val) = payload.celebrated_by { intention.celebrated_by = Some(val); }
+    if let Some(val) = payload.notes { intention.notes = Some(val); }
+
+    if intention.stipend_amount < Decimal::ZERO {
+        return Err((StatusCode::BAD_REQUEST, "The stipend cannot be negative".to_string()));
+    }
+    if payload.stipend_amount.is_some() || payload.income_transaction_id.is_some() {
+        check_stipend(&mut tx, intention.parish_id, intention.income_transaction_id, intention.stipend_amount, Some(id)).await?;
+    }
+
+    let scheduled = matches!(intention.status, MassIntentionStatus::Booked | MassIntentionStatus::Celebrated);
+    if scheduled {
+        let (Some(schedule_id), Some(mass_date)) = (intention.mass_schedule_id, intention.mass_date) else {
+            return Err((StatusCode::BAD_REQUEST, "Give the Mass the intention is booked for".to_string()));
+        };
+        if moved || !was_scheduled {
+            let parish_id = book_slot(&mut tx, schedule_id, mass_date, Some(id)).await?;
+            if parish_id != intention.parish_id {
+                return Err((StatusCode::BAD_REQUEST, "The Mass belongs to another parish".to_string()));
+            }
+        }
+        if intention.status == MassIntentionStatus::Celebrated && mass_date > Utc::now().date_naive() {
+            return Err((StatusCode::BAD_REQUEST, "The Mass has not been celebrated yet".to_string()));
+        }
+        intention.transferred_to = None;
+        intention.transferred_on = None;
+        intention.transfer_reference = None;
+    }
+
+    sqlx::query(
+        r#"
+        UPDATE mass_intention SET
+            mass_schedule_id = $1, mass_date = $2, intention = $3, requested_by_phone = $4, stipend_amount = $5,
+            income_transaction_id = $6, status = $7, celebrated_by = $8, transferred_to = $9, transferred_on = $10,
+            transfer_reference = $11, notes = $12
+        WHERE id = $13
+        "#
+    )
+    .bind(intention.mass_schedule_id)
+    .bind(intention.mass_date)
+    .bind(intention.intention.trim())
+    .bind(&intention.requested_by_phone)
+    .bind(intention.stipend_amount)
+    .bind(intention.income_transaction_id)
+    .bind(intention.status)
+    .bind(&intention.celebrated_by)
+    .bind(&intention.transferred_to)
+    .bind(intention.transferred_on)
+    .bind(&intention.transfer_reference)
+    .bind(&intention.notes)
+    .bind(id)
+    .execute(&mut *tx)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    let intention = fetch_intention(&mut tx, id).await?;
+    tx.commit().await.map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(intention))
+}
+
+/// Passes a booked intention, with its stipend, to another priest to
+/// celebrate. Its place at the parish Mass is freed.
+pub async fn transfer_mass_intention(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+    Json(payload): Json<TransferMassIntentionRequest>,
+) -> Result<Json<MassIntention>, (StatusCode, String)> {
+    rbac::require_write(&auth)?;
+    let mut conn = acquire(&state).await?;
+    let intention = fetch_intention(&mut conn, id).await?;
+    rbac::resolve_parish_id(&auth, Some(intention.parish_id))?;
+
+    if intention.status != MassIntentionStatus::Booked {
+        return Err((StatusCode::CONFLICT, "Only a booked intention can be transferred".to_string()));
+    }
+    if payload.transferred_to.trim().is_empty() {
+        return Err((StatusCode::BAD_REQUEST, "Give the priest the intention is transferred to".to_string()));
+    }
+
+    sqlx::query(
+        r#"
+        UPDATE mass_intention SET
+            status = 'TRANSFERRED', transferred_to = $1, transferred_on = $2, transfer_reference = $3,
+            notes = COALESCE($4, notes)
+        WHERE id = $5
+        "#
+    )
+    .bind(payload.transferred_to.trim())
+    .bind(payload.transferred_on.unwrap_or_else(|| Utc::now().date_naive()))
+    .bind(payload.transfer_reference)
+    .bind(payload.notes)
+    .bind(id)
+    .execute(&mut *conn)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(fetch_intention(&mut conn, id).await?))
+}
+
+pub async fn delete_mass_intention(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> Result<StatusCode, (StatusCode, String)> {
+    rbac::require_write(&auth)?;
+    let mut conn = acquire(&state).await?;
+    let intention = fetch_intention(&mut conn, id).await?;
+    rbac::resolve_parish_id(&auth, Some(intention.parish_id))?;
+
+    sqlx::query("UPDATE mass_intention SET deleted_at = NOW() WHERE id = $1")
+        .bind(id)
+        .execute(&mut *conn)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+// ============================================================================
+// HELPERS
+// ============================================================================
+
+async fn acquire(state: &AppState) -> Result<sqlx::pool::PoolConnection<sqlx::Postgres>, (StatusCode, String)> {
+    state.db.acquire().await.map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+}
+
+fn validate_schedule(
+    max_intentions: Option<i32>,
+    valid_from: Option<NaiveDate>,
+    valid_to: Option<NaiveDate>,
+) -> Result<(), (StatusCode, String)> {
+    if max_intentions.is_some_and(|m| m < 1) {
+        return Err((StatusCode::BAD_REQUEST, "A Mass takes at least one intention".to_string()));
+    }
+    if let (Some(from), Some(to)) = (valid_from, valid_to) {
+        if to < from {
+            return Err((StatusCode::BAD_REQUEST, "valid_to is before valid_from".to_string()));
+        }
+    }
+    Ok(())
+}
+
+/// Intentions booked for coming celebrations that the schedule would no
+/// longer offer: all of them when it is inactive, otherwise those outside
+/// its validity.
+async fn stranded_intentions(
+    conn: &mut PgConnection,
+    schedule_id: Uuid,
+    is_active: bool,
+    valid_from: Option<NaiveDate>,
+    valid_to: Option<NaiveDate>,
+) -> Result<i64, (StatusCode, String)> {
+    sqlx::query_scalar(
+        r#"
+        SELECT COUNT(*) FROM mass_intention
+        WHERE mass_schedule_id = $1 AND status = 'BOOKED' AND mass_date >= CURRENT_DATE AND deleted_at IS NULL
+          AND (NOT $2 OR mass_date < $3 OR mass_date > $4)
+        "#
+    )
+    .bind(schedule_id)
+    .bind(is_active)
+    .bind(valid_from)
+    .bind(valid_to)
+    .fetch_one(conn)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+}
+
+async fn check_cluster<'e, E>(db: E, parish_id: Uuid, cluster_id: Option<Uuid>) -> Result<(), (StatusCode, String)>
+where
+    E: sqlx::Executor<'e, Database = Postgres>,
+{
+    let Some(cluster_id) = cluster_id else { return Ok(()) };
+    let exists: bool = sqlx::query_scalar(
+        "SELECT EXISTS (SELECT 1 FROM cluster WHERE id = $1 AND parish_id = $2 AND deleted_at IS NULL)"
+    )
+    .bind(cluster_id)
+    .bind(parish_id)
+    .fetch_one(db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+    if !exists {
+        return Err((StatusCode::BAD_REQUEST, "The outstation is not in this parish".to_string()));
+    }
+    Ok(())
+}
+
+async fn fetch_schedule<'e, E>(db: E, auth: &AuthUser, id: Uuid) -> Result<MassSchedule, (StatusCode, String)>
+where
+    E: sqlx::Executor<'e, Database = Postgres>,
+{
+    let schedule = sqlx::query_as::<_, MassSchedule>(
+        r#"
+        SELECT s.*, cl.cluster_name
+        FROM mass_schedule s
+        LEFT JOIN cluster cl ON cl.id = s.cluster_id
+        WHERE s.id = $1 AND s.deleted_at IS NULL
+        "#
+    )
+    .bind(id)
+    .fetch_optional(db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+    .ok_or((StatusCode::NOT_FOUND, "Mass schedule not found".to_string()))?;
+    rbac::resolve_parish_id(auth, Some(schedule.parish_id))?;
+    Ok(schedule)
+}
+
+/// Holds the schedule row until the caller's transaction ends; bookings
+/// take the same lock in `book_slot`.
+async fn lock_schedule(conn: &mut PgConnection, id: Uuid) -> Result<(), (StatusCode, String)> {
+    sqlx::query("SELECT id FROM mass_schedule WHERE id = $1 FOR UPDATE")
+        .bind(id)
+        .execute(conn)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+    Ok(())
+}
+
+async fn fetch_intention(conn: &mut PgConnection, id: Uuid) -> Result<MassIntention, (StatusCode, String)> {
+    sqlx::query_as::<_, MassIntention>(&format!("{} WHERE i.id = $1 AND i.deleted_at IS NULL", INTENTION_WITH_MASS))
+        .bind(id)
+        .fetch_optional(conn)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+        .ok_or((StatusCode::NOT_FOUND, "Mass intention not found".to_string()))
+}
+
+async fn member_name(conn: &mut PgConnection, member_id: Uuid) -> Result<String, (StatusCode, String)> {
+    sqlx::query_scalar("SELECT CONCAT_WS(' ', first_name, middle_name, last_name) FROM member WHERE id = $1 AND deleted_at IS NULL")
+        .bind(member_id)
+        .fetch_optional(conn)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+        .ok_or((StatusCode::NOT_FOUND, "Member not found".to_string()))
+}
+
+async fn slots(db: &PgPool, parish_id: Uuid, from: NaiveDate, to: NaiveDate) -> Result<Vec<MassSlot>, (StatusCode, String)> {
+    sqlx::query_as::<_, MassSlot>(
+        r#"
+        SELECT s.id AS mass_schedule_id, d.day::date AS mass_date, s.mass_time, s.church_name, cl.cluster_name,
+               s.language, s.max_intentions, COUNT(i.id) AS booked,
+               GREATEST(s.max_intentions - COUNT(i.id), 0) AS available
+        FROM mass_schedule s
+        CROSS JOIN generate_series($2::date, $3::date, INTERVAL '1 day') AS d(day)
+        LEFT JOIN cluster cl ON cl.id = s.cluster_id
+        LEFT JOIN mass_intention i ON i.mass_schedule_id = s.id AND i.mass_date = d.day::date
+             AND i.status IN ('BOOKED', 'CELEBRATED') AND i.deleted_at IS NULL
+        WHERE s.parish_id = $1 AND s.deleted_at IS NULL AND s.is_active
+          AND EXTRACT(ISODOW FROM d.day) = s.day_of_week
+          AND (s.valid_from IS NULL OR d.day::date >= s.valid_from)
+          AND (s.valid_to IS NULL OR d.day::date <= s.valid_to)
+        GROUP BY s.id, d.day, cl.cluster_name
+        ORDER BY d.day, s.mass_time
+        "#
+    )
+    .bind(parish_id)
+    .bind(from)
+    .bind(to)
+    .fetch_all(db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+}
+
+/// Checks the Mass is celebrated on the date and has room for another
+/// intention, returning its parish. The schedule row is locked so two
+/// bookings cannot take the last place together.
+async fn book_slot(
+    conn: &mut PgConnection,
+    schedule_id: Uuid,
+    mass_date: NaiveDate,
+    exclude_id: Option<Uuid>,
+) -> Result<Uuid, (StatusCode, String)> {
+    let schedule = sqlx::query_as::<_, MassSchedule>(
+        "SELECT * FROM mass_schedule WHERE id = $1 AND deleted_at IS NULL FOR UPDATE"
+    )
+    .bind(schedule_id)
+    .fetch_optional(&mut *conn)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+    .ok_or((StatusCode::NOT_FOUND, "Mass schedule not found".to_string()))?;
+
+    let celebrated = schedule.is_active
+        && mass_date.weekday().number_from_monday() as i16 == schedule.day_of_week
+        && schedule.valid_from.is_none_or(|from| mass_date >= from)
+        && schedule.valid_to.is_none_or(|to| mass_date <= to);
+    if !celebrated {
+        return Err((StatusCode::BAD_REQUEST, format!(
+            "The {} Mass is not celebrated on {}", schedule.mass_time.format("%H:%M"), mass_date.format("%A %d %b %Y")
+        )));
+    }
+
+    let booked: i64 = sqlx::query_scalar(
+        r#"
+        SELECT COUNT(*) FROM mass_intention
+        WHERE mass_schedule_id = $1 AND mass_date = $2 AND status IN ('BOOKED', 'CELEBRATED')
+          AND deleted_at IS NULL AND ($3::uuid IS NULL OR id <> $3)
+        "#
+    )
+    .bind(schedule_id)
+    .bind(mass_date)
+    .bind(exclude_id)
+    .fetch_one(&mut *conn)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+    if booked >= schedule.max_intentions as i64 {
+        return Err((StatusCode::CONFLICT, format!(
+            "The {} Mass on {} already has its {} intention(s)",
+            schedule.mass_time.format("%H:%M"), mass_date.format("%d %b %Y"), schedule.max_intentions
+        )));
+    }
+    Ok(schedule.parish_id)
+}
+
+/// A linked receipt must be a Mass offering taken by the same parish, and
+/// the stipends drawn on it cannot add up to more than was received.
+async fn check_stipend(
+    conn: &mut PgConnection,
+    parish_id: Uuid,
+    income_transaction_id: Option<Uuid>,
+    stipend_amount: Decimal,
+    exclude_id: Option<Uuid>,
+) -> Result<(), (StatusCode, String)> {
+    let Some(transaction_id) = income_transaction_id else { return Ok(()) };
+    let (category, amount, number): (TransactionCategory, Decimal, String) = sqlx::query_as(
+        "SELECT category, amount, transaction_number FROM income_transaction WHERE id = $1 AND parish_id = $2 AND deleted_at IS NULL"
+    )
+    .bind(transaction_id)
+    .bind(parish_id)
+    .fetch_optional(&mut *conn)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+    .ok_or((StatusCode::NOT_FOUND, "Income transaction not found".to_string()))?;
+
+    if category != TransactionCategory::MassOffering {
+        return Err((StatusCode::BAD_REQUEST, "The linked income transaction is not a Mass offering".to_string()));
+    }
+
+    let allocated: Decimal = sqlx::query_scalar(
+        r#"
+        SELECT COALESCE(SUM(stipend_amount), 0) FROM mass_intention
+        WHERE income_transaction_id = $1 AND deleted_at IS NULL AND ($2::uuid IS NULL OR id <> $2)
+        "#
+    )
+    .bind(transaction_id)
+    .bind(exclude_id)
+    .fetch_one(&mut *conn)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+    if allocated + stipend_amount > amount {
+        return Err((StatusCode::BAD_REQUEST, format!(
+            "Receipt {} is for {} and {} of it is already allocated to other intentions",
+            number, export::format_amount(amount), export::format_amount(allocated)
+        )));
+    }
+    Ok(())
+}
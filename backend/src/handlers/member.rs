@@ -1,10 +1,12 @@
 use axum::{
     extract::{Path, State, Query},
     http::StatusCode,
+    response::{IntoResponse, Response},
     Json,
 };
 use uuid::Uuid;
 use crate::{AppState, models::member::{Member, GenderType, MaritalStatus, FamilyRole}, handlers::auth::AuthUser, handlers::rbac};
+use crate::export::{self, Cell, ExportFormat, ReportHeader, Row, Table};
 use serde::Deserialize;
 
 #[derive(Debug, Deserialize)]
@@ -12,6 +14,9 @@ pub struct ListMembersQuery {
     pub parish_id: Option<Uuid>,
     pub limit: Option<i64>,
     pub offset: Option<i64>,
+    /// Deceased members are left out unless asked for.
+    #[serde(default)]
+    pub include_deceased: bool,
 }
 
 #[derive(Debug, Deserialize)]
@@ -40,23 +45,52 @@ pub async fn list_members(
     auth: AuthUser,
     State(state): State<AppState>,
     Query(query): Query<ListMembersQuery>,
-) -> Result<Json<Vec<Member>>, (StatusCode, String)> {
-    let limit = query.limit.unwrap_or(50);
+    format: ExportFormat,
+) -> Result<Response, (StatusCode, String)> {
+    // Exports cover the whole register unless a page is asked for explicitly
+    let exporting = format != ExportFormat::Json;
+    let limit = query.limit.unwrap_or(if exporting { i64::MAX } else { 50 });
     let offset = query.offset.unwrap_or(0);
 
     let parish_id = rbac::resolve_parish_id(&auth, query.parish_id)?;
 
     let members = sqlx::query_as::<_, Member>(
-        "SELECT * FROM member WHERE parish_id = $1 AND deleted_at IS NULL ORDER BY last_name, first_name LIMIT $2 OFFSET $3"
+        "SELECT * FROM member WHERE parish_id = $1 AND deleted_at IS NULL AND ($4 OR date_of_death IS NULL) ORDER BY last_name, first_name LIMIT $2 OFFSET $3"
     )
     .bind(parish_id)
     .bind(limit)
     .bind(offset)
+    .bind(query.include_deceased)
     .fetch_all(&state.db)
     .await
     .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
 
-    Ok(Json(members))
+    if !exporting {
+        return Ok(Json(members).into_response());
+    }
+
+    let mut table = Table::new(&[
+        "Member Code", "Last Name", "First Name", "Middle Name", "Gender", "Date of Birth",
+        "Marital Status", "Phone", "Email", "Address",
+    ]);
+    for m in &members {
+        table.rows.push(Row::detail(vec![
+            Cell::text(&m.member_code),
+            Cell::text(&m.last_name),
+            Cell::text(&m.first_name),
+            Cell::opt(m.middle_name.as_ref()),
+            Cell::opt(m.gender.as_ref().map(export::enum_label)),
+            Cell::opt(m.date_of_birth),
+            Cell::opt(m.marital_status.as_ref().map(export::enum_label)),
+            Cell::opt(m.phone_number.as_ref()),
+            Cell::opt(m.email.as_ref()),
+            Cell::opt(m.physical_address.as_ref()),
+        ]));
+    }
+    table.rows.push(Row::total(vec![Cell::text("Total Members"), Cell::Count(members.len() as i64)]));
+
+    let header = ReportHeader::parish(parish_id, "Member Register", None);
+    export::respond_table(&state.db, &auth, format, header, table).await
 }
 
 pub async fn get_member(
@@ -157,6 +191,10 @@ pub async fn update_member(
     .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
     .ok_or((StatusCode::NOT_FOUND, "Member not found".to_string()))?;
 
+    if payload.is_active == Some(true) && member.date_of_death.is_some() {
+        return Err((StatusCode::CONFLICT, "The member is recorded as deceased; delete the burial record if the death was entered in error".to_string()));
+    }
+
     // In a real app, we might use a helper or macro to avoid this boilerplate
     if let Some(val) = payload.family_id { member.family_id = Some(val); }
     if let Some(val) = payload.scc_id { member.scc_id = Some(val); }
@@ -0,0 +1,536 @@
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    Json,
+};
+use sqlx::{FromRow, PgConnection, PgPool};
+use uuid::Uuid;
+use chrono::NaiveDate;
+use serde::Deserialize;
+use crate::{AppState, handlers::auth::AuthUser, handlers::rbac};
+use crate::models::member::{SacramentRecord, SacramentType};
+use crate::models::notation::{
+    BaptismNotation, CreateNotationRequest, NotationNotice, NotationNoticeStatus, NotationOutcome,
+    NotationType, NoticeDirection, RejectNotationNoticeRequest,
+};
+
+// ============================================================================
+// Notations on a baptism entry
+// ============================================================================
+
+pub async fn list_notations(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Path(baptism_id): Path<Uuid>,
+) -> Result<Json<Vec<BaptismNotation>>, (StatusCode, String)> {
+    let baptism = fetch_baptism(&state.db, baptism_id).await?;
+    rbac::resolve_parish_id(&auth, Some(baptism.parish_id))?;
+
+    let notations = sqlx::query_as::<_, BaptismNotation>(
+        "SELECT * FROM baptism_notation WHERE baptism_record_id = $1 AND deleted_at IS NULL ORDER BY notation_date, created_at"
+    )
+    .bind(baptism_id)
+    .fetch_all(&state.db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(notations))
+}
+
+/// Adds a notation by hand. The parish that keeps the baptism writes it
+/// straight into its register; any other parish sends it as a notice.
+pub async fn create_notation(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Path(baptism_id): Path<Uuid>,
+    Json(payload): Json<CreateNotationRequest>,
+) -> Result<Json<NotationOutcome>, (StatusCode, String)> {
+    rbac::require_write(&auth)?;
+    let baptism = fetch_baptism(&state.db, baptism_id).await?;
+    let parish_id = rbac::resolve_parish_id(&auth, payload.parish_id.or(auth.parish_id).or(Some(baptism.parish_id)))?;
+
+    let text = payload.notation_text.trim();
+    if text.is_empty() {
+        return Err((StatusCode::BAD_REQUEST, "Notation text is required".to_string()));
+    }
+
+    let mut conn = state.db.acquire().await.map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+    let outcome = deliver(&mut conn, auth.user_id, &baptism, NewNotation {
+        notation_type: payload.notation_type,
+        notation_date: payload.notation_date,
+        notation_text: text.to_string(),
+        source_record_id: None,
+        source_parish_id: parish_id,
+    })
+    .await?;
+
+    Ok(Json(outcome))
+}
+
+pub async fn delete_notation(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> Result<StatusCode, (StatusCode, String)> {
+    rbac::require_write(&auth)?;
+    let parish_id: Option<Uuid> = sqlx::query_scalar(
+        r#"
+        SELECT s.parish_id FROM baptism_notation n
+        JOIN sacrament_record s ON s.id = n.baptism_record_id
+        WHERE n.id = $1 AND n.deleted_at IS NULL
+        "#
+    )
+    .bind(id)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+    let parish_id = parish_id.ok_or((StatusCode::NOT_FOUND, "Notation not found".to_string()))?;
+    rbac::resolve_parish_id(&auth, Some(parish_id))?;
+
+    sqlx::query("UPDATE baptism_notation SET deleted_at = NOW() WHERE id = $1")
+        .bind(id)
+        .execute(&state.db)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+// ============================================================================
+// Notices between parishes
+// ============================================================================
+
+#[derive(Debug, Deserialize)]
+pub struct NoticeQuery {
+    pub parish_id: Option<Uuid>,
+    /// Defaults to INCOMING.
+    pub direction: Option<NoticeDirection>,
+    pub status: Option<NotationNoticeStatus>,
+}
+
+pub async fn list_notices(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Query(query): Query<NoticeQuery>,
+) -> Result<Json<Vec<NotationNotice>>, (StatusCode, String)> {
+    let parish_id = rbac::resolve_parish_id(&auth, query.parish_id)?;
+    let outgoing = query.direction == Some(NoticeDirection::Outgoing);
+
+    let notices = sqlx::query_as::<_, NotationNotice>(
+        r#"
+        SELECT n.*, fp.parish_name AS from_parish_name, tp.parish_name AS to_parish_name,
+               CONCAT_WS(' ', m.first_name, m.middle_name, m.last_name) AS member_name
+        FROM notation_notice n
+        JOIN parish fp ON fp.id = n.from_parish_id
+        JOIN parish tp ON tp.id = n.to_parish_id
+        JOIN member m ON m.id = n.member_id
+        WHERE (CASE WHEN $2 THEN n.from_parish_id ELSE n.to_parish_id END) = $1
+          AND ($3::notation_notice_status IS NULL OR n.status = $3)
+        ORDER BY n.sent_at DESC
+        "#
+    )
+    .bind(parish_id)
+    .bind(outgoing)
+    .bind(query.status)
+    .fetch_all(&state.db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(notices))
+}
+
+/// Writes a pending notice into the register of the parish that keeps the
+/// baptism, or for a withdrawal notice removes the notation it names.
+pub async fn apply_notice(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<BaptismNotation>, (StatusCode, String)> {
+    rbac::require_write(&auth)?;
+    let mut tx = state.db.begin().await.map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+    let notice = fetch_pending_notice(&mut tx, &auth, id).await?;
+
+    let notation = match notice.withdraws_notation_id {
+        Some(notation_id) => sqlx::query_as::<_, BaptismNotation>(
+            "UPDATE baptism_notation SET deleted_at = COALESCE(deleted_at, NOW()) WHERE id = $1 RETURNING *"
+        )
+        .bind(notation_id)
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?,
+        None => insert_notation(&mut tx, auth.user_id, notice.baptism_record_id, &NewNotation {
+            notation_type: notice.notation_type,
+            notation_date: notice.notation_date,
+            notation_text: notice.notation_text.clone(),
+            source_record_id: notice.source_record_id,
+            source_parish_id: notice.from_parish_id,
+        })
+        .await?,
+    };
+
+    sqlx::query(
+        r#"
+        UPDATE notation_notice
+        SET status = 'APPLIED', processed_by = $2, processed_at = NOW(), notation_id = $3
+        WHERE id = $1
+        "#
+    )
+    .bind(id)
+    .bind(auth.user_id)
+    .bind(notation.id)
+    .execute(&mut *tx)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    tx.commit().await.map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(notation))
+}
+
+/// Sends a notice back, e.g. when it names the wrong person.
+pub async fn reject_notice(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+    Json(payload): Json<RejectNotationNoticeRequest>,
+) -> Result<Json<NotationNotice>, (StatusCode, String)> {
+    rbac::require_write(&auth)?;
+    if payload.reason.trim().is_empty() {
+        return Err((StatusCode::BAD_REQUEST, "A reason is required".to_string()));
+    }
+    let mut tx = state.db.begin().await.map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+    fetch_pending_notice(&mut tx, &auth, id).await?;
+
+    let notice = sqlx::query_as::<_, NotationNotice>(
+        r#"
+        UPDATE notation_notice
+        SET status = 'REJECTED', processed_by = $2, processed_at = NOW(), rejection_reason = $3
+        WHERE id = $1
+        RETURNING *
+        "#
+    )
+    .bind(id)
+    .bind(auth.user_id)
+    .bind(payload.reason.trim())
+    .fetch_one(&mut *tx)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    tx.commit().await.map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(notice))
+}
+
+/// The notice, locked until the caller's transaction ends so it is only
+/// processed once.
+async fn fetch_pending_notice(conn: &mut PgConnection, auth: &AuthUser, id: Uuid) -> Result<NotationNotice, (StatusCode, String)> {
+    let notice = sqlx::query_as::<_, NotationNotice>("SELECT * FROM notation_notice WHERE id = $1 FOR UPDATE")
+        .bind(id)
+        .fetch_optional(&mut *conn)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+        .ok_or((StatusCode::NOT_FOUND, "Notice not found".to_string()))?;
+    rbac::resolve_parish_id(auth, Some(notice.to_parish_id))?;
+
+    if notice.status != NotationNoticeStatus::Pending {
+        return Err((StatusCode::CONFLICT, "Notice has already been processed".to_string()));
+    }
+    Ok(notice)
+}
+
+// ============================================================================
+// Automatic notations
+// ============================================================================
+
+#[derive(Debug, FromRow)]
+struct Baptism {
+    id: Uuid,
+    parish_id: Uuid,
+    member_id: Uuid,
+}
+
+struct NewNotation {
+    notation_type: NotationType,
+    notation_date: NaiveDate,
+    notation_text: String,
+    source_record_id: Option<Uuid>,
+    source_parish_id: Uuid,
+}
+
+async fn fetch_baptism(db: &PgPool, id: Uuid) -> Result<Baptism, (StatusCode, String)> {
+    let (kind, baptism) = sqlx::query_as::<_, (SacramentType, Uuid, Uuid, Uuid)>(
+        "SELECT sacrament_type, id, parish_id, member_id FROM sacrament_record WHERE id = $1 AND deleted_at IS NULL"
+    )
+    .bind(id)
+    .fetch_optional(db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+    .map(|(kind, id, parish_id, member_id)| (kind, Baptism { id, parish_id, member_id }))
+    .ok_or((StatusCode::NOT_FOUND, "Sacrament record not found".to_string()))?;
+
+    if kind != SacramentType::Baptism {
+        return Err((StatusCode::BAD_REQUEST, "Notations are made on baptism entries".to_string()));
+    }
+    Ok(baptism)
+}
+
+async fn member_baptism(conn: &mut PgConnection, member_id: Uuid) -> Result<Option<Baptism>, (StatusCode, String)> {
+    sqlx::query_as::<_, Baptism>(
+        "SELECT id, parish_id, member_id FROM sacrament_record WHERE member_id = $1 AND sacrament_type = 'BAPTISM' AND deleted_at IS NULL"
+    )
+    .bind(member_id)
+    .fetch_optional(conn)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+}
+
+/// Notes a confirmation, marriage or ordination on the baptism entry of
+/// the member (and, for a marriage, of the spouse). Recording a baptism
+/// picks up the later sacraments already in the register. Entries already
+/// carrying the same notation are left alone.
+pub async fn annotate_baptism(
+    conn: &mut PgConnection,
+    user_id: Uuid,
+    record: &SacramentRecord,
+) -> Result<(), (StatusCode, String)> {
+    if record.sacrament_type != SacramentType::Baptism {
+        return annotate_from(conn, user_id, record).await;
+    }
+
+    let later = sqlx::query_as::<_, SacramentRecord>(
+        r#"
+        SELECT * FROM sacrament_record
+        WHERE deleted_at IS NULL
+          AND ((member_id = $1 AND sacrament_type IN ('CONFIRMATION', 'MARRIAGE', 'HOLY_ORDERS'))
+               OR (spouse_id = $1 AND sacrament_type = 'MARRIAGE'))
+        ORDER BY sacrament_date
+        "#
+    )
+    .bind(record.member_id)
+    .fetch_all(&mut *conn)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    for source in &later {
+        annotate_from(conn, user_id, source).await?;
+    }
+    Ok(())
+}
+
+/// Takes back the notations made from a sacrament that was corrected or
+/// removed, withdrawing notices not yet applied. Notations already entered
+/// in another parish's register are left for that parish to remove, on a
+/// withdrawal notice.
+pub async fn withdraw_notations(conn: &mut PgConnection, user_id: Uuid, source_record_id: Uuid) -> Result<(), (StatusCode, String)> {
+    sqlx::query(
+        r#"
+        UPDATE baptism_notation n SET deleted_at = NOW()
+        FROM sacrament_record b
+        WHERE b.id = n.baptism_record_id
+          AND n.source_record_id = $1 AND n.deleted_at IS NULL
+          AND (n.source_parish_id IS NULL OR n.source_parish_id = b.parish_id)
+        "#
+    )
+    .bind(source_record_id)
+    .execute(&mut *conn)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    sqlx::query(
+        r#"
+        UPDATE notation_notice SET status = 'WITHDRAWN', processed_at = NOW()
+        WHERE source_record_id = $1 AND status = 'PENDING' AND withdraws_notation_id IS NULL
+        "#
+    )
+    .bind(source_record_id)
+    .execute(&mut *conn)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    sqlx::query(
+        r#"
+        INSERT INTO notation_notice (
+            from_parish_id, to_parish_id, baptism_record_id, member_id, notation_type,
+            notation_date, notation_text, source_record_id, sent_by, withdraws_notation_id
+        )
+        SELECT n.source_parish_id, b.parish_id, b.id, b.member_id, n.notation_type,
+               n.notation_date, n.notation_text, n.source_record_id, $2, n.id
+        FROM baptism_notation n
+        JOIN sacrament_record b ON b.id = n.baptism_record_id
+        WHERE n.source_record_id = $1 AND n.deleted_at IS NULL
+          AND n.source_parish_id <> b.parish_id
+          AND NOT EXISTS (
+              SELECT 1 FROM notation_notice w
+              WHERE w.withdraws_notation_id = n.id AND w.status = 'PENDING'
+          )
+        "#
+    )
+    .bind(source_record_id)
+    .bind(user_id)
+    .execute(&mut *conn)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(())
+}
+
+async fn annotate_from(
+    conn: &mut PgConnection,
+    user_id: Uuid,
+    record: &SacramentRecord,
+) -> Result<(), (StatusCode, String)> {
+    let notation_type = match record.sacrament_type {
+        SacramentType::Confirmation => NotationType::Confirmation,
+        SacramentType::Marriage => NotationType::Marriage,
+        SacramentType::HolyOrders => NotationType::HolyOrders,
+        _ => return Ok(()),
+    };
+
+    let (place, member_name, spouse_name): (String, String, Option<String>) = sqlx::query_as(
+        r#"
+        SELECT COALESCE($1, p.parish_name),
+               CONCAT_WS(' ', m.first_name, m.middle_name, m.last_name),
+               NULLIF(CONCAT_WS(' ', s.first_name, s.middle_name, s.last_name), '')
+        FROM parish p
+        JOIN member m ON m.id = $3
+        LEFT JOIN member s ON s.id = $4
+        WHERE p.id = $2
+        "#
+    )
+    .bind(record.church_name.as_deref().filter(|c| !c.trim().is_empty()))
+    .bind(record.parish_id)
+    .bind(record.member_id)
+    .bind(record.spouse_id)
+    .fetch_one(&mut *conn)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    let by = record.officiating_minister.as_deref()
+        .filter(|m| !m.trim().is_empty())
+        .map(|m| format!(" by {}", m.trim()))
+        .unwrap_or_default();
+    let date = record.sacrament_date.format("%-d %B %Y");
+    let married = |to: Option<&str>| match to {
+        Some(name) => format!("Married {} at {} on {}", name, place, date),
+        None => format!("Married at {} on {}", place, date),
+    };
+
+    let mut parties = Vec::new();
+    match notation_type {
+        NotationType::Confirmation => parties.push((record.member_id, format!("Confirmed at {} on {}{}", place, date, by))),
+        NotationType::HolyOrders => parties.push((record.member_id, format!("Ordained at {} on {}{}", place, date, by))),
+        _ => {
+            let spouse = record.spouse_name.as_deref().filter(|s| !s.trim().is_empty()).or(spouse_name.as_deref());
+            parties.push((record.member_id, married(spouse)));
+            if let Some(spouse_id) = record.spouse_id {
+                parties.push((spouse_id, married(Some(&member_name))));
+            }
+        }
+    }
+
+    for (member_id, text) in parties {
+        let Some(baptism) = member_baptism(conn, member_id).await? else { continue };
+        let exists: bool = sqlx::query_scalar(
+            r#"
+            SELECT EXISTS (
+                SELECT 1 FROM baptism_notation n
+                WHERE baptism_record_id = $1 AND notation_type = $2 AND notation_date = $3 AND deleted_at IS NULL
+                  AND NOT EXISTS (
+                      SELECT 1 FROM notation_notice w
+                      WHERE w.withdraws_notation_id = n.id AND w.status = 'PENDING'
+                  )
+            ) OR EXISTS (
+                SELECT 1 FROM notation_notice
+                WHERE baptism_record_id = $1 AND notation_type = $2 AND notation_date = $3 AND status = 'PENDING'
+                  AND withdraws_notation_id IS NULL
+            )
+            "#
+        )
+        .bind(baptism.id)
+        .bind(notation_type)
+        .bind(record.sacrament_date)
+        .fetch_one(&mut *conn)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+        if exists {
+            continue;
+        }
+
+        deliver(conn, user_id, &baptism, NewNotation {
+            notation_type,
+            notation_date: record.sacrament_date,
+            notation_text: text,
+            source_record_id: Some(record.id),
+            source_parish_id: record.parish_id,
+        })
+        .await?;
+    }
+    Ok(())
+}
+
+/// Writes the notation when the baptism is kept by the parish making it,
+/// and otherwise queues it for the baptizing parish.
+async fn deliver(
+    conn: &mut PgConnection,
+    user_id: Uuid,
+    baptism: &Baptism,
+    notation: NewNotation,
+) -> Result<NotationOutcome, (StatusCode, String)> {
+    if baptism.parish_id == notation.source_parish_id {
+        let notation = insert_notation(conn, user_id, baptism.id, &notation).await?;
+        return Ok(NotationOutcome::Recorded { notation });
+    }
+
+    let notice = sqlx::query_as::<_, NotationNotice>(
+        r#"
+        INSERT INTO notation_notice (
+            from_parish_id, to_parish_id, baptism_record_id, member_id, notation_type,
+            notation_date, notation_text, source_record_id, sent_by
+        )
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+        RETURNING *
+        "#
+    )
+    .bind(notation.source_parish_id)
+    .bind(baptism.parish_id)
+    .bind(baptism.id)
+    .bind(baptism.member_id)
+    .bind(notation.notation_type)
+    .bind(notation.notation_date)
+    .bind(&notation.notation_text)
+    .bind(notation.source_record_id)
+    .bind(user_id)
+    .fetch_one(conn)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(NotationOutcome::Sent { notice })
+}
+
+async fn insert_notation(
+    conn: &mut PgConnection,
+    user_id: Uuid,
+    baptism_id: Uuid,
+    notation: &NewNotation,
+) -> Result<BaptismNotation, (StatusCode, String)> {
+    sqlx::query_as::<_, BaptismNotation>(
+        r#"
+        INSERT INTO baptism_notation (
+            baptism_record_id, notation_type, notation_date, notation_text,
+            source_record_id, source_parish_id, created_by
+        )
+        VALUES ($1, $2, $3, $4, $5, $6, $7)
+        RETURNING *
+        "#
+    )
+    .bind(baptism_id)
+    .bind(notation.notation_type)
+    .bind(notation.notation_date)
+    .bind(&notation.notation_text)
+    .bind(notation.source_record_id)
+    .bind(notation.source_parish_id)
+    .bind(user_id)
+    .fetch_one(conn)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+}
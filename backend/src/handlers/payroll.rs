@@ -0,0 +1,1128 @@
+use axum::{
+    extract::{Path, State, Query},
+    http::StatusCode,
+    response::Response,
+    Json,
+};
+use uuid::Uuid;
+use serde::Deserialize;
+use chrono::{Months, NaiveDate};
+use rust_decimal::Decimal;
+use sqlx::{postgres::PgPool, Postgres, Transaction};
+use crate::{
+    AppState,
+    models::payroll::{
+        Staff, EmploymentType, CreateStaffRequest, UpdateStaffRequest, StatutoryDeduction, StatutoryDeductionDetail,
+        DeductionBand, DeductionBasis, SaveDeductionRequest, PayrollRun, PayrollRunDetail, PayrollStatus,
+        Payslip, PayslipDeduction, PayslipDetail, CreatePayrollRunRequest, UpdatePayslipRequest,
+    },
+    models::transaction::{ExpenseVoucher, PaymentMethod, TransactionCategory},
+    models::user::UserRole,
+    handlers::auth::AuthUser,
+    handlers::{rbac, transaction},
+    handlers::transaction::CreateExpenseRequest,
+    export::{self, ExportFormat, ReportHeader},
+};
+
+// ============================================================================
+// Staff register
+// ============================================================================
+
+#[derive(Debug, Deserialize)]
+pub struct StaffQuery {
+    pub parish_id: Option<Uuid>,
+    pub include_inactive: Option<bool>,
+}
+
+pub async fn list_staff(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Query(query): Query<StaffQuery>,
+) -> Result<Json<Vec<Staff>>, (StatusCode, String)> {
+    rbac::require_finance(&auth)?;
+    let parish_id = rbac::resolve_parish_id(&auth, query.parish_id)?;
+
+    let staff = sqlx::query_as::<_, Staff>(
+        r#"
+        SELECT * FROM staff
+        WHERE parish_id = $1 AND deleted_at IS NULL AND ($2 OR is_active)
+        ORDER BY full_name
+        "#
+    )
+    .bind(parish_id)
+    .bind(query.include_inactive.unwrap_or(false))
+    .fetch_all(&state.db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(staff))
+}
+
+pub async fn get_staff(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<Staff>, (StatusCode, String)> {
+    rbac::require_finance(&auth)?;
+    let staff = fetch_staff(&state.db, id).await?;
+    rbac::resolve_parish_id(&auth, Some(staff.parish_id))?;
+    Ok(Json(staff))
+}
+
+pub async fn create_staff(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Json(payload): Json<CreateStaffRequest>,
+) -> Result<Json<Staff>, (StatusCode, String)> {
+    rbac::require_finance(&auth)?;
+    let parish_id = rbac::resolve_parish_id(&auth, Some(payload.parish_id))?;
+
+    let allowances = payload.allowances.unwrap_or(Decimal::ZERO);
+    if payload.basic_salary < Decimal::ZERO || allowances < Decimal::ZERO {
+        return Err((StatusCode::BAD_REQUEST, "Salary and allowances cannot be negative".to_string()));
+    }
+
+    let staff = sqlx::query_as::<_, Staff>(
+        r#"
+        INSERT INTO staff (
+            parish_id, staff_number, member_id, full_name, position, employment_type, phone,
+            national_id, tax_number, social_security_number, bank_name, bank_account,
+            basic_salary, allowances, statutory_exempt, start_date, created_by
+        )
+        VALUES (
+            $1, COALESCE($2, 'STF-' || LPAD(nextval('staff_seq')::TEXT, 5, '0')), $3, $4, $5, $6, $7,
+            $8, $9, $10, $11, $12, $13, $14, $15, $16, $17
+        )
+        RETURNING *
+        "#
+    )
+    .bind(parish_id)
+    .bind(payload.staff_number)
+    .bind(payload.member_id)
+    .bind(payload.full_name)
+    .bind(payload.position)
+    .bind(payload.employment_type.unwrap_or(EmploymentType::FullTime))
+    .bind(payload.phone)
+    .bind(payload.national_id)
+    .bind(payload.tax_number)
+    .bind(payload.social_security_number)
+    .bind(payload.bank_name)
+    .bind(payload.bank_account)
+    .bind(payload.basic_salary)
+    .bind(allowances)
+    .bind(payload.statutory_exempt.unwrap_or(false))
+    .bind(payload.start_date)
+    .bind(auth.user_id)
+    .fetch_one(&state.db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(staff))
+}
+
+pub async fn update_staff(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+    Json(payload): Json<UpdateStaffRequest>,
+) -> Result<Json<Staff>, (StatusCode, String)> {
+    rbac::require_finance(&auth)?;
+    let existing = fetch_staff(&state.db, id).await?;
+    rbac::resolve_parish_id(&auth, Some(existing.parish_id))?;
+
+    if payload.basic_salary.is_some_and(|s| s < Decimal::ZERO) || payload.allowances.is_some_and(|a| a < Decimal::ZERO) {
+        return Err((StatusCode::BAD_REQUEST, "Salary and allowances cannot be negative".to_string()));
+    }
+
+    let staff = sqlx::query_as::<_, Staff>(
+        r#"
+        UPDATE staff SET
+            member_id = $1,
+            full_name = $2,
+            position = $3,
+            employment_type = $4,
+            phone = $5,
+            national_id = $6,
+            tax_number = $7,
+            social_security_number = $8,
+            bank_name = $9,
+            bank_account = $10,
+            basic_salary = $11,
+            allowances = $12,
+            statutory_exempt = $13,
+            end_date = $14,
+            is_active = $15,
+            updated_at = NOW()
+        WHERE id = $16
+        RETURNING *
+        "#
+    )
+    .bind(payload.member_id.or(existing.member_id))
+    .bind(payload.full_name.unwrap_or(existing.full_name))
+    .bind(payload.position.unwrap_or(existing.position))
+    .bind(payload.employment_type.unwrap_or(existing.employment_type))
+    .bind(payload.phone.or(existing.phone))
+    .bind(payload.national_id.or(existing.national_id))
+    .bind(payload.tax_number.or(existing.tax_number))
+    .bind(payload.social_security_number.or(existing.social_security_number))
+    .bind(payload.bank_name.or(existing.bank_name))
+    .bind(payload.bank_account.or(existing.bank_account))
+    .bind(payload.basic_salary.unwrap_or(existing.basic_salary))
+    .bind(payload.allowances.unwrap_or(existing.allowances))
+    .bind(payload.statutory_exempt.unwrap_or(existing.statutory_exempt))
+    .bind(payload.end_date.or(existing.end_date))
+    .bind(payload.is_active.unwrap_or(existing.is_active))
+    .bind(id)
+    .fetch_one(&state.db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(staff))
+}
+
+pub async fn delete_staff(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> Result<StatusCode, (StatusCode, String)> {
+    rbac::require_finance(&auth)?;
+    let staff = fetch_staff(&state.db, id).await?;
+    rbac::resolve_parish_id(&auth, Some(staff.parish_id))?;
+
+    sqlx::query("UPDATE staff SET deleted_at = NOW(), is_active = FALSE WHERE id = $1")
+        .bind(id)
+        .execute(&state.db)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+// ============================================================================
+// Statutory deductions
+// ============================================================================
+
+#[derive(Debug, Deserialize)]
+pub struct DeductionQuery {
+    pub parish_id: Option<Uuid>,
+}
+
+/// The deductions in force for a parish: its own, and the national ones it
+/// has not replaced.
+pub async fn list_statutory_deductions(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Query(query): Query<DeductionQuery>,
+) -> Result<Json<Vec<StatutoryDeductionDetail>>, (StatusCode, String)> {
+    rbac::require_finance(&auth)?;
+    let parish_id = rbac::resolve_parish_id(&auth, query.parish_id)?;
+
+    let deductions = effective_deductions(&state.db, parish_id)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+    Ok(Json(deductions))
+}
+
+pub async fn create_statutory_deduction(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Json(payload): Json<SaveDeductionRequest>,
+) -> Result<Json<StatutoryDeductionDetail>, (StatusCode, String)> {
+    let parish_id = deduction_scope(&auth, payload.parish_id)?;
+    validate_bands(&payload.bands)?;
+
+    let mut tx = state.db.begin().await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    let deduction = sqlx::query_as::<_, StatutoryDeduction>(
+        r#"
+        INSERT INTO statutory_deduction (
+            parish_id, deduction_code, deduction_name, basis, reduces_taxable, employer_rate, payee_name, is_active
+        )
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+        RETURNING *
+        "#
+    )
+    .bind(parish_id)
+    .bind(payload.deduction_code.trim().to_uppercase())
+    .bind(payload.deduction_name)
+    .bind(payload.basis)
+    .bind(payload.reduces_taxable.unwrap_or(false))
+    .bind(payload.employer_rate.unwrap_or(Decimal::ZERO))
+    .bind(payload.payee_name)
+    .bind(payload.is_active.unwrap_or(true))
+    .fetch_one(&mut *tx)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    let bands = replace_bands(&mut tx, deduction.id, &payload.bands).await?;
+    tx.commit().await.map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(StatutoryDeductionDetail { deduction, bands }))
+}
+
+pub async fn update_statutory_deduction(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+    Json(payload): Json<SaveDeductionRequest>,
+) -> Result<Json<StatutoryDeductionDetail>, (StatusCode, String)> {
+    let existing = fetch_deduction(&state.db, id).await?;
+    deduction_scope(&auth, existing.parish_id)?;
+    validate_bands(&payload.bands)?;
+
+    let mut tx = state.db.begin().await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    let deduction = sqlx::query_as::<_, StatutoryDeduction>(
+        r#"
+        UPDATE statutory_deduction SET
+            deduction_code = $1,
+            deduction_name = $2,
+            basis = $3,
+            reduces_taxable = $4,
+            employer_rate = $5,
+            payee_name = $6,
+            is_active = $7,
+            updated_at = NOW()
+        WHERE id = $8
+        RETURNING *
+        "#
+    )
+    .bind(payload.deduction_code.trim().to_uppercase())
+    .bind(payload.deduction_name)
+    .bind(payload.basis)
+    .bind(payload.reduces_taxable.unwrap_or(existing.reduces_taxable))
+    .bind(payload.employer_rate.unwrap_or(existing.employer_rate))
+    .bind(payload.payee_name)
+    .bind(payload.is_active.unwrap_or(existing.is_active))
+    .bind(id)
+    .fetch_one(&mut *tx)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    let bands = replace_bands(&mut tx, id, &payload.bands).await?;
+    tx.commit().await.map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(StatutoryDeductionDetail { deduction, bands }))
+}
+
+pub async fn delete_statutory_deduction(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> Result<StatusCode, (StatusCode, String)> {
+    let deduction = fetch_deduction(&state.db, id).await?;
+    deduction_scope(&auth, deduction.parish_id)?;
+
+    sqlx::query("UPDATE statutory_deduction SET deleted_at = NOW() WHERE id = $1")
+        .bind(id)
+        .execute(&state.db)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+// ============================================================================
+// Payroll runs
+// ============================================================================
+
+#[derive(Debug, Deserialize)]
+pub struct PayrollRunQuery {
+    pub parish_id: Option<Uuid>,
+    pub period_year: Option<i32>,
+}
+
+pub async fn list_payroll_runs(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Query(query): Query<PayrollRunQuery>,
+) -> Result<Json<Vec<PayrollRun>>, (StatusCode, String)> {
+    rbac::require_finance(&auth)?;
+    let parish_id = rbac::resolve_parish_id(&auth, query.parish_id)?;
+
+    let runs = sqlx::query_as::<_, PayrollRun>(
+        r#"
+        SELECT * FROM payroll_run
+        WHERE parish_id = $1 AND ($2::int IS NULL OR period_year = $2)
+        ORDER BY period_year DESC, period_month DESC, created_at DESC
+        "#
+    )
+    .bind(parish_id)
+    .bind(query.period_year)
+    .fetch_all(&state.db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(runs))
+}
+
+/// The run with its payslips and vouchers, or the payroll sheet as a file.
+pub async fn get_payroll_run(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+    format: ExportFormat,
+) -> Result<Response, (StatusCode, String)> {
+    rbac::require_finance(&auth)?;
+    let run = fetch_run(&state.db, id).await?;
+    rbac::resolve_parish_id(&auth, Some(run.parish_id))?;
+
+    let header = ReportHeader::parish(run.parish_id, "Payroll", Some(period_label(&run)));
+    let detail = run_detail(&state.db, run).await?;
+    export::respond(&state.db, &auth, format, header, detail).await
+}
+
+/// Opens the month's payroll with a payslip for every active staff member.
+pub async fn create_payroll_run(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Json(payload): Json<CreatePayrollRunRequest>,
+) -> Result<Json<PayrollRun>, (StatusCode, String)> {
+    rbac::require_finance(&auth)?;
+    let parish_id = rbac::resolve_parish_id(&auth, Some(payload.parish_id))?;
+
+    let month_start = NaiveDate::from_ymd_opt(payload.period_year, payload.period_month as u32, 1)
+        .ok_or((StatusCode::BAD_REQUEST, "Invalid payroll month".to_string()))?;
+    let month_end = month_start + Months::new(1) - chrono::Days::new(1);
+
+    let open: Option<PayrollStatus> = sqlx::query_scalar(
+        "SELECT status FROM payroll_run WHERE parish_id = $1 AND period_year = $2 AND period_month = $3 AND status <> 'CANCELLED'"
+    )
+    .bind(parish_id)
+    .bind(payload.period_year)
+    .bind(payload.period_month)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+    if open.is_some() {
+        return Err((StatusCode::CONFLICT, format!("A payroll for {} already exists", month_start.format("%B %Y"))));
+    }
+
+    let staff = sqlx::query_as::<_, Staff>(
+        r#"
+        SELECT * FROM staff
+        WHERE parish_id = $1 AND deleted_at IS NULL AND is_active
+          AND start_date <= $3 AND (end_date IS NULL OR end_date >= $2)
+        ORDER BY full_name
+        "#
+    )
+    .bind(parish_id)
+    .bind(month_start)
+    .bind(month_end)
+    .fetch_all(&state.db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+    if staff.is_empty() {
+        return Err((StatusCode::BAD_REQUEST, "No active staff to pay for this month".to_string()));
+    }
+
+    let deductions = active_deductions(&state.db, parish_id).await?;
+
+    let mut tx = state.db.begin().await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    let run = sqlx::query_as::<_, PayrollRun>(
+        r#"
+        INSERT INTO payroll_run (parish_id, period_year, period_month, payment_date, notes, created_by)
+        VALUES ($1, $2, $3, $4, $5, $6)
+        RETURNING *
+        "#
+    )
+    .bind(parish_id)
+    .bind(payload.period_year)
+    .bind(payload.period_month)
+    .bind(payload.payment_date.unwrap_or(month_end))
+    .bind(payload.notes)
+    .bind(auth.user_id)
+    .fetch_one(&mut *tx)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    for member in &staff {
+        let pay = PayInput {
+            basic_salary: member.basic_salary,
+            allowances: member.allowances,
+            other_earnings: Decimal::ZERO,
+            other_deductions: Decimal::ZERO,
+            statutory_exempt: member.statutory_exempt,
+        };
+        let computed = compute_pay(&pay, &deductions);
+        if computed.net_pay < Decimal::ZERO {
+            return Err((StatusCode::BAD_REQUEST, format!("Deductions exceed the gross pay of {}", member.full_name)));
+        }
+
+        let payslip_id: Uuid = sqlx::query_scalar(
+            r#"
+            INSERT INTO payslip (
+                payroll_run_id, staff_id, basic_salary, allowances, other_earnings, gross_pay,
+                taxable_pay, statutory_deductions, other_deductions, net_pay, employer_contributions
+            )
+            VALUES ($1, $2, $3, $4, 0, $5, $6, $7, 0, $8, $9)
+            RETURNING id
+            "#
+        )
+        .bind(run.id)
+        .bind(member.id)
+        .bind(pay.basic_salary)
+        .bind(pay.allowances)
+        .bind(computed.gross_pay)
+        .bind(computed.taxable_pay)
+        .bind(computed.statutory_deductions())
+        .bind(computed.net_pay)
+        .bind(computed.employer_contributions())
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+        insert_deduction_lines(&mut tx, payslip_id, &computed.lines).await?;
+    }
+
+    let run = refresh_totals(&mut tx, run.id).await?;
+    tx.commit().await.map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(run))
+}
+
+/// Adjusts one payslip of a draft run and recomputes its deductions with
+/// the rates in force now.
+pub async fn update_payslip(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+    Json(payload): Json<UpdatePayslipRequest>,
+) -> Result<Json<PayslipDetail>, (StatusCode, String)> {
+    rbac::require_finance(&auth)?;
+    let payslip = fetch_payslip(&state.db, id).await?;
+    let run = fetch_run(&state.db, payslip.payroll_run_id).await?;
+    rbac::resolve_parish_id(&auth, Some(run.parish_id))?;
+
+    if run.status != PayrollStatus::Draft {
+        return Err((StatusCode::BAD_REQUEST, "Only draft payrolls can be changed".to_string()));
+    }
+    let statutory_exempt: bool = sqlx::query_scalar("SELECT statutory_exempt FROM staff WHERE id = $1")
+        .bind(payslip.staff_id)
+        .fetch_one(&state.db)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+    let pay = PayInput {
+        basic_salary: payslip.basic_salary,
+        allowances: payload.allowances.unwrap_or(payslip.allowances),
+        other_earnings: payload.other_earnings.unwrap_or(payslip.other_earnings),
+        other_deductions: payload.other_deductions.unwrap_or(payslip.other_deductions),
+        statutory_exempt,
+    };
+    if pay.allowances < Decimal::ZERO || pay.other_earnings < Decimal::ZERO || pay.other_deductions < Decimal::ZERO {
+        return Err((StatusCode::BAD_REQUEST, "Amounts cannot be negative".to_string()));
+    }
+
+    let deductions = active_deductions(&state.db, run.parish_id).await?;
+    let computed = compute_pay(&pay, &deductions);
+    if computed.net_pay < Decimal::ZERO {
+        return Err((StatusCode::BAD_REQUEST, "Deductions exceed the gross pay".to_string()));
+    }
+
+    let mut tx = state.db.begin().await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    // Locked so the run cannot be approved, and its vouchers raised from
+    // the old totals, while this payslip changes.
+    let status: PayrollStatus = sqlx::query_scalar("SELECT status FROM payroll_run WHERE id = $1 FOR UPDATE")
+        .bind(run.id)
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+    if status != PayrollStatus::Draft {
+        return Err((StatusCode::CONFLICT, "The payroll was approved or cancelled while the payslip was being changed".to_string()));
+    }
+
+    sqlx::query(
+        r#"
+        UPDATE payslip SET
+            allowances = $1,
+            other_earnings = $2,
+            gross_pay = $3,
+            taxable_pay = $4,
+            statutory_deductions = $5,
+            other_deductions = $6,
+            net_pay = $7,
+            employer_contributions = $8,
+            notes = COALESCE($9, notes),
+            updated_at = NOW()
+        WHERE id = $10
+        "#
+    )
+    .bind(pay.allowances)
+    .bind(pay.other_earnings)
+    .bind(computed.gross_pay)
+    .bind(computed.taxable_pay)
+    .bind(computed.statutory_deductions())
+    .bind(pay.other_deductions)
+    .bind(computed.net_pay)
+    .bind(computed.employer_contributions())
+    .bind(payload.notes)
+    .bind(id)
+    .execute(&mut *tx)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    sqlx::query("DELETE FROM payslip_deduction WHERE payslip_id = $1")
+        .bind(id)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+    insert_deduction_lines(&mut tx, id, &computed.lines).await?;
+    refresh_totals(&mut tx, run.id).await?;
+
+    tx.commit().await.map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    let payslip = fetch_payslip(&state.db, id).await?;
+    Ok(Json(payslip_detail(&state.db, payslip, &run).await?))
+}
+
+/// A single payslip, or `?format=pdf` for the printable slip.
+pub async fn get_payslip(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+    format: ExportFormat,
+) -> Result<Response, (StatusCode, String)> {
+    rbac::require_finance(&auth)?;
+    let payslip = fetch_payslip(&state.db, id).await?;
+    let run = fetch_run(&state.db, payslip.payroll_run_id).await?;
+    rbac::resolve_parish_id(&auth, Some(run.parish_id))?;
+
+    let detail = payslip_detail(&state.db, payslip, &run).await?;
+    let header = ReportHeader::parish(
+        run.parish_id,
+        &format!("Payslip - {} {}", detail.staff_number, detail.full_name),
+        Some(period_label(&run)),
+    );
+    export::respond(&state.db, &auth, format, header, detail).await
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ApprovePayrollRequest {
+    pub payment_method: Option<PaymentMethod>,
+    pub fund_id: Option<Uuid>,
+    pub cashbook_id: Option<Uuid>,
+}
+
+/// Approves a draft run and raises the expense vouchers: one for the net
+/// pay and one per statutory deduction for the employee and employer
+/// shares together.
+pub async fn approve_payroll_run(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+    Json(payload): Json<ApprovePayrollRequest>,
+) -> Result<Json<PayrollRunDetail>, (StatusCode, String)> {
+    rbac::require_permission(&state.db, &auth, "payroll.approve").await?;
+    let run = fetch_run(&state.db, id).await?;
+    rbac::resolve_parish_id(&auth, Some(run.parish_id))?;
+
+    if run.status != PayrollStatus::Draft {
+        return Err((StatusCode::BAD_REQUEST, "Only draft payrolls can be approved".to_string()));
+    }
+
+    // Claim the run before raising anything: only one approval can move it
+    // out of draft, and its payslips can no longer be edited.
+    let run = sqlx::query_as::<_, PayrollRun>(
+        r#"
+        UPDATE payroll_run SET status = 'APPROVED', approved_by = $1, approved_at = NOW()
+        WHERE id = $2 AND status = 'DRAFT'
+        RETURNING *
+        "#
+    )
+    .bind(auth.user_id)
+    .bind(id)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+    .ok_or((StatusCode::CONFLICT, "The payroll has already been approved or cancelled".to_string()))?;
+
+    if let Err(e) = raise_salary_vouchers(&state.db, &run, &payload, auth.user_id).await {
+        sqlx::query("UPDATE payroll_run SET status = 'DRAFT', approved_by = NULL, approved_at = NULL WHERE id = $1")
+            .bind(id)
+            .execute(&state.db)
+            .await
+            .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+        return Err(e);
+    }
+
+    Ok(Json(run_detail(&state.db, run).await?))
+}
+
+/// Raises and links the salary vouchers of a claimed run. Vouchers are
+/// committed as they are raised, so on any failure those already raised
+/// are withdrawn again.
+async fn raise_salary_vouchers(
+    db: &PgPool,
+    run: &PayrollRun,
+    payload: &ApprovePayrollRequest,
+    user_id: Uuid,
+) -> Result<(), (StatusCode, String)> {
+    let remittances: Vec<(String, String, Decimal)> = sqlx::query_as(
+        r#"
+        SELECT pd.deduction_name, COALESCE(sd.payee_name, pd.deduction_name),
+               SUM(pd.employee_amount + pd.employer_amount)
+        FROM payslip_deduction pd
+        JOIN payslip p ON p.id = pd.payslip_id
+        LEFT JOIN statutory_deduction sd ON sd.id = pd.deduction_id
+        WHERE p.payroll_run_id = $1
+        GROUP BY pd.deduction_code, pd.deduction_name, sd.payee_name
+        HAVING SUM(pd.employee_amount + pd.employer_amount) > 0
+        ORDER BY pd.deduction_code
+        "#
+    )
+    .bind(run.id)
+    .fetch_all(db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    let period = period_label(run);
+    let mut payments = vec![("Parish staff".to_string(), format!("Net salaries for {}", period), run.total_net)];
+    payments.extend(remittances.into_iter().map(|(name, payee, amount)| (payee, format!("{} for {}", name, period), amount)));
+
+    let mut raised: Vec<ExpenseVoucher> = Vec::new();
+    for (payee_name, description, amount) in payments.into_iter().filter(|(_, _, amount)| *amount > Decimal::ZERO) {
+        let request = CreateExpenseRequest {
+            parish_id: run.parish_id,
+            fund_id: payload.fund_id,
+            cashbook_id: payload.cashbook_id,
+            supplier_id: None,
+            category: TransactionCategory::SalaryExpense,
+            amount,
+            currency_code: None,
+            exchange_rate: None,
+            payment_method: payload.payment_method.unwrap_or(PaymentMethod::BankTransfer),
+            payee_name,
+            payee_phone: None,
+            expense_date: run.payment_date,
+            description,
+            reference_number: Some(format!("PAYROLL-{}-{:02}", run.period_year, run.period_month)),
+        };
+        let voucher = match db.begin().await {
+            Ok(mut tx) => match transaction::raise_expense_voucher(&mut tx, run.parish_id, request, user_id).await {
+                Ok(voucher) => tx.commit().await
+                    .map(|_| voucher)
+                    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string())),
+                Err(e) => Err(e),
+            },
+            Err(e) => Err((StatusCode::INTERNAL_SERVER_ERROR, e.to_string())),
+        };
+        match voucher {
+            Ok(voucher) => raised.push(voucher),
+            Err(e) => {
+                withdraw_vouchers(db, &raised).await?;
+                return Err(e);
+            }
+        }
+    }
+
+    let ids: Vec<Uuid> = raised.iter().map(|v| v.id).collect();
+    let linked = sqlx::query(
+        "INSERT INTO payroll_run_voucher (payroll_run_id, expense_voucher_id) SELECT $1, UNNEST($2::uuid[])"
+    )
+    .bind(run.id)
+    .bind(&ids)
+    .execute(db)
+    .await;
+    if let Err(e) = linked {
+        withdraw_vouchers(db, &raised).await?;
+        return Err((StatusCode::INTERNAL_SERVER_ERROR, e.to_string()));
+    }
+
+    Ok(())
+}
+
+pub async fn cancel_payroll_run(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<PayrollRun>, (StatusCode, String)> {
+    rbac::require_finance(&auth)?;
+    let run = fetch_run(&state.db, id).await?;
+    rbac::resolve_parish_id(&auth, Some(run.parish_id))?;
+
+    if run.status != PayrollStatus::Draft {
+        return Err((StatusCode::BAD_REQUEST, "Only draft payrolls can be cancelled".to_string()));
+    }
+
+    let run = sqlx::query_as::<_, PayrollRun>(
+        "UPDATE payroll_run SET status = 'CANCELLED' WHERE id = $1 AND status = 'DRAFT' RETURNING *"
+    )
+    .bind(id)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+    .ok_or((StatusCode::CONFLICT, "The payroll was approved while it was being cancelled".to_string()))?;
+
+    Ok(Json(run))
+}
+
+// ============================================================================
+// Pay calculation
+// ============================================================================
+
+struct PayInput {
+    basic_salary: Decimal,
+    allowances: Decimal,
+    other_earnings: Decimal,
+    other_deductions: Decimal,
+    statutory_exempt: bool,
+}
+
+struct DeductionLine {
+    deduction_id: Uuid,
+    deduction_code: String,
+    deduction_name: String,
+    base_amount: Decimal,
+    employee_amount: Decimal,
+    employer_amount: Decimal,
+}
+
+struct ComputedPay {
+    gross_pay: Decimal,
+    taxable_pay: Decimal,
+    net_pay: Decimal,
+    lines: Vec<DeductionLine>,
+}
+
+impl ComputedPay {
+    fn statutory_deductions(&self) -> Decimal {
+        self.lines.iter().map(|l| l.employee_amount).sum()
+    }
+
+    fn employer_contributions(&self) -> Decimal {
+        self.lines.iter().map(|l| l.employer_amount).sum()
+    }
+}
+
+/// Gross-based deductions come first; those that reduce taxable pay (such
+/// as pension contributions) are taken off before the taxable-based ones
+/// (such as PAYE) are worked out.
+fn compute_pay(pay: &PayInput, deductions: &[StatutoryDeductionDetail]) -> ComputedPay {
+    let gross_pay = pay.basic_salary + pay.allowances + pay.other_earnings;
+    let mut lines = Vec::new();
+    let mut taxable_pay = gross_pay;
+
+    if !pay.statutory_exempt {
+        for basis in [DeductionBasis::Gross, DeductionBasis::Taxable] {
+            let base = if basis == DeductionBasis::Gross { gross_pay } else { taxable_pay };
+            for detail in deductions.iter().filter(|d| d.deduction.basis == basis) {
+                let employee_amount = band_amount(&detail.bands, base);
+                let employer_amount = (base * detail.deduction.employer_rate / Decimal::from(100)).round_dp(2);
+                if detail.deduction.reduces_taxable {
+                    taxable_pay -= employee_amount;
+                }
+                lines.push(DeductionLine {
+                    deduction_id: detail.deduction.id,
+                    deduction_code: detail.deduction.deduction_code.clone(),
+                    deduction_name: detail.deduction.deduction_name.clone(),
+                    base_amount: base,
+                    employee_amount,
+                    employer_amount,
+                });
+            }
+        }
+    }
+
+    let statutory: Decimal = lines.iter().map(|l| l.employee_amount).sum();
+    ComputedPay {
+        gross_pay,
+        taxable_pay: taxable_pay.max(Decimal::ZERO),
+        net_pay: gross_pay - statutory - pay.other_deductions,
+        lines,
+    }
+}
+
+/// Amount due on `base` from the band it falls in.
+fn band_amount(bands: &[DeductionBand], base: Decimal) -> Decimal {
+    bands
+        .iter()
+        .filter(|b| base >= b.lower_bound && b.upper_bound.is_none_or(|upper| base < upper))
+        .map(|b| b.fixed_amount + (base - b.lower_bound) * b.rate / Decimal::from(100))
+        .next()
+        .unwrap_or(Decimal::ZERO)
+        .round_dp(2)
+}
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+async fn effective_deductions(db: &PgPool, parish_id: Uuid) -> Result<Vec<StatutoryDeductionDetail>, sqlx::Error> {
+    let deductions = sqlx::query_as::<_, StatutoryDeduction>(
+        r#"
+        SELECT * FROM statutory_deduction d
+        WHERE d.deleted_at IS NULL
+          AND (d.parish_id = $1 OR (d.parish_id IS NULL AND NOT EXISTS (
+              SELECT 1 FROM statutory_deduction p
+              WHERE p.parish_id = $1 AND p.deduction_code = d.deduction_code AND p.deleted_at IS NULL
+          )))
+        ORDER BY d.basis, d.deduction_code
+        "#
+    )
+    .bind(parish_id)
+    .fetch_all(db)
+    .await?;
+
+    let mut result = Vec::with_capacity(deductions.len());
+    for deduction in deductions {
+        let bands = sqlx::query_as::<_, DeductionBand>(
+            "SELECT * FROM statutory_deduction_band WHERE deduction_id = $1 ORDER BY lower_bound"
+        )
+        .bind(deduction.id)
+        .fetch_all(db)
+        .await?;
+        result.push(StatutoryDeductionDetail { deduction, bands });
+    }
+    Ok(result)
+}
+
+async fn active_deductions(db: &PgPool, parish_id: Uuid) -> Result<Vec<StatutoryDeductionDetail>, (StatusCode, String)> {
+    let mut deductions = effective_deductions(db, parish_id)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+    deductions.retain(|d| d.deduction.is_active);
+    Ok(deductions)
+}
+
+/// National deductions are kept by the super admin; parish ones by the
+/// parish's finance staff.
+fn deduction_scope(auth: &AuthUser, parish_id: Option<Uuid>) -> Result<Option<Uuid>, (StatusCode, String)> {
+    match parish_id {
+        None => {
+            rbac::require_role(auth, &[UserRole::SuperAdmin])?;
+            Ok(None)
+        }
+        Some(parish_id) => {
+            rbac::require_finance(auth)?;
+            rbac::resolve_parish_id(auth, Some(parish_id)).map(Some)
+        }
+    }
+}
+
+fn validate_bands(bands: &[DeductionBand]) -> Result<(), (StatusCode, String)> {
+    if bands.is_empty() {
+        return Err((StatusCode::BAD_REQUEST, "A deduction needs at least one band".to_string()));
+    }
+    let mut sorted: Vec<&DeductionBand> = bands.iter().collect();
+    sorted.sort_by_key(|b| b.lower_bound);
+    for pair in sorted.windows(2) {
+        if pair[0].upper_bound.is_none_or(|upper| upper > pair[1].lower_bound) {
+            return Err((StatusCode::BAD_REQUEST, format!("Band starting at {} overlaps the next band", pair[0].lower_bound)));
+        }
+    }
+    if bands.iter().any(|b| b.rate < Decimal::ZERO || b.rate > Decimal::from(100) || b.fixed_amount < Decimal::ZERO) {
+        return Err((StatusCode::BAD_REQUEST, "Band rates must be between 0 and 100% and fixed amounts not negative".to_string()));
+    }
+    Ok(())
+}
+
+async fn replace_bands(
+    tx: &mut Transaction<'_, Postgres>,
+    deduction_id: Uuid,
+    bands: &[DeductionBand],
+) -> Result<Vec<DeductionBand>, (StatusCode, String)> {
+    sqlx::query("DELETE FROM statutory_deduction_band WHERE deduction_id = $1")
+        .bind(deduction_id)
+        .execute(&mut **tx)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    let mut saved = Vec::with_capacity(bands.len());
+    for band in bands {
+        let row = sqlx::query_as::<_, DeductionBand>(
+            r#"
+            INSERT INTO statutory_deduction_band (deduction_id, lower_bound, upper_bound, rate, fixed_amount)
+            VALUES ($1, $2, $3, $4, $5)
+            RETURNING *
+            "#
+        )
+        .bind(deduction_id)
+        .bind(band.lower_bound)
+        .bind(band.upper_bound)
+        .bind(band.rate)
+        .bind(band.fixed_amount)
+        .fetch_one(&mut **tx)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+        saved.push(row);
+    }
+    saved.sort_by_key(|b| b.lower_bound);
+    Ok(saved)
+}
+
+async fn insert_deduction_lines(
+    tx: &mut Transaction<'_, Postgres>,
+    payslip_id: Uuid,
+    lines: &[DeductionLine],
+) -> Result<(), (StatusCode, String)> {
+    for line in lines {
+        sqlx::query(
+            r#"
+            INSERT INTO payslip_deduction (
+                payslip_id, deduction_id, deduction_code, deduction_name, base_amount, employee_amount, employer_amount
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            "#
+        )
+        .bind(payslip_id)
+        .bind(line.deduction_id)
+        .bind(&line.deduction_code)
+        .bind(&line.deduction_name)
+        .bind(line.base_amount)
+        .bind(line.employee_amount)
+        .bind(line.employer_amount)
+        .execute(&mut **tx)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+    }
+    Ok(())
+}
+
+async fn refresh_totals(tx: &mut Transaction<'_, Postgres>, run_id: Uuid) -> Result<PayrollRun, (StatusCode, String)> {
+    sqlx::query_as::<_, PayrollRun>(
+        r#"
+        UPDATE payroll_run r SET
+            total_gross = t.gross,
+            total_deductions = t.deductions,
+            total_net = t.net,
+            total_employer_contributions = t.employer,
+            updated_at = NOW()
+        FROM (
+            SELECT COALESCE(SUM(gross_pay), 0) AS gross,
+                   COALESCE(SUM(statutory_deductions + other_deductions), 0) AS deductions,
+                   COALESCE(SUM(net_pay), 0) AS net,
+                   COALESCE(SUM(employer_contributions), 0) AS employer
+            FROM payslip WHERE payroll_run_id = $1
+        ) t
+        WHERE r.id = $1
+        RETURNING r.*
+        "#
+    )
+    .bind(run_id)
+    .fetch_one(&mut **tx)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+}
+
+/// Takes back vouchers already raised when a later one is refused, so an
+/// approval either raises them all or none.
+async fn withdraw_vouchers(db: &PgPool, vouchers: &[ExpenseVoucher]) -> Result<(), (StatusCode, String)> {
+    let ids: Vec<Uuid> = vouchers.iter().map(|v| v.id).collect();
+    sqlx::query("UPDATE expense_voucher SET deleted_at = NOW() WHERE id = ANY($1)")
+        .bind(&ids)
+        .execute(db)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+    Ok(())
+}
+
+async fn run_detail(db: &PgPool, run: PayrollRun) -> Result<PayrollRunDetail, (StatusCode, String)> {
+    let payslips = sqlx::query_as::<_, Payslip>(
+        r#"
+        SELECT p.* FROM payslip p JOIN staff s ON s.id = p.staff_id
+        WHERE p.payroll_run_id = $1
+        ORDER BY s.full_name
+        "#
+    )
+    .bind(run.id)
+    .fetch_all(db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    let mut details = Vec::with_capacity(payslips.len());
+    for payslip in payslips {
+        details.push(payslip_detail(db, payslip, &run).await?);
+    }
+
+    let vouchers = sqlx::query_as::<_, ExpenseVoucher>(
+        r#"
+        SELECT v.* FROM expense_voucher v
+        JOIN payroll_run_voucher prv ON prv.expense_voucher_id = v.id
+        WHERE prv.payroll_run_id = $1 AND v.deleted_at IS NULL
+        ORDER BY v.created_at
+        "#
+    )
+    .bind(run.id)
+    .fetch_all(db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(PayrollRunDetail { run, payslips: details, vouchers })
+}
+
+async fn payslip_detail(db: &PgPool, payslip: Payslip, run: &PayrollRun) -> Result<PayslipDetail, (StatusCode, String)> {
+    let (staff_number, full_name, position): (String, String, String) = sqlx::query_as(
+        "SELECT staff_number, full_name, position FROM staff WHERE id = $1"
+    )
+    .bind(payslip.staff_id)
+    .fetch_one(db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    let deductions = sqlx::query_as::<_, PayslipDeduction>(
+        "SELECT * FROM payslip_deduction WHERE payslip_id = $1 ORDER BY deduction_code"
+    )
+    .bind(payslip.id)
+    .fetch_all(db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(PayslipDetail {
+        payslip,
+        staff_number,
+        full_name,
+        position,
+        period_year: run.period_year,
+        period_month: run.period_month,
+        payment_date: run.payment_date,
+        deductions,
+    })
+}
+
+fn period_label(run: &PayrollRun) -> String {
+    NaiveDate::from_ymd_opt(run.period_year, run.period_month as u32, 1)
+        .map(|d| d.format("%B %Y").to_string())
+        .unwrap_or_default()
+}
+
+async fn fetch_staff(db: &PgPool, id: Uuid) -> Result<Staff, (StatusCode, String)> {
+    sqlx::query_as::<_, Staff>("SELECT * FROM staff WHERE id = $1 AND deleted_at IS NULL")
+        .bind(id)
+        .fetch_optional(db)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+        .ok_or((StatusCode::NOT_FOUND, "Staff member not found".to_string()))
+}
+
+async fn fetch_deduction(db: &PgPool, id: Uuid) -> Result<StatutoryDeduction, (StatusCode, String)> {
+    sqlx::query_as::<_, StatutoryDeduction>("SELECT * FROM statutory_deduction WHERE id = $1 AND deleted_at IS NULL")
+        .bind(id)
+        .fetch_optional(db)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+        .ok_or((StatusCode::NOT_FOUND, "Statutory deduction not found".to_string()))
+}
+
+async fn fetch_run(db: &PgPool, id: Uuid) -> Result<PayrollRun, (StatusCode, String)> {
+    sqlx::query_as::<_, PayrollRun>("SELECT * FROM payroll_run WHERE id = $1")
+        .bind(id)
+        .fetch_optional(db)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+        .ok_or((StatusCode::NOT_FOUND, "Payroll run not found".to_string()))
+}
+
+async fn fetch_payslip(db: &PgPool, id: Uuid) -> Result<Payslip, (StatusCode, String)> {
+    sqlx::query_as::<_, Payslip>("SELECT * FROM payslip WHERE id = $1")
+        .bind(id)
+        .fetch_optional(db)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+        .ok_or((StatusCode::NOT_FOUND, "Payslip not found".to_string()))
+}
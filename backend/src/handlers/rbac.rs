@@ -1,5 +1,6 @@
 use axum::http::StatusCode;
 use uuid::Uuid;
+use sqlx::postgres::PgPool;
 use crate::models::user::UserRole;
 use crate::handlers::auth::AuthUser;
 
@@ -67,3 +68,81 @@ pub fn require_finance(auth: &AuthUser) -> Result<(), (StatusCode, String)> {
 pub fn require_admin(auth: &AuthUser) -> Result<(), (StatusCode, String)> {
     require_role(auth, &[UserRole::SuperAdmin, UserRole::ParishAdmin])
 }
+
+/// Check that the user works at diocese level: the diocese admin, or a
+/// viewer (bishop, auditors) who is not tied to a parish
+pub fn require_diocesan(auth: &AuthUser) -> Result<(), (StatusCode, String)> {
+    match auth.role {
+        UserRole::SuperAdmin => Ok(()),
+        UserRole::Viewer if auth.parish_id.is_none() => Ok(()),
+        _ => Err((StatusCode::FORBIDDEN, "Diocesan access required".to_string())),
+    }
+}
+
+/// Returns the diocese_id a diocesan user is allowed to operate on.
+/// Falls back to the diocese on the user's account when none is requested,
+/// and refuses a different diocese than the one the user is assigned to.
+pub async fn resolve_diocese_id(
+    db: &PgPool,
+    auth: &AuthUser,
+    requested_diocese_id: Option<Uuid>,
+) -> Result<Uuid, (StatusCode, String)> {
+    require_diocesan(auth)?;
+
+    let user_diocese: Option<Uuid> = sqlx::query_scalar("SELECT diocese_id FROM app_user WHERE id = $1")
+        .bind(auth.user_id)
+        .fetch_optional(db)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+        .flatten();
+
+    match (requested_diocese_id, user_diocese) {
+        (Some(req), Some(own)) if req != own => Err((
+            StatusCode::FORBIDDEN,
+            "You can only access your own diocese data".to_string(),
+        )),
+        (Some(req), _) => Ok(req),
+        (None, Some(own)) => Ok(own),
+        (None, None) => Err((StatusCode::BAD_REQUEST, "diocese_id is required".to_string())),
+    }
+}
+
+/// Check a granular permission from the permission catalog: granted to the
+/// user's role, or through an active user override. SuperAdmin always passes.
+pub async fn require_permission(
+    db: &PgPool,
+    auth: &AuthUser,
+    permission_key: &str,
+) -> Result<(), (StatusCode, String)> {
+    if auth.role == UserRole::SuperAdmin {
+        return Ok(());
+    }
+
+    let granted: bool = sqlx::query_scalar(
+        r#"
+        SELECT EXISTS (
+            SELECT 1 FROM role_permission rp
+            JOIN custom_role cr ON cr.id = rp.role_id
+            JOIN permission p ON p.id = rp.permission_id
+            WHERE cr.role_name = $2::user_role::text AND p.permission_key = $3
+        ) OR EXISTS (
+            SELECT 1 FROM user_permission_override upo
+            JOIN permission p ON p.id = upo.permission_id
+            WHERE upo.user_id = $1 AND p.permission_key = $3
+              AND upo.is_active = TRUE AND (upo.expires_at IS NULL OR upo.expires_at > NOW())
+        )
+        "#
+    )
+    .bind(auth.user_id)
+    .bind(auth.role)
+    .bind(permission_key)
+    .fetch_one(db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    if granted {
+        Ok(())
+    } else {
+        Err((StatusCode::FORBIDDEN, "Insufficient permissions".to_string()))
+    }
+}
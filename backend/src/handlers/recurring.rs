@@ -0,0 +1,617 @@
+use axum::{
+    extract::{Path, State, Query},
+    http::StatusCode,
+    Json,
+};
+use uuid::Uuid;
+use serde::{Deserialize, Serialize};
+use chrono::{Days, Months, NaiveDate, Utc};
+use rust_decimal::Decimal;
+use sqlx::{postgres::PgPool, Acquire};
+use crate::{
+    AppState,
+    models::recurring::{
+        RecurringTransaction, RecurringOccurrence, UpcomingOccurrence, RecurringKind,
+        RecurrenceFrequency, OccurrenceStatus, CreateRecurringRequest, UpdateRecurringRequest,
+        AdjustOccurrenceRequest, ReceiveOccurrenceRequest,
+    },
+    models::transaction::{PaymentMethod, IncomeTransaction},
+    models::user::UserRole,
+    handlers::auth::AuthUser,
+    handlers::{rbac, fund, cashbook, transaction},
+    handlers::transaction::{CreateExpenseRequest, CreateIncomeRequest},
+};
+
+#[derive(Debug, Deserialize)]
+pub struct RecurringQuery {
+    pub parish_id: Option<Uuid>,
+    pub kind: Option<RecurringKind>,
+}
+
+pub async fn list_recurring_transactions(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Query(query): Query<RecurringQuery>,
+) -> Result<Json<Vec<RecurringTransaction>>, (StatusCode, String)> {
+    rbac::require_finance(&auth)?;
+    let parish_id = rbac::resolve_parish_id(&auth, query.parish_id)?;
+
+    let templates = sqlx::query_as::<_, RecurringTransaction>(
+        r#"
+        SELECT * FROM recurring_transaction
+        WHERE parish_id = $1 AND ($2::recurring_kind IS NULL OR kind = $2) AND deleted_at IS NULL
+        ORDER BY kind, template_name
+        "#
+    )
+    .bind(parish_id)
+    .bind(query.kind)
+    .fetch_all(&state.db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(templates))
+}
+
+pub async fn get_recurring_transaction(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<RecurringTransaction>, (StatusCode, String)> {
+    rbac::require_finance(&auth)?;
+    let template = fetch_template(&state.db, id).await?;
+    rbac::resolve_parish_id(&auth, Some(template.parish_id))?;
+
+    Ok(Json(template))
+}
+
+pub async fn create_recurring_transaction(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Json(payload): Json<CreateRecurringRequest>,
+) -> Result<Json<RecurringTransaction>, (StatusCode, String)> {
+    rbac::require_finance(&auth)?;
+    let parish_id = rbac::resolve_parish_id(&auth, Some(payload.parish_id))?;
+
+    if payload.amount <= Decimal::ZERO {
+        return Err((StatusCode::BAD_REQUEST, "Amount must be greater than zero".to_string()));
+    }
+    if payload.kind == RecurringKind::Expense && payload.payee_name.as_deref().is_none_or(|p| p.trim().is_empty()) {
+        return Err((StatusCode::BAD_REQUEST, "Recurring expenses need a payee".to_string()));
+    }
+    if payload.end_date.is_some_and(|end| end < payload.start_date) {
+        return Err((StatusCode::BAD_REQUEST, "End date is before start date".to_string()));
+    }
+    if let Some(fund_id) = payload.fund_id {
+        fund::fund_for_parish(&state.db, fund_id, parish_id).await?;
+    }
+    if let Some(cashbook_id) = payload.cashbook_id {
+        cashbook::cashbook_for_parish(&state.db, cashbook_id, parish_id).await?;
+    }
+
+    let template = sqlx::query_as::<_, RecurringTransaction>(
+        r#"
+        INSERT INTO recurring_transaction (
+            parish_id, template_name, kind, category, amount, payment_method, fund_id,
+            payee_name, payee_phone, member_id, family_id, description, frequency,
+            start_date, end_date, next_due_date, created_by, cashbook_id
+        )
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $14, $16, $17)
+        RETURNING *
+        "#
+    )
+    .bind(parish_id)
+    .bind(payload.template_name)
+    .bind(payload.kind)
+    .bind(payload.category)
+    .bind(payload.amount)
+    .bind(payload.payment_method.unwrap_or(PaymentMethod::Cash))
+    .bind(payload.fund_id)
+    .bind(payload.payee_name)
+    .bind(payload.payee_phone)
+    .bind(payload.member_id)
+    .bind(payload.family_id)
+    .bind(payload.description)
+    .bind(payload.frequency.unwrap_or(RecurrenceFrequency::Monthly))
+    .bind(payload.start_date)
+    .bind(payload.end_date)
+    .bind(auth.user_id)
+    .bind(payload.cashbook_id)
+    .fetch_one(&state.db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(template))
+}
+
+pub async fn update_recurring_transaction(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+    Json(payload): Json<UpdateRecurringRequest>,
+) -> Result<Json<RecurringTransaction>, (StatusCode, String)> {
+    rbac::require_finance(&auth)?;
+    let existing = fetch_template(&state.db, id).await?;
+    rbac::resolve_parish_id(&auth, Some(existing.parish_id))?;
+
+    let amount = payload.amount.unwrap_or(existing.amount);
+    if amount <= Decimal::ZERO {
+        return Err((StatusCode::BAD_REQUEST, "Amount must be greater than zero".to_string()));
+    }
+    if let Some(fund_id) = payload.fund_id {
+        fund::fund_for_parish(&state.db, fund_id, existing.parish_id).await?;
+    }
+    if let Some(cashbook_id) = payload.cashbook_id {
+        cashbook::cashbook_for_parish(&state.db, cashbook_id, existing.parish_id).await?;
+    }
+
+    let end_date = payload.end_date.or(existing.end_date);
+    // Periods missed while a template was paused are not backfilled: on
+    // reactivation it resumes at the first due date after today.
+    let next_due_date = if !existing.is_active && payload.is_active == Some(true) {
+        let today = Utc::now().date_naive();
+        (0..)
+            .map_while(|n| nth_due_date(existing.start_date, existing.frequency, n))
+            .find(|d| *d > today)
+    } else {
+        existing.next_due_date
+    };
+    let next_due_date = next_due_date.filter(|d| end_date.is_none_or(|end| *d <= end));
+
+    let template = sqlx::query_as::<_, RecurringTransaction>(
+        r#"
+        UPDATE recurring_transaction SET
+            template_name = $1,
+            amount = $2,
+            payment_method = $3,
+            fund_id = $4,
+            payee_name = $5,
+            payee_phone = $6,
+            description = $7,
+            end_date = $8,
+            next_due_date = $9,
+            is_active = $10,
+            cashbook_id = $11,
+            updated_at = NOW()
+        WHERE id = $12
+        RETURNING *
+        "#
+    )
+    .bind(payload.template_name.unwrap_or(existing.template_name))
+    .bind(amount)
+    .bind(payload.payment_method.unwrap_or(existing.payment_method))
+    .bind(payload.fund_id.or(existing.fund_id))
+    .bind(payload.payee_name.or(existing.payee_name))
+    .bind(payload.payee_phone.or(existing.payee_phone))
+    .bind(payload.description.or(existing.description))
+    .bind(end_date)
+    .bind(next_due_date)
+    .bind(payload.is_active.unwrap_or(existing.is_active))
+    .bind(payload.cashbook_id.or(existing.cashbook_id))
+    .bind(id)
+    .fetch_one(&state.db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(template))
+}
+
+pub async fn delete_recurring_transaction(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> Result<StatusCode, (StatusCode, String)> {
+    rbac::require_finance(&auth)?;
+    let template = fetch_template(&state.db, id).await?;
+    rbac::resolve_parish_id(&auth, Some(template.parish_id))?;
+
+    sqlx::query("UPDATE recurring_transaction SET deleted_at = NOW(), is_active = FALSE WHERE id = $1")
+        .bind(id)
+        .execute(&state.db)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+pub async fn list_template_occurrences(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<Vec<RecurringOccurrence>>, (StatusCode, String)> {
+    rbac::require_finance(&auth)?;
+    let template = fetch_template(&state.db, id).await?;
+    rbac::resolve_parish_id(&auth, Some(template.parish_id))?;
+
+    let occurrences = sqlx::query_as::<_, RecurringOccurrence>(
+        "SELECT * FROM recurring_occurrence WHERE recurring_id = $1 ORDER BY due_date DESC"
+    )
+    .bind(id)
+    .fetch_all(&state.db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(occurrences))
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UpcomingQuery {
+    pub count: Option<usize>,
+}
+
+pub async fn list_upcoming_occurrences(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+    Query(query): Query<UpcomingQuery>,
+) -> Result<Json<Vec<UpcomingOccurrence>>, (StatusCode, String)> {
+    rbac::require_finance(&auth)?;
+    let template = fetch_template(&state.db, id).await?;
+    rbac::resolve_parish_id(&auth, Some(template.parish_id))?;
+
+    let planned = sqlx::query_as::<_, RecurringOccurrence>(
+        "SELECT * FROM recurring_occurrence WHERE recurring_id = $1 AND status IN ('PLANNED', 'SKIPPED')"
+    )
+    .bind(id)
+    .fetch_all(&state.db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    let mut upcoming = Vec::new();
+    let mut due = template.next_due_date;
+    while let Some(date) = due {
+        if upcoming.len() >= query.count.unwrap_or(6).min(60) {
+            break;
+        }
+        let adjustment = planned.iter().find(|o| o.due_date == date);
+        upcoming.push(UpcomingOccurrence {
+            due_date: date,
+            amount: adjustment.and_then(|o| o.amount).unwrap_or(template.amount),
+            skipped: adjustment.is_some_and(|o| o.status == OccurrenceStatus::Skipped),
+            adjusted: adjustment.is_some_and(|o| o.amount.is_some()),
+            note: adjustment.and_then(|o| o.note.clone()),
+        });
+        due = due_after(&template, date);
+    }
+
+    Ok(Json(upcoming))
+}
+
+/// Skips or re-prices one future due date. Sending neither `skip` nor
+/// `amount` clears an earlier adjustment.
+pub async fn adjust_occurrence(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+    Json(payload): Json<AdjustOccurrenceRequest>,
+) -> Result<Json<Option<RecurringOccurrence>>, (StatusCode, String)> {
+    rbac::require_finance(&auth)?;
+    let template = fetch_template(&state.db, id).await?;
+    rbac::resolve_parish_id(&auth, Some(template.parish_id))?;
+
+    if !is_due_date(&template, payload.due_date) {
+        return Err((StatusCode::BAD_REQUEST, format!("{} is not a due date of this template", payload.due_date)));
+    }
+    if template.next_due_date.is_none_or(|next| payload.due_date < next) {
+        return Err((StatusCode::BAD_REQUEST, "This occurrence has already been processed".to_string()));
+    }
+    if payload.amount.is_some_and(|a| a <= Decimal::ZERO) {
+        return Err((StatusCode::BAD_REQUEST, "Amount must be greater than zero".to_string()));
+    }
+
+    let skip = payload.skip.unwrap_or(false);
+    if !skip && payload.amount.is_none() {
+        sqlx::query("DELETE FROM recurring_occurrence WHERE recurring_id = $1 AND due_date = $2 AND status IN ('PLANNED', 'SKIPPED')")
+            .bind(id)
+            .bind(payload.due_date)
+            .execute(&state.db)
+            .await
+            .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+        return Ok(Json(None));
+    }
+
+    let status = if skip { OccurrenceStatus::Skipped } else { OccurrenceStatus::Planned };
+    let occurrence = sqlx::query_as::<_, RecurringOccurrence>(
+        r#"
+        INSERT INTO recurring_occurrence (recurring_id, parish_id, due_date, status, amount, note, created_by)
+        VALUES ($1, $2, $3, $4, $5, $6, $7)
+        ON CONFLICT (recurring_id, due_date) DO UPDATE SET
+            status = EXCLUDED.status, amount = EXCLUDED.amount, note = EXCLUDED.note, created_by = EXCLUDED.created_by
+        RETURNING *
+        "#
+    )
+    .bind(id)
+    .bind(template.parish_id)
+    .bind(payload.due_date)
+    .bind(status)
+    .bind(payload.amount)
+    .bind(payload.note)
+    .bind(auth.user_id)
+    .fetch_one(&state.db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(Some(occurrence)))
+}
+
+#[derive(Debug, Deserialize)]
+pub struct OccurrenceQuery {
+    pub parish_id: Option<Uuid>,
+    pub status: Option<OccurrenceStatus>,
+}
+
+/// Occurrences across a parish's templates, e.g. `status=EXPECTED` for
+/// income still to be received.
+pub async fn list_occurrences(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Query(query): Query<OccurrenceQuery>,
+) -> Result<Json<Vec<RecurringOccurrence>>, (StatusCode, String)> {
+    rbac::require_finance(&auth)?;
+    let parish_id = rbac::resolve_parish_id(&auth, query.parish_id)?;
+
+    let occurrences = sqlx::query_as::<_, RecurringOccurrence>(
+        r#"
+        SELECT * FROM recurring_occurrence
+        WHERE parish_id = $1 AND ($2::occurrence_status IS NULL OR status = $2)
+        ORDER BY due_date DESC
+        LIMIT 500
+        "#
+    )
+    .bind(parish_id)
+    .bind(query.status)
+    .fetch_all(&state.db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(occurrences))
+}
+
+/// Records expected standing income as received.
+pub async fn receive_occurrence(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+    Json(payload): Json<ReceiveOccurrenceRequest>,
+) -> Result<Json<IncomeTransaction>, (StatusCode, String)> {
+    rbac::require_finance(&auth)?;
+
+    let occurrence = sqlx::query_as::<_, RecurringOccurrence>("SELECT * FROM recurring_occurrence WHERE id = $1")
+        .bind(id)
+        .fetch_optional(&state.db)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+        .ok_or((StatusCode::NOT_FOUND, "Occurrence not found".to_string()))?;
+    rbac::resolve_parish_id(&auth, Some(occurrence.parish_id))?;
+
+    if occurrence.status != OccurrenceStatus::Expected {
+        return Err((StatusCode::BAD_REQUEST, "Only expected income can be received".to_string()));
+    }
+    let template = fetch_template(&state.db, occurrence.recurring_id).await?;
+
+    // Claimed before the income is booked, so a second submission of the
+    // same occurrence cannot receipt it twice.
+    let mut tx = state.db.begin().await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+    let occurrence = sqlx::query_as::<_, RecurringOccurrence>(
+        "UPDATE recurring_occurrence SET status = 'RECEIVED' WHERE id = $1 AND status = 'EXPECTED' RETURNING *"
+    )
+    .bind(id)
+    .fetch_optional(&mut *tx)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+    .ok_or((StatusCode::CONFLICT, "This income has already been received".to_string()))?;
+
+    let income = transaction::record_income(&mut tx, template.parish_id, CreateIncomeRequest {
+        parish_id: template.parish_id,
+        member_id: template.member_id,
+        family_id: template.family_id,
+        fund_id: template.fund_id,
+        cashbook_id: template.cashbook_id,
+        category: template.category,
+        amount: payload.amount.or(occurrence.amount).unwrap_or(template.amount),
+        currency_code: None,
+        exchange_rate: None,
+        payment_method: payload.payment_method.unwrap_or(template.payment_method),
+        transaction_date: payload.transaction_date.unwrap_or_else(|| Utc::now().date_naive()),
+        description: Some(template.description.unwrap_or(template.template_name)),
+        reference_number: payload.reference_number,
+        received_by: Some(auth.user_id),
+    }).await?;
+
+    sqlx::query("UPDATE recurring_occurrence SET income_transaction_id = $1 WHERE id = $2")
+        .bind(income.id)
+        .bind(id)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    tx.commit().await.map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(income))
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RunRecurringQuery {
+    pub as_of: Option<NaiveDate>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RecurringRunResponse {
+    pub occurrences_processed: usize,
+}
+
+/// Materializes due occurrences now instead of waiting for the background job.
+pub async fn run_recurring_transactions(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Query(query): Query<RunRecurringQuery>,
+) -> Result<Json<RecurringRunResponse>, (StatusCode, String)> {
+    rbac::require_role(&auth, &[UserRole::SuperAdmin])?;
+    let as_of = query.as_of.unwrap_or_else(|| Utc::now().date_naive());
+
+    let occurrences_processed = materialize_due(&state.db, as_of)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(RecurringRunResponse { occurrences_processed }))
+}
+
+// ============================================================================
+// Background job
+// ============================================================================
+
+/// Turns every due occurrence up to `today` into a pending voucher
+/// (expenses) or an expected entry (income), honouring skips and
+/// adjustments. Returns the number of due dates processed.
+pub async fn materialize_due(db: &PgPool, today: NaiveDate) -> Result<usize, sqlx::Error> {
+    let templates = sqlx::query_as::<_, RecurringTransaction>(
+        r#"
+        SELECT * FROM recurring_transaction
+        WHERE is_active = TRUE AND deleted_at IS NULL AND next_due_date <= $1
+        "#
+    )
+    .bind(today)
+    .fetch_all(db)
+    .await?;
+
+    let mut processed = 0;
+    for template in &templates {
+        let mut due = template.next_due_date;
+        while let Some(date) = due.filter(|d| *d <= today) {
+            materialize(db, template, date).await?;
+            processed += 1;
+            due = due_after(template, date);
+        }
+
+        sqlx::query("UPDATE recurring_transaction SET next_due_date = $1 WHERE id = $2")
+            .bind(due)
+            .bind(template.id)
+            .execute(db)
+            .await?;
+    }
+
+    Ok(processed)
+}
+
+async fn materialize(db: &PgPool, template: &RecurringTransaction, due_date: NaiveDate) -> Result<(), sqlx::Error> {
+    let mut tx = db.begin().await?;
+
+    // Claim the due date before raising anything: the occurrence row is
+    // written (or locked, when it was planned ahead) in this transaction,
+    // so a concurrent run waits here and then finds it already processed.
+    let claimed = sqlx::query_as::<_, RecurringOccurrence>(
+        r#"
+        INSERT INTO recurring_occurrence (recurring_id, parish_id, due_date, status)
+        VALUES ($1, $2, $3, 'PLANNED')
+        ON CONFLICT (recurring_id, due_date) DO UPDATE SET updated_at = NOW()
+        WHERE recurring_occurrence.status = 'PLANNED'
+        RETURNING *
+        "#
+    )
+    .bind(template.id)
+    .bind(template.parish_id)
+    .bind(due_date)
+    .fetch_optional(&mut *tx)
+    .await?;
+
+    let Some(occurrence) = claimed else {
+        return Ok(());
+    };
+    let amount = occurrence.amount.unwrap_or(template.amount);
+
+    let (status, voucher_id, error) = match template.kind {
+        RecurringKind::Income => (OccurrenceStatus::Expected, None, None),
+        RecurringKind::Expense => {
+            let request = CreateExpenseRequest {
+                parish_id: template.parish_id,
+                fund_id: template.fund_id,
+                cashbook_id: template.cashbook_id,
+                supplier_id: None,
+                category: template.category,
+                amount,
+                currency_code: None,
+                exchange_rate: None,
+                payment_method: template.payment_method,
+                payee_name: template.payee_name.clone().unwrap_or_default(),
+                payee_phone: template.payee_phone.clone(),
+                expense_date: due_date,
+                description: template.description.clone().unwrap_or_else(|| template.template_name.clone()),
+                reference_number: None,
+            };
+            // A savepoint keeps a refused voucher from aborting the claim.
+            let mut savepoint = tx.begin().await?;
+            match transaction::raise_expense_voucher(&mut savepoint, template.parish_id, request, template.created_by).await {
+                Ok(voucher) => {
+                    savepoint.commit().await?;
+                    (OccurrenceStatus::Raised, Some(voucher.id), None)
+                }
+                Err((_, e)) => {
+                    savepoint.rollback().await?;
+                    tracing::warn!("Recurring expense '{}' due {} not raised: {}", template.template_name, due_date, e);
+                    (OccurrenceStatus::Failed, None, Some(e))
+                }
+            }
+        }
+    };
+
+    sqlx::query(
+        r#"
+        UPDATE recurring_occurrence SET
+            status = $1,
+            amount = $2,
+            note = COALESCE($3, note),
+            expense_voucher_id = $4
+        WHERE id = $5
+        "#
+    )
+    .bind(status)
+    .bind(amount)
+    .bind(error)
+    .bind(voucher_id)
+    .bind(occurrence.id)
+    .execute(&mut *tx)
+    .await?;
+
+    tx.commit().await
+}
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+async fn fetch_template(db: &PgPool, id: Uuid) -> Result<RecurringTransaction, (StatusCode, String)> {
+    sqlx::query_as::<_, RecurringTransaction>("SELECT * FROM recurring_transaction WHERE id = $1 AND deleted_at IS NULL")
+        .bind(id)
+        .fetch_optional(db)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+        .ok_or((StatusCode::NOT_FOUND, "Recurring transaction not found".to_string()))
+}
+
+/// The n-th due date counted from the start date, so a template starting on
+/// the 31st falls on the last day of shorter months without drifting.
+fn nth_due_date(start: NaiveDate, frequency: RecurrenceFrequency, n: u32) -> Option<NaiveDate> {
+    match frequency {
+        RecurrenceFrequency::Weekly => start.checked_add_days(Days::new(7 * n as u64)),
+        RecurrenceFrequency::Monthly => start.checked_add_months(Months::new(n)),
+        RecurrenceFrequency::Quarterly => start.checked_add_months(Months::new(3 * n)),
+        RecurrenceFrequency::Annual => start.checked_add_months(Months::new(12 * n)),
+    }
+}
+
+/// First due date after `after`, or `None` once the template has ended.
+fn due_after(template: &RecurringTransaction, after: NaiveDate) -> Option<NaiveDate> {
+    (0..)
+        .map_while(|n| nth_due_date(template.start_date, template.frequency, n))
+        .find(|d| *d > after)
+        .filter(|d| template.end_date.is_none_or(|end| *d <= end))
+}
+
+fn is_due_date(template: &RecurringTransaction, date: NaiveDate) -> bool {
+    date >= template.start_date
+        && template.end_date.is_none_or(|end| date <= end)
+        && (0..)
+            .map_while(|n| nth_due_date(template.start_date, template.frequency, n))
+            .take_while(|d| *d <= date)
+            .any(|d| d == date)
+}
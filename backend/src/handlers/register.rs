@@ -0,0 +1,528 @@
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::Response,
+    Json,
+};
+use sqlx::{PgConnection, PgPool};
+use uuid::Uuid;
+use chrono::Utc;
+use std::collections::{HashMap, HashSet};
+use serde::Deserialize;
+use crate::{AppState, handlers::auth::AuthUser, handlers::rbac};
+use crate::export::{self, ExportFormat, ReportHeader};
+use crate::models::member::{SacramentRecord, SacramentType};
+use crate::models::register::{
+    CreateRegisterBookRequest, EntryGap, RegisterBook, RegisterBookAudit, RegisterEntryLine,
+    RegisterEntryRef, UpdateRegisterBookRequest,
+};
+use crate::models::sacrament_rule::{RuleSeverity, RuleViolation};
+
+const BOOK_WITH_USAGE: &str = r#"
+    SELECT b.*, u.entry_count, u.last_entry, u.last_page
+    FROM register_book b
+    LEFT JOIN LATERAL (
+        SELECT COUNT(*) AS entry_count, MAX(register_entry) AS last_entry, MAX(register_page) AS last_page
+        FROM sacrament_record r
+        WHERE r.register_book_id = b.id AND r.deleted_at IS NULL
+    ) u ON TRUE
+"#;
+
+#[derive(Debug, Deserialize)]
+pub struct RegisterBookQuery {
+    pub parish_id: Option<Uuid>,
+    pub sacrament_type: Option<SacramentType>,
+}
+
+pub async fn list_register_books(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Query(query): Query<RegisterBookQuery>,
+) -> Result<Json<Vec<RegisterBook>>, (StatusCode, String)> {
+    let parish_id = rbac::resolve_parish_id(&auth, query.parish_id)?;
+
+    let books = sqlx::query_as::<_, RegisterBook>(&format!(
+        "{} WHERE b.parish_id = $1 AND b.deleted_at IS NULL AND ($2::sacrament_type IS NULL OR b.sacrament_type = $2) ORDER BY b.sacrament_type, b.volume_number",
+        BOOK_WITH_USAGE
+    ))
+    .bind(parish_id)
+    .bind(query.sacrament_type)
+    .fetch_all(&state.db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(books))
+}
+
+pub async fn create_register_book(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Json(payload): Json<CreateRegisterBookRequest>,
+) -> Result<Json<RegisterBook>, (StatusCode, String)> {
+    rbac::require_write(&auth)?;
+    let parish_id = rbac::resolve_parish_id(&auth, Some(payload.parish_id))?;
+    validate_layout(payload.volume_number, payload.page_count, payload.entries_per_page, payload.first_entry_number)?;
+    let opened_on = payload.opened_on.unwrap_or_else(|| Utc::now().date_naive());
+    if payload.closed_on.is_some_and(|c| c < opened_on) {
+        return Err((StatusCode::BAD_REQUEST, "A volume cannot close before it opens".to_string()));
+    }
+
+    let mut tx = state.db.begin().await.map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    let volume_number = match payload.volume_number {
+        Some(v) => v,
+        None => sqlx::query_scalar::<_, i32>(
+            "SELECT COALESCE(MAX(volume_number), 0) + 1 FROM register_book WHERE parish_id = $1 AND sacrament_type = $2 AND deleted_at IS NULL"
+        )
+        .bind(parish_id)
+        .bind(payload.sacrament_type)
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?,
+    };
+
+    let taken: bool = sqlx::query_scalar(
+        "SELECT EXISTS (SELECT 1 FROM register_book WHERE parish_id = $1 AND sacrament_type = $2 AND volume_number = $3 AND deleted_at IS NULL)"
+    )
+    .bind(parish_id)
+    .bind(payload.sacrament_type)
+    .bind(volume_number)
+    .fetch_one(&mut *tx)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+    if taken {
+        return Err((StatusCode::CONFLICT, format!("Volume {} already exists", volume_number)));
+    }
+
+    if payload.closed_on.is_none() {
+        sqlx::query(
+            r#"
+            UPDATE register_book SET closed_on = GREATEST(opened_on, $3)
+            WHERE parish_id = $1 AND sacrament_type = $2 AND closed_on IS NULL AND deleted_at IS NULL
+            "#
+        )
+        .bind(parish_id)
+        .bind(payload.sacrament_type)
+        .bind(opened_on)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+    }
+
+    let id: Uuid = sqlx::query_scalar(
+        r#"
+        INSERT INTO register_book (
+            parish_id, sacrament_type, volume_number, title, page_count, entries_per_page,
+            first_entry_number, opened_on, closed_on, notes, created_by
+        )
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
+        RETURNING id
+        "#
+    )
+    .bind(parish_id)
+    .bind(payload.sacrament_type)
+    .bind(volume_number)
+    .bind(payload.title)
+    .bind(payload.page_count)
+    .bind(payload.entries_per_page)
+    .bind(payload.first_entry_number.unwrap_or(1))
+    .bind(opened_on)
+    .bind(payload.closed_on)
+    .bind(payload.notes)
+    .bind(auth.user_id)
+    .fetch_one(&mut *tx)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    tx.commit().await.map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(fetch_book(&state.db, id).await?))
+}
+
+pub async fn update_register_book(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+    Json(payload): Json<UpdateRegisterBookRequest>,
+) -> Result<Json<RegisterBook>, (StatusCode, String)> {
+    rbac::require_write(&auth)?;
+    let mut book = fetch_book(&state.db, id).await?;
+    rbac::resolve_parish_id(&auth, Some(book.parish_id))?;
+    validate_layout(None, payload.page_count, payload.entries_per_page, payload.first_entry_number)?;
+
+    if let Some(val) = payload.title { book.title = Some(val); }
+    if let Some(val) = payload.page_count { book.page_count = Some(val); }
+    if let Some(val) = payload.entries_per_page { book.entries_per_page = Some(val); }
+    if let Some(val) = payload.first_entry_number { book.first_entry_number = val; }
+    if let Some(val) = payload.opened_on { book.opened_on = val; }
+    if let Some(val) = payload.closed_on { book.closed_on = Some(val); }
+    if let Some(val) = payload.notes { book.notes = Some(val); }
+
+    if book.closed_on.is_some_and(|c| c < book.opened_on) {
+        return Err((StatusCode::BAD_REQUEST, "A volume cannot close before it opens".to_string()));
+    }
+
+    sqlx::query(
+        r#"
+        UPDATE register_book
+        SET title = $1, page_count = $2, entries_per_page = $3, first_entry_number = $4,
+            opened_on = $5, closed_on = $6, notes = $7
+        WHERE id = $8
+        "#
+    )
+    .bind(&book.title)
+    .bind(book.page_count)
+    .bind(book.entries_per_page)
+    .bind(book.first_entry_number)
+    .bind(book.opened_on)
+    .bind(book.closed_on)
+    .bind(&book.notes)
+    .bind(id)
+    .execute(&state.db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(fetch_book(&state.db, id).await?))
+}
+
+pub async fn delete_register_book(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> Result<StatusCode, (StatusCode, String)> {
+    rbac::require_write(&auth)?;
+    let book = fetch_book(&state.db, id).await?;
+    rbac::resolve_parish_id(&auth, Some(book.parish_id))?;
+
+    if book.entry_count.unwrap_or(0) > 0 {
+        return Err((StatusCode::CONFLICT, "Records cite this volume; correct them first".to_string()));
+    }
+
+    sqlx::query("UPDATE register_book SET deleted_at = NOW() WHERE id = $1")
+        .bind(id)
+        .execute(&state.db)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Gaps, duplicate entry numbers and pages that disagree with the entry
+/// numbering in a volume.
+pub async fn get_register_book_audit(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+    format: ExportFormat,
+) -> Result<Response, (StatusCode, String)> {
+    let book = fetch_book(&state.db, id).await?;
+    let parish_id = rbac::resolve_parish_id(&auth, Some(book.parish_id))?;
+
+    let entries = sqlx::query_as::<_, RegisterEntryLine>(
+        r#"
+        SELECT r.id AS record_id, r.member_id, CONCAT_WS(' ', m.first_name, m.middle_name, m.last_name) AS member_name,
+               r.sacrament_date, r.register_page, r.register_entry,
+               LEAST(r.member_id, COALESCE(r.spouse_id, r.member_id)) AS couple_id
+        FROM sacrament_record r
+        JOIN member m ON m.id = r.member_id
+        WHERE r.register_book_id = $1 AND r.deleted_at IS NULL AND r.register_entry IS NOT NULL
+        ORDER BY r.register_entry, r.sacrament_date
+        "#
+    )
+    .bind(id)
+    .fetch_all(&state.db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    let mut gaps = Vec::new();
+    let mut expected = book.first_entry_number;
+    for e in &entries {
+        if e.register_entry > expected {
+            gaps.push(EntryGap { from_entry: expected, to_entry: e.register_entry - 1 });
+        }
+        expected = expected.max(e.register_entry + 1);
+    }
+
+    // Spouses citing the same marriage entry count once
+    let mut per_entry: HashMap<i32, HashSet<Uuid>> = HashMap::new();
+    for e in &entries {
+        per_entry.entry(e.register_entry).or_default().insert(e.couple_id.unwrap_or(e.record_id));
+    }
+    let mut duplicates = Vec::new();
+    let mut page_mismatches = Vec::new();
+    for e in entries {
+        let page = expected_page(&book, e.register_entry);
+        if page.is_some() && e.register_page.is_some() && page != e.register_page {
+            page_mismatches.push(e.clone());
+        }
+        if per_entry[&e.register_entry].len() > 1 {
+            duplicates.push(e);
+        }
+    }
+
+    let uncited_records: i64 = sqlx::query_scalar(
+        r#"
+        SELECT COUNT(*) FROM sacrament_record
+        WHERE parish_id = $1 AND sacrament_type = $2 AND deleted_at IS NULL
+          AND (register_book_id IS NULL OR register_entry IS NULL)
+        "#
+    )
+    .bind(parish_id)
+    .bind(book.sacrament_type)
+    .fetch_one(&state.db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    let reference = book_label(book.sacrament_type, book.title.as_deref(), book.volume_number);
+    let header = ReportHeader::parish(parish_id, &format!("Register Audit: {}", reference), None);
+    let audit = RegisterBookAudit { book, reference, gaps, duplicates, page_mismatches, uncited_records };
+
+    export::respond(&state.db, &auth, format, header, audit).await
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RegisterLookupQuery {
+    pub parish_id: Option<Uuid>,
+    pub sacrament_type: SacramentType,
+    pub volume_number: i32,
+    pub page: Option<i32>,
+    pub entry: Option<i32>,
+}
+
+/// Finds the records citing a volume, page and/or entry, e.g. to see
+/// whether a paper entry has already been digitised.
+pub async fn lookup_register_entry(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Query(query): Query<RegisterLookupQuery>,
+) -> Result<Json<Vec<RegisterEntryLine>>, (StatusCode, String)> {
+    let parish_id = rbac::resolve_parish_id(&auth, query.parish_id)?;
+    if query.page.is_none() && query.entry.is_none() {
+        return Err((StatusCode::BAD_REQUEST, "Give a page or an entry number".to_string()));
+    }
+
+    let entries = sqlx::query_as::<_, RegisterEntryLine>(
+        r#"
+        SELECT r.id AS record_id, r.member_id, CONCAT_WS(' ', m.first_name, m.middle_name, m.last_name) AS member_name,
+               r.sacrament_date, r.register_page, r.register_entry,
+               LEAST(r.member_id, COALESCE(r.spouse_id, r.member_id)) AS couple_id
+        FROM sacrament_record r
+        JOIN register_book b ON b.id = r.register_book_id
+        JOIN member m ON m.id = r.member_id
+        WHERE b.parish_id = $1 AND b.sacrament_type = $2 AND b.volume_number = $3 AND b.deleted_at IS NULL
+          AND r.deleted_at IS NULL AND r.register_entry IS NOT NULL
+          AND ($4::int IS NULL OR r.register_page = $4)
+          AND ($5::int IS NULL OR r.register_entry = $5)
+        ORDER BY r.register_entry
+        "#
+    )
+    .bind(parish_id)
+    .bind(query.sacrament_type)
+    .bind(query.volume_number)
+    .bind(query.page)
+    .bind(query.entry)
+    .fetch_all(&state.db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(entries))
+}
+
+// ============================================================================
+// Entry references on sacrament records
+// ============================================================================
+
+async fn fetch_book(db: &PgPool, id: Uuid) -> Result<RegisterBook, (StatusCode, String)> {
+    sqlx::query_as::<_, RegisterBook>(&format!("{} WHERE b.id = $1 AND b.deleted_at IS NULL", BOOK_WITH_USAGE))
+        .bind(id)
+        .fetch_optional(db)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+        .ok_or((StatusCode::NOT_FOUND, "Register book not found".to_string()))
+}
+
+fn validate_layout(
+    volume_number: Option<i32>,
+    page_count: Option<i32>,
+    entries_per_page: Option<i32>,
+    first_entry_number: Option<i32>,
+) -> Result<(), (StatusCode, String)> {
+    if [volume_number, page_count, entries_per_page, first_entry_number].iter().flatten().any(|n| *n < 1) {
+        return Err((StatusCode::BAD_REQUEST, "Volume, page and entry numbers start at 1".to_string()));
+    }
+    Ok(())
+}
+
+fn expected_page(book: &RegisterBook, entry: i32) -> Option<i32> {
+    book.entries_per_page
+        .filter(|_| entry >= book.first_entry_number)
+        .map(|per_page| (entry - book.first_entry_number) / per_page + 1)
+}
+
+pub fn register_name(sacrament_type: SacramentType) -> &'static str {
+    match sacrament_type {
+        SacramentType::Baptism => "Baptism Register",
+        SacramentType::FirstCommunion => "First Communion Register",
+        SacramentType::Confirmation => "Confirmation Register",
+        SacramentType::Marriage => "Marriage Register",
+        SacramentType::HolyOrders => "Ordination Register",
+        SacramentType::AnointingOfSick => "Register of the Sick",
+    }
+}
+
+pub fn book_label(sacrament_type: SacramentType, title: Option<&str>, volume_number: i32) -> String {
+    let name = title.filter(|t| !t.trim().is_empty()).unwrap_or(register_name(sacrament_type));
+    format!("{} Vol. {}", name, volume_number)
+}
+
+/// "Baptism Register Vol. 12, page 34, entry 211", or `None` when the
+/// record cites no register.
+pub async fn reference(db: &PgPool, record: &SacramentRecord) -> Result<Option<String>, (StatusCode, String)> {
+    let Some(book_id) = record.register_book_id else { return Ok(None) };
+    let book: Option<(SacramentType, Option<String>, i32)> = sqlx::query_as(
+        "SELECT sacrament_type, title, volume_number FROM register_book WHERE id = $1"
+    )
+    .bind(book_id)
+    .fetch_optional(db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(book.map(|(sacrament_type, title, volume)| {
+        cite(book_label(sacrament_type, title.as_deref(), volume), record.register_page, record.register_entry)
+    }))
+}
+
+pub fn cite(mut label: String, page: Option<i32>, entry: Option<i32>) -> String {
+    if let Some(page) = page {
+        label.push_str(&format!(", page {}", page));
+    }
+    if let Some(entry) = entry {
+        label.push_str(&format!(", entry {}", entry));
+    }
+    label
+}
+
+/// Works out where a record is written on paper. Without a volume the next
+/// entry in the open volume is allocated; within a volume a missing entry
+/// number is allocated and a missing page worked out from the entry. A
+/// full open volume or an entry number already cited is reported as a
+/// warning rather than refused, since the paper is what counts. A couple
+/// share one marriage entry, so the spouse's record may cite it too.
+pub async fn place_entry(
+    conn: &mut PgConnection,
+    parish_id: Uuid,
+    sacrament_type: SacramentType,
+    record_id: Option<Uuid>,
+    spouse_id: Option<Uuid>,
+    requested: RegisterEntryRef,
+) -> Result<(RegisterEntryRef, Vec<RuleViolation>), (StatusCode, String)> {
+    let explicit = requested.register_book_id.is_some();
+    let book = match requested.register_book_id {
+        Some(id) => {
+            let book = sqlx::query_as::<_, RegisterBook>(
+                "SELECT * FROM register_book WHERE id = $1 AND deleted_at IS NULL FOR UPDATE"
+            )
+            .bind(id)
+            .fetch_optional(&mut *conn)
+            .await
+            .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+            .ok_or((StatusCode::NOT_FOUND, "Register book not found".to_string()))?;
+            if book.parish_id != parish_id || book.sacrament_type != sacrament_type {
+                return Err((StatusCode::BAD_REQUEST, "Register book belongs to another parish or sacrament".to_string()));
+            }
+            Some(book)
+        }
+        None if requested.register_page.is_some() || requested.register_entry.is_some() => {
+            return Err((StatusCode::BAD_REQUEST, "register_book_id is required with a page or entry".to_string()));
+        }
+        None => sqlx::query_as::<_, RegisterBook>(
+            r#"
+            SELECT * FROM register_book
+            WHERE parish_id = $1 AND sacrament_type = $2 AND closed_on IS NULL AND deleted_at IS NULL
+            FOR UPDATE
+            "#
+        )
+        .bind(parish_id)
+        .bind(sacrament_type)
+        .fetch_optional(&mut *conn)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?,
+    };
+    let Some(book) = book else { return Ok((RegisterEntryRef::default(), Vec::new())) };
+    let label = book_label(book.sacrament_type, book.title.as_deref(), book.volume_number);
+    let mut warnings = Vec::new();
+
+    let (last_entry, last_page): (Option<i32>, Option<i32>) = sqlx::query_as(
+        r#"
+        SELECT register_entry, register_page FROM sacrament_record
+        WHERE register_book_id = $1 AND deleted_at IS NULL AND register_entry IS NOT NULL AND ($2::uuid IS NULL OR id <> $2)
+        ORDER BY register_entry DESC
+        LIMIT 1
+        "#
+    )
+    .bind(book.id)
+    .bind(record_id)
+    .fetch_optional(&mut *conn)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+    .unwrap_or_default();
+
+    let entry = requested.register_entry
+        .unwrap_or_else(|| last_entry.map_or(book.first_entry_number, |e| e + 1));
+    if entry < book.first_entry_number {
+        return Err((StatusCode::BAD_REQUEST, format!(
+            "{} starts at entry {}", label, book.first_entry_number
+        )));
+    }
+
+    let computed_page = expected_page(&book, entry);
+    let page = requested.register_page.or(computed_page).unwrap_or(last_page.unwrap_or(1));
+    if let Some(pages) = book.page_count.filter(|pages| page > *pages) {
+        if !explicit {
+            warnings.push(RuleViolation {
+                rule: "REGISTER_FULL".to_string(),
+                severity: RuleSeverity::Warning,
+                message: format!("{} has no room left after page {}; open the next volume and cite the entry", label, pages),
+            });
+            return Ok((RegisterEntryRef::default(), warnings));
+        }
+        return Err((StatusCode::BAD_REQUEST, format!("{} has only {} pages", label, pages)));
+    }
+
+    if requested.register_page.is_some() && computed_page.is_some_and(|p| p != page) {
+        warnings.push(RuleViolation {
+            rule: "REGISTER_PAGE_MISMATCH".to_string(),
+            severity: RuleSeverity::Warning,
+            message: format!("Entry {} would fall on page {} of {}", entry, computed_page.unwrap_or_default(), label),
+        });
+    }
+
+    let cited: bool = sqlx::query_scalar(
+        r#"
+        SELECT EXISTS (
+            SELECT 1 FROM sacrament_record
+            WHERE register_book_id = $1 AND register_entry = $2 AND deleted_at IS NULL AND ($3::uuid IS NULL OR id <> $3)
+              AND ($4::uuid IS NULL OR member_id <> $4)
+        )
+        "#
+    )
+    .bind(book.id)
+    .bind(entry)
+    .bind(record_id)
+    .bind(spouse_id)
+    .fetch_one(&mut *conn)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+    if cited {
+        warnings.push(RuleViolation {
+            rule: "DUPLICATE_REGISTER_ENTRY".to_string(),
+            severity: RuleSeverity::Warning,
+            message: format!("{}, entry {} is already cited by another record", label, entry),
+        });
+    }
+
+    Ok((
+        RegisterEntryRef { register_book_id: Some(book.id), register_page: Some(page), register_entry: Some(entry) },
+        warnings,
+    ))
+}
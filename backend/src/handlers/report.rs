@@ -1,17 +1,22 @@
 use axum::{
     extract::{State, Query},
     http::StatusCode,
-    Json,
+    response::Response,
 };
 use uuid::Uuid;
 use crate::{AppState, models::report::{
     TrialBalance, TrialBalanceEntry, IncomeExpenditureStatement, ReportEntry,
     BudgetVsActualReport, BudgetVsActualEntry, BalanceSheet, BalanceSheetSection,
-    BalanceSheetEntry, CashFlowStatement, CashFlowSection, CashFlowEntry
-}, handlers::auth::AuthUser, handlers::rbac};
+    BalanceSheetEntry, CashFlowStatement, CashFlowSection, CashFlowEntry,
+    FundBalanceReport, FundBalanceEntry, ConsolidatedParish, ConsolidatedLine,
+    ConsolidatedSection, ConsolidatedStatement, ComparativeEntry, ComparativeSection,
+    ComparativeReport, MonthlyLine, MonthlySection, MonthlyReport
+}, handlers::auth::AuthUser, handlers::{rbac, asset}, export::{self, ExportFormat, ReportHeader}};
 use serde::Deserialize;
-use chrono::{NaiveDate, Datelike};
+use chrono::{NaiveDate, Datelike, Months, Utc};
 use rust_decimal::Decimal;
+use sqlx::postgres::PgPool;
+use std::collections::{BTreeMap, HashMap};
 
 #[derive(Debug, Deserialize)]
 pub struct ReportQuery {
@@ -24,9 +29,20 @@ pub async fn get_trial_balance(
     auth: AuthUser,
     State(state): State<AppState>,
     Query(query): Query<ReportQuery>,
-) -> Result<Json<TrialBalance>, (StatusCode, String)> {
+    format: ExportFormat,
+) -> Result<Response, (StatusCode, String)> {
     let parish_id = rbac::resolve_parish_id(&auth, Some(query.parish_id))?;
+    let (report, header) = trial_balance(&state.db, parish_id, query.start_date, query.end_date).await?;
 
+    export::respond(&state.db, &auth, format, header, report).await
+}
+
+pub async fn trial_balance(
+    db: &PgPool,
+    parish_id: Uuid,
+    start_date: NaiveDate,
+    end_date: NaiveDate,
+) -> Result<(TrialBalance, ReportHeader), (StatusCode, String)> {
     let income_entries = sqlx::query!(
         r#"
         SELECT category as "category: String", SUM(amount) as "total!"
@@ -34,9 +50,9 @@ pub async fn get_trial_balance(
         WHERE parish_id = $1 AND transaction_date BETWEEN $2 AND $3 AND deleted_at IS NULL
         GROUP BY category
         "#,
-        parish_id, query.start_date, query.end_date
+        parish_id, start_date, end_date
     )
-    .fetch_all(&state.db)
+    .fetch_all(db)
     .await
     .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
 
@@ -47,9 +63,9 @@ pub async fn get_trial_balance(
         WHERE parish_id = $1 AND expense_date BETWEEN $2 AND $3 AND deleted_at IS NULL AND approval_status = 'APPROVED'
         GROUP BY category
         "#,
-        parish_id, query.start_date, query.end_date
+        parish_id, start_date, end_date
     )
-    .fetch_all(&state.db)
+    .fetch_all(db)
     .await
     .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
 
@@ -75,20 +91,33 @@ pub async fn get_trial_balance(
         total_debit += exp.total;
     }
 
-    Ok(Json(TrialBalance {
+    let header = ReportHeader::parish(parish_id, "Trial Balance", Some(export::period_label(start_date, end_date)));
+
+    Ok((TrialBalance {
         entries,
         total_debit,
         total_credit,
-    }))
+    }, header))
 }
 
 pub async fn get_income_expenditure(
     auth: AuthUser,
     State(state): State<AppState>,
     Query(query): Query<ReportQuery>,
-) -> Result<Json<IncomeExpenditureStatement>, (StatusCode, String)> {
+    format: ExportFormat,
+) -> Result<Response, (StatusCode, String)> {
     let parish_id = rbac::resolve_parish_id(&auth, Some(query.parish_id))?;
+    let (report, header) = income_expenditure(&state.db, parish_id, query.start_date, query.end_date).await?;
 
+    export::respond(&state.db, &auth, format, header, report).await
+}
+
+pub async fn income_expenditure(
+    db: &PgPool,
+    parish_id: Uuid,
+    start_date: NaiveDate,
+    end_date: NaiveDate,
+) -> Result<(IncomeExpenditureStatement, ReportHeader), (StatusCode, String)> {
     let income_data = sqlx::query!(
         r#"
         SELECT category as "category: String", SUM(amount) as "total!"
@@ -96,9 +125,9 @@ pub async fn get_income_expenditure(
         WHERE parish_id = $1 AND transaction_date BETWEEN $2 AND $3 AND deleted_at IS NULL
         GROUP BY category
         "#,
-        parish_id, query.start_date, query.end_date
+        parish_id, start_date, end_date
     )
-    .fetch_all(&state.db)
+    .fetch_all(db)
     .await
     .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
 
@@ -109,9 +138,9 @@ pub async fn get_income_expenditure(
         WHERE parish_id = $1 AND expense_date BETWEEN $2 AND $3 AND deleted_at IS NULL AND approval_status = 'APPROVED'
         GROUP BY category
         "#,
-        parish_id, query.start_date, query.end_date
+        parish_id, start_date, end_date
     )
-    .fetch_all(&state.db)
+    .fetch_all(db)
     .await
     .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
 
@@ -135,33 +164,48 @@ pub async fn get_income_expenditure(
         total_expenditure += row.total;
     }
 
-    Ok(Json(IncomeExpenditureStatement {
+    let header = ReportHeader::parish(parish_id, "Income and Expenditure Statement", Some(export::period_label(start_date, end_date)));
+
+    Ok((IncomeExpenditureStatement {
         income_entries,
         expenditure_entries,
         total_income,
         total_expenditure,
         net_surplus_deficit: total_income - total_expenditure,
-    }))
+    }, header))
 }
 
 pub async fn get_budget_vs_actual(
     auth: AuthUser,
     State(state): State<AppState>,
     Query(query): Query<ReportQuery>,
-) -> Result<Json<BudgetVsActualReport>, (StatusCode, String)> {
+    format: ExportFormat,
+) -> Result<Response, (StatusCode, String)> {
     let parish_id = rbac::resolve_parish_id(&auth, Some(query.parish_id))?;
+    let (report, header) = budget_vs_actual(&state.db, parish_id, query.start_date, query.end_date).await?;
 
-    let year = query.start_date.year();
+    export::respond(&state.db, &auth, format, header, report).await
+}
+
+/// Compares actuals with the approved version of the year's budget.
+pub async fn budget_vs_actual(
+    db: &PgPool,
+    parish_id: Uuid,
+    start_date: NaiveDate,
+    end_date: NaiveDate,
+) -> Result<(BudgetVsActualReport, ReportHeader), (StatusCode, String)> {
+    let year = start_date.year();
     let budgets = sqlx::query!(
         r#"
         SELECT category as "category: String", SUM(amount) as "total!"
         FROM budget
         WHERE parish_id = $1 AND fiscal_year = $2 AND deleted_at IS NULL
+          AND version_id IN (SELECT id FROM budget_version WHERE status = 'APPROVED' AND deleted_at IS NULL)
         GROUP BY category
         "#,
         parish_id, year
     )
-    .fetch_all(&state.db)
+    .fetch_all(db)
     .await
     .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
 
@@ -170,19 +214,18 @@ pub async fn get_budget_vs_actual(
            FROM income_transaction 
            WHERE parish_id = $1 AND transaction_date BETWEEN $2 AND $3 AND deleted_at IS NULL
            GROUP BY category"#,
-        parish_id, query.start_date, query.end_date
-    ).fetch_all(&state.db).await.map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+        parish_id, start_date, end_date
+    ).fetch_all(db).await.map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
 
     let expenses = sqlx::query!(
         r#"SELECT category as "category: String", SUM(amount) as "total!"
            FROM expense_voucher
            WHERE parish_id = $1 AND expense_date BETWEEN $2 AND $3 AND deleted_at IS NULL AND approval_status = 'APPROVED'
            GROUP BY category"#,
-        parish_id, query.start_date, query.end_date
-    ).fetch_all(&state.db).await.map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+        parish_id, start_date, end_date
+    ).fetch_all(db).await.map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
 
     // 3. Merge
-    use std::collections::HashMap;
     let mut map: HashMap<String, (Decimal, Decimal)> = HashMap::new(); // Category -> (Budget, Actual)
 
     for b in budgets { map.insert(b.category, (b.total, Decimal::ZERO)); }
@@ -210,32 +253,44 @@ pub async fn get_budget_vs_actual(
         total_actual += act;
     }
 
-    Ok(Json(BudgetVsActualReport {
+    let header = ReportHeader::parish(parish_id, "Budget vs Actual", Some(export::period_label(start_date, end_date)));
+
+    Ok((BudgetVsActualReport {
         entries,
         total_budget,
         total_actual,
         total_variance: total_budget - total_actual,
-    }))
+    }, header))
 }
 
 pub async fn get_balance_sheet(
     auth: AuthUser,
     State(state): State<AppState>,
     Query(query): Query<ReportQuery>,
-) -> Result<Json<BalanceSheet>, (StatusCode, String)> {
+    format: ExportFormat,
+) -> Result<Response, (StatusCode, String)> {
     let parish_id = rbac::resolve_parish_id(&auth, Some(query.parish_id))?;
+    let (report, header) = balance_sheet(&state.db, parish_id, query.end_date).await?;
+
+    export::respond(&state.db, &auth, format, header, report).await
+}
 
+pub async fn balance_sheet(
+    db: &PgPool,
+    parish_id: Uuid,
+    end_date: NaiveDate,
+) -> Result<(BalanceSheet, ReportHeader), (StatusCode, String)> {
     let total_income = sqlx::query!(
         r#"SELECT COALESCE(SUM(amount), 0) as "total!" FROM income_transaction 
            WHERE parish_id = $1 AND transaction_date <= $2 AND deleted_at IS NULL"#,
-        parish_id, query.end_date
-    ).fetch_one(&state.db).await.map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?.total;
+        parish_id, end_date
+    ).fetch_one(db).await.map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?.total;
 
     let total_paid_expenses = sqlx::query!(
         r#"SELECT COALESCE(SUM(amount), 0) as "total!" FROM expense_voucher
            WHERE parish_id = $1 AND expense_date <= $2 AND paid = TRUE AND deleted_at IS NULL"#,
-        parish_id, query.end_date
-    ).fetch_one(&state.db).await.map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?.total;
+        parish_id, end_date
+    ).fetch_one(db).await.map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?.total;
 
     let cash_on_hand = total_income - total_paid_expenses;
 
@@ -243,12 +298,21 @@ pub async fn get_balance_sheet(
         r#"SELECT COALESCE(SUM(amount), 0) as "total!" FROM expense_voucher
            WHERE parish_id = $1 AND approval_status = 'APPROVED' AND paid = FALSE AND deleted_at IS NULL"#,
         parish_id
-    ).fetch_one(&state.db).await.map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?.total;
+    ).fetch_one(db).await.map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?.total;
+
+    let mut asset_entries = vec![BalanceSheetEntry { name: "Cash on Hand".to_string(), amount: cash_on_hand }];
+    asset_entries.extend(
+        asset::book_values_by_category(db, parish_id, end_date)
+            .await?
+            .into_iter()
+            .map(|(category, amount)| BalanceSheetEntry { name: category.label().to_string(), amount }),
+    );
+    let total_assets = asset_entries.iter().map(|e| e.amount).sum();
 
     let assets = BalanceSheetSection {
         section_name: "Assets".to_string(),
-        entries: vec![BalanceSheetEntry { name: "Cash on Hand".to_string(), amount: cash_on_hand }],
-        total: cash_on_hand,
+        entries: asset_entries,
+        total: total_assets,
     };
 
     let liabilities = BalanceSheetSection {
@@ -257,34 +321,47 @@ pub async fn get_balance_sheet(
         total: unpaid_expenses,
     };
 
-    let equity_amount = cash_on_hand - unpaid_expenses;
+    let equity_amount = total_assets - unpaid_expenses;
     let equity = BalanceSheetSection {
         section_name: "Equity".to_string(),
         entries: vec![BalanceSheetEntry { name: "Net Assets".to_string(), amount: equity_amount }],
         total: equity_amount,
     };
 
-    Ok(Json(BalanceSheet { assets, liabilities, equity }))
+    let header = ReportHeader::parish(parish_id, "Balance Sheet", Some(format!("As at {}", end_date.format("%d %b %Y"))));
+
+    Ok((BalanceSheet { assets, liabilities, equity }, header))
 }
 
 pub async fn get_cash_flow(
     auth: AuthUser,
     State(state): State<AppState>,
     Query(query): Query<ReportQuery>,
-) -> Result<Json<CashFlowStatement>, (StatusCode, String)> {
+    format: ExportFormat,
+) -> Result<Response, (StatusCode, String)> {
     let parish_id = rbac::resolve_parish_id(&auth, Some(query.parish_id))?;
+    let (report, header) = cash_flow(&state.db, parish_id, query.start_date, query.end_date).await?;
 
+    export::respond(&state.db, &auth, format, header, report).await
+}
+
+pub async fn cash_flow(
+    db: &PgPool,
+    parish_id: Uuid,
+    start_date: NaiveDate,
+    end_date: NaiveDate,
+) -> Result<(CashFlowStatement, ReportHeader), (StatusCode, String)> {
     let income_sum = sqlx::query!(
         r#"SELECT COALESCE(SUM(amount), 0) as "total!" FROM income_transaction 
            WHERE parish_id = $1 AND transaction_date BETWEEN $2 AND $3 AND deleted_at IS NULL"#,
-        parish_id, query.start_date, query.end_date
-    ).fetch_one(&state.db).await.map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?.total;
+        parish_id, start_date, end_date
+    ).fetch_one(db).await.map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?.total;
 
     let expense_sum = sqlx::query!(
         r#"SELECT COALESCE(SUM(amount), 0) as "total!" FROM expense_voucher
            WHERE parish_id = $1 AND expense_date BETWEEN $2 AND $3 AND paid = TRUE AND deleted_at IS NULL"#,
-        parish_id, query.start_date, query.end_date
-    ).fetch_one(&state.db).await.map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?.total;
+        parish_id, start_date, end_date
+    ).fetch_one(db).await.map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?.total;
 
     let operating = CashFlowSection {
         section_name: "Operating Activities".to_string(),
@@ -295,10 +372,854 @@ pub async fn get_cash_flow(
         total: income_sum - expense_sum,
     };
 
-    Ok(Json(CashFlowStatement {
+    let header = ReportHeader::parish(parish_id, "Cash Flow Statement", Some(export::period_label(start_date, end_date)));
+
+    Ok((CashFlowStatement {
         sections: vec![operating],
         net_cash_flow: income_sum - expense_sum,
         opening_balance: Decimal::ZERO, // Need proper accounting period close logic for this
         closing_balance: income_sum - expense_sum,
-    }))
+    }, header))
+}
+
+pub async fn get_fund_balances(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Query(query): Query<ReportQuery>,
+    format: ExportFormat,
+) -> Result<Response, (StatusCode, String)> {
+    let parish_id = rbac::resolve_parish_id(&auth, Some(query.parish_id))?;
+    let (report, header) = fund_balances(&state.db, parish_id, query.start_date, query.end_date).await?;
+
+    export::respond(&state.db, &auth, format, header, report).await
+}
+
+pub async fn fund_balances(
+    db: &PgPool,
+    parish_id: Uuid,
+    start_date: NaiveDate,
+    end_date: NaiveDate,
+) -> Result<(FundBalanceReport, ReportHeader), (StatusCode, String)> {
+    let funds = sqlx::query!(
+        r#"
+        SELECT
+            f.id, f.fund_code, f.fund_name, f.fund_type::text as "fund_type!", f.opening_balance,
+            (SELECT COALESCE(SUM(amount), 0) FROM income_transaction
+             WHERE fund_id = f.id AND transaction_date < $2 AND deleted_at IS NULL) as "income_before!",
+            (SELECT COALESCE(SUM(amount), 0) FROM expense_voucher
+             WHERE fund_id = f.id AND expense_date < $2 AND deleted_at IS NULL AND approval_status = 'APPROVED') as "expenditure_before!",
+            (SELECT COALESCE(SUM(amount), 0) FROM income_transaction
+             WHERE fund_id = f.id AND transaction_date BETWEEN $2 AND $3 AND deleted_at IS NULL) as "income!",
+            (SELECT COALESCE(SUM(amount), 0) FROM expense_voucher
+             WHERE fund_id = f.id AND expense_date BETWEEN $2 AND $3 AND deleted_at IS NULL AND approval_status = 'APPROVED') as "expenditure!",
+            (SELECT COALESCE(SUM(amount), 0) FROM expense_voucher
+             WHERE fund_id = f.id AND expense_date <= $3 AND deleted_at IS NULL AND approval_status = 'PENDING') as "pending!"
+        FROM fund f
+        WHERE f.parish_id = $1 AND f.deleted_at IS NULL
+        ORDER BY f.fund_type, f.fund_name
+        "#,
+        parish_id, start_date, end_date
+    )
+    .fetch_all(db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    // Income and expenses not tagged to any fund form the general unrestricted pool
+    let general = sqlx::query!(
+        r#"
+        SELECT
+            (SELECT COALESCE(SUM(amount), 0) FROM income_transaction
+             WHERE parish_id = $1 AND fund_id IS NULL AND transaction_date < $2 AND deleted_at IS NULL) as "income_before!",
+            (SELECT COALESCE(SUM(amount), 0) FROM expense_voucher
+             WHERE parish_id = $1 AND fund_id IS NULL AND expense_date < $2 AND deleted_at IS NULL AND approval_status = 'APPROVED') as "expenditure_before!",
+            (SELECT COALESCE(SUM(amount), 0) FROM income_transaction
+             WHERE parish_id = $1 AND fund_id IS NULL AND transaction_date BETWEEN $2 AND $3 AND deleted_at IS NULL) as "income!",
+            (SELECT COALESCE(SUM(amount), 0) FROM expense_voucher
+             WHERE parish_id = $1 AND fund_id IS NULL AND expense_date BETWEEN $2 AND $3 AND deleted_at IS NULL AND approval_status = 'APPROVED') as "expenditure!",
+            (SELECT COALESCE(SUM(amount), 0) FROM expense_voucher
+             WHERE parish_id = $1 AND fund_id IS NULL AND expense_date <= $3 AND deleted_at IS NULL AND approval_status = 'PENDING') as "pending!"
+        "#,
+        parish_id, start_date, end_date
+    )
+    .fetch_one(db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    let mut entries = Vec::new();
+    let mut total_restricted = Decimal::ZERO;
+    let mut total_unrestricted = Decimal::ZERO;
+
+    for f in funds {
+        let opening_balance = f.opening_balance + f.income_before - f.expenditure_before;
+        let closing_balance = opening_balance + f.income - f.expenditure;
+        if f.fund_type == "RESTRICTED" {
+            total_restricted += closing_balance;
+        } else {
+            total_unrestricted += closing_balance;
+        }
+        entries.push(FundBalanceEntry {
+            fund_id: Some(f.id),
+            fund_code: f.fund_code,
+            fund_name: f.fund_name,
+            fund_type: f.fund_type,
+            opening_balance,
+            income: f.income,
+            expenditure: f.expenditure,
+            closing_balance,
+            pending_commitments: f.pending,
+        });
+    }
+
+    let opening_balance = general.income_before - general.expenditure_before;
+    let closing_balance = opening_balance + general.income - general.expenditure;
+    total_unrestricted += closing_balance;
+    entries.push(FundBalanceEntry {
+        fund_id: None,
+        fund_code: "GENERAL".to_string(),
+        fund_name: "General Fund".to_string(),
+        fund_type: "UNRESTRICTED".to_string(),
+        opening_balance,
+        income: general.income,
+        expenditure: general.expenditure,
+        closing_balance,
+        pending_commitments: general.pending,
+    });
+
+    let header = ReportHeader::parish(parish_id, "Fund Balances", Some(export::period_label(start_date, end_date)));
+
+    Ok((FundBalanceReport {
+        entries,
+        total_restricted,
+        total_unrestricted,
+        total_closing_balance: total_restricted + total_unrestricted,
+    }, header))
+}
+
+// ============================================================================
+// Diocese-wide consolidated reports
+// ============================================================================
+
+#[derive(Debug, Deserialize)]
+pub struct ConsolidatedReportQuery {
+    pub diocese_id: Option<Uuid>,
+    pub parish_ids: Option<String>, // Comma-separated; all parishes of the diocese when omitted
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+}
+
+/// Per-parish columns of a consolidated report.
+struct Columns {
+    diocese_id: Uuid,
+    parishes: Vec<ConsolidatedParish>,
+    index: HashMap<Uuid, usize>,
+}
+
+impl Columns {
+    fn ids(&self) -> Vec<Uuid> {
+        self.parishes.iter().map(|p| p.parish_id).collect()
+    }
+
+    fn zeros(&self) -> Vec<Decimal> {
+        vec![Decimal::ZERO; self.parishes.len()]
+    }
+
+    /// Builds a section with one line per label, columns in parish order.
+    fn section(&self, name: &str, rows: impl IntoIterator<Item = (Uuid, String, Decimal)>) -> ConsolidatedSection {
+        let mut by_label: BTreeMap<String, Vec<Decimal>> = BTreeMap::new();
+        for (parish_id, label, amount) in rows {
+            if let Some(&i) = self.index.get(&parish_id) {
+                by_label.entry(label).or_insert_with(|| self.zeros())[i] += amount;
+            }
+        }
+        self.section_from(name, by_label)
+    }
+
+    fn section_from(&self, name: &str, by_label: BTreeMap<String, Vec<Decimal>>) -> ConsolidatedSection {
+        let mut sums = self.zeros();
+        let lines: Vec<ConsolidatedLine> = by_label
+            .into_iter()
+            .map(|(label, amounts)| {
+                for (sum, amount) in sums.iter_mut().zip(&amounts) {
+                    *sum += amount;
+                }
+                consolidated_line(label, amounts)
+            })
+            .collect();
+
+        ConsolidatedSection {
+            section_name: name.to_string(),
+            lines,
+            totals: consolidated_line(format!("Total {}", name), sums),
+        }
+    }
+
+    fn statement(
+        self,
+        report: &str,
+        query: &ConsolidatedReportQuery,
+        sections: Vec<ConsolidatedSection>,
+        net: Option<ConsolidatedLine>,
+    ) -> ConsolidatedStatement {
+        ConsolidatedStatement {
+            report: report.to_string(),
+            diocese_id: self.diocese_id,
+            start_date: query.start_date,
+            end_date: query.end_date,
+            parishes: self.parishes,
+            sections,
+            net,
+        }
+    }
+}
+
+fn consolidated_line(label: String, by_parish: Vec<Decimal>) -> ConsolidatedLine {
+    let total = by_parish.iter().sum();
+    ConsolidatedLine { label, by_parish, total }
+}
+
+/// Column-wise `a - b`, e.g. income totals less expenditure totals.
+fn difference_line(label: &str, a: &ConsolidatedLine, b: &ConsolidatedLine) -> ConsolidatedLine {
+    let by_parish = a.by_parish.iter().zip(&b.by_parish).map(|(x, y)| x - y).collect();
+    consolidated_line(label.to_string(), by_parish)
+}
+
+/// Resolves the diocese and the parishes to consolidate. Only diocesan
+/// roles may consolidate, and a chosen set must lie within the diocese.
+async fn consolidation_columns(
+    db: &PgPool,
+    auth: &AuthUser,
+    query: &ConsolidatedReportQuery,
+) -> Result<Columns, (StatusCode, String)> {
+    let diocese_id = rbac::resolve_diocese_id(db, auth, query.diocese_id).await?;
+
+    let selected: Option<Vec<Uuid>> = match query.parish_ids.as_deref().map(str::trim) {
+        None | Some("") => None,
+        Some(ids) => Some(
+            ids.split(',')
+                .map(|id| id.trim().parse::<Uuid>())
+                .collect::<Result<_, _>>()
+                .map_err(|_| (StatusCode::BAD_REQUEST, "parish_ids must be a comma-separated list of UUIDs".to_string()))?,
+        ),
+    };
+
+    let parishes = sqlx::query_as::<_, ConsolidatedParish>(
+        r#"
+        SELECT id AS parish_id, parish_code, parish_name FROM parish
+        WHERE diocese_id = $1 AND deleted_at IS NULL AND ($2::uuid[] IS NULL OR id = ANY($2))
+        ORDER BY parish_name
+        "#
+    )
+    .bind(diocese_id)
+    .bind(&selected)
+    .fetch_all(db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    if let Some(selected) = &selected {
+        let mut unique = selected.clone();
+        unique.sort();
+        unique.dedup();
+        if unique.len() != parishes.len() {
+            return Err((StatusCode::BAD_REQUEST, "Some of the selected parishes are not in this diocese".to_string()));
+        }
+    }
+
+    let index = parishes.iter().enumerate().map(|(i, p)| (p.parish_id, i)).collect();
+    Ok(Columns { diocese_id, parishes, index })
+}
+
+pub async fn get_consolidated_trial_balance(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Query(query): Query<ConsolidatedReportQuery>,
+    format: ExportFormat,
+) -> Result<Response, (StatusCode, String)> {
+    let columns = consolidation_columns(&state.db, &auth, &query).await?;
+    let ids = columns.ids();
+
+    let income = sqlx::query!(
+        r#"
+        SELECT parish_id, category as "category: String", SUM(amount) as "total!"
+        FROM income_transaction
+        WHERE parish_id = ANY($1) AND transaction_date BETWEEN $2 AND $3 AND deleted_at IS NULL
+        GROUP BY parish_id, category
+        "#,
+        &ids, query.start_date, query.end_date
+    )
+    .fetch_all(&state.db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    let expenses = sqlx::query!(
+        r#"
+        SELECT parish_id, category as "category: String", SUM(amount) as "total!"
+        FROM expense_voucher
+        WHERE parish_id = ANY($1) AND expense_date BETWEEN $2 AND $3 AND deleted_at IS NULL AND approval_status = 'APPROVED'
+        GROUP BY parish_id, category
+        "#,
+        &ids, query.start_date, query.end_date
+    )
+    .fetch_all(&state.db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    let debit = columns.section("Debit", expenses.into_iter().map(|r| (r.parish_id, r.category, r.total)));
+    let credit = columns.section("Credit", income.into_iter().map(|r| (r.parish_id, r.category, r.total)));
+
+    let header = ReportHeader::diocese(columns.diocese_id, "Consolidated Trial Balance", Some(export::period_label(query.start_date, query.end_date)));
+    let report = columns.statement("TRIAL_BALANCE", &query, vec![debit, credit], None);
+
+    export::respond(&state.db, &auth, format, header, report).await
+}
+
+pub async fn get_consolidated_income_expenditure(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Query(query): Query<ConsolidatedReportQuery>,
+    format: ExportFormat,
+) -> Result<Response, (StatusCode, String)> {
+    let columns = consolidation_columns(&state.db, &auth, &query).await?;
+    let ids = columns.ids();
+
+    let income = sqlx::query!(
+        r#"
+        SELECT parish_id, category as "category: String", SUM(amount) as "total!"
+        FROM income_transaction
+        WHERE parish_id = ANY($1) AND transaction_date BETWEEN $2 AND $3 AND deleted_at IS NULL
+        GROUP BY parish_id, category
+        "#,
+        &ids, query.start_date, query.end_date
+    )
+    .fetch_all(&state.db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    let expenses = sqlx::query!(
+        r#"
+        SELECT parish_id, category as "category: String", SUM(amount) as "total!"
+        FROM expense_voucher
+        WHERE parish_id = ANY($1) AND expense_date BETWEEN $2 AND $3 AND deleted_at IS NULL AND approval_status = 'APPROVED'
+        GROUP BY parish_id, category
+        "#,
+        &ids, query.start_date, query.end_date
+    )
+    .fetch_all(&state.db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    let income = columns.section("Income", income.into_iter().map(|r| (r.parish_id, r.category, r.total)));
+    let expenditure = columns.section("Expenditure", expenses.into_iter().map(|r| (r.parish_id, r.category, r.total)));
+    let net = difference_line("Net Surplus/(Deficit)", &income.totals, &expenditure.totals);
+
+    let header = ReportHeader::diocese(columns.diocese_id, "Consolidated Income and Expenditure", Some(export::period_label(query.start_date, query.end_date)));
+    let report = columns.statement("INCOME_EXPENDITURE", &query, vec![income, expenditure], Some(net));
+
+    export::respond(&state.db, &auth, format, header, report).await
+}
+
+pub async fn get_consolidated_budget_vs_actual(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Query(query): Query<ConsolidatedReportQuery>,
+    format: ExportFormat,
+) -> Result<Response, (StatusCode, String)> {
+    let columns = consolidation_columns(&state.db, &auth, &query).await?;
+    let ids = columns.ids();
+
+    let year = query.start_date.year();
+    let budgets = sqlx::query!(
+        r#"
+        SELECT parish_id, category as "category: String", SUM(amount) as "total!"
+        FROM budget
+        WHERE parish_id = ANY($1) AND fiscal_year = $2 AND deleted_at IS NULL
+          AND version_id IN (SELECT id FROM budget_version WHERE status = 'APPROVED' AND deleted_at IS NULL)
+        GROUP BY parish_id, category
+        "#,
+        &ids, year
+    )
+    .fetch_all(&state.db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    let income = sqlx::query!(
+        r#"SELECT parish_id, category as "category: String", SUM(amount) as "total!"
+           FROM income_transaction
+           WHERE parish_id = ANY($1) AND transaction_date BETWEEN $2 AND $3 AND deleted_at IS NULL
+           GROUP BY parish_id, category"#,
+        &ids, query.start_date, query.end_date
+    ).fetch_all(&state.db).await.map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    let expenses = sqlx::query!(
+        r#"SELECT parish_id, category as "category: String", SUM(amount) as "total!"
+           FROM expense_voucher
+           WHERE parish_id = ANY($1) AND expense_date BETWEEN $2 AND $3 AND deleted_at IS NULL AND approval_status = 'APPROVED'
+           GROUP BY parish_id, category"#,
+        &ids, query.start_date, query.end_date
+    ).fetch_all(&state.db).await.map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    let budget = columns.section("Budget", budgets.into_iter().map(|r| (r.parish_id, r.category, r.total)));
+    let actual = columns.section(
+        "Actual",
+        income.into_iter().map(|r| (r.parish_id, r.category, r.total))
+            .chain(expenses.into_iter().map(|r| (r.parish_id, r.category, r.total))),
+    );
+
+    // Variance per category, same sign convention as the single-parish report
+    let mut variance: BTreeMap<String, Vec<Decimal>> = BTreeMap::new();
+    for line in &budget.lines {
+        let v = variance.entry(line.label.clone()).or_insert_with(|| columns.zeros());
+        for (v, b) in v.iter_mut().zip(&line.by_parish) { *v += b; }
+    }
+    for line in &actual.lines {
+        let v = variance.entry(line.label.clone()).or_insert_with(|| columns.zeros());
+        for (v, a) in v.iter_mut().zip(&line.by_parish) { *v -= a; }
+    }
+    let variance = columns.section_from("Variance", variance);
+
+    let header = ReportHeader::diocese(columns.diocese_id, "Consolidated Budget vs Actual", Some(export::period_label(query.start_date, query.end_date)));
+    let report = columns.statement("BUDGET_VS_ACTUAL", &query, vec![budget, actual, variance], None);
+
+    export::respond(&state.db, &auth, format, header, report).await
+}
+
+pub async fn get_consolidated_cash_flow(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Query(query): Query<ConsolidatedReportQuery>,
+    format: ExportFormat,
+) -> Result<Response, (StatusCode, String)> {
+    let columns = consolidation_columns(&state.db, &auth, &query).await?;
+    let ids = columns.ids();
+
+    let income = sqlx::query!(
+        r#"SELECT parish_id, SUM(amount) as "total!" FROM income_transaction
+           WHERE parish_id = ANY($1) AND transaction_date BETWEEN $2 AND $3 AND deleted_at IS NULL
+           GROUP BY parish_id"#,
+        &ids, query.start_date, query.end_date
+    ).fetch_all(&state.db).await.map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    let expenses = sqlx::query!(
+        r#"SELECT parish_id, SUM(amount) as "total!" FROM expense_voucher
+           WHERE parish_id = ANY($1) AND expense_date BETWEEN $2 AND $3 AND paid = TRUE AND deleted_at IS NULL
+           GROUP BY parish_id"#,
+        &ids, query.start_date, query.end_date
+    ).fetch_all(&state.db).await.map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    let operating = columns.section(
+        "Operating Activities",
+        income.into_iter().map(|r| (r.parish_id, "Cash Receipts from Donors".to_string(), r.total))
+            .chain(expenses.into_iter().map(|r| (r.parish_id, "Cash Paid for Expenses".to_string(), -r.total))),
+    );
+    let net = consolidated_line("Net Cash Flow".to_string(), operating.totals.by_parish.clone());
+
+    let header = ReportHeader::diocese(columns.diocese_id, "Consolidated Cash Flow", Some(export::period_label(query.start_date, query.end_date)));
+    let report = columns.statement("CASH_FLOW", &query, vec![operating], Some(net));
+
+    export::respond(&state.db, &auth, format, header, report).await
+}
+
+// ============================================================================
+// Comparative reports
+// ============================================================================
+
+type CategoryTotals = Vec<(String, Decimal)>;
+type MonthlyTotals = Vec<(String, u32, Decimal)>;
+
+/// Same date one year earlier (29 February falls back to the 28th).
+fn previous_year(date: NaiveDate) -> NaiveDate {
+    date.checked_sub_months(Months::new(12)).unwrap_or(date)
+}
+
+fn change_percent(current: Decimal, previous: Decimal) -> Option<Decimal> {
+    if previous.is_zero() {
+        None
+    } else {
+        Some(((current - previous) / previous.abs() * Decimal::ONE_HUNDRED).round_dp(2))
+    }
+}
+
+fn comparative_entry(category: String, current: Decimal, previous: Decimal) -> ComparativeEntry {
+    ComparativeEntry {
+        category,
+        current,
+        previous,
+        change: current - previous,
+        change_percent: change_percent(current, previous),
+    }
+}
+
+fn comparative_section(name: &str, current: CategoryTotals, previous: CategoryTotals) -> ComparativeSection {
+    let mut map: BTreeMap<String, (Decimal, Decimal)> = BTreeMap::new();
+    for (category, amount) in current {
+        map.entry(category).or_default().0 += amount;
+    }
+    for (category, amount) in previous {
+        map.entry(category).or_default().1 += amount;
+    }
+
+    let (total_current, total_previous) = map.values()
+        .fold((Decimal::ZERO, Decimal::ZERO), |(c, p), (cur, prev)| (c + cur, p + prev));
+    let entries = map.into_iter()
+        .map(|(category, (cur, prev))| comparative_entry(category, cur, prev))
+        .collect();
+
+    ComparativeSection {
+        section_name: name.to_string(),
+        entries,
+        totals: comparative_entry(format!("Total {}", name), total_current, total_previous),
+    }
+}
+
+fn negated(totals: &CategoryTotals) -> CategoryTotals {
+    totals.iter().map(|(c, a)| (c.clone(), -a)).collect()
+}
+
+async fn income_by_category(db: &PgPool, parish_id: Uuid, start: NaiveDate, end: NaiveDate) -> Result<CategoryTotals, (StatusCode, String)> {
+    let rows = sqlx::query!(
+        r#"SELECT category as "category: String", SUM(amount) as "total!"
+           FROM income_transaction
+           WHERE parish_id = $1 AND transaction_date BETWEEN $2 AND $3 AND deleted_at IS NULL
+           GROUP BY category"#,
+        parish_id, start, end
+    ).fetch_all(db).await.map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(rows.into_iter().map(|r| (r.category, r.total)).collect())
+}
+
+async fn expense_by_category(db: &PgPool, parish_id: Uuid, start: NaiveDate, end: NaiveDate) -> Result<CategoryTotals, (StatusCode, String)> {
+    let rows = sqlx::query!(
+        r#"SELECT category as "category: String", SUM(amount) as "total!"
+           FROM expense_voucher
+           WHERE parish_id = $1 AND expense_date BETWEEN $2 AND $3 AND deleted_at IS NULL AND approval_status = 'APPROVED'
+           GROUP BY category"#,
+        parish_id, start, end
+    ).fetch_all(db).await.map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(rows.into_iter().map(|r| (r.category, r.total)).collect())
+}
+
+async fn budget_by_category(db: &PgPool, parish_id: Uuid, year: i32) -> Result<CategoryTotals, (StatusCode, String)> {
+    let rows = sqlx::query!(
+        r#"SELECT category as "category: String", SUM(amount) as "total!"
+           FROM budget
+           WHERE parish_id = $1 AND fiscal_year = $2 AND deleted_at IS NULL
+             AND version_id IN (SELECT id FROM budget_version WHERE status = 'APPROVED' AND deleted_at IS NULL)
+           GROUP BY category"#,
+        parish_id, year
+    ).fetch_all(db).await.map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(rows.into_iter().map(|r| (r.category, r.total)).collect())
+}
+
+pub async fn get_comparative_income_expenditure(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Query(query): Query<ReportQuery>,
+    format: ExportFormat,
+) -> Result<Response, (StatusCode, String)> {
+    let parish_id = rbac::resolve_parish_id(&auth, Some(query.parish_id))?;
+    let (report, header) = comparative_income_expenditure(&state.db, parish_id, query.start_date, query.end_date).await?;
+
+    export::respond(&state.db, &auth, format, header, report).await
+}
+
+pub async fn comparative_income_expenditure(
+    db: &PgPool,
+    parish_id: Uuid,
+    start_date: NaiveDate,
+    end_date: NaiveDate,
+) -> Result<(ComparativeReport, ReportHeader), (StatusCode, String)> {
+    let previous_start = previous_year(start_date);
+    let previous_end = previous_year(end_date);
+
+    let income = comparative_section(
+        "Income",
+        income_by_category(db, parish_id, start_date, end_date).await?,
+        income_by_category(db, parish_id, previous_start, previous_end).await?,
+    );
+    let expenditure = comparative_section(
+        "Expenditure",
+        expense_by_category(db, parish_id, start_date, end_date).await?,
+        expense_by_category(db, parish_id, previous_start, previous_end).await?,
+    );
+    let net = comparative_entry(
+        "Net Surplus/(Deficit)".to_string(),
+        income.totals.current - expenditure.totals.current,
+        income.totals.previous - expenditure.totals.previous,
+    );
+
+    let header = ReportHeader::parish(parish_id, "Comparative Income and Expenditure", Some(format!("{} compared with {}", export::period_label(start_date, end_date), export::period_label(previous_start, previous_end))));
+
+    Ok((ComparativeReport {
+        report: "INCOME_EXPENDITURE".to_string(),
+        start_date,
+        end_date,
+        previous_start_date: previous_start,
+        previous_end_date: previous_end,
+        sections: vec![income, expenditure],
+        net: Some(net),
+    }, header))
+}
+
+pub async fn get_comparative_budget_vs_actual(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Query(query): Query<ReportQuery>,
+    format: ExportFormat,
+) -> Result<Response, (StatusCode, String)> {
+    let parish_id = rbac::resolve_parish_id(&auth, Some(query.parish_id))?;
+    let (report, header) = comparative_budget_vs_actual(&state.db, parish_id, query.start_date, query.end_date).await?;
+
+    export::respond(&state.db, &auth, format, header, report).await
+}
+
+pub async fn comparative_budget_vs_actual(
+    db: &PgPool,
+    parish_id: Uuid,
+    start_date: NaiveDate,
+    end_date: NaiveDate,
+) -> Result<(ComparativeReport, ReportHeader), (StatusCode, String)> {
+    let previous_start = previous_year(start_date);
+    let previous_end = previous_year(end_date);
+
+    let year = start_date.year();
+    let budget = budget_by_category(db, parish_id, year).await?;
+    let previous_budget = budget_by_category(db, parish_id, year - 1).await?;
+
+    let mut actual = income_by_category(db, parish_id, start_date, end_date).await?;
+    actual.extend(expense_by_category(db, parish_id, start_date, end_date).await?);
+    let mut previous_actual = income_by_category(db, parish_id, previous_start, previous_end).await?;
+    previous_actual.extend(expense_by_category(db, parish_id, previous_start, previous_end).await?);
+
+    // Variance = budget - actual, as in the single-period report
+    let variance = comparative_section(
+        "Variance",
+        budget.iter().cloned().chain(negated(&actual)).collect(),
+        previous_budget.iter().cloned().chain(negated(&previous_actual)).collect(),
+    );
+
+    let header = ReportHeader::parish(parish_id, "Comparative Budget vs Actual", Some(format!("{} compared with {}", export::period_label(start_date, end_date), export::period_label(previous_start, previous_end))));
+
+    Ok((ComparativeReport {
+        report: "BUDGET_VS_ACTUAL".to_string(),
+        start_date,
+        end_date,
+        previous_start_date: previous_start,
+        previous_end_date: previous_end,
+        sections: vec![
+            comparative_section("Budget", budget, previous_budget),
+            comparative_section("Actual", actual, previous_actual),
+            variance,
+        ],
+        net: None,
+    }, header))
+}
+
+// ============================================================================
+// Month-by-month reports for a fiscal year
+// ============================================================================
+
+#[derive(Debug, Deserialize)]
+pub struct MonthlyReportQuery {
+    pub parish_id: Uuid,
+    pub fiscal_year: Option<i32>,
+}
+
+fn fiscal_year_bounds(year: i32) -> Result<(NaiveDate, NaiveDate), (StatusCode, String)> {
+    NaiveDate::from_ymd_opt(year, 1, 1)
+        .zip(NaiveDate::from_ymd_opt(year, 12, 31))
+        .ok_or((StatusCode::BAD_REQUEST, "Invalid fiscal_year".to_string()))
+}
+
+fn monthly_line(category: String, by_month: Vec<Decimal>, previous_year_total: Decimal) -> MonthlyLine {
+    let total = by_month.iter().sum();
+    MonthlyLine {
+        category,
+        by_month,
+        total,
+        previous_year_total,
+        change_percent: change_percent(total, previous_year_total),
+    }
+}
+
+fn monthly_section(name: &str, rows: MonthlyTotals, previous: CategoryTotals) -> MonthlySection {
+    let mut map: BTreeMap<String, (Vec<Decimal>, Decimal)> = BTreeMap::new();
+    for (category, month, amount) in rows {
+        map.entry(category).or_insert_with(|| (vec![Decimal::ZERO; 12], Decimal::ZERO)).0[month as usize - 1] += amount;
+    }
+    for (category, amount) in previous {
+        map.entry(category).or_insert_with(|| (vec![Decimal::ZERO; 12], Decimal::ZERO)).1 += amount;
+    }
+
+    let mut sums = vec![Decimal::ZERO; 12];
+    let mut previous_sum = Decimal::ZERO;
+    let lines = map.into_iter()
+        .map(|(category, (by_month, prev))| {
+            for (sum, amount) in sums.iter_mut().zip(&by_month) {
+                *sum += amount;
+            }
+            previous_sum += prev;
+            monthly_line(category, by_month, prev)
+        })
+        .collect();
+
+    MonthlySection {
+        section_name: name.to_string(),
+        lines,
+        totals: monthly_line(format!("Total {}", name), sums, previous_sum),
+    }
+}
+
+fn month_labels(year: i32) -> Vec<String> {
+    (1..=12).map(|m| format!("{}-{:02}", year, m)).collect()
+}
+
+async fn monthly_income(db: &PgPool, parish_id: Uuid, start: NaiveDate, end: NaiveDate) -> Result<MonthlyTotals, (StatusCode, String)> {
+    let rows = sqlx::query!(
+        r#"SELECT category as "category: String", EXTRACT(MONTH FROM transaction_date)::int as "month!", SUM(amount) as "total!"
+           FROM income_transaction
+           WHERE parish_id = $1 AND transaction_date BETWEEN $2 AND $3 AND deleted_at IS NULL
+           GROUP BY 1, 2"#,
+        parish_id, start, end
+    ).fetch_all(db).await.map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(rows.into_iter().map(|r| (r.category, r.month as u32, r.total)).collect())
+}
+
+async fn monthly_expenses(db: &PgPool, parish_id: Uuid, start: NaiveDate, end: NaiveDate) -> Result<MonthlyTotals, (StatusCode, String)> {
+    let rows = sqlx::query!(
+        r#"SELECT category as "category: String", EXTRACT(MONTH FROM expense_date)::int as "month!", SUM(amount) as "total!"
+           FROM expense_voucher
+           WHERE parish_id = $1 AND expense_date BETWEEN $2 AND $3 AND deleted_at IS NULL AND approval_status = 'APPROVED'
+           GROUP BY 1, 2"#,
+        parish_id, start, end
+    ).fetch_all(db).await.map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(rows.into_iter().map(|r| (r.category, r.month as u32, r.total)).collect())
+}
+
+/// Budget per month. Annual budget lines (no fiscal_month) are spread evenly,
+/// with the rounding difference landing in December.
+async fn monthly_budget(db: &PgPool, parish_id: Uuid, year: i32) -> Result<MonthlyTotals, (StatusCode, String)> {
+    let rows = sqlx::query!(
+        r#"SELECT category as "category: String", fiscal_month, SUM(amount) as "total!"
+           FROM budget
+           WHERE parish_id = $1 AND fiscal_year = $2 AND deleted_at IS NULL
+             AND version_id IN (SELECT id FROM budget_version WHERE status = 'APPROVED' AND deleted_at IS NULL)
+           GROUP BY category, fiscal_month"#,
+        parish_id, year
+    ).fetch_all(db).await.map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    let mut out = Vec::new();
+    for r in rows {
+        match r.fiscal_month {
+            Some(month @ 1..=12) => out.push((r.category, month as u32, r.total)),
+            _ => {
+                let share = (r.total / Decimal::from(12)).round_dp(2);
+                for month in 1..=11 {
+                    out.push((r.category.clone(), month, share));
+                }
+                out.push((r.category, 12, r.total - share * Decimal::from(11)));
+            }
+        }
+    }
+    Ok(out)
+}
+
+pub async fn get_monthly_income_expenditure(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Query(query): Query<MonthlyReportQuery>,
+    format: ExportFormat,
+) -> Result<Response, (StatusCode, String)> {
+    let parish_id = rbac::resolve_parish_id(&auth, Some(query.parish_id))?;
+    let year = query.fiscal_year.unwrap_or_else(|| Utc::now().year());
+    let (report, header) = monthly_income_expenditure(&state.db, parish_id, year).await?;
+
+    export::respond(&state.db, &auth, format, header, report).await
+}
+
+pub async fn monthly_income_expenditure(
+    db: &PgPool,
+    parish_id: Uuid,
+    year: i32,
+) -> Result<(MonthlyReport, ReportHeader), (StatusCode, String)> {
+    let (start, end) = fiscal_year_bounds(year)?;
+    let (previous_start, previous_end) = fiscal_year_bounds(year - 1)?;
+
+    let income = monthly_section(
+        "Income",
+        monthly_income(db, parish_id, start, end).await?,
+        income_by_category(db, parish_id, previous_start, previous_end).await?,
+    );
+    let expenditure = monthly_section(
+        "Expenditure",
+        monthly_expenses(db, parish_id, start, end).await?,
+        expense_by_category(db, parish_id, previous_start, previous_end).await?,
+    );
+    let net = monthly_line(
+        "Net Surplus/(Deficit)".to_string(),
+        income.totals.by_month.iter().zip(&expenditure.totals.by_month).map(|(i, e)| i - e).collect(),
+        income.totals.previous_year_total - expenditure.totals.previous_year_total,
+    );
+
+    let header = ReportHeader::parish(parish_id, "Monthly Income and Expenditure", Some(format!("Fiscal year {}", year)));
+
+    Ok((MonthlyReport {
+        report: "INCOME_EXPENDITURE".to_string(),
+        fiscal_year: year,
+        months: month_labels(year),
+        sections: vec![income, expenditure],
+        net: Some(net),
+    }, header))
+}
+
+pub async fn get_monthly_budget_vs_actual(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Query(query): Query<MonthlyReportQuery>,
+    format: ExportFormat,
+) -> Result<Response, (StatusCode, String)> {
+    let parish_id = rbac::resolve_parish_id(&auth, Some(query.parish_id))?;
+    let year = query.fiscal_year.unwrap_or_else(|| Utc::now().year());
+    let (report, header) = monthly_budget_vs_actual(&state.db, parish_id, year).await?;
+
+    export::respond(&state.db, &auth, format, header, report).await
+}
+
+pub async fn monthly_budget_vs_actual(
+    db: &PgPool,
+    parish_id: Uuid,
+    year: i32,
+) -> Result<(MonthlyReport, ReportHeader), (StatusCode, String)> {
+    let (start, end) = fiscal_year_bounds(year)?;
+    let (previous_start, previous_end) = fiscal_year_bounds(year - 1)?;
+
+    let budget = monthly_budget(db, parish_id, year).await?;
+    let previous_budget = budget_by_category(db, parish_id, year - 1).await?;
+
+    let mut actual = monthly_income(db, parish_id, start, end).await?;
+    actual.extend(monthly_expenses(db, parish_id, start, end).await?);
+    let mut previous_actual = income_by_category(db, parish_id, previous_start, previous_end).await?;
+    previous_actual.extend(expense_by_category(db, parish_id, previous_start, previous_end).await?);
+
+    let variance = monthly_section(
+        "Variance",
+        budget.iter().cloned().chain(actual.iter().map(|(c, m, a)| (c.clone(), *m, -a))).collect(),
+        previous_budget.iter().cloned().chain(negated(&previous_actual)).collect(),
+    );
+
+    let header = ReportHeader::parish(parish_id, "Monthly Budget vs Actual", Some(format!("Fiscal year {}", year)));
+
+    Ok((MonthlyReport {
+        report: "BUDGET_VS_ACTUAL".to_string(),
+        fiscal_year: year,
+        months: month_labels(year),
+        sections: vec![
+            monthly_section("Budget", budget, previous_budget),
+            monthly_section("Actual", actual, previous_actual),
+            variance,
+        ],
+        net: None,
+    }, header))
 }
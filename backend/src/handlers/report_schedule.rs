@@ -0,0 +1,523 @@
+use axum::{
+    extract::{Path, State, Query},
+    http::{header, StatusCode},
+    response::{IntoResponse, Response},
+    Json,
+};
+use uuid::Uuid;
+use serde::Deserialize;
+use chrono::{DateTime, Datelike, Months, NaiveDate, Utc};
+use sqlx::postgres::PgPool;
+use std::path::PathBuf;
+use std::str::FromStr;
+use crate::{
+    AppState,
+    models::report_schedule::{
+        ReportSchedule, ReportRun, ScheduledReportType, ReportDateRange, ReportRunStatus,
+        CreateReportScheduleRequest, UpdateReportScheduleRequest,
+    },
+    handlers::auth::AuthUser,
+    handlers::{rbac, report},
+    export::{self, ExportFormat, ReportHeader, Table, Tabular},
+};
+
+const REPORTS_DIR: &str = "generated_reports";
+
+#[derive(Debug, Deserialize)]
+pub struct ReportScheduleQuery {
+    pub parish_id: Option<Uuid>,
+}
+
+pub async fn list_report_schedules(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Query(query): Query<ReportScheduleQuery>,
+) -> Result<Json<Vec<ReportSchedule>>, (StatusCode, String)> {
+    let parish_id = rbac::resolve_parish_id(&auth, query.parish_id)?;
+
+    let schedules = sqlx::query_as::<_, ReportSchedule>(
+        "SELECT * FROM report_schedule WHERE parish_id = $1 AND deleted_at IS NULL ORDER BY schedule_name"
+    )
+    .bind(parish_id)
+    .fetch_all(&state.db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(schedules))
+}
+
+pub async fn create_report_schedule(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Json(payload): Json<CreateReportScheduleRequest>,
+) -> Result<Json<ReportSchedule>, (StatusCode, String)> {
+    rbac::require_permission(&state.db, &auth, "reports.schedule").await?;
+    let parish_id = rbac::resolve_parish_id(&auth, Some(payload.parish_id))?;
+
+    let format = payload.format.unwrap_or(ExportFormat::Pdf);
+    validate_schedule(format, &payload.recipients)?;
+    let next_run_at = next_run(&payload.cron_expression, Utc::now())
+        .map_err(|e| (StatusCode::BAD_REQUEST, e))?;
+
+    let schedule = sqlx::query_as::<_, ReportSchedule>(
+        r#"
+        INSERT INTO report_schedule (
+            parish_id, schedule_name, report_type, date_range, format,
+            cron_expression, recipients, next_run_at, created_by
+        )
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+        RETURNING *
+        "#
+    )
+    .bind(parish_id)
+    .bind(payload.schedule_name)
+    .bind(payload.report_type)
+    .bind(payload.date_range.unwrap_or(ReportDateRange::PreviousMonth))
+    .bind(format)
+    .bind(payload.cron_expression.trim())
+    .bind(payload.recipients)
+    .bind(next_run_at)
+    .bind(auth.user_id)
+    .fetch_one(&state.db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(schedule))
+}
+
+pub async fn update_report_schedule(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+    Json(payload): Json<UpdateReportScheduleRequest>,
+) -> Result<Json<ReportSchedule>, (StatusCode, String)> {
+    rbac::require_permission(&state.db, &auth, "reports.schedule").await?;
+    let existing = fetch_schedule(&state.db, id).await?;
+    rbac::resolve_parish_id(&auth, Some(existing.parish_id))?;
+
+    let format = payload.format.unwrap_or(existing.format);
+    let recipients = payload.recipients.unwrap_or(existing.recipients);
+    validate_schedule(format, &recipients)?;
+
+    let cron_expression = payload.cron_expression.unwrap_or(existing.cron_expression);
+    let next_run_at = next_run(&cron_expression, Utc::now())
+        .map_err(|e| (StatusCode::BAD_REQUEST, e))?;
+
+    let schedule = sqlx::query_as::<_, ReportSchedule>(
+        r#"
+        UPDATE report_schedule SET
+            schedule_name = $1,
+            report_type = $2,
+            date_range = $3,
+            format = $4,
+            cron_expression = $5,
+            recipients = $6,
+            is_active = $7,
+            next_run_at = $8,
+            updated_at = NOW()
+        WHERE id = $9
+        RETURNING *
+        "#
+    )
+    .bind(payload.schedule_name.unwrap_or(existing.schedule_name))
+    .bind(payload.report_type.unwrap_or(existing.report_type))
+    .bind(payload.date_range.unwrap_or(existing.date_range))
+    .bind(format)
+    .bind(cron_expression.trim())
+    .bind(recipients)
+    .bind(payload.is_active.unwrap_or(existing.is_active))
+    .bind(next_run_at)
+    .bind(id)
+    .fetch_one(&state.db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(schedule))
+}
+
+pub async fn delete_report_schedule(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> Result<StatusCode, (StatusCode, String)> {
+    rbac::require_permission(&state.db, &auth, "reports.schedule").await?;
+    let schedule = fetch_schedule(&state.db, id).await?;
+    rbac::resolve_parish_id(&auth, Some(schedule.parish_id))?;
+
+    sqlx::query("UPDATE report_schedule SET deleted_at = NOW(), is_active = FALSE WHERE id = $1")
+        .bind(id)
+        .execute(&state.db)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Runs a schedule immediately, outside its cron timing.
+pub async fn run_report_schedule(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ReportRun>, (StatusCode, String)> {
+    rbac::require_permission(&state.db, &auth, "reports.schedule").await?;
+    let schedule = fetch_schedule(&state.db, id).await?;
+    rbac::resolve_parish_id(&auth, Some(schedule.parish_id))?;
+
+    let run = execute_schedule(&state.db, &schedule, Utc::now().date_naive())
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(run))
+}
+
+pub async fn list_report_runs(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<Vec<ReportRun>>, (StatusCode, String)> {
+    let schedule = fetch_schedule(&state.db, id).await?;
+    rbac::resolve_parish_id(&auth, Some(schedule.parish_id))?;
+
+    let runs = sqlx::query_as::<_, ReportRun>(
+        "SELECT * FROM report_run WHERE schedule_id = $1 ORDER BY generated_at DESC LIMIT 100"
+    )
+    .bind(id)
+    .fetch_all(&state.db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(runs))
+}
+
+pub async fn download_report_run(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> Result<Response, (StatusCode, String)> {
+    let run = sqlx::query_as::<_, ReportRun>("SELECT * FROM report_run WHERE id = $1")
+        .bind(id)
+        .fetch_optional(&state.db)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+        .ok_or((StatusCode::NOT_FOUND, "Report run not found".to_string()))?;
+    rbac::resolve_parish_id(&auth, Some(run.parish_id))?;
+
+    let (Some(path), Some(file_name)) = (run.file_path, run.file_name) else {
+        return Err((StatusCode::NOT_FOUND, "This run produced no file".to_string()));
+    };
+    let bytes = tokio::fs::read(&path).await
+        .map_err(|_| (StatusCode::NOT_FOUND, "Report file is no longer available".to_string()))?;
+
+    Ok((
+        [
+            (header::CONTENT_TYPE, run.format.content_type().to_string()),
+            (header::CONTENT_DISPOSITION, format!("attachment; filename=\"{}\"", file_name)),
+        ],
+        bytes,
+    ).into_response())
+}
+
+// ============================================================================
+// Scheduler
+// ============================================================================
+
+/// Runs every active schedule whose next run time has passed, then moves
+/// it to its next slot. Called once a minute by the background jobs.
+pub async fn run_due_schedules(db: &PgPool) -> Result<usize, sqlx::Error> {
+    let due = sqlx::query_as::<_, ReportSchedule>(
+        r#"
+        SELECT * FROM report_schedule
+        WHERE is_active = TRUE AND deleted_at IS NULL AND next_run_at <= NOW()
+        ORDER BY next_run_at
+        "#
+    )
+    .fetch_all(db)
+    .await?;
+
+    let now = Utc::now();
+    for schedule in &due {
+        // A failing schedule still moves on to its next slot, so it cannot
+        // hold up the schedules due after it.
+        if let Err(e) = execute_schedule(db, schedule, now.date_naive()).await {
+            tracing::error!("Scheduled report '{}' could not be run: {}", schedule.schedule_name, e);
+        }
+
+        // A schedule whose expression no longer parses is switched off
+        // rather than retried every minute.
+        let next = next_run(&schedule.cron_expression, now).ok();
+        if let Err(e) = sqlx::query("UPDATE report_schedule SET next_run_at = $1, is_active = $2 WHERE id = $3")
+            .bind(next)
+            .bind(next.is_some())
+            .bind(schedule.id)
+            .execute(db)
+            .await
+        {
+            tracing::error!("Scheduled report '{}' could not be rescheduled: {}", schedule.schedule_name, e);
+        }
+    }
+
+    Ok(due.len())
+}
+
+/// Generates the report, stores the file and queues one outbox email per
+/// recipient. Generation failures are recorded on the run, not returned.
+async fn execute_schedule(db: &PgPool, schedule: &ReportSchedule, today: NaiveDate) -> Result<ReportRun, sqlx::Error> {
+    let (period_start, period_end) = date_range_bounds(schedule.date_range, today);
+
+    let generated = generate(db, schedule, period_start, period_end).await;
+    let (status, file_path, file_name, file_size, error_message) = match &generated {
+        Ok((path, name, size)) => (ReportRunStatus::Succeeded, Some(path.to_string_lossy().to_string()), Some(name.clone()), Some(*size), None),
+        Err(e) => {
+            tracing::error!("Scheduled report '{}' failed: {}", schedule.schedule_name, e);
+            (ReportRunStatus::Failed, None, None, None, Some(e.clone()))
+        }
+    };
+
+    let mut tx = db.begin().await?;
+
+    let run = sqlx::query_as::<_, ReportRun>(
+        r#"
+        INSERT INTO report_run (
+            schedule_id, parish_id, period_start, period_end, format, status,
+            file_path, file_name, file_size, error_message
+        )
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+        RETURNING *
+        "#
+    )
+    .bind(schedule.id)
+    .bind(schedule.parish_id)
+    .bind(period_start)
+    .bind(period_end)
+    .bind(schedule.format)
+    .bind(status)
+    .bind(&file_path)
+    .bind(&file_name)
+    .bind(file_size)
+    .bind(&error_message)
+    .fetch_one(&mut *tx)
+    .await?;
+
+    let mut queued = 0;
+    if status == ReportRunStatus::Succeeded {
+        let subject = format!("{} ({} to {})", schedule.schedule_name, period_start, period_end);
+        let body = format!(
+            "Please find attached the scheduled report \"{}\" for {} to {}.\n\nThis message was generated automatically.",
+            schedule.schedule_name, period_start.format("%d %b %Y"), period_end.format("%d %b %Y")
+        );
+        for recipient in &schedule.recipients {
+            sqlx::query(
+                r#"
+                INSERT INTO email_log (
+                    recipient_email, subject, body_text, email_type, user_id, status,
+                    attachment_path, attachment_name, report_run_id
+                )
+                VALUES ($1, $2, $3, 'REPORT', $4, 'PENDING', $5, $6, $7)
+                "#
+            )
+            .bind(recipient)
+            .bind(&subject)
+            .bind(&body)
+            .bind(schedule.created_by)
+            .bind(&file_path)
+            .bind(&file_name)
+            .bind(run.id)
+            .execute(&mut *tx)
+            .await?;
+            queued += 1;
+        }
+    }
+
+    let run = sqlx::query_as::<_, ReportRun>(
+        "UPDATE report_run SET emails_queued = $1 WHERE id = $2 RETURNING *"
+    )
+    .bind(queued)
+    .bind(run.id)
+    .fetch_one(&mut *tx)
+    .await?;
+
+    sqlx::query("UPDATE report_schedule SET last_run_at = NOW() WHERE id = $1")
+        .bind(schedule.id)
+        .execute(&mut *tx)
+        .await?;
+
+    tx.commit().await?;
+    Ok(run)
+}
+
+/// Builds and renders the report, returning (path, file name, size).
+async fn generate(
+    db: &PgPool,
+    schedule: &ReportSchedule,
+    start: NaiveDate,
+    end: NaiveDate,
+) -> Result<(PathBuf, String, i64), String> {
+    let (header, table) = build_report(db, schedule.report_type, schedule.parish_id, start, end)
+        .await
+        .map_err(|(_, e)| e)?;
+    let bytes = export::render(db, schedule.format, &header, &table).await?;
+
+    let dir = PathBuf::from(REPORTS_DIR).join(schedule.parish_id.to_string());
+    tokio::fs::create_dir_all(&dir).await.map_err(|e| e.to_string())?;
+
+    let slug = schedule.schedule_name
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c.to_ascii_lowercase() } else { '-' })
+        .collect::<String>();
+    let file_name = format!("{}-{}.{}", slug.trim_matches('-'), end, schedule.format.extension());
+    let path = dir.join(format!("{}-{}", Uuid::new_v4(), file_name));
+
+    tokio::fs::write(&path, &bytes).await.map_err(|e| e.to_string())?;
+    Ok((path, file_name, bytes.len() as i64))
+}
+
+async fn build_report(
+    db: &PgPool,
+    report_type: ScheduledReportType,
+    parish_id: Uuid,
+    start: NaiveDate,
+    end: NaiveDate,
+) -> Result<(ReportHeader, Table), (StatusCode, String)> {
+    fn table<T: Tabular>((report, header): (T, ReportHeader)) -> (ReportHeader, Table) {
+        (header, report.to_table())
+    }
+
+    Ok(match report_type {
+        ScheduledReportType::TrialBalance => table(report::trial_balance(db, parish_id, start, end).await?),
+        ScheduledReportType::IncomeExpenditure => table(report::income_expenditure(db, parish_id, start, end).await?),
+        ScheduledReportType::BudgetVsActual => table(report::budget_vs_actual(db, parish_id, start, end).await?),
+        ScheduledReportType::BalanceSheet => table(report::balance_sheet(db, parish_id, end).await?),
+        ScheduledReportType::CashFlow => table(report::cash_flow(db, parish_id, start, end).await?),
+        ScheduledReportType::FundBalances => table(report::fund_balances(db, parish_id, start, end).await?),
+        ScheduledReportType::ComparativeIncomeExpenditure => {
+            table(report::comparative_income_expenditure(db, parish_id, start, end).await?)
+        }
+        ScheduledReportType::ComparativeBudgetVsActual => {
+            table(report::comparative_budget_vs_actual(db, parish_id, start, end).await?)
+        }
+        ScheduledReportType::MonthlyIncomeExpenditure => {
+            table(report::monthly_income_expenditure(db, parish_id, start.year()).await?)
+        }
+        ScheduledReportType::MonthlyBudgetVsActual => {
+            table(report::monthly_budget_vs_actual(db, parish_id, start.year()).await?)
+        }
+    })
+}
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+async fn fetch_schedule(db: &PgPool, id: Uuid) -> Result<ReportSchedule, (StatusCode, String)> {
+    sqlx::query_as::<_, ReportSchedule>("SELECT * FROM report_schedule WHERE id = $1 AND deleted_at IS NULL")
+        .bind(id)
+        .fetch_optional(db)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+        .ok_or((StatusCode::NOT_FOUND, "Report schedule not found".to_string()))
+}
+
+fn validate_schedule(format: ExportFormat, recipients: &[String]) -> Result<(), (StatusCode, String)> {
+    if format == ExportFormat::Json {
+        return Err((StatusCode::BAD_REQUEST, "Scheduled reports must be CSV, XLSX or PDF".to_string()));
+    }
+    if let Some(bad) = recipients.iter().find(|r| !r.contains('@')) {
+        return Err((StatusCode::BAD_REQUEST, format!("Invalid recipient email: {}", bad)));
+    }
+    Ok(())
+}
+
+/// Next time a 5-field cron expression (minute hour day month weekday)
+/// fires after `after`.
+fn next_run(expression: &str, after: DateTime<Utc>) -> Result<DateTime<Utc>, String> {
+    let expression = expression.trim();
+    let fields: Vec<&str> = expression.split_whitespace().collect();
+    let [minute, hour, day, month, weekday] = fields[..] else {
+        return Err(format!(
+            "Invalid cron expression '{}': expected 5 fields (minute hour day month weekday)",
+            expression
+        ));
+    };
+
+    // The cron crate wants a seconds field and counts weekdays from
+    // 1 = Sunday, so the weekday field is rewritten as day names.
+    let weekday = weekday_names(weekday)
+        .map_err(|e| format!("Invalid cron expression '{}': {}", expression, e))?;
+    let full = format!("0 {} {} {} {} {}", minute, hour, day, month, weekday);
+
+    cron::Schedule::from_str(&full)
+        .map_err(|e| format!("Invalid cron expression '{}': {}", expression, e))?
+        .after(&after)
+        .next()
+        .ok_or_else(|| format!("Cron expression '{}' never fires", expression))
+}
+
+/// Standard cron weekdays (0-7, Sunday being 0 or 7, or SUN-SAT; with
+/// lists, ranges and steps) as a list of day names.
+fn weekday_names(field: &str) -> Result<String, String> {
+    const NAMES: [&str; 7] = ["SUN", "MON", "TUE", "WED", "THU", "FRI", "SAT"];
+    if field == "*" || field == "?" {
+        return Ok(field.to_string());
+    }
+
+    let day = |token: &str| -> Result<usize, String> {
+        let n = match token.parse::<usize>() {
+            Ok(n) if n <= 7 => n,
+            Ok(_) => return Err(format!("weekday '{}' is out of range 0-7", token)),
+            Err(_) => NAMES.iter()
+                .position(|name| name.eq_ignore_ascii_case(token))
+                .ok_or_else(|| format!("unknown weekday '{}'", token))?,
+        };
+        Ok(n)
+    };
+
+    let mut days = [false; 7];
+    for item in field.split(',') {
+        let (range, step) = match item.split_once('/') {
+            Some((range, step)) => (range, step.parse::<usize>()
+                .ok()
+                .filter(|s| *s > 0)
+                .ok_or_else(|| format!("invalid weekday step in '{}'", item))?),
+            None => (item, 1),
+        };
+        let (from, to) = match range {
+            "*" => (0, 6),
+            _ => match range.split_once('-') {
+                Some((a, b)) => (day(a)?, day(b)?),
+                // `n/step` runs from n to the end of the week
+                None if step > 1 => (day(range)?, 6),
+                None => (day(range)?, day(range)?),
+            },
+        };
+        if from > to {
+            return Err(format!("weekday range '{}' runs backwards", range));
+        }
+        for n in (from..=to).step_by(step) {
+            days[n % 7] = true;
+        }
+    }
+
+    Ok(NAMES.iter()
+        .zip(days)
+        .filter(|(_, on)| *on)
+        .map(|(name, _)| *name)
+        .collect::<Vec<_>>()
+        .join(","))
+}
+
+/// Concrete dates for a relative range, as seen on `today`.
+fn date_range_bounds(range: ReportDateRange, today: NaiveDate) -> (NaiveDate, NaiveDate) {
+    let month_start = today.with_day(1).expect("day 1 exists");
+    let quarter_start = NaiveDate::from_ymd_opt(today.year(), today.month0() / 3 * 3 + 1, 1).expect("valid quarter");
+    let year_start = NaiveDate::from_ymd_opt(today.year(), 1, 1).expect("valid year");
+    let day_before = |d: NaiveDate| d.pred_opt().expect("date in range");
+
+    match range {
+        ReportDateRange::PreviousMonth => (month_start - Months::new(1), day_before(month_start)),
+        ReportDateRange::MonthToDate => (month_start, today),
+        ReportDateRange::PreviousQuarter => (quarter_start - Months::new(3), day_before(quarter_start)),
+        ReportDateRange::QuarterToDate => (quarter_start, today),
+        ReportDateRange::PreviousYear => (year_start - Months::new(12), day_before(year_start)),
+        ReportDateRange::YearToDate => (year_start, today),
+    }
+}
@@ -1,10 +1,18 @@
 use axum::{
     extract::{Path, State, Query},
     http::StatusCode,
+    response::{IntoResponse, Response},
     Json,
 };
+use sqlx::PgConnection;
 use uuid::Uuid;
+use std::collections::HashMap;
 use crate::{AppState, models::member::{SacramentRecord, SacramentType}, handlers::auth::AuthUser, handlers::rbac};
+use crate::handlers::{notation, register, sacrament_rule, sponsor};
+use crate::models::sacrament_rule::{CheckSacramentRequest, RuleViolation, SavedSacrament};
+use crate::models::register::RegisterEntryRef;
+use crate::models::sponsor::SponsorInput;
+use crate::export::{self, Cell, ExportFormat, ReportHeader, Row, Table};
 use serde::Deserialize;
 use chrono::NaiveDate;
 
@@ -32,6 +40,19 @@ pub struct CreateSacramentRequest {
     pub spouse_name: Option<String>,
     pub witnesses: Option<String>,
     pub notes: Option<String>,
+    /// Godparents, sponsors and witnesses, linked to members where they
+    /// are parishioners. Takes the place of the three name fields above.
+    pub sponsors: Option<Vec<SponsorInput>>,
+    /// Saves the record although canonical checks failed.
+    pub override_reason: Option<String>,
+    /// Left out to take the next entry in the open register volume.
+    pub register_book_id: Option<Uuid>,
+    pub register_page: Option<i32>,
+    pub register_entry: Option<i32>,
+    /// The sacrament is written in another parish's register, so no entry
+    /// is allocated here.
+    #[serde(default)]
+    pub unregistered: bool,
 }
 
 #[derive(Debug, Deserialize)]
@@ -46,16 +67,29 @@ pub struct UpdateSacramentRequest {
     pub spouse_name: Option<String>,
     pub witnesses: Option<String>,
     pub notes: Option<String>,
+    pub sponsors: Option<Vec<SponsorInput>>,
+    /// Saves the record although canonical checks failed.
+    pub override_reason: Option<String>,
+    pub register_book_id: Option<Uuid>,
+    pub register_page: Option<i32>,
+    pub register_entry: Option<i32>,
 }
 
 pub async fn list_sacraments(
     auth: AuthUser,
     State(state): State<AppState>,
     Query(query): Query<ListSacramentsQuery>,
-) -> Result<Json<Vec<SacramentRecord>>, (StatusCode, String)> {
-    let limit = query.limit.unwrap_or(50);
+    format: ExportFormat,
+) -> Result<Response, (StatusCode, String)> {
+    let exporting = format != ExportFormat::Json;
+    let limit = query.limit.unwrap_or(if exporting { i64::MAX } else { 50 });
     let offset = query.offset.unwrap_or(0);
 
+    if exporting {
+        let parish_id = rbac::resolve_parish_id(&auth, query.parish_id)?;
+        return export_sacrament_register(&state, &auth, format, parish_id, query.member_id, limit, offset).await;
+    }
+
     let sacraments = if let Some(member_id) = query.member_id {
         sqlx::query_as::<_, SacramentRecord>(
             "SELECT * FROM sacrament_record WHERE member_id = $1 AND deleted_at IS NULL ORDER BY sacrament_date DESC"
@@ -77,7 +111,91 @@ pub async fn list_sacraments(
 
     let sacraments = sacraments.map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
 
-    Ok(Json(sacraments))
+    Ok(Json(sacraments).into_response())
+}
+
+async fn export_sacrament_register(
+    state: &AppState,
+    auth: &AuthUser,
+    format: ExportFormat,
+    parish_id: Uuid,
+    member_id: Option<Uuid>,
+    limit: i64,
+    offset: i64,
+) -> Result<Response, (StatusCode, String)> {
+    // Same rows as the JSON listing: a member's records wherever they
+    // were entered, otherwise the parish register.
+    let records = sqlx::query_as::<_, SacramentRecord>(
+        r#"
+        SELECT * FROM sacrament_record
+        WHERE CASE WHEN $4::uuid IS NULL THEN parish_id = $1 ELSE member_id = $4 END
+          AND deleted_at IS NULL
+        ORDER BY sacrament_date, sacrament_type
+        LIMIT $2 OFFSET $3
+        "#
+    )
+    .bind(parish_id)
+    .bind(limit)
+    .bind(offset)
+    .bind(member_id)
+    .fetch_all(&state.db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    let member_ids: Vec<Uuid> = records.iter().map(|r| r.member_id).collect();
+    let members: HashMap<Uuid, (String, String)> = sqlx::query_as::<_, (Uuid, String, String)>(
+        "SELECT id, member_code, CONCAT_WS(' ', first_name, middle_name, last_name) FROM member WHERE id = ANY($1)"
+    )
+    .bind(&member_ids)
+    .fetch_all(&state.db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+    .into_iter()
+    .map(|(id, code, name)| (id, (code, name)))
+    .collect();
+
+    let book_ids: Vec<Uuid> = records.iter().filter_map(|r| r.register_book_id).collect();
+    let books: HashMap<Uuid, String> = sqlx::query_as::<_, (Uuid, SacramentType, Option<String>, i32)>(
+        "SELECT id, sacrament_type, title, volume_number FROM register_book WHERE id = ANY($1)"
+    )
+    .bind(&book_ids)
+    .fetch_all(&state.db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+    .into_iter()
+    .map(|(id, kind, title, volume)| (id, register::book_label(kind, title.as_deref(), volume)))
+    .collect();
+
+    let mut table = Table::new(&[
+        "Date", "Sacrament", "Member Code", "Name", "Minister", "Church",
+        "Certificate No.", "Register Ref.", "Godparents / Witnesses", "Spouse",
+    ]);
+    for r in &records {
+        let (code, name) = members.get(&r.member_id).cloned().unwrap_or_default();
+        let sponsors = [r.godparent_1_name.as_deref(), r.godparent_2_name.as_deref(), r.witnesses.as_deref()]
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>()
+            .join("; ");
+        table.rows.push(Row::detail(vec![
+            Cell::text(r.sacrament_date.to_string()),
+            Cell::text(export::enum_label(&r.sacrament_type)),
+            Cell::text(code),
+            Cell::text(name),
+            Cell::opt(r.officiating_minister.as_ref()),
+            Cell::opt(r.church_name.as_ref()),
+            Cell::opt(r.certificate_number.as_ref()),
+            Cell::opt(r.register_book_id
+                .and_then(|b| books.get(&b))
+                .map(|label| register::cite(label.clone(), r.register_page, r.register_entry))),
+            Cell::text(sponsors),
+            Cell::opt(r.spouse_name.as_ref()),
+        ]));
+    }
+    table.rows.push(Row::total(vec![Cell::text("Total Records"), Cell::Count(records.len() as i64)]));
+
+    let header = ReportHeader::parish(parish_id, "Sacrament Register", None);
+    export::respond_table(&state.db, auth, format, header, table).await
 }
 
 pub async fn get_sacrament(
@@ -101,18 +219,79 @@ pub async fn create_sacrament(
     auth: AuthUser,
     State(state): State<AppState>,
     Json(payload): Json<CreateSacramentRequest>,
-) -> Result<Json<SacramentRecord>, (StatusCode, String)> {
+) -> Result<Response, (StatusCode, String)> {
     rbac::require_write(&auth)?;
     let parish_id = rbac::resolve_parish_id(&auth, Some(payload.parish_id))?;
 
+    let check = sacrament_rule::check(&state.db, parish_id, &CheckSacramentRequest {
+        member_id: payload.member_id,
+        sacrament_type: payload.sacrament_type,
+        sacrament_date: payload.sacrament_date,
+        spouse_id: payload.spouse_id,
+        record_id: None,
+        sponsors: payload.sponsors.clone().unwrap_or_default(),
+    })
+    .await?;
+    let override_reason = match sacrament_rule::override_reason(&auth, &check, payload.override_reason.as_deref()) {
+        Ok(reason) => reason,
+        Err(refused) => return Ok(*refused),
+    };
+
+    let mut tx = state.db.begin().await.map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+    let saved = insert_record(&mut tx, &auth, parish_id, payload, &check.violations, override_reason).await?;
+    tx.commit().await.map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(saved).into_response())
+}
+
+/// Writes a checked record: allocates its register entry, stores its
+/// sponsors, logs any override and notes it on the baptism. `violations`
+/// are the rule violations the record was checked with.
+pub async fn insert_record(
+    conn: &mut PgConnection,
+    auth: &AuthUser,
+    parish_id: Uuid,
+    payload: CreateSacramentRequest,
+    violations: &[RuleViolation],
+    override_reason: Option<String>,
+) -> Result<SavedSacrament, (StatusCode, String)> {
+    let sponsors = match &payload.sponsors {
+        Some(inputs) => sponsor::resolve(&mut *conn, inputs).await?,
+        None => sponsor::from_text(
+            payload.sacrament_type, payload.godparent_1_name.as_deref(),
+            payload.godparent_2_name.as_deref(), payload.witnesses.as_deref(),
+        ),
+    };
+    let (godparent_1_name, godparent_2_name, witnesses) = match payload.sponsors {
+        Some(_) => sponsor::printed(&sponsors),
+        None => (payload.godparent_1_name, payload.godparent_2_name, payload.witnesses),
+    };
+
+    let mut warnings = violations.to_vec();
+    let entry = if payload.unregistered {
+        RegisterEntryRef::default()
+    } else {
+        let requested = RegisterEntryRef {
+            register_book_id: payload.register_book_id,
+            register_page: payload.register_page,
+            register_entry: payload.register_entry,
+        };
+        let (entry, register_warnings) = register::place_entry(&mut *conn, parish_id, payload.sacrament_type, None, payload.spouse_id, requested).await?;
+        warnings.extend(register_warnings);
+        entry
+    };
+
     let sacrament = sqlx::query_as::<_, SacramentRecord>(
         r#"
         INSERT INTO sacrament_record (
             member_id, sacrament_type, sacrament_date, officiating_minister,
             parish_id, church_name, certificate_number, godparent_1_name,
-            godparent_2_name, spouse_id, spouse_name, witnesses, notes
+            godparent_2_name, spouse_id, spouse_name, witnesses, notes,
+            override_reason, overridden_by, overridden_at,
+            register_book_id, register_page, register_entry
         )
-        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15,
+                CASE WHEN $14::text IS NULL THEN NULL ELSE NOW() END, $16, $17, $18)
         RETURNING *
         "#
     )
@@ -123,17 +302,28 @@ pub async fn create_sacrament(
     .bind(parish_id)
     .bind(payload.church_name)
     .bind(payload.certificate_number)
-    .bind(payload.godparent_1_name)
-    .bind(payload.godparent_2_name)
+    .bind(godparent_1_name)
+    .bind(godparent_2_name)
     .bind(payload.spouse_id)
     .bind(payload.spouse_name)
-    .bind(payload.witnesses)
+    .bind(witnesses)
     .bind(payload.notes)
-    .fetch_one(&state.db)
+    .bind(&override_reason)
+    .bind(override_reason.as_ref().map(|_| auth.user_id))
+    .bind(entry.register_book_id)
+    .bind(entry.register_page)
+    .bind(entry.register_entry)
+    .fetch_one(&mut *conn)
     .await
     .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
 
-    Ok(Json(sacrament))
+    if let Some(reason) = &override_reason {
+        sacrament_rule::log_override(&mut *conn, auth, parish_id, sacrament.id, reason, violations).await?;
+    }
+    notation::annotate_baptism(&mut *conn, auth.user_id, &sacrament).await?;
+    let sponsors = sponsor::replace(&mut *conn, sacrament.id, &sponsors).await?;
+
+    Ok(SavedSacrament { record: sacrament, sponsors, warnings })
 }
 
 pub async fn update_sacrament(
@@ -141,7 +331,7 @@ pub async fn update_sacrament(
     State(state): State<AppState>,
     Path(id): Path<Uuid>,
     Json(payload): Json<UpdateSacramentRequest>,
-) -> Result<Json<SacramentRecord>, (StatusCode, String)> {
+) -> Result<Response, (StatusCode, String)> {
     rbac::require_write(&auth)?;
     let mut sacrament = sqlx::query_as::<_, SacramentRecord>(
         "SELECT * FROM sacrament_record WHERE id = $1 AND deleted_at IS NULL"
@@ -152,6 +342,16 @@ pub async fn update_sacrament(
     .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
     .ok_or((StatusCode::NOT_FOUND, "Sacrament record not found".to_string()))?;
 
+    // Only a new date, spouse or sponsors can change the outcome of the checks
+    let recheck = payload.sacrament_date.is_some_and(|d| d != sacrament.sacrament_date)
+        || payload.spouse_id.is_some_and(|s| Some(s) != sacrament.spouse_id)
+        || payload.sponsors.is_some();
+    let names_edited = payload.godparent_1_name.is_some() || payload.godparent_2_name.is_some() || payload.witnesses.is_some();
+    let noted = (
+        sacrament.sacrament_date, sacrament.spouse_id, sacrament.spouse_name.clone(),
+        sacrament.church_name.clone(), sacrament.officiating_minister.clone(),
+    );
+
     if let Some(val) = payload.sacrament_date { sacrament.sacrament_date = val; }
     if let Some(val) = payload.officiating_minister { sacrament.officiating_minister = Some(val); }
     if let Some(val) = payload.church_name { sacrament.church_name = Some(val); }
@@ -163,6 +363,67 @@ pub async fn update_sacrament(
     if let Some(val) = payload.witnesses { sacrament.witnesses = Some(val); }
     if let Some(val) = payload.notes { sacrament.notes = Some(val); }
 
+    let mut violations = Vec::new();
+    let mut override_reason = None;
+    if recheck {
+        let check = sacrament_rule::check(&state.db, sacrament.parish_id, &CheckSacramentRequest {
+            member_id: sacrament.member_id,
+            sacrament_type: sacrament.sacrament_type,
+            sacrament_date: sacrament.sacrament_date,
+            spouse_id: sacrament.spouse_id,
+            record_id: Some(id),
+            sponsors: match &payload.sponsors {
+                Some(inputs) => inputs.clone(),
+                None => sponsor::current_inputs(&state.db, id).await?,
+            },
+        })
+        .await?;
+        override_reason = match sacrament_rule::override_reason(&auth, &check, payload.override_reason.as_deref()) {
+            Ok(reason) => reason,
+            Err(refused) => return Ok(*refused),
+        };
+        violations = check.violations;
+    }
+
+    let mut tx = state.db.begin().await.map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    let sponsors = match &payload.sponsors {
+        Some(inputs) => Some(sponsor::resolve(&mut tx, inputs).await?),
+        None if names_edited => Some(sponsor::keep_links(
+            sponsor::from_text(
+                sacrament.sacrament_type, sacrament.godparent_1_name.as_deref(),
+                sacrament.godparent_2_name.as_deref(), sacrament.witnesses.as_deref(),
+            ),
+            &sponsor::fetch_sponsors(&state.db, id).await?,
+        )),
+        None => None,
+    };
+    if let (Some(resolved), Some(_)) = (&sponsors, &payload.sponsors) {
+        (sacrament.godparent_1_name, sacrament.godparent_2_name, sacrament.witnesses) = sponsor::printed(resolved);
+    }
+
+    let mut warnings = violations.clone();
+    let mut entry = RegisterEntryRef {
+        register_book_id: sacrament.register_book_id,
+        register_page: sacrament.register_page,
+        register_entry: sacrament.register_entry,
+    };
+    if payload.register_book_id.is_some() || payload.register_page.is_some() || payload.register_entry.is_some() {
+        let same_book = payload.register_book_id.is_none_or(|b| Some(b) == sacrament.register_book_id);
+        let requested = RegisterEntryRef {
+            register_book_id: payload.register_book_id.or(sacrament.register_book_id),
+            register_page: payload.register_page
+                .or(sacrament.register_page.filter(|_| same_book && payload.register_entry.is_none())),
+            register_entry: payload.register_entry.or(sacrament.register_entry.filter(|_| same_book)),
+        };
+        let (placed, register_warnings) = register::place_entry(
+            &mut tx, sacrament.parish_id, sacrament.sacrament_type, Some(id), sacrament.spouse_id, requested,
+        )
+        .await?;
+        entry = placed;
+        warnings.extend(register_warnings);
+    }
+
     let updated_sacrament = sqlx::query_as::<_, SacramentRecord>(
         r#"
         UPDATE sacrament_record
@@ -170,6 +431,10 @@ pub async fn update_sacrament(
             sacrament_date = $1, officiating_minister = $2, church_name = $3,
             certificate_number = $4, godparent_1_name = $5, godparent_2_name = $6,
             spouse_id = $7, spouse_name = $8, witnesses = $9, notes = $10,
+            override_reason = COALESCE($12, override_reason),
+            overridden_by = CASE WHEN $12::text IS NULL THEN overridden_by ELSE $13 END,
+            overridden_at = CASE WHEN $12::text IS NULL THEN overridden_at ELSE NOW() END,
+            register_book_id = $14, register_page = $15, register_entry = $16,
             updated_at = NOW()
         WHERE id = $11
         RETURNING *
@@ -186,11 +451,37 @@ pub async fn update_sacrament(
     .bind(sacrament.witnesses)
     .bind(sacrament.notes)
     .bind(id)
-    .fetch_one(&state.db)
+    .bind(&override_reason)
+    .bind(auth.user_id)
+    .bind(entry.register_book_id)
+    .bind(entry.register_page)
+    .bind(entry.register_entry)
+    .fetch_one(&mut *tx)
     .await
     .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
 
-    Ok(Json(updated_sacrament))
+    if let Some(reason) = &override_reason {
+        sacrament_rule::log_override(&mut tx, &auth, updated_sacrament.parish_id, id, reason, &violations).await?;
+    }
+
+    // Marginal notations quote the date, place, minister and spouse
+    let renoted = (
+        updated_sacrament.sacrament_date, updated_sacrament.spouse_id, updated_sacrament.spouse_name.clone(),
+        updated_sacrament.church_name.clone(), updated_sacrament.officiating_minister.clone(),
+    );
+    if renoted != noted {
+        notation::withdraw_notations(&mut tx, auth.user_id, id).await?;
+        notation::annotate_baptism(&mut tx, auth.user_id, &updated_sacrament).await?;
+    }
+
+    if let Some(resolved) = &sponsors {
+        sponsor::replace(&mut tx, id, resolved).await?;
+    }
+
+    tx.commit().await.map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    let sponsors = sponsor::fetch_sponsors(&state.db, id).await?;
+    Ok(Json(SavedSacrament { record: updated_sacrament, sponsors, warnings }).into_response())
 }
 
 pub async fn delete_sacrament(
@@ -199,11 +490,13 @@ pub async fn delete_sacrament(
     Path(id): Path<Uuid>,
 ) -> Result<StatusCode, (StatusCode, String)> {
     rbac::require_write(&auth)?;
+    let mut tx = state.db.begin().await.map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
     let result = sqlx::query(
         "UPDATE sacrament_record SET deleted_at = NOW() WHERE id = $1 AND deleted_at IS NULL"
     )
     .bind(id)
-    .execute(&state.db)
+    .execute(&mut *tx)
     .await
     .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
 
@@ -211,5 +504,8 @@ pub async fn delete_sacrament(
         return Err((StatusCode::NOT_FOUND, "Sacrament record not found or already deleted".to_string()));
     }
 
+    notation::withdraw_notations(&mut tx, auth.user_id, id).await?;
+    tx.commit().await.map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
     Ok(StatusCode::NO_CONTENT)
 }
@@ -0,0 +1,497 @@
+use axum::{
+    extract::State,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use sqlx::{FromRow, PgConnection, PgPool};
+use uuid::Uuid;
+use chrono::NaiveDate;
+use crate::{AppState, handlers::auth::AuthUser, handlers::rbac, handlers::sponsor};
+use crate::models::member::{FamilyRole, GenderType, MaritalStatus, SacramentType};
+use crate::models::sponsor::{SponsorInput, SponsorRole};
+use crate::models::sacrament_rule::{
+    CheckSacramentRequest, RuleSeverity, RuleViolation, SacramentCheck, SacramentRulePolicy,
+};
+
+#[derive(Debug, FromRow)]
+struct Party {
+    id: Uuid,
+    full_name: String,
+    date_of_birth: Option<NaiveDate>,
+    gender: Option<GenderType>,
+    marital_status: Option<MaritalStatus>,
+    family_id: Option<Uuid>,
+    family_role: Option<FamilyRole>,
+    is_active: Option<bool>,
+    date_of_death: Option<NaiveDate>,
+}
+
+#[derive(Debug, FromRow)]
+struct HistoryEntry {
+    sacrament_type: SacramentType,
+    sacrament_date: NaiveDate,
+    spouse_id: Option<Uuid>,
+}
+
+/// Checks a sacrament against the member's record without saving it, so
+/// the form can show problems before the user submits.
+pub async fn check_sacrament(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Json(payload): Json<CheckSacramentRequest>,
+) -> Result<Json<SacramentCheck>, (StatusCode, String)> {
+    let parish_id: Uuid = sqlx::query_scalar("SELECT parish_id FROM member WHERE id = $1 AND deleted_at IS NULL")
+        .bind(payload.member_id)
+        .fetch_optional(&state.db)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+        .ok_or((StatusCode::NOT_FOUND, "Member not found".to_string()))?;
+    let parish_id = rbac::resolve_parish_id(&auth, Some(parish_id))?;
+
+    Ok(Json(check(&state.db, parish_id, &payload).await?))
+}
+
+pub async fn rule_policy(db: &PgPool, parish_id: Uuid) -> Result<SacramentRulePolicy, (StatusCode, String)> {
+    Ok(match setting(db, parish_id, "sacraments.rule_policy").await?.as_deref() {
+        Some("WARN") => SacramentRulePolicy::Warn,
+        _ => SacramentRulePolicy::Block,
+    })
+}
+
+/// A setting for the parish, falling back to the default.
+pub async fn setting(db: &PgPool, parish_id: Uuid, key: &str) -> Result<Option<String>, (StatusCode, String)> {
+    let value: Option<String> = sqlx::query_scalar(
+        r#"
+        SELECT setting_value FROM app_setting
+        WHERE setting_key = $1 AND (parish_id = $2 OR parish_id IS NULL)
+        ORDER BY parish_id NULLS LAST
+        LIMIT 1
+        "#
+    )
+    .bind(key)
+    .bind(parish_id)
+    .fetch_optional(db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(value.map(|v| v.trim().to_string()))
+}
+
+/// Minimum age in whole years, from `sacraments.min_age.<key>`; no setting
+/// means no limit.
+async fn min_age(db: &PgPool, parish_id: Uuid, key: &str) -> Result<Option<u32>, (StatusCode, String)> {
+    let value = setting(db, parish_id, &format!("sacraments.min_age.{}", key)).await?;
+    Ok(value.and_then(|v| v.parse().ok()))
+}
+
+async fn fetch_party(db: &PgPool, member_id: Uuid) -> Result<Option<Party>, (StatusCode, String)> {
+    sqlx::query_as::<_, Party>(
+        r#"
+        SELECT id, CONCAT_WS(' ', first_name, middle_name, last_name) AS full_name,
+               date_of_birth, gender, marital_status, family_id, family_role, is_active, date_of_death
+        FROM member WHERE id = $1 AND deleted_at IS NULL
+        "#
+    )
+    .bind(member_id)
+    .fetch_optional(db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+}
+
+async fn fetch_history(db: &PgPool, member_id: Uuid, exclude: Option<Uuid>) -> Result<Vec<HistoryEntry>, (StatusCode, String)> {
+    sqlx::query_as::<_, HistoryEntry>(
+        r#"
+        SELECT sacrament_type, sacrament_date, spouse_id FROM sacrament_record
+        WHERE member_id = $1 AND deleted_at IS NULL AND ($2::uuid IS NULL OR id <> $2)
+        ORDER BY sacrament_date
+        "#
+    )
+    .bind(member_id)
+    .bind(exclude)
+    .fetch_all(db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+}
+
+fn violation(severity: RuleSeverity, rule: &str, message: String) -> RuleViolation {
+    RuleViolation { rule: rule.to_string(), severity, message }
+}
+
+/// Marriage and the Anointing of the Sick may be received more than once.
+pub fn is_repeatable(sacrament_type: SacramentType) -> bool {
+    matches!(sacrament_type, SacramentType::Marriage | SacramentType::AnointingOfSick)
+}
+
+/// Checks a sacrament against the member's (and for a marriage, the
+/// spouse's) date of birth, gender, marital status and earlier sacraments,
+/// following the parish's policy. A second record of a sacrament that can
+/// be received only once is refused outright with 409.
+pub async fn check(
+    db: &PgPool,
+    parish_id: Uuid,
+    req: &CheckSacramentRequest,
+) -> Result<SacramentCheck, (StatusCode, String)> {
+    sponsor::validate(req.sacrament_type, &req.sponsors)?;
+    let policy = rule_policy(db, parish_id).await?;
+    let member = fetch_party(db, req.member_id)
+        .await?
+        .ok_or((StatusCode::NOT_FOUND, "Member not found".to_string()))?;
+    let history = fetch_history(db, member.id, req.record_id).await?;
+    let date = req.sacrament_date;
+    let kind = req.sacrament_type;
+
+    if !is_repeatable(kind) {
+        if let Some(existing) = history.iter().find(|r| r.sacrament_type == kind) {
+            return Err((StatusCode::CONFLICT, format!(
+                "{} already has a {} record dated {}", member.full_name, sacrament_label(kind), existing.sacrament_date
+            )));
+        }
+    }
+
+    let mut violations = Vec::new();
+
+    if let Some(dob) = member.date_of_birth {
+        if date < dob {
+            violations.push(violation(RuleSeverity::Error, "DATE_BEFORE_BIRTH", format!(
+                "The sacrament date {} is before {}'s date of birth {}", date, member.full_name, dob
+            )));
+        }
+    }
+    if let Some(died) = member.date_of_death {
+        if date > died {
+            violations.push(violation(RuleSeverity::Error, "DATE_AFTER_DEATH", format!(
+                "The sacrament date {} is after {}'s death on {}", date, member.full_name, died
+            )));
+        }
+    }
+
+    if kind != SacramentType::Baptism {
+        match history.iter().find(|r| r.sacrament_type == SacramentType::Baptism) {
+            None => violations.push(violation(RuleSeverity::Error, "BAPTISM_REQUIRED", format!(
+                "No baptism is recorded for {}", member.full_name
+            ))),
+            Some(b) if b.sacrament_date > date => violations.push(violation(RuleSeverity::Error, "BAPTISM_AFTER", format!(
+                "{} was baptised on {}, after this date", member.full_name, b.sacrament_date
+            ))),
+            _ => {}
+        }
+    }
+
+    let age_key = match kind {
+        SacramentType::FirstCommunion => Some("first_communion"),
+        SacramentType::Confirmation => Some("confirmation"),
+        SacramentType::HolyOrders => Some("holy_orders"),
+        SacramentType::Marriage => Some(marriage_age_key(member.gender)),
+        _ => None,
+    };
+    if let Some(key) = age_key {
+        if let Some(min) = min_age(db, parish_id, key).await? {
+            check_age(&mut violations, &member, date, min);
+        }
+    }
+
+    let confirmed = history
+        .iter()
+        .any(|r| r.sacrament_type == SacramentType::Confirmation && r.sacrament_date <= date);
+
+    match kind {
+        SacramentType::HolyOrders => {
+            if !confirmed {
+                violations.push(violation(RuleSeverity::Error, "CONFIRMATION_REQUIRED", format!(
+                    "{} must be confirmed before ordination (canon 1033)", member.full_name
+                )));
+            }
+            if member.gender == Some(GenderType::Female) {
+                violations.push(violation(RuleSeverity::Error, "MALE_REQUIRED", format!(
+                    "Only a baptised man can be ordained (canon 1024); {} is recorded as female", member.full_name
+                )));
+            }
+        }
+        SacramentType::Marriage => {
+            if !confirmed {
+                violations.push(violation(RuleSeverity::Warning, "CONFIRMATION_RECOMMENDED", format!(
+                    "{} is not recorded as confirmed; confirmation before marriage is expected where possible (canon 1065)",
+                    member.full_name
+                )));
+            }
+            check_bond(&mut violations, &member, &history, req.spouse_id, req.record_id.is_none());
+
+            if let Some(spouse_id) = req.spouse_id {
+                check_spouse(db, parish_id, &mut violations, &member, spouse_id, date).await?;
+            }
+        }
+        _ => {}
+    }
+
+    check_sponsors(db, parish_id, &mut violations, &member, kind, date, &req.sponsors).await?;
+
+    if policy == SacramentRulePolicy::Warn {
+        for v in violations.iter_mut() {
+            v.severity = RuleSeverity::Warning;
+        }
+    }
+    let can_save = violations.iter().all(|v| v.severity == RuleSeverity::Warning);
+
+    Ok(SacramentCheck { policy, violations, can_save })
+}
+
+fn marriage_age_key(gender: Option<GenderType>) -> &'static str {
+    // Without a recorded gender the lower canonical age applies
+    match gender {
+        Some(GenderType::Male) => "marriage_male",
+        _ => "marriage_female",
+    }
+}
+
+fn check_age(violations: &mut Vec<RuleViolation>, party: &Party, date: NaiveDate, min: u32) {
+    match party.date_of_birth {
+        None => violations.push(violation(RuleSeverity::Warning, "AGE_UNKNOWN", format!(
+            "{} has no date of birth recorded, so the minimum age of {} cannot be checked", party.full_name, min
+        ))),
+        Some(dob) => {
+            let age = date.years_since(dob).unwrap_or(0);
+            if age < min {
+                violations.push(violation(RuleSeverity::Error, "MINIMUM_AGE", format!(
+                    "{} would be {} on {}, below the minimum age of {}", party.full_name, age, date, min
+                )));
+            }
+        }
+    }
+}
+
+/// An earlier marriage (other than to `spouse_id`) binds unless the party
+/// is widowed; ordination is an impediment of its own. The marital status
+/// alone is only trusted when no earlier record exists and a new record is
+/// being made, since editing a marriage already shows the party as married.
+fn check_bond(
+    violations: &mut Vec<RuleViolation>,
+    party: &Party,
+    history: &[HistoryEntry],
+    spouse_id: Option<Uuid>,
+    new_record: bool,
+) {
+    let prior = history.iter().find(|r| {
+        r.sacrament_type == SacramentType::Marriage && (spouse_id.is_none() || r.spouse_id != spouse_id)
+    });
+    let already_married_to_spouse = spouse_id.is_some() && history.iter().any(|r| {
+        r.sacrament_type == SacramentType::Marriage && r.spouse_id == spouse_id
+    });
+
+    match (prior, party.marital_status) {
+        (Some(_), Some(MaritalStatus::Widowed)) => {}
+        (Some(p), _) => violations.push(violation(RuleSeverity::Error, "EXISTING_BOND", format!(
+            "{} has a marriage recorded on {} and is not recorded as widowed; a declaration of nullity is required (canon 1085)",
+            party.full_name, p.sacrament_date
+        ))),
+        (None, Some(status @ (MaritalStatus::Separated | MaritalStatus::Divorced))) if new_record && !already_married_to_spouse => {
+            violations.push(violation(RuleSeverity::Error, "EXISTING_BOND", format!(
+                "{} is recorded as {}, so an earlier bond exists (canon 1085)",
+                party.full_name, if status == MaritalStatus::Separated { "separated" } else { "divorced" }
+            )));
+        }
+        (None, Some(MaritalStatus::Married)) if new_record && !already_married_to_spouse => {
+            violations.push(violation(RuleSeverity::Warning, "MARITAL_STATUS_MARRIED", format!(
+                "{} is already recorded as married; confirm there is no earlier bond", party.full_name
+            )));
+        }
+        _ => {}
+    }
+
+    if history.iter().any(|r| r.sacrament_type == SacramentType::HolyOrders) {
+        violations.push(violation(RuleSeverity::Error, "HOLY_ORDERS_IMPEDIMENT", format!(
+            "{} is recorded as ordained (canon 1087)", party.full_name
+        )));
+    }
+}
+
+async fn check_spouse(
+    db: &PgPool,
+    parish_id: Uuid,
+    violations: &mut Vec<RuleViolation>,
+    member: &Party,
+    spouse_id: Uuid,
+    date: NaiveDate,
+) -> Result<(), (StatusCode, String)> {
+    if spouse_id == member.id {
+        violations.push(violation(RuleSeverity::Error, "SPOUSE_SAME_MEMBER", "The spouse is the member themself".to_string()));
+        return Ok(());
+    }
+
+    let spouse = fetch_party(db, spouse_id)
+        .await?
+        .ok_or((StatusCode::NOT_FOUND, "Spouse not found".to_string()))?;
+    let history = fetch_history(db, spouse.id, None).await?;
+
+    let baptised = history
+        .iter()
+        .any(|r| r.sacrament_type == SacramentType::Baptism && r.sacrament_date <= date);
+    if !baptised {
+        violations.push(violation(RuleSeverity::Warning, "DISPARITY_OF_WORSHIP", format!(
+            "No baptism is recorded for {}; if they are not baptised a dispensation is required (canon 1086)",
+            spouse.full_name
+        )));
+    }
+
+    if let Some(min) = min_age(db, parish_id, marriage_age_key(spouse.gender)).await? {
+        check_age(violations, &spouse, date, min);
+    }
+
+    check_bond(violations, &spouse, &history, Some(member.id), true);
+
+    if let (Some(a), Some(b)) = (member.gender, spouse.gender) {
+        if a == b {
+            violations.push(violation(RuleSeverity::Error, "SAME_GENDER", format!(
+                "{} and {} are recorded with the same gender (canon 1055)", member.full_name, spouse.full_name
+            )));
+        }
+    }
+
+    Ok(())
+}
+
+/// Linked godparents and sponsors must be old enough, confirmed, have
+/// received the Eucharist, be active in the parish and not be the
+/// candidate's parent (canon 874). Outsiders named in free text and
+/// witnesses are not checked.
+async fn check_sponsors(
+    db: &PgPool,
+    parish_id: Uuid,
+    violations: &mut Vec<RuleViolation>,
+    candidate: &Party,
+    kind: SacramentType,
+    date: NaiveDate,
+    sponsors: &[SponsorInput],
+) -> Result<(), (StatusCode, String)> {
+    let min = min_age(db, parish_id, "sponsor").await?;
+    let mut genders = Vec::new();
+
+    for input in sponsors.iter().filter(|s| s.role != SponsorRole::Witness) {
+        let Some(member_id) = input.member_id else { continue };
+        let role = sponsor::role_name(input.role);
+        if member_id == candidate.id {
+            violations.push(violation(RuleSeverity::Error, "SPONSOR_SELF", format!(
+                "{} cannot be their own {}", candidate.full_name, role
+            )));
+            continue;
+        }
+        let sponsor = fetch_party(db, member_id)
+            .await?
+            .ok_or((StatusCode::NOT_FOUND, "Sponsor not found".to_string()))?;
+        let history = fetch_history(db, member_id, None).await?;
+        let received = |t: SacramentType| history.iter().any(|r| r.sacrament_type == t && r.sacrament_date <= date);
+
+        if let Some(min) = min {
+            match sponsor.date_of_birth {
+                None => violations.push(violation(RuleSeverity::Warning, "SPONSOR_AGE_UNKNOWN", format!(
+                    "{} has no date of birth recorded, so the minimum age of {} for a {} cannot be checked",
+                    sponsor.full_name, min, role
+                ))),
+                Some(dob) if date.years_since(dob).unwrap_or(0) < min => {
+                    violations.push(violation(RuleSeverity::Error, "SPONSOR_MIN_AGE", format!(
+                        "{} would be {}, below the minimum age of {} for a {} (canon 874)",
+                        sponsor.full_name, date.years_since(dob).unwrap_or(0), min, role
+                    )));
+                }
+                _ => {}
+            }
+        }
+        if !received(SacramentType::Confirmation) {
+            violations.push(violation(RuleSeverity::Error, "SPONSOR_NOT_CONFIRMED", format!(
+                "{} is not recorded as confirmed (canon 874)", sponsor.full_name
+            )));
+        }
+        if !received(SacramentType::FirstCommunion) {
+            violations.push(violation(RuleSeverity::Error, "SPONSOR_NO_COMMUNION", format!(
+                "{} is not recorded as having received First Communion (canon 874)", sponsor.full_name
+            )));
+        }
+        if sponsor.date_of_death.is_some_and(|d| d < date) {
+            violations.push(violation(RuleSeverity::Error, "SPONSOR_DECEASED", format!(
+                "{} died before this date", sponsor.full_name
+            )));
+        } else if sponsor.is_active == Some(false) {
+            violations.push(violation(RuleSeverity::Error, "SPONSOR_NOT_PRACTICING", format!(
+                "{} is not an active member of the parish", sponsor.full_name
+            )));
+        }
+        let is_parent = candidate.family_id.is_some()
+            && sponsor.family_id == candidate.family_id
+            && candidate.family_role == Some(FamilyRole::Member)
+            && matches!(sponsor.family_role, Some(FamilyRole::Head | FamilyRole::Spouse));
+        if is_parent {
+            violations.push(violation(RuleSeverity::Error, "SPONSOR_IS_PARENT", format!(
+                "{} is a parent of {} and cannot be their {} (canon 874)", sponsor.full_name, candidate.full_name, role
+            )));
+        }
+        genders.extend(sponsor.gender);
+    }
+
+    if kind == SacramentType::Baptism && genders.len() == 2 && genders[0] == genders[1] {
+        violations.push(violation(RuleSeverity::Warning, "SPONSORS_SAME_SEX",
+            "Where there are two godparents they should be one man and one woman (canon 873)".to_string()
+        ));
+    }
+    Ok(())
+}
+
+/// Decides whether a checked sacrament may be saved. Returns the reason to
+/// store on the record when failed checks are overridden, or the 422
+/// response carrying the check when they are not.
+pub fn override_reason(
+    auth: &AuthUser,
+    check: &SacramentCheck,
+    reason: Option<&str>,
+) -> Result<Option<String>, Box<Response>> {
+    if check.can_save {
+        return Ok(None);
+    }
+    match reason.map(str::trim).filter(|r| !r.is_empty()) {
+        Some(reason) => {
+            rbac::require_admin(auth).map_err(|(status, _)| {
+                Box::new((status, "Only a parish administrator can override canonical checks".to_string()).into_response())
+            })?;
+            Ok(Some(reason.to_string()))
+        }
+        None => Err(Box::new((StatusCode::UNPROCESSABLE_ENTITY, Json(check)).into_response())),
+    }
+}
+
+/// Records who saved a sacrament past failed checks, why, and what failed.
+pub async fn log_override(
+    conn: &mut PgConnection,
+    auth: &AuthUser,
+    parish_id: Uuid,
+    record_id: Uuid,
+    reason: &str,
+    violations: &[RuleViolation],
+) -> Result<(), (StatusCode, String)> {
+    let details = serde_json::json!({ "reason": reason, "violations": violations });
+
+    sqlx::query(
+        r#"
+        INSERT INTO audit_log (user_id, parish_id, action_type, table_name, record_id, new_values)
+        VALUES ($1, $2, 'SACRAMENT_RULE_OVERRIDE', 'sacrament_record', $3, $4::jsonb)
+        "#
+    )
+    .bind(auth.user_id)
+    .bind(parish_id)
+    .bind(record_id)
+    .bind(details.to_string())
+    .execute(conn)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(())
+}
+
+fn sacrament_label(kind: SacramentType) -> &'static str {
+    match kind {
+        SacramentType::Baptism => "baptism",
+        SacramentType::FirstCommunion => "first communion",
+        SacramentType::Confirmation => "confirmation",
+        SacramentType::Marriage => "marriage",
+        SacramentType::HolyOrders => "holy orders",
+        SacramentType::AnointingOfSick => "anointing of the sick",
+    }
+}
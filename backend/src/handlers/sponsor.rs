@@ -0,0 +1,257 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    Json,
+};
+use sqlx::{PgConnection, PgPool};
+use uuid::Uuid;
+use crate::{AppState, handlers::auth::AuthUser, handlers::rbac};
+use crate::models::member::SacramentType;
+use crate::models::sponsor::{SacramentSponsor, SponsorInput, SponsorRole, Sponsorship};
+
+pub async fn list_sponsors(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Path(record_id): Path<Uuid>,
+) -> Result<Json<Vec<SacramentSponsor>>, (StatusCode, String)> {
+    let parish_id: Uuid = sqlx::query_scalar("SELECT parish_id FROM sacrament_record WHERE id = $1 AND deleted_at IS NULL")
+        .bind(record_id)
+        .fetch_optional(&state.db)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+        .ok_or((StatusCode::NOT_FOUND, "Sacrament record not found".to_string()))?;
+    rbac::resolve_parish_id(&auth, Some(parish_id))?;
+
+    Ok(Json(fetch_sponsors(&state.db, record_id).await?))
+}
+
+/// Everyone the member stood for as godparent, sponsor or witness.
+pub async fn list_sponsorships(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Path(member_id): Path<Uuid>,
+) -> Result<Json<Vec<Sponsorship>>, (StatusCode, String)> {
+    let parish_id: Uuid = sqlx::query_scalar("SELECT parish_id FROM member WHERE id = $1 AND deleted_at IS NULL")
+        .bind(member_id)
+        .fetch_optional(&state.db)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+        .ok_or((StatusCode::NOT_FOUND, "Member not found".to_string()))?;
+    rbac::resolve_parish_id(&auth, Some(parish_id))?;
+
+    let sponsorships = sqlx::query_as::<_, Sponsorship>(
+        r#"
+        SELECT sp.sacrament_record_id, sp.role, r.sacrament_type, r.sacrament_date,
+               m.id AS member_id, m.member_code, CONCAT_WS(' ', m.first_name, m.middle_name, m.last_name) AS member_name,
+               p.parish_name
+        FROM sacrament_sponsor sp
+        JOIN sacrament_record r ON r.id = sp.sacrament_record_id AND r.deleted_at IS NULL
+        JOIN member m ON m.id = r.member_id
+        JOIN parish p ON p.id = r.parish_id
+        WHERE sp.member_id = $1
+        ORDER BY r.sacrament_date DESC
+        "#
+    )
+    .bind(member_id)
+    .fetch_all(&state.db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(sponsorships))
+}
+
+pub async fn fetch_sponsors(db: &PgPool, record_id: Uuid) -> Result<Vec<SacramentSponsor>, (StatusCode, String)> {
+    sqlx::query_as::<_, SacramentSponsor>(
+        "SELECT * FROM sacrament_sponsor WHERE sacrament_record_id = $1 ORDER BY sort_order"
+    )
+    .bind(record_id)
+    .fetch_all(db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+}
+
+/// The linked sponsors already on a record, to check them again when the
+/// sacrament date changes.
+pub async fn current_inputs(db: &PgPool, record_id: Uuid) -> Result<Vec<SponsorInput>, (StatusCode, String)> {
+    Ok(fetch_sponsors(db, record_id)
+        .await?
+        .into_iter()
+        .map(|s| SponsorInput { role: s.role, member_id: s.member_id, full_name: Some(s.full_name) })
+        .collect())
+}
+
+/// Godparents belong to a baptism and sponsors to a confirmation, at most
+/// two of them (canon 873); witnesses may stand at any sacrament.
+pub fn validate(sacrament_type: SacramentType, sponsors: &[SponsorInput]) -> Result<(), (StatusCode, String)> {
+    for s in sponsors {
+        let allowed = match s.role {
+            SponsorRole::Godparent => sacrament_type == SacramentType::Baptism,
+            SponsorRole::Sponsor => sacrament_type == SacramentType::Confirmation,
+            SponsorRole::Witness => true,
+        };
+        if !allowed {
+            return Err((StatusCode::BAD_REQUEST, format!(
+                "A {} does not stand at this sacrament", role_name(s.role)
+            )));
+        }
+        if s.member_id.is_none() && s.full_name.as_deref().is_none_or(|n| n.trim().is_empty()) {
+            return Err((StatusCode::BAD_REQUEST, "Each sponsor needs a member_id or a full_name".to_string()));
+        }
+    }
+
+    if sponsors.iter().filter(|s| s.role != SponsorRole::Witness).count() > 2 {
+        return Err((StatusCode::BAD_REQUEST, "At most two godparents or sponsors may stand (canon 873)".to_string()));
+    }
+
+    let mut linked: Vec<Uuid> = sponsors.iter().filter_map(|s| s.member_id).collect();
+    let count = linked.len();
+    linked.sort();
+    linked.dedup();
+    if linked.len() != count {
+        return Err((StatusCode::BAD_REQUEST, "The same member is listed twice".to_string()));
+    }
+    Ok(())
+}
+
+pub fn role_name(role: SponsorRole) -> &'static str {
+    match role {
+        SponsorRole::Godparent => "godparent",
+        SponsorRole::Sponsor => "sponsor",
+        SponsorRole::Witness => "witness",
+    }
+}
+
+/// A sponsor ready to store, with the name as it will be printed.
+#[derive(Debug, Clone)]
+pub struct ResolvedSponsor {
+    pub role: SponsorRole,
+    pub member_id: Option<Uuid>,
+    pub full_name: String,
+}
+
+/// Fills in the names of linked members where no name was given.
+pub async fn resolve(conn: &mut PgConnection, sponsors: &[SponsorInput]) -> Result<Vec<ResolvedSponsor>, (StatusCode, String)> {
+    let mut resolved = Vec::with_capacity(sponsors.len());
+    for s in sponsors {
+        let given = s.full_name.as_deref().map(str::trim).filter(|n| !n.is_empty());
+        let full_name = match (given, s.member_id) {
+            (Some(name), _) => name.to_string(),
+            (None, Some(member_id)) => sqlx::query_scalar::<_, String>(
+                "SELECT CONCAT_WS(' ', first_name, middle_name, last_name) FROM member WHERE id = $1 AND deleted_at IS NULL"
+            )
+            .bind(member_id)
+            .fetch_optional(&mut *conn)
+            .await
+            .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+            .ok_or((StatusCode::NOT_FOUND, "Sponsor not found".to_string()))?,
+            (None, None) => continue,
+        };
+        resolved.push(ResolvedSponsor { role: s.role, member_id: s.member_id, full_name });
+    }
+    Ok(resolved)
+}
+
+/// Unlinked sponsors from the free-text name fields. Godparent names on
+/// sacraments that have no godparents or sponsors stay as text only.
+pub fn from_text(
+    sacrament_type: SacramentType,
+    godparent_1: Option<&str>,
+    godparent_2: Option<&str>,
+    witnesses: Option<&str>,
+) -> Vec<ResolvedSponsor> {
+    let role = match sacrament_type {
+        SacramentType::Baptism => Some(SponsorRole::Godparent),
+        SacramentType::Confirmation => Some(SponsorRole::Sponsor),
+        _ => None,
+    };
+    let godparents = role
+        .map(|role| {
+            [godparent_1, godparent_2]
+                .into_iter()
+                .flatten()
+                .map(|name| (role, name.trim().to_string()))
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+    let witnesses = witnesses
+        .map(|w| split_names(w).into_iter().map(|name| (SponsorRole::Witness, name)).collect::<Vec<_>>())
+        .unwrap_or_default();
+
+    godparents
+        .into_iter()
+        .chain(witnesses)
+        .filter(|(_, name)| !name.is_empty())
+        .map(|(role, full_name)| ResolvedSponsor { role, member_id: None, full_name })
+        .collect()
+}
+
+/// Names in a free-text list, separated by commas, semicolons, `&` or the
+/// word "and". The sponsor backfill migration splits the same way.
+fn split_names(text: &str) -> Vec<String> {
+    text.split([',', ';', '&'])
+        .flat_map(|part| {
+            let words = part.split_whitespace().collect::<Vec<_>>();
+            words.split(|w| *w == "and").map(|name| name.join(" ")).collect::<Vec<_>>()
+        })
+        .filter(|name| !name.is_empty())
+        .collect()
+}
+
+/// Keeps the member links of sponsors whose names were left unchanged when
+/// the free-text fields are edited.
+pub fn keep_links(mut sponsors: Vec<ResolvedSponsor>, current: &[SacramentSponsor]) -> Vec<ResolvedSponsor> {
+    for s in &mut sponsors {
+        s.member_id = current
+            .iter()
+            .find(|c| c.role == s.role && c.full_name == s.full_name)
+            .and_then(|c| c.member_id);
+    }
+    sponsors
+}
+
+/// The free-text fields as printed on registers and certificates:
+/// the two godparents or sponsors, and the witnesses.
+pub fn printed(sponsors: &[ResolvedSponsor]) -> (Option<String>, Option<String>, Option<String>) {
+    let mut godparents = sponsors.iter().filter(|s| s.role != SponsorRole::Witness).map(|s| s.full_name.clone());
+    let witnesses = sponsors
+        .iter()
+        .filter(|s| s.role == SponsorRole::Witness)
+        .map(|s| s.full_name.as_str())
+        .collect::<Vec<_>>()
+        .join(", ");
+    (godparents.next(), godparents.next(), Some(witnesses).filter(|w| !w.is_empty()))
+}
+
+/// Replaces the sponsors stored for a record.
+pub async fn replace(
+    conn: &mut PgConnection,
+    record_id: Uuid,
+    sponsors: &[ResolvedSponsor],
+) -> Result<Vec<SacramentSponsor>, (StatusCode, String)> {
+    sqlx::query("DELETE FROM sacrament_sponsor WHERE sacrament_record_id = $1")
+        .bind(record_id)
+        .execute(&mut *conn)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    let mut rows = Vec::with_capacity(sponsors.len());
+    for (i, s) in sponsors.iter().enumerate() {
+        let row = sqlx::query_as::<_, SacramentSponsor>(
+            r#"
+            INSERT INTO sacrament_sponsor (sacrament_record_id, role, member_id, full_name, sort_order)
+            VALUES ($1, $2, $3, $4, $5)
+            RETURNING *
+            "#
+        )
+        .bind(record_id)
+        .bind(s.role)
+        .bind(s.member_id)
+        .bind(&s.full_name)
+        .bind(i as i32)
+        .fetch_one(&mut *conn)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+        rows.push(row);
+    }
+    Ok(rows)
+}
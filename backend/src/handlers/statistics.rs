@@ -0,0 +1,570 @@
+use std::collections::HashMap;
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use sqlx::{PgConnection, PgPool};
+use uuid::Uuid;
+use chrono::{Datelike, Utc};
+use serde::Deserialize;
+use crate::{AppState, handlers::auth::AuthUser, handlers::rbac};
+use crate::export::{self, Cell, ExportFormat, ReportHeader, Row, Table};
+use crate::models::statistics::{
+    AdjustStatisticalLineRequest, CreateStatisticalReturnRequest, ReturnParish, StatisticalLine, StatisticalReturn,
+    StatisticalReturnDetail, StatisticalReturnStatus,
+};
+
+/// The figures on the return, in the order of the conference's form:
+/// (line code, section, label).
+const LINES: &[(&str, &str, &str)] = &[
+    ("BAPTISM_UNDER_1", "Baptisms", "Infants under 1 year"),
+    ("BAPTISM_1_TO_6", "Baptisms", "Children aged 1 to 6"),
+    ("BAPTISM_7_AND_OVER", "Baptisms", "Aged 7 and over"),
+    ("BAPTISM_AGE_UNKNOWN", "Baptisms", "Age not recorded"),
+    ("FIRST_COMMUNIONS", "Sacraments", "First Communions"),
+    ("CONFIRMATIONS", "Sacraments", "Confirmations"),
+    ("MARRIAGES_BOTH_MEMBERS", "Marriages", "Both parties Catholic members"),
+    ("MARRIAGES_ONE_MEMBER", "Marriages", "One party a Catholic member"),
+    ("CATHOLIC_POPULATION", "Population", "Catholic population"),
+    ("POPULATION_MALE", "Population", "Male"),
+    ("POPULATION_FEMALE", "Population", "Female"),
+    ("FAMILIES", "Population", "Catholic families"),
+    ("CATECHISTS", "Catechesis", "Catechists"),
+];
+
+#[derive(Debug, Deserialize)]
+pub struct StatisticalReturnQuery {
+    pub parish_id: Option<Uuid>,
+    /// Lists the diocesan return and every parish return in the diocese.
+    pub diocese_id: Option<Uuid>,
+    pub year: Option<i32>,
+}
+
+pub async fn list_statistical_returns(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Query(query): Query<StatisticalReturnQuery>,
+) -> Result<Json<Vec<StatisticalReturn>>, (StatusCode, String)> {
+    let (diocese_id, parish_id) = if query.parish_id.is_none() && (query.diocese_id.is_some() || auth.parish_id.is_none()) {
+        (Some(rbac::resolve_diocese_id(&state.db, &auth, query.diocese_id).await?), None)
+    } else {
+        (None, Some(rbac::resolve_parish_id(&auth, query.parish_id)?))
+    };
+
+    let returns = sqlx::query_as::<_, StatisticalReturn>(
+        r#"
+        SELECT r.*, p.parish_name
+        FROM statistical_return r
+        LEFT JOIN parish p ON p.id = r.parish_id
+        WHERE r.deleted_at IS NULL
+          AND ($1::uuid IS NULL OR r.diocese_id = $1)
+          AND ($2::uuid IS NULL OR r.parish_id = $2)
+          AND ($3::int IS NULL OR r.return_year = $3)
+        ORDER BY r.return_year DESC, r.parish_id NULLS FIRST, p.parish_name
+        "#
+    )
+    .bind(diocese_id)
+    .bind(parish_id)
+    .bind(query.year)
+    .fetch_all(&state.db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(returns))
+}
+
+/// The return with its figures, or rendered in the conference's tabular
+/// format.
+pub async fn get_statistical_return(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+    format: ExportFormat,
+) -> Result<Response, (StatusCode, String)> {
+    let statistical_return = fetch_return(&state.db, &auth, id).await?;
+    let detail = detail(&mut *acquire(&state).await?, statistical_return).await?;
+
+    if format == ExportFormat::Json {
+        return Ok(Json(detail).into_response());
+    }
+
+    let mut table = Table::new(&["Item", "From Registers", "Adjustment", "Returned", "Note"]);
+    let mut section = None;
+    for line in &detail.lines {
+        if section != Some(line.section.as_str()) {
+            section = Some(line.section.as_str());
+            table.rows.push(Row::heading(line.section.as_str()));
+        }
+        table.rows.push(Row::detail(vec![
+            Cell::text(line.label.as_str()),
+            Cell::Count(line.computed_value),
+            if line.adjustment == 0 { Cell::Empty } else { Cell::Count(line.adjustment) },
+            Cell::Count(line.total),
+            Cell::opt(line.adjustment_note.as_ref()),
+        ]));
+    }
+
+    let r = &detail.statistical_return;
+    let title = match r.status {
+        StatisticalReturnStatus::Draft => "Annual Statistical Return (Draft)",
+        StatisticalReturnStatus::Submitted => "Annual Statistical Return",
+    };
+    let header = match r.parish_id {
+        Some(parish_id) => ReportHeader::parish(parish_id, title, Some(r.return_year.to_string())),
+        None => ReportHeader::diocese(r.diocese_id, title, Some(r.return_year.to_string())),
+    };
+    export::respond_table(&state.db, &auth, format, header, table).await
+}
+
+/// Starts the return for a year with the figures from the registers.
+/// A diocesan return totals the parish returns for the year, or the
+/// registers of parishes that have none.
+pub async fn create_statistical_return(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Json(payload): Json<CreateStatisticalReturnRequest>,
+) -> Result<Json<StatisticalReturnDetail>, (StatusCode, String)> {
+    rbac::require_write(&auth)?;
+    if payload.return_year < 1900 || payload.return_year > Utc::now().year() {
+        return Err((StatusCode::BAD_REQUEST, "Returns are made for the current or a past year".to_string()));
+    }
+
+    let (diocese_id, parish_id) = match (payload.parish_id, payload.diocese_id) {
+        (None, Some(diocese_id)) => (rbac::resolve_diocese_id(&state.db, &auth, Some(diocese_id)).await?, None),
+        (requested, _) => {
+            let parish_id = rbac::resolve_parish_id(&auth, requested)?;
+            let diocese_id: Uuid = sqlx::query_scalar("SELECT diocese_id FROM parish WHERE id = $1 AND deleted_at IS NULL")
+                .bind(parish_id)
+                .fetch_optional(&state.db)
+                .await
+                .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+                .ok_or((StatusCode::NOT_FOUND, "Parish not found".to_string()))?;
+            (diocese_id, Some(parish_id))
+        }
+    };
+
+    let mut tx = state.db.begin().await.map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    let exists: bool = sqlx::query_scalar(
+        r#"
+        SELECT EXISTS (
+            SELECT 1 FROM statistical_return
+            WHERE return_year = $1 AND deleted_at IS NULL
+              AND (parish_id = $2 OR ($2::uuid IS NULL AND parish_id IS NULL AND diocese_id = $3))
+        )
+        "#
+    )
+    .bind(payload.return_year)
+    .bind(parish_id)
+    .bind(diocese_id)
+    .fetch_one(&mut *tx)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+    if exists {
+        return Err((StatusCode::CONFLICT, format!("A return for {} has already been started", payload.return_year)));
+    }
+
+    let statistical_return = sqlx::query_as::<_, StatisticalReturn>(
+        r#"
+        INSERT INTO statistical_return (diocese_id, parish_id, return_year, notes, created_by)
+        VALUES ($1, $2, $3, $4, $5)
+        RETURNING *
+        "#
+    )
+    .bind(diocese_id)
+    .bind(parish_id)
+    .bind(payload.return_year)
+    .bind(payload.notes)
+    .bind(auth.user_id)
+    .fetch_one(&mut *tx)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    refresh_lines(&mut tx, &statistical_return).await?;
+    let detail = detail(&mut tx, statistical_return).await?;
+
+    tx.commit().await.map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(detail))
+}
+
+/// Takes the figures from the registers again. Adjustments are kept.
+pub async fn recompute_statistical_return(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<StatisticalReturnDetail>, (StatusCode, String)> {
+    rbac::require_write(&auth)?;
+    let statistical_return = fetch_draft(&state.db, &auth, id).await?;
+
+    let mut tx = state.db.begin().await.map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+    lock_draft(&mut tx, id).await?;
+    refresh_lines(&mut tx, &statistical_return).await?;
+    let statistical_return = sqlx::query_as::<_, StatisticalReturn>(
+        "UPDATE statistical_return SET computed_at = NOW() WHERE id = $1 RETURNING *"
+    )
+    .bind(id)
+    .fetch_one(&mut *tx)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+    let detail = detail(&mut tx, statistical_return).await?;
+
+    tx.commit().await.map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(detail))
+}
+
+/// Corrects a figure by hand, with the reason noted on the return.
+pub async fn adjust_statistical_line(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Path((id, line_code)): Path<(Uuid, String)>,
+    Json(payload): Json<AdjustStatisticalLineRequest>,
+) -> Result<Json<StatisticalReturnDetail>, (StatusCode, String)> {
+    rbac::require_write(&auth)?;
+    let statistical_return = fetch_draft(&state.db, &auth, id).await?;
+    if !LINES.iter().any(|(code, _, _)| *code == line_code) {
+        return Err((StatusCode::NOT_FOUND, format!("The return has no line {}", line_code)));
+    }
+    let note = payload.adjustment_note.map(|n| n.trim().to_string()).filter(|n| !n.is_empty());
+    if payload.adjustment != 0 && note.is_none() {
+        return Err((StatusCode::BAD_REQUEST, "Note why the figure is adjusted".to_string()));
+    }
+
+    let mut tx = state.db.begin().await.map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+    lock_draft(&mut tx, id).await?;
+
+    let computed: i64 = sqlx::query_scalar(
+        "SELECT computed_value FROM statistical_return_line WHERE return_id = $1 AND line_code = $2"
+    )
+    .bind(id)
+    .bind(&line_code)
+    .fetch_one(&mut *tx)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+    if computed + payload.adjustment < 0 {
+        return Err((StatusCode::BAD_REQUEST, "The adjusted figure cannot be negative".to_string()));
+    }
+
+    sqlx::query(
+        r#"
+        UPDATE statistical_return_line
+        SET adjustment = $1, adjustment_note = $2, adjusted_by = $3, adjusted_at = NOW()
+        WHERE return_id = $4 AND line_code = $5
+        "#
+    )
+    .bind(payload.adjustment)
+    .bind(note)
+    .bind(auth.user_id)
+    .bind(id)
+    .bind(&line_code)
+    .execute(&mut *tx)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    let detail = detail(&mut tx, statistical_return).await?;
+    tx.commit().await.map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(detail))
+}
+
+/// Submits the return and locks its figures.
+pub async fn submit_statistical_return(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<StatisticalReturnDetail>, (StatusCode, String)> {
+    rbac::require_admin(&auth)?;
+    fetch_draft(&state.db, &auth, id).await?;
+
+    let mut conn = acquire(&state).await?;
+    let statistical_return = sqlx::query_as::<_, StatisticalReturn>(
+        r#"
+        UPDATE statistical_return SET status = 'SUBMITTED', submitted_by = $1, submitted_at = NOW()
+        WHERE id = $2 AND status = 'DRAFT'
+        RETURNING *
+        "#
+    )
+    .bind(auth.user_id)
+    .bind(id)
+    .fetch_optional(&mut *conn)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+    .ok_or((StatusCode::CONFLICT, "The return has already been submitted".to_string()))?;
+
+    Ok(Json(detail(&mut conn, statistical_return).await?))
+}
+
+pub async fn delete_statistical_return(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> Result<StatusCode, (StatusCode, String)> {
+    rbac::require_write(&auth)?;
+    fetch_draft(&state.db, &auth, id).await?;
+
+    let deleted = sqlx::query("UPDATE statistical_return SET deleted_at = NOW() WHERE id = $1 AND status = 'DRAFT'")
+        .bind(id)
+        .execute(&state.db)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+    if deleted.rows_affected() == 0 {
+        return Err((StatusCode::CONFLICT, "The return has been submitted and is locked".to_string()));
+    }
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+// ============================================================================
+// HELPERS
+// ============================================================================
+
+async fn acquire(state: &AppState) -> Result<sqlx::pool::PoolConnection<sqlx::Postgres>, (StatusCode, String)> {
+    state.db.acquire().await.map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+}
+
+/// Parish users see their parish's returns; diocesan users see every
+/// return in their diocese.
+async fn fetch_return(db: &PgPool, auth: &AuthUser, id: Uuid) -> Result<StatisticalReturn, (StatusCode, String)> {
+    let statistical_return = sqlx::query_as::<_, StatisticalReturn>(
+        r#"
+        SELECT r.*, p.parish_name
+        FROM statistical_return r
+        LEFT JOIN parish p ON p.id = r.parish_id
+        WHERE r.id = $1 AND r.deleted_at IS NULL
+        "#
+    )
+    .bind(id)
+    .fetch_optional(db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+    .ok_or((StatusCode::NOT_FOUND, "Statistical return not found".to_string()))?;
+
+    match statistical_return.parish_id {
+        Some(parish_id) if auth.parish_id.is_some() => { rbac::resolve_parish_id(auth, Some(parish_id))?; }
+        _ => { rbac::resolve_diocese_id(db, auth, Some(statistical_return.diocese_id)).await?; }
+    }
+    Ok(statistical_return)
+}
+
+async fn fetch_draft(db: &PgPool, auth: &AuthUser, id: Uuid) -> Result<StatisticalReturn, (StatusCode, String)> {
+    let statistical_return = fetch_return(db, auth, id).await?;
+    if statistical_return.status != StatisticalReturnStatus::Draft {
+        return Err((StatusCode::CONFLICT, "The return has been submitted and is locked".to_string()));
+    }
+    Ok(statistical_return)
+}
+
+/// Locks the return for the rest of the transaction, so a submit cannot
+/// slip in between the draft check and the changes to its figures.
+async fn lock_draft(conn: &mut PgConnection, id: Uuid) -> Result<(), (StatusCode, String)> {
+    let status: StatisticalReturnStatus = sqlx::query_scalar("SELECT status FROM statistical_return WHERE id = $1 FOR UPDATE")
+        .bind(id)
+        .fetch_one(conn)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+    if status != StatisticalReturnStatus::Draft {
+        return Err((StatusCode::CONFLICT, "The return has been submitted and is locked".to_string()));
+    }
+    Ok(())
+}
+
+async fn detail(
+    conn: &mut PgConnection,
+    statistical_return: StatisticalReturn,
+) -> Result<StatisticalReturnDetail, (StatusCode, String)> {
+    let mut lines = sqlx::query_as::<_, StatisticalLine>(
+        "SELECT *, computed_value + adjustment AS total FROM statistical_return_line WHERE return_id = $1"
+    )
+    .bind(statistical_return.id)
+    .fetch_all(&mut *conn)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    let position = |code: &str| LINES.iter().position(|(c, _, _)| *c == code).unwrap_or(LINES.len());
+    lines.sort_by_key(|l| position(&l.line_code));
+    for line in &mut lines {
+        if let Some((_, section, label)) = LINES.iter().find(|(c, _, _)| *c == line.line_code) {
+            line.section = section.to_string();
+            line.label = label.to_string();
+        }
+    }
+
+    let parishes = match statistical_return.parish_id {
+        Some(_) => None,
+        None => Some(
+            sqlx::query_as::<_, ReturnParish>(
+                r#"
+                SELECT p.id AS parish_id, p.parish_name, r.id AS return_id, r.status
+                FROM parish p
+                LEFT JOIN statistical_return r
+                     ON r.parish_id = p.id AND r.return_year = $2 AND r.deleted_at IS NULL
+                WHERE p.diocese_id = $1 AND p.deleted_at IS NULL
+                ORDER BY p.parish_name
+                "#
+            )
+            .bind(statistical_return.diocese_id)
+            .bind(statistical_return.return_year)
+            .fetch_all(&mut *conn)
+            .await
+            .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?,
+        ),
+    };
+
+    Ok(StatisticalReturnDetail { statistical_return, lines, parishes })
+}
+
+/// Writes the computed figures, keeping any adjustments already made.
+async fn refresh_lines(conn: &mut PgConnection, statistical_return: &StatisticalReturn) -> Result<(), (StatusCode, String)> {
+    let figures = match statistical_return.parish_id {
+        Some(parish_id) => compute(&mut *conn, &[parish_id], statistical_return.return_year).await?,
+        None => diocesan_figures(&mut *conn, statistical_return.diocese_id, statistical_return.return_year).await?,
+    };
+
+    let codes: Vec<&str> = LINES.iter().map(|(code, _, _)| *code).collect();
+    let values: Vec<i64> = codes.iter().map(|code| figures.get(*code).copied().unwrap_or(0)).collect();
+    sqlx::query(
+        r#"
+        INSERT INTO statistical_return_line (return_id, line_code, computed_value)
+        SELECT $1, UNNEST($2::text[]), UNNEST($3::bigint[])
+        ON CONFLICT (return_id, line_code) DO UPDATE SET computed_value = EXCLUDED.computed_value
+        "#
+    )
+    .bind(statistical_return.id)
+    .bind(&codes)
+    .bind(&values)
+    .execute(&mut *conn)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+    Ok(())
+}
+
+/// Totals the parish returns for the year, adjustments included; parishes
+/// without a return are counted from their registers.
+async fn diocesan_figures(
+    conn: &mut PgConnection,
+    diocese_id: Uuid,
+    year: i32,
+) -> Result<HashMap<String, i64>, (StatusCode, String)> {
+    let parishes: Vec<(Uuid, bool)> = sqlx::query_as(
+        r#"
+        SELECT p.id, EXISTS (
+            SELECT 1 FROM statistical_return r
+            WHERE r.parish_id = p.id AND r.return_year = $2 AND r.deleted_at IS NULL
+        )
+        FROM parish p
+        WHERE p.diocese_id = $1 AND p.deleted_at IS NULL
+        "#
+    )
+    .bind(diocese_id)
+    .bind(year)
+    .fetch_all(&mut *conn)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    let returned: Vec<Uuid> = parishes.iter().filter(|(_, r)| *r).map(|(id, _)| *id).collect();
+    let unreturned: Vec<Uuid> = parishes.iter().filter(|(_, r)| !*r).map(|(id, _)| *id).collect();
+
+    let mut figures: HashMap<String, i64> = sqlx::query_as::<_, (String, i64)>(
+        r#"
+        SELECT l.line_code, SUM(l.computed_value + l.adjustment)::bigint
+        FROM statistical_return r
+        JOIN statistical_return_line l ON l.return_id = r.id
+        WHERE r.parish_id = ANY($1) AND r.return_year = $2 AND r.deleted_at IS NULL
+        GROUP BY l.line_code
+        "#
+    )
+    .bind(&returned)
+    .bind(year)
+    .fetch_all(&mut *conn)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+    .into_iter()
+    .collect();
+
+    if !unreturned.is_empty() {
+        for (code, value) in compute(&mut *conn, &unreturned, year).await? {
+            *figures.entry(code).or_default() += value;
+        }
+    }
+    Ok(figures)
+}
+
+/// Counts the figures for the parishes from the registers. Sacraments are
+/// those celebrated in the parishes during the year; population, families
+/// and catechists are as they stand now.
+async fn compute(
+    conn: &mut PgConnection,
+    parish_ids: &[Uuid],
+    year: i32,
+) -> Result<HashMap<String, i64>, (StatusCode, String)> {
+    let rows: Vec<(String, i64)> = sqlx::query_as(
+        r#"
+        WITH r AS (
+            SELECT s.sacrament_type, s.member_id, s.spouse_id, AGE(s.sacrament_date, m.date_of_birth) AS age
+            FROM sacrament_record s
+            JOIN member m ON m.id = s.member_id
+            WHERE s.parish_id = ANY($1) AND s.deleted_at IS NULL AND EXTRACT(YEAR FROM s.sacrament_date) = $2
+        ),
+        sacraments AS (
+            SELECT
+                COUNT(*) FILTER (WHERE sacrament_type = 'BAPTISM' AND age < INTERVAL '1 year') AS baptism_under_1,
+                COUNT(*) FILTER (WHERE sacrament_type = 'BAPTISM' AND age >= INTERVAL '1 year' AND age < INTERVAL '7 years') AS baptism_1_to_6,
+                COUNT(*) FILTER (WHERE sacrament_type = 'BAPTISM' AND age >= INTERVAL '7 years') AS baptism_7_and_over,
+                COUNT(*) FILTER (WHERE sacrament_type = 'BAPTISM' AND age IS NULL) AS baptism_age_unknown,
+                COUNT(*) FILTER (WHERE sacrament_type = 'FIRST_COMMUNION') AS first_communions,
+                COUNT(*) FILTER (WHERE sacrament_type = 'CONFIRMATION') AS confirmations,
+                -- Both spouses' records describe one marriage
+                COUNT(DISTINCT LEAST(member_id, spouse_id)::text || GREATEST(member_id, spouse_id)::text)
+                    FILTER (WHERE sacrament_type = 'MARRIAGE' AND spouse_id IS NOT NULL) AS marriages_both_members,
+                COUNT(*) FILTER (WHERE sacrament_type = 'MARRIAGE' AND spouse_id IS NULL) AS marriages_one_member
+            FROM r
+        ),
+        population AS (
+            SELECT COUNT(*) AS total,
+                   COUNT(*) FILTER (WHERE gender = 'MALE') AS male,
+                   COUNT(*) FILTER (WHERE gender = 'FEMALE') AS female
+            FROM member
+            WHERE parish_id = ANY($1) AND deleted_at IS NULL AND COALESCE(is_active, TRUE) AND date_of_death IS NULL
+        ),
+        families AS (
+            SELECT COUNT(*) AS total FROM family
+            WHERE parish_id = ANY($1) AND deleted_at IS NULL AND COALESCE(is_active, TRUE)
+        ),
+        catechists AS (
+            SELECT COUNT(DISTINCT COALESCE(k.member_id::text, LOWER(k.full_name))) AS total
+            FROM catechesis_catechist k
+            JOIN catechesis_class c ON c.id = k.class_id
+            WHERE c.parish_id = ANY($1) AND c.deleted_at IS NULL
+              AND EXTRACT(YEAR FROM c.start_date) <= $2
+              AND (c.end_date IS NULL OR EXTRACT(YEAR FROM c.end_date) >= $2)
+        )
+        SELECT v.code, v.value
+        FROM sacraments s, population p, families f, catechists k,
+        LATERAL (VALUES
+            ('BAPTISM_UNDER_1', s.baptism_under_1),
+            ('BAPTISM_1_TO_6', s.baptism_1_to_6),
+            ('BAPTISM_7_AND_OVER', s.baptism_7_and_over),
+            ('BAPTISM_AGE_UNKNOWN', s.baptism_age_unknown),
+            ('FIRST_COMMUNIONS', s.first_communions),
+            ('CONFIRMATIONS', s.confirmations),
+            ('MARRIAGES_BOTH_MEMBERS', s.marriages_both_members),
+            ('MARRIAGES_ONE_MEMBER', s.marriages_one_member),
+            ('CATHOLIC_POPULATION', p.total),
+            ('POPULATION_MALE', p.male),
+            ('POPULATION_FEMALE', p.female),
+            ('FAMILIES', f.total),
+            ('CATECHISTS', k.total)
+        ) AS v(code, value)
+        "#
+    )
+    .bind(parish_ids)
+    .bind(year)
+    .fetch_all(conn)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(rows.into_iter().collect())
+}
@@ -0,0 +1,443 @@
+use axum::{
+    extract::{Path, State, Query},
+    http::StatusCode,
+    response::Response,
+    Json,
+};
+use uuid::Uuid;
+use serde::Deserialize;
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use sqlx::{postgres::PgPool, Postgres};
+use crate::{
+    AppState,
+    models::supplier::{
+        Supplier, CreateSupplierRequest, UpdateSupplierRequest, MergeSuppliersRequest,
+        SupplierStatement, SupplierStatementLine, SpendBySupplierLine, SpendBySupplierReport,
+    },
+    handlers::auth::AuthUser,
+    handlers::rbac,
+    export::{self, ExportFormat, ReportHeader},
+};
+
+#[derive(Debug, Deserialize)]
+pub struct SupplierQuery {
+    pub parish_id: Option<Uuid>,
+    pub search: Option<String>,
+    pub include_inactive: Option<bool>,
+}
+
+pub async fn list_suppliers(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Query(query): Query<SupplierQuery>,
+) -> Result<Json<Vec<Supplier>>, (StatusCode, String)> {
+    rbac::require_finance(&auth)?;
+    let parish_id = rbac::resolve_parish_id(&auth, query.parish_id)?;
+
+    let suppliers = sqlx::query_as::<_, Supplier>(
+        r#"
+        SELECT * FROM supplier
+        WHERE parish_id = $1 AND deleted_at IS NULL AND ($2 OR is_active)
+          AND ($3::text IS NULL OR supplier_name ILIKE '%' || $3 || '%' OR name_key LIKE '%' || normalize_payee_name($3) || '%')
+        ORDER BY supplier_name
+        "#
+    )
+    .bind(parish_id)
+    .bind(query.include_inactive.unwrap_or(false))
+    .bind(query.search)
+    .fetch_all(&state.db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(suppliers))
+}
+
+pub async fn get_supplier(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<Supplier>, (StatusCode, String)> {
+    rbac::require_finance(&auth)?;
+    let supplier = fetch_supplier(&state.db, id).await?;
+    rbac::resolve_parish_id(&auth, Some(supplier.parish_id))?;
+    Ok(Json(supplier))
+}
+
+pub async fn create_supplier(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Json(payload): Json<CreateSupplierRequest>,
+) -> Result<Json<Supplier>, (StatusCode, String)> {
+    rbac::require_finance(&auth)?;
+    let parish_id = rbac::resolve_parish_id(&auth, Some(payload.parish_id))?;
+    ensure_unique_name(&state.db, parish_id, &payload.supplier_name, None).await?;
+
+    let supplier = sqlx::query_as::<_, Supplier>(
+        r#"
+        INSERT INTO supplier (
+            parish_id, supplier_name, contact_person, phone, email, address, tax_number,
+            bank_name, bank_account, mobile_money_number, notes, created_by
+        )
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)
+        RETURNING *
+        "#
+    )
+    .bind(parish_id)
+    .bind(payload.supplier_name.trim())
+    .bind(payload.contact_person)
+    .bind(payload.phone)
+    .bind(payload.email)
+    .bind(payload.address)
+    .bind(payload.tax_number)
+    .bind(payload.bank_name)
+    .bind(payload.bank_account)
+    .bind(payload.mobile_money_number)
+    .bind(payload.notes)
+    .bind(auth.user_id)
+    .fetch_one(&state.db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    if payload.link_existing.unwrap_or(true) {
+        sqlx::query(
+            r#"
+            UPDATE expense_voucher SET supplier_id = $1
+            WHERE parish_id = $2 AND supplier_id IS NULL AND deleted_at IS NULL
+              AND normalize_payee_name(payee_name) = $3
+            "#
+        )
+        .bind(supplier.id)
+        .bind(parish_id)
+        .bind(&supplier.name_key)
+        .execute(&state.db)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+    }
+
+    Ok(Json(supplier))
+}
+
+pub async fn update_supplier(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+    Json(payload): Json<UpdateSupplierRequest>,
+) -> Result<Json<Supplier>, (StatusCode, String)> {
+    rbac::require_finance(&auth)?;
+    let existing = fetch_supplier(&state.db, id).await?;
+    rbac::resolve_parish_id(&auth, Some(existing.parish_id))?;
+
+    if let Some(name) = &payload.supplier_name {
+        ensure_unique_name(&state.db, existing.parish_id, name, Some(id)).await?;
+    }
+
+    let supplier = sqlx::query_as::<_, Supplier>(
+        r#"
+        UPDATE supplier SET
+            supplier_name = $1,
+            contact_person = $2,
+            phone = $3,
+            email = $4,
+            address = $5,
+            tax_number = $6,
+            bank_name = $7,
+            bank_account = $8,
+            mobile_money_number = $9,
+            notes = $10,
+            is_active = $11,
+            updated_at = NOW()
+        WHERE id = $12
+        RETURNING *
+        "#
+    )
+    .bind(payload.supplier_name.map_or(existing.supplier_name, |n| n.trim().to_string()))
+    .bind(payload.contact_person.or(existing.contact_person))
+    .bind(payload.phone.or(existing.phone))
+    .bind(payload.email.or(existing.email))
+    .bind(payload.address.or(existing.address))
+    .bind(payload.tax_number.or(existing.tax_number))
+    .bind(payload.bank_name.or(existing.bank_name))
+    .bind(payload.bank_account.or(existing.bank_account))
+    .bind(payload.mobile_money_number.or(existing.mobile_money_number))
+    .bind(payload.notes.or(existing.notes))
+    .bind(payload.is_active.unwrap_or(existing.is_active))
+    .bind(id)
+    .fetch_one(&state.db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(supplier))
+}
+
+pub async fn delete_supplier(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> Result<StatusCode, (StatusCode, String)> {
+    rbac::require_finance(&auth)?;
+    let supplier = fetch_supplier(&state.db, id).await?;
+    rbac::resolve_parish_id(&auth, Some(supplier.parish_id))?;
+
+    sqlx::query("UPDATE supplier SET deleted_at = NOW(), is_active = FALSE WHERE id = $1")
+        .bind(id)
+        .execute(&state.db)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Moves the vouchers of duplicate suppliers onto this one and removes the
+/// duplicates.
+pub async fn merge_suppliers(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+    Json(payload): Json<MergeSuppliersRequest>,
+) -> Result<Json<Supplier>, (StatusCode, String)> {
+    rbac::require_finance(&auth)?;
+    let target = fetch_supplier(&state.db, id).await?;
+    rbac::resolve_parish_id(&auth, Some(target.parish_id))?;
+
+    if payload.supplier_ids.is_empty() || payload.supplier_ids.contains(&id) {
+        return Err((StatusCode::BAD_REQUEST, "Give the other suppliers to merge into this one".to_string()));
+    }
+    for other in &payload.supplier_ids {
+        supplier_for_parish(&state.db, *other, target.parish_id).await?;
+    }
+
+    let mut tx = state.db.begin().await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    sqlx::query("UPDATE expense_voucher SET supplier_id = $1 WHERE supplier_id = ANY($2)")
+        .bind(id)
+        .bind(&payload.supplier_ids)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    sqlx::query("UPDATE supplier SET deleted_at = NOW(), is_active = FALSE WHERE id = ANY($1)")
+        .bind(&payload.supplier_ids)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    tx.commit().await.map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(target))
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PeriodQuery {
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+}
+
+/// Vouchers raised to one supplier in a period, with what is still owed.
+pub async fn get_supplier_statement(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+    Query(query): Query<PeriodQuery>,
+    format: ExportFormat,
+) -> Result<Response, (StatusCode, String)> {
+    rbac::require_finance(&auth)?;
+    let supplier = fetch_supplier_any(&state.db, id).await?;
+    rbac::resolve_parish_id(&auth, Some(supplier.parish_id))?;
+
+    let lines = sqlx::query_as::<_, SupplierStatementLine>(
+        r#"
+        SELECT id AS voucher_id, expense_date, voucher_number, description,
+               COALESCE(approval_status::text, 'PENDING') AS approval_status, amount, COALESCE(paid, FALSE) AS paid
+        FROM expense_voucher
+        WHERE supplier_id = $1 AND deleted_at IS NULL AND expense_date BETWEEN $2 AND $3
+        ORDER BY expense_date, created_at
+        "#
+    )
+    .bind(id)
+    .bind(query.start_date)
+    .bind(query.end_date)
+    .fetch_all(&state.db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    let outstanding: Decimal = sqlx::query_scalar(
+        r#"
+        SELECT COALESCE(SUM(amount), 0) FROM expense_voucher
+        WHERE supplier_id = $1 AND deleted_at IS NULL AND expense_date <= $2
+          AND approval_status = 'APPROVED' AND paid = FALSE
+        "#
+    )
+    .bind(id)
+    .bind(query.end_date)
+    .fetch_one(&state.db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    let statement = SupplierStatement {
+        supplier_id: supplier.id,
+        supplier_name: supplier.supplier_name.clone(),
+        start_date: query.start_date,
+        end_date: query.end_date,
+        total_approved: lines.iter().filter(|l| l.approval_status == "APPROVED").map(|l| l.amount).sum(),
+        total_paid: lines.iter().filter(|l| l.paid).map(|l| l.amount).sum(),
+        outstanding,
+        lines,
+    };
+
+    let header = ReportHeader::parish(
+        supplier.parish_id,
+        &format!("Supplier Statement - {}", supplier.supplier_name),
+        Some(export::period_label(query.start_date, query.end_date)),
+    );
+    export::respond(&state.db, &auth, format, header, statement).await
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SpendQuery {
+    pub parish_id: Uuid,
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+}
+
+/// Approved spending per supplier, largest first. Vouchers without a
+/// supplier are grouped by their payee name.
+pub async fn get_spend_by_supplier(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Query(query): Query<SpendQuery>,
+    format: ExportFormat,
+) -> Result<Response, (StatusCode, String)> {
+    let parish_id = rbac::resolve_parish_id(&auth, Some(query.parish_id))?;
+
+    let mut lines = sqlx::query_as::<_, SpendBySupplierLine>(
+        r#"
+        SELECT v.supplier_id,
+               COALESCE(MAX(s.supplier_name), MIN(btrim(v.payee_name))) AS supplier_name,
+               COUNT(*) AS voucher_count,
+               SUM(v.amount) AS total_amount,
+               COALESCE(SUM(v.amount) FILTER (WHERE v.paid), 0) AS paid_amount
+        FROM expense_voucher v
+        LEFT JOIN supplier s ON s.id = v.supplier_id
+        WHERE v.parish_id = $1 AND v.deleted_at IS NULL AND v.approval_status = 'APPROVED'
+          AND v.expense_date BETWEEN $2 AND $3
+        GROUP BY v.supplier_id, CASE WHEN v.supplier_id IS NULL THEN normalize_payee_name(v.payee_name) END
+        ORDER BY total_amount DESC, supplier_name
+        "#
+    )
+    .bind(parish_id)
+    .bind(query.start_date)
+    .bind(query.end_date)
+    .fetch_all(&state.db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    let total_amount: Decimal = lines.iter().map(|l| l.total_amount).sum();
+    let total_paid: Decimal = lines.iter().map(|l| l.paid_amount).sum();
+    if !total_amount.is_zero() {
+        for line in &mut lines {
+            line.share_percent = Some((line.total_amount / total_amount * Decimal::ONE_HUNDRED).round_dp(2));
+        }
+    }
+
+    let report = SpendBySupplierReport {
+        start_date: query.start_date,
+        end_date: query.end_date,
+        lines,
+        total_amount,
+        total_paid,
+    };
+
+    let header = ReportHeader::parish(parish_id, "Spend by Supplier", Some(export::period_label(query.start_date, query.end_date)));
+    export::respond(&state.db, &auth, format, header, report).await
+}
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+async fn fetch_supplier<'e, E>(db: E, id: Uuid) -> Result<Supplier, (StatusCode, String)>
+where
+    E: sqlx::Executor<'e, Database = Postgres>,
+{
+    sqlx::query_as::<_, Supplier>("SELECT * FROM supplier WHERE id = $1 AND deleted_at IS NULL")
+        .bind(id)
+        .fetch_optional(db)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+        .ok_or((StatusCode::NOT_FOUND, "Supplier not found".to_string()))
+}
+
+/// Statements stay available for suppliers that were merged or removed.
+async fn fetch_supplier_any(db: &PgPool, id: Uuid) -> Result<Supplier, (StatusCode, String)> {
+    sqlx::query_as::<_, Supplier>("SELECT * FROM supplier WHERE id = $1")
+        .bind(id)
+        .fetch_optional(db)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+        .ok_or((StatusCode::NOT_FOUND, "Supplier not found".to_string()))
+}
+
+/// Loads an active supplier and checks that it belongs to the given parish.
+pub async fn supplier_for_parish<'e, E>(db: E, supplier_id: Uuid, parish_id: Uuid) -> Result<Supplier, (StatusCode, String)>
+where
+    E: sqlx::Executor<'e, Database = Postgres>,
+{
+    let supplier = fetch_supplier(db, supplier_id).await?;
+    if supplier.parish_id != parish_id {
+        return Err((StatusCode::BAD_REQUEST, "Supplier does not belong to this parish".to_string()));
+    }
+    if !supplier.is_active {
+        return Err((StatusCode::BAD_REQUEST, format!("Supplier '{}' is inactive", supplier.supplier_name)));
+    }
+    Ok(supplier)
+}
+
+/// The supplier a free-text payee name belongs to: one registered under
+/// the same normalised name, or the supplier earlier vouchers to that
+/// payee were linked to.
+pub async fn match_payee<'e, E>(db: E, parish_id: Uuid, payee_name: &str) -> Result<Option<Uuid>, (StatusCode, String)>
+where
+    E: sqlx::Executor<'e, Database = Postgres>,
+{
+    sqlx::query_scalar(
+        r#"
+        (SELECT id FROM supplier
+         WHERE parish_id = $1 AND deleted_at IS NULL AND is_active AND name_key = normalize_payee_name($2))
+        UNION ALL
+        (SELECT v.supplier_id FROM expense_voucher v
+         JOIN supplier s ON s.id = v.supplier_id AND s.deleted_at IS NULL AND s.is_active
+         WHERE v.parish_id = $1 AND normalize_payee_name(v.payee_name) = normalize_payee_name($2)
+         ORDER BY v.created_at DESC LIMIT 1)
+        LIMIT 1
+        "#
+    )
+    .bind(parish_id)
+    .bind(payee_name)
+    .fetch_optional(db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+}
+
+async fn ensure_unique_name(db: &PgPool, parish_id: Uuid, name: &str, except: Option<Uuid>) -> Result<(), (StatusCode, String)> {
+    let clash: Option<String> = sqlx::query_scalar(
+        r#"
+        SELECT supplier_name FROM supplier
+        WHERE parish_id = $1 AND deleted_at IS NULL AND name_key = normalize_payee_name($2)
+          AND ($3::uuid IS NULL OR id <> $3)
+        "#
+    )
+    .bind(parish_id)
+    .bind(name)
+    .bind(except)
+    .fetch_optional(db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    match clash {
+        Some(existing) => Err((StatusCode::CONFLICT, format!("Supplier '{}' is already registered", existing))),
+        None if name.trim().is_empty() => Err((StatusCode::BAD_REQUEST, "Supplier name is required".to_string())),
+        None => Ok(()),
+    }
+}
@@ -4,10 +4,11 @@ use axum::{
     Json,
 };
 use uuid::Uuid;
-use crate::{AppState, models::transaction::{IncomeTransaction, ExpenseVoucher, TransactionCategory, PaymentMethod}, handlers::auth::AuthUser, handlers::rbac};
+use crate::{AppState, models::transaction::{IncomeTransaction, ExpenseVoucher, TransactionCategory, PaymentMethod}, handlers::auth::AuthUser, handlers::rbac, handlers::fund, handlers::cashbook, handlers::supplier, handlers::currency, handlers::budget, models::budget::BudgetOverrunPolicy};
 use serde::Deserialize;
 use rust_decimal::Decimal;
 use chrono::NaiveDate;
+use sqlx::PgConnection;
 
 #[derive(Debug, Deserialize)]
 pub struct ListTransactionsQuery {
@@ -21,8 +22,13 @@ pub struct CreateIncomeRequest {
     pub parish_id: Uuid,
     pub member_id: Option<Uuid>,
     pub family_id: Option<Uuid>,
+    pub fund_id: Option<Uuid>,
+    pub cashbook_id: Option<Uuid>,
     pub category: TransactionCategory,
+    /// In `currency_code` when given, otherwise the base currency.
     pub amount: Decimal,
+    pub currency_code: Option<String>,
+    pub exchange_rate: Option<Decimal>,
     pub payment_method: PaymentMethod,
     pub transaction_date: NaiveDate,
     pub description: Option<String>,
@@ -33,8 +39,14 @@ pub struct CreateIncomeRequest {
 #[derive(Debug, Deserialize)]
 pub struct CreateExpenseRequest {
     pub parish_id: Uuid,
+    pub fund_id: Option<Uuid>,
+    pub cashbook_id: Option<Uuid>,
+    pub supplier_id: Option<Uuid>,
     pub category: TransactionCategory,
+    /// In `currency_code` when given, otherwise the base currency.
     pub amount: Decimal,
+    pub currency_code: Option<String>,
+    pub exchange_rate: Option<Decimal>,
     pub payment_method: PaymentMethod,
     pub payee_name: String,
     pub payee_phone: Option<String>,
@@ -75,13 +87,40 @@ pub async fn create_income_transaction(
     rbac::require_finance(&auth)?;
     let parish_id = rbac::resolve_parish_id(&auth, Some(payload.parish_id))?;
 
-    let transaction = sqlx::query_as::<_, IncomeTransaction>(
+    let mut conn = state.db.acquire().await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+    let transaction = record_income(&mut conn, parish_id, payload).await?;
+
+    Ok(Json(transaction))
+}
+
+/// Inserts an income transaction after checking its fund, booking foreign
+/// currency at its base amount. Everything is read and written on `conn`,
+/// so a caller can record it inside its own transaction. Shared with the
+/// recurring-income job.
+pub async fn record_income(
+    conn: &mut PgConnection,
+    parish_id: Uuid,
+    payload: CreateIncomeRequest,
+) -> Result<IncomeTransaction, (StatusCode, String)> {
+    if let Some(fund_id) = payload.fund_id {
+        fund::fund_for_parish(&mut *conn, fund_id, parish_id).await?;
+    }
+    if let Some(cashbook_id) = payload.cashbook_id {
+        cashbook::cashbook_for_parish(&mut *conn, cashbook_id, parish_id).await?;
+    }
+    let amount = currency::to_base(
+        conn, parish_id, payload.currency_code.as_deref(), payload.exchange_rate, payload.amount, payload.transaction_date,
+    ).await?;
+
+    sqlx::query_as::<_, IncomeTransaction>(
         r#"
         INSERT INTO income_transaction (
             parish_id, member_id, family_id, category, amount, payment_method,
-            transaction_date, description, reference_number, received_by
+            transaction_date, description, reference_number, received_by, fund_id,
+            cashbook_id, currency_code, original_amount, exchange_rate
         )
-        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15)
         RETURNING *
         "#
     )
@@ -89,17 +128,20 @@ pub async fn create_income_transaction(
     .bind(payload.member_id)
     .bind(payload.family_id)
     .bind(payload.category)
-    .bind(payload.amount)
+    .bind(amount.amount)
     .bind(payload.payment_method)
     .bind(payload.transaction_date)
     .bind(payload.description)
     .bind(payload.reference_number)
     .bind(payload.received_by)
-    .fetch_one(&state.db)
+    .bind(payload.fund_id)
+    .bind(payload.cashbook_id)
+    .bind(amount.currency_code)
+    .bind(amount.original_amount)
+    .bind(amount.exchange_rate)
+    .fetch_one(conn)
     .await
-    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
-
-    Ok(Json(transaction))
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
 }
 
 // Expense Vouchers Handlers
@@ -134,27 +176,143 @@ pub async fn create_expense_voucher(
     rbac::require_finance(&auth)?;
     let parish_id = rbac::resolve_parish_id(&auth, Some(payload.parish_id))?;
 
+    let mut tx = state.db.begin().await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+    let voucher = raise_expense_voucher(&mut tx, parish_id, payload, auth.user_id).await?;
+    tx.commit().await.map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(voucher))
+}
+
+/// Inserts a pending expense voucher after the fund guard and budget check,
+/// links it to its supplier, then raises any budget alerts. Foreign currency
+/// is converted first, so the guards and budget see the base amount. Every
+/// lookup and write goes through `conn`, so a caller can raise it inside its
+/// own transaction without waiting on a second pooled connection. Shared
+/// with the recurring-expense job.
+pub async fn raise_expense_voucher(
+    conn: &mut PgConnection,
+    parish_id: Uuid,
+    mut payload: CreateExpenseRequest,
+    requested_by: Uuid,
+) -> Result<ExpenseVoucher, (StatusCode, String)> {
+    let supplier_id = match payload.supplier_id {
+        Some(supplier_id) => {
+            let supplier = supplier::supplier_for_parish(&mut *conn, supplier_id, parish_id).await?;
+            if payload.payee_name.trim().is_empty() {
+                payload.payee_name = supplier.supplier_name;
+            }
+            payload.payee_phone = payload.payee_phone.or(supplier.phone);
+            Some(supplier_id)
+        }
+        None => supplier::match_payee(&mut *conn, parish_id, &payload.payee_name).await?,
+    };
+    if payload.payee_name.trim().is_empty() {
+        return Err((StatusCode::BAD_REQUEST, "Payee name or supplier is required".to_string()));
+    }
+    let amount = currency::to_base(
+        conn, parish_id, payload.currency_code.as_deref(), payload.exchange_rate, payload.amount, payload.expense_date,
+    ).await?;
+    if let Some(cashbook_id) = payload.cashbook_id {
+        cashbook::cashbook_for_parish(&mut *conn, cashbook_id, parish_id).await?;
+    }
+    if let Some(fund_id) = payload.fund_id {
+        fund::guard_expense(conn, parish_id, fund_id, amount.amount).await?;
+    }
+
+    let check = budget::check_expense(conn, parish_id, payload.category, payload.expense_date, amount.amount).await?;
+    let mut overrun_approval_required = false;
+    let budget_warning = match &check {
+        Some(c) if c.exceeds_budget => {
+            let warning = format!(
+                "Exceeds the budget for this category by {} ({}% of {} used)",
+                -c.remaining, c.percent_used, c.budget
+            );
+            match budget::overrun_policy(&mut *conn, parish_id).await? {
+                BudgetOverrunPolicy::Block => return Err((StatusCode::BAD_REQUEST, warning)),
+                BudgetOverrunPolicy::RequireApproval => overrun_approval_required = true,
+                BudgetOverrunPolicy::Warn => {}
+            }
+            Some(warning)
+        }
+        _ => None,
+    };
+
     let voucher = sqlx::query_as::<_, ExpenseVoucher>(
         r#"
         INSERT INTO expense_voucher (
             parish_id, category, amount, payment_method,
             payee_name, payee_phone, expense_date, description,
-            reference_number, requested_by
+            reference_number, requested_by, fund_id,
+            budget_overrun, budget_warning, overrun_approval_required, cashbook_id,
+            supplier_id, currency_code, original_amount, exchange_rate
         )
-        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18, $19)
         RETURNING *
         "#
     )
     .bind(parish_id)
     .bind(payload.category)
-    .bind(payload.amount)
+    .bind(amount.amount)
     .bind(payload.payment_method)
     .bind(payload.payee_name)
     .bind(payload.payee_phone)
     .bind(payload.expense_date)
     .bind(payload.description)
     .bind(payload.reference_number)
+    .bind(requested_by)
+    .bind(payload.fund_id)
+    .bind(budget_warning.is_some())
+    .bind(budget_warning)
+    .bind(overrun_approval_required)
+    .bind(payload.cashbook_id)
+    .bind(supplier_id)
+    .bind(amount.currency_code)
+    .bind(amount.original_amount)
+    .bind(amount.exchange_rate)
+    .fetch_one(&mut *conn)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    if let Some(check) = &check {
+        budget::raise_alerts(conn, parish_id, check, voucher.id).await?;
+    }
+
+    Ok(voucher)
+}
+
+/// Signs off a voucher that was held because it exceeds its budget.
+pub async fn approve_voucher_overrun(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ExpenseVoucher>, (StatusCode, String)> {
+    rbac::require_permission(&state.db, &auth, "budgets.approve").await?;
+
+    let voucher = sqlx::query_as::<_, ExpenseVoucher>(
+        "SELECT * FROM expense_voucher WHERE id = $1 AND deleted_at IS NULL"
+    )
+    .bind(id)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+    .ok_or((StatusCode::NOT_FOUND, "Voucher not found".to_string()))?;
+    rbac::resolve_parish_id(&auth, Some(voucher.parish_id))?;
+
+    if !voucher.overrun_approval_required || voucher.overrun_approved_at.is_some() {
+        return Err((StatusCode::BAD_REQUEST, "Voucher is not awaiting budget overrun approval".to_string()));
+    }
+
+    let voucher = sqlx::query_as::<_, ExpenseVoucher>(
+        r#"
+        UPDATE expense_voucher
+        SET overrun_approved_by = $1, overrun_approved_at = NOW(), updated_at = NOW()
+        WHERE id = $2
+        RETURNING *
+        "#
+    )
     .bind(auth.user_id)
+    .bind(id)
     .fetch_one(&state.db)
     .await
     .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
@@ -0,0 +1,512 @@
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    Json,
+};
+use sqlx::PgConnection;
+use uuid::Uuid;
+use serde::Deserialize;
+use crate::{AppState, handlers::auth::AuthUser, handlers::rbac};
+use crate::models::transfer::{
+    CreateMemberTransferRequest, DecideMemberTransferRequest, MemberTransfer, MemberTransferDetail,
+    MemberTransferStatus, TransferredMember,
+};
+
+const TRANSFER_WITH_NAMES: &str = r#"
+    SELECT t.*, fp.parish_name AS from_parish_name, tp.parish_name AS to_parish_name, f.family_name
+    FROM member_transfer t
+    JOIN parish fp ON fp.id = t.from_parish_id
+    JOIN parish tp ON tp.id = t.to_parish_id
+    LEFT JOIN family f ON f.id = t.family_id
+"#;
+
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum TransferDirection {
+    Incoming,
+    Outgoing,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MemberTransferQuery {
+    pub parish_id: Option<Uuid>,
+    /// Both directions when absent.
+    pub direction: Option<TransferDirection>,
+    pub status: Option<MemberTransferStatus>,
+}
+
+pub async fn list_member_transfers(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Query(query): Query<MemberTransferQuery>,
+) -> Result<Json<Vec<MemberTransfer>>, (StatusCode, String)> {
+    let parish_id = rbac::resolve_parish_id(&auth, query.parish_id)?;
+    let incoming = query.direction != Some(TransferDirection::Outgoing);
+    let outgoing = query.direction != Some(TransferDirection::Incoming);
+
+    let transfers = sqlx::query_as::<_, MemberTransfer>(&format!(
+        r#"{}
+        WHERE (($2 AND t.to_parish_id = $1) OR ($3 AND t.from_parish_id = $1))
+          AND ($4::member_transfer_status IS NULL OR t.status = $4)
+        ORDER BY t.created_at DESC"#,
+        TRANSFER_WITH_NAMES
+    ))
+    .bind(parish_id)
+    .bind(incoming)
+    .bind(outgoing)
+    .bind(query.status)
+    .fetch_all(&state.db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(transfers))
+}
+
+pub async fn get_member_transfer(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<MemberTransferDetail>, (StatusCode, String)> {
+    let mut conn = acquire(&state).await?;
+    let transfer = fetch_transfer(&mut conn, &auth, id).await?;
+    Ok(Json(detail(&mut conn, transfer).await?))
+}
+
+/// The transfers a member has been through, most recent first.
+pub async fn list_member_transfer_history(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Path(member_id): Path<Uuid>,
+) -> Result<Json<Vec<MemberTransfer>>, (StatusCode, String)> {
+    let parish_id: Uuid = sqlx::query_scalar("SELECT parish_id FROM member WHERE id = $1 AND deleted_at IS NULL")
+        .bind(member_id)
+        .fetch_optional(&state.db)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+        .ok_or((StatusCode::NOT_FOUND, "Member not found".to_string()))?;
+    rbac::resolve_parish_id(&auth, Some(parish_id))?;
+
+    let transfers = sqlx::query_as::<_, MemberTransfer>(&format!(
+        r#"{}
+        JOIN member_transfer_member tm ON tm.transfer_id = t.id
+        WHERE tm.member_id = $1
+        ORDER BY t.created_at DESC"#,
+        TRANSFER_WITH_NAMES
+    ))
+    .bind(member_id)
+    .fetch_all(&state.db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(transfers))
+}
+
+/// Asks another parish of the diocese to release a family, or some of its
+/// members, to the user's parish.
+pub async fn create_member_transfer(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Json(payload): Json<CreateMemberTransferRequest>,
+) -> Result<Json<MemberTransferDetail>, (StatusCode, String)> {
+    rbac::require_write(&auth)?;
+    let to_parish_id = rbac::resolve_parish_id(&auth, payload.to_parish_id)?;
+    let from_parish_id = payload.from_parish_id;
+    if from_parish_id == to_parish_id {
+        return Err((StatusCode::BAD_REQUEST, "Members are already in this parish".to_string()));
+    }
+
+    let mut tx = state.db.begin().await.map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    let same_diocese: Option<bool> = sqlx::query_scalar(
+        r#"
+        SELECT f.diocese_id = t.diocese_id FROM parish f, parish t
+        WHERE f.id = $1 AND t.id = $2 AND f.deleted_at IS NULL AND t.deleted_at IS NULL
+        "#
+    )
+    .bind(from_parish_id)
+    .bind(to_parish_id)
+    .fetch_optional(&mut *tx)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+    match same_diocese {
+        None => return Err((StatusCode::NOT_FOUND, "Parish not found".to_string())),
+        Some(false) => return Err((StatusCode::BAD_REQUEST, "Transfers are between parishes of the same diocese".to_string())),
+        Some(true) => {}
+    }
+
+    if let Some(scc_id) = payload.to_scc_id {
+        let in_parish: bool = sqlx::query_scalar("SELECT EXISTS (SELECT 1 FROM scc WHERE id = $1 AND parish_id = $2 AND deleted_at IS NULL)")
+            .bind(scc_id)
+            .bind(to_parish_id)
+            .fetch_one(&mut *tx)
+            .await
+            .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+        if !in_parish {
+            return Err((StatusCode::BAD_REQUEST, "The small Christian community is not in the receiving parish".to_string()));
+        }
+    }
+
+    let family_code = payload.family_code.as_deref().map(str::trim).filter(|c| !c.is_empty());
+    let (family_id, members): (Option<Uuid>, Vec<(Uuid, String)>) = match (family_code, payload.member_codes.is_empty()) {
+        (Some(code), true) => {
+            let family_id: Uuid = sqlx::query_scalar(
+                "SELECT id FROM family WHERE parish_id = $1 AND family_code = $2 AND deleted_at IS NULL"
+            )
+            .bind(from_parish_id)
+            .bind(code)
+            .fetch_optional(&mut *tx)
+            .await
+            .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+            .ok_or((StatusCode::NOT_FOUND, format!("No family {} in the sending parish", code)))?;
+            // The whole family moves, its departed members with it
+            let members = sqlx::query_as(
+                "SELECT id, member_code FROM member WHERE family_id = $1 AND parish_id = $2 AND deleted_at IS NULL"
+            )
+            .bind(family_id)
+            .bind(from_parish_id)
+            .fetch_all(&mut *tx)
+            .await
+            .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+            (Some(family_id), members)
+        }
+        (None, false) => {
+            let members: Vec<(Uuid, String, bool)> = sqlx::query_as(
+                r#"
+                SELECT id, member_code, date_of_death IS NOT NULL FROM member
+                WHERE parish_id = $1 AND member_code = ANY($2) AND deleted_at IS NULL
+                "#
+            )
+            .bind(from_parish_id)
+            .bind(&payload.member_codes)
+            .fetch_all(&mut *tx)
+            .await
+            .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+            let missing: Vec<&str> = payload.member_codes.iter()
+                .filter(|c| !members.iter().any(|(_, code, _)| code == *c))
+                .map(String::as_str)
+                .collect();
+            if !missing.is_empty() {
+                return Err((StatusCode::NOT_FOUND, format!("Not members of the sending parish: {}", missing.join(", "))));
+            }
+            if let Some((_, code, _)) = members.iter().find(|(_, _, deceased)| *deceased) {
+                return Err((StatusCode::BAD_REQUEST, format!("Member {} is deceased", code)));
+            }
+            (None, members.into_iter().map(|(id, code, _)| (id, code)).collect())
+        }
+        _ => return Err((StatusCode::BAD_REQUEST, "Give either the family_code or the member_codes to transfer".to_string())),
+    };
+    if members.is_empty() {
+        return Err((StatusCode::BAD_REQUEST, "The family has no members to transfer".to_string()));
+    }
+
+    let member_ids: Vec<Uuid> = members.iter().map(|(id, _)| *id).collect();
+    let pending: i64 = sqlx::query_scalar(
+        r#"
+        SELECT COUNT(*) FROM member_transfer_member tm
+        JOIN member_transfer t ON t.id = tm.transfer_id
+        WHERE tm.member_id = ANY($1) AND t.status = 'REQUESTED'
+        "#
+    )
+    .bind(&member_ids)
+    .fetch_one(&mut *tx)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+    if pending > 0 {
+        return Err((StatusCode::CONFLICT, "A transfer is already pending for some of these members".to_string()));
+    }
+
+    let id: Uuid = sqlx::query_scalar(
+        r#"
+        INSERT INTO member_transfer (from_parish_id, to_parish_id, family_id, old_family_code, to_scc_id, reason, requested_by)
+        VALUES ($1, $2, $3, $4, $5, $6, $7)
+        RETURNING id
+        "#
+    )
+    .bind(from_parish_id)
+    .bind(to_parish_id)
+    .bind(family_id)
+    .bind(family_code)
+    .bind(payload.to_scc_id)
+    .bind(payload.reason)
+    .bind(auth.user_id)
+    .fetch_one(&mut *tx)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    let codes: Vec<String> = members.into_iter().map(|(_, code)| code).collect();
+    sqlx::query(
+        r#"
+        INSERT INTO member_transfer_member (transfer_id, member_id, old_member_code)
+        SELECT $1, UNNEST($2::uuid[]), UNNEST($3::text[])
+        "#
+    )
+    .bind(id)
+    .bind(&member_ids)
+    .bind(&codes)
+    .execute(&mut *tx)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    let transfer = fetch_transfer(&mut tx, &auth, id).await?;
+    let detail = detail(&mut tx, transfer).await?;
+    tx.commit().await.map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(detail))
+}
+
+/// Approved by the sending parish: the members, and the family when it
+/// moves whole, are given new codes in the receiving parish. Their
+/// sacramental records stay with the parish that celebrated them.
+pub async fn approve_member_transfer(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+    Json(payload): Json<DecideMemberTransferRequest>,
+) -> Result<Json<MemberTransferDetail>, (StatusCode, String)> {
+    rbac::require_admin(&auth)?;
+    let mut tx = state.db.begin().await.map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+    let transfer = fetch_requested(&mut tx, &auth, id).await?;
+    rbac::resolve_parish_id(&auth, Some(transfer.from_parish_id))?;
+
+    let still_here: bool = sqlx::query_scalar(
+        r#"
+        SELECT bool_and(m.parish_id = $2 AND m.deleted_at IS NULL)
+        FROM member_transfer_member tm JOIN member m ON m.id = tm.member_id
+        WHERE tm.transfer_id = $1
+        "#
+    )
+    .bind(id)
+    .bind(transfer.from_parish_id)
+    .fetch_one(&mut *tx)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+    if !still_here {
+        return Err((StatusCode::CONFLICT, "Some of the members have left the parish since the request".to_string()));
+    }
+
+    // New codes continue the receiving parish's own numbering for the
+    // year; lock the parish so two approvals do not take the same codes
+    sqlx::query("SELECT id FROM parish WHERE id = $1 FOR UPDATE")
+        .bind(transfer.to_parish_id)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    let new_family_code: Option<String> = match transfer.family_id {
+        Some(family_id) => Some(
+            sqlx::query_scalar(
+                r#"
+                UPDATE family SET
+                    parish_id = $1, scc_id = $2,
+                    family_code = 'FAM-' || TO_CHAR(NOW(), 'YYYY') || '-' || LPAD((
+                        SELECT COALESCE(MAX(SUBSTRING(f.family_code FROM '^FAM-' || TO_CHAR(NOW(), 'YYYY') || '-([0-9]+)$')::BIGINT), 0) + 1
+                        FROM family f WHERE f.parish_id = $1
+                    )::TEXT, 6, '0')
+                WHERE id = $3
+                RETURNING family_code
+                "#
+            )
+            .bind(transfer.to_parish_id)
+            .bind(transfer.to_scc_id)
+            .bind(family_id)
+            .fetch_one(&mut *tx)
+            .await
+            .map_err(code_taken)?,
+        ),
+        None => {
+            // Members leaving their family behind no longer head it
+            sqlx::query(
+                r#"
+                UPDATE family SET head_of_family_id = NULL
+                WHERE head_of_family_id IN (SELECT member_id FROM member_transfer_member WHERE transfer_id = $1)
+                "#
+            )
+            .bind(id)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+            None
+        }
+    };
+
+    sqlx::query(
+        r#"
+        WITH last_code AS (
+            SELECT COALESCE(MAX(SUBSTRING(member_code FROM '^MEM-' || TO_CHAR(NOW(), 'YYYY') || '-([0-9]+)$')::BIGINT), 0) AS n
+            FROM member WHERE parish_id = $2
+        ), numbered AS (
+            SELECT tm.member_id,
+                   'MEM-' || TO_CHAR(NOW(), 'YYYY') || '-'
+                       || LPAD((last_code.n + ROW_NUMBER() OVER (ORDER BY tm.old_member_code))::TEXT, 6, '0') AS member_code
+            FROM member_transfer_member tm, last_code
+            WHERE tm.transfer_id = $1
+        ), moved AS (
+            UPDATE member m SET
+                parish_id = $2, scc_id = $3,
+                family_id = CASE WHEN $4 THEN m.family_id END,
+                family_role = CASE WHEN $4 THEN m.family_role END,
+                member_code = numbered.member_code
+            FROM numbered
+            WHERE m.id = numbered.member_id
+            RETURNING m.id, m.member_code
+        )
+        UPDATE member_transfer_member tm SET new_member_code = moved.member_code
+        FROM moved
+        WHERE tm.transfer_id = $1 AND tm.member_id = moved.id
+        "#
+    )
+    .bind(id)
+    .bind(transfer.to_parish_id)
+    .bind(transfer.to_scc_id)
+    .bind(transfer.family_id.is_some())
+    .execute(&mut *tx)
+    .await
+    .map_err(code_taken)?;
+
+    sqlx::query(
+        r#"
+        UPDATE member_transfer SET
+            status = 'COMPLETED', new_family_code = $1, decided_by = $2, decided_at = NOW(), decision_note = $3
+        WHERE id = $4
+        "#
+    )
+    .bind(new_family_code)
+    .bind(auth.user_id)
+    .bind(payload.decision_note)
+    .bind(id)
+    .execute(&mut *tx)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    let transfer = fetch_transfer(&mut tx, &auth, id).await?;
+    let detail = detail(&mut tx, transfer).await?;
+    tx.commit().await.map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(detail))
+}
+
+pub async fn reject_member_transfer(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+    Json(payload): Json<DecideMemberTransferRequest>,
+) -> Result<Json<MemberTransferDetail>, (StatusCode, String)> {
+    rbac::require_admin(&auth)?;
+    let mut conn = acquire(&state).await?;
+    let transfer = fetch_requested(&mut conn, &auth, id).await?;
+    rbac::resolve_parish_id(&auth, Some(transfer.from_parish_id))?;
+    let note = payload.decision_note.map(|n| n.trim().to_string()).filter(|n| !n.is_empty())
+        .ok_or((StatusCode::BAD_REQUEST, "Give the reason the transfer is refused".to_string()))?;
+
+    decide(&mut conn, &auth, id, MemberTransferStatus::Rejected, Some(note)).await?;
+    let transfer = fetch_transfer(&mut conn, &auth, id).await?;
+    Ok(Json(detail(&mut conn, transfer).await?))
+}
+
+/// Withdrawn by the receiving parish before the sending parish decides.
+pub async fn cancel_member_transfer(
+    auth: AuthUser,
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<MemberTransferDetail>, (StatusCode, String)> {
+    rbac::require_write(&auth)?;
+    let mut conn = acquire(&state).await?;
+    let transfer = fetch_requested(&mut conn, &auth, id).await?;
+    rbac::resolve_parish_id(&auth, Some(transfer.to_parish_id))?;
+
+    decide(&mut conn, &auth, id, MemberTransferStatus::Cancelled, None).await?;
+    let transfer = fetch_transfer(&mut conn, &auth, id).await?;
+    Ok(Json(detail(&mut conn, transfer).await?))
+}
+
+// ============================================================================
+// HELPERS
+// ============================================================================
+
+async fn acquire(state: &AppState) -> Result<sqlx::pool::PoolConnection<sqlx::Postgres>, (StatusCode, String)> {
+    state.db.acquire().await.map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+}
+
+/// A new code can still clash with one entered by hand in the receiving
+/// parish meanwhile; that is a conflict to retry, not a server error.
+fn code_taken(e: sqlx::Error) -> (StatusCode, String) {
+    match &e {
+        sqlx::Error::Database(db) if db.is_unique_violation() => (
+            StatusCode::CONFLICT,
+            "A member or family code given by the transfer is already taken in the receiving parish; try again".to_string(),
+        ),
+        _ => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()),
+    }
+}
+
+/// Either parish may see the transfer.
+async fn fetch_transfer(conn: &mut PgConnection, auth: &AuthUser, id: Uuid) -> Result<MemberTransfer, (StatusCode, String)> {
+    let transfer = sqlx::query_as::<_, MemberTransfer>(&format!("{} WHERE t.id = $1", TRANSFER_WITH_NAMES))
+        .bind(id)
+        .fetch_optional(conn)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+        .ok_or((StatusCode::NOT_FOUND, "Member transfer not found".to_string()))?;
+    rbac::resolve_parish_id(auth, Some(transfer.to_parish_id))
+        .or_else(|_| rbac::resolve_parish_id(auth, Some(transfer.from_parish_id)))?;
+    Ok(transfer)
+}
+
+/// A transfer still awaiting a decision. Within a transaction it stays
+/// locked until the caller commits.
+async fn fetch_requested(conn: &mut PgConnection, auth: &AuthUser, id: Uuid) -> Result<MemberTransfer, (StatusCode, String)> {
+    sqlx::query("SELECT id FROM member_transfer WHERE id = $1 FOR UPDATE")
+        .bind(id)
+        .execute(&mut *conn)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+    let transfer = fetch_transfer(conn, auth, id).await?;
+    if transfer.status != MemberTransferStatus::Requested {
+        return Err((StatusCode::CONFLICT, "The transfer has already been decided".to_string()));
+    }
+    Ok(transfer)
+}
+
+async fn decide(
+    conn: &mut PgConnection,
+    auth: &AuthUser,
+    id: Uuid,
+    status: MemberTransferStatus,
+    note: Option<String>,
+) -> Result<(), (StatusCode, String)> {
+    let decided = sqlx::query(
+        r#"
+        UPDATE member_transfer SET status = $1, decided_by = $2, decided_at = NOW(), decision_note = $3
+        WHERE id = $4 AND status = 'REQUESTED'
+        "#
+    )
+    .bind(status)
+    .bind(auth.user_id)
+    .bind(note)
+    .bind(id)
+    .execute(conn)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+    if decided.rows_affected() == 0 {
+        return Err((StatusCode::CONFLICT, "The transfer has already been decided".to_string()));
+    }
+    Ok(())
+}
+
+async fn detail(conn: &mut PgConnection, transfer: MemberTransfer) -> Result<MemberTransferDetail, (StatusCode, String)> {
+    let members = sqlx::query_as::<_, TransferredMember>(
+        r#"
+        SELECT tm.*, CONCAT_WS(' ', m.first_name, m.middle_name, m.last_name) AS member_name
+        FROM member_transfer_member tm
+        JOIN member m ON m.id = tm.member_id
+        WHERE tm.transfer_id = $1
+        ORDER BY m.date_of_birth NULLS LAST, m.first_name
+        "#
+    )
+    .bind(transfer.id)
+    .fetch_all(conn)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(MemberTransferDetail { transfer, members })
+}
@@ -0,0 +1,68 @@
+use chrono::Utc;
+use sqlx::postgres::PgPool;
+use std::time::Duration;
+use crate::handlers::{levy, recurring, report_schedule};
+use crate::mailer::{self, MailTransport};
+
+/// How often the background jobs wake up.
+const JOB_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+/// How often report schedules and the email outbox are checked.
+const SCHEDULER_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Starts the periodic background jobs on the Tokio runtime.
+pub fn spawn(pool: PgPool) {
+    let hourly_pool = pool.clone();
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(JOB_INTERVAL);
+        loop {
+            interval.tick().await;
+            run_levy_assessments(&hourly_pool).await;
+            run_recurring_transactions(&hourly_pool).await;
+        }
+    });
+
+    let transport = MailTransport::from_env();
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(SCHEDULER_INTERVAL);
+        loop {
+            interval.tick().await;
+            run_report_schedules(&pool).await;
+            if let Some(transport) = &transport {
+                deliver_outbox(&pool, transport).await;
+            }
+        }
+    });
+}
+
+async fn run_levy_assessments(pool: &PgPool) {
+    match levy::assess_due_levies(pool, Utc::now().date_naive()).await {
+        Ok(0) => {}
+        Ok(created) => tracing::info!("Raised {} diocesan levy assessments", created),
+        Err(e) => tracing::error!("Levy assessment run failed: {}", e),
+    }
+}
+
+async fn run_recurring_transactions(pool: &PgPool) {
+    match recurring::materialize_due(pool, Utc::now().date_naive()).await {
+        Ok(0) => {}
+        Ok(processed) => tracing::info!("Processed {} recurring transaction occurrences", processed),
+        Err(e) => tracing::error!("Recurring transaction run failed: {}", e),
+    }
+}
+
+async fn run_report_schedules(pool: &PgPool) {
+    match report_schedule::run_due_schedules(pool).await {
+        Ok(0) => {}
+        Ok(ran) => tracing::info!("Ran {} scheduled reports", ran),
+        Err(e) => tracing::error!("Scheduled report run failed: {}", e),
+    }
+}
+
+async fn deliver_outbox(pool: &PgPool, transport: &MailTransport) {
+    match mailer::dispatch_pending(pool, transport).await {
+        Ok(0) => {}
+        Ok(sent) => tracing::info!("Delivered {} outbox emails", sent),
+        Err(e) => tracing::error!("Outbox delivery failed: {}", e),
+    }
+}
@@ -0,0 +1,172 @@
+use base64::{Engine as _, engine::general_purpose};
+use chrono::Utc;
+use sqlx::postgres::PgPool;
+use std::env;
+use std::path::PathBuf;
+use uuid::Uuid;
+
+/// How pending `email_log` rows leave the outbox.
+///
+/// Only the file-drop transport exists for now: each message is written as
+/// an `.eml` file so deliveries can be inspected locally. Without a
+/// configured transport emails simply stay `PENDING` in the outbox.
+pub enum MailTransport {
+    FileDrop { dir: PathBuf, from: String },
+}
+
+impl MailTransport {
+    /// Reads `MAIL_TRANSPORT` (`file`), `MAIL_DROP_DIR` and `MAIL_FROM`.
+    pub fn from_env() -> Option<Self> {
+        let from = env::var("MAIL_FROM").unwrap_or_else(|_| "no-reply@sanctus.local".to_string());
+        match env::var("MAIL_TRANSPORT").ok()?.to_lowercase().as_str() {
+            "file" => Some(MailTransport::FileDrop {
+                dir: PathBuf::from(env::var("MAIL_DROP_DIR").unwrap_or_else(|_| "mail_drop".to_string())),
+                from,
+            }),
+            other => {
+                tracing::warn!("Unknown MAIL_TRANSPORT '{}', outbox will not be delivered", other);
+                None
+            }
+        }
+    }
+}
+
+#[derive(sqlx::FromRow)]
+struct PendingEmail {
+    id: Uuid,
+    recipient_email: String,
+    subject: String,
+    body_text: Option<String>,
+    attachment_path: Option<String>,
+    attachment_name: Option<String>,
+}
+
+/// Delivers pending outbox emails, marking each `SENT` or `FAILED`.
+pub async fn dispatch_pending(db: &PgPool, transport: &MailTransport) -> Result<usize, sqlx::Error> {
+    let pending = sqlx::query_as::<_, PendingEmail>(
+        r#"
+        SELECT id, recipient_email, subject, body_text, attachment_path, attachment_name
+        FROM email_log WHERE status = 'PENDING'
+        ORDER BY created_at
+        LIMIT 100
+        "#
+    )
+    .fetch_all(db)
+    .await?;
+
+    let mut sent = 0;
+    for email in &pending {
+        match deliver(transport, email).await {
+            Ok(()) => {
+                sqlx::query("UPDATE email_log SET status = 'SENT', sent_at = NOW() WHERE id = $1")
+                    .bind(email.id)
+                    .execute(db)
+                    .await?;
+                sent += 1;
+            }
+            Err(e) => {
+                tracing::error!("Failed to deliver email {} to {}: {}", email.id, email.recipient_email, e);
+                sqlx::query("UPDATE email_log SET status = 'FAILED', failed_reason = $1 WHERE id = $2")
+                    .bind(e)
+                    .bind(email.id)
+                    .execute(db)
+                    .await?;
+            }
+        }
+    }
+
+    Ok(sent)
+}
+
+async fn deliver(transport: &MailTransport, email: &PendingEmail) -> Result<(), String> {
+    match transport {
+        MailTransport::FileDrop { dir, from } => {
+            let attachment = match (&email.attachment_path, &email.attachment_name) {
+                (Some(path), Some(name)) => {
+                    let bytes = tokio::fs::read(path).await
+                        .map_err(|e| format!("Cannot read attachment {}: {}", path, e))?;
+                    Some((name.as_str(), bytes))
+                }
+                _ => None,
+            };
+            let message = build_message(from, email, attachment);
+
+            tokio::fs::create_dir_all(dir).await.map_err(|e| e.to_string())?;
+            tokio::fs::write(dir.join(format!("{}.eml", email.id)), message)
+                .await
+                .map_err(|e| e.to_string())
+        }
+    }
+}
+
+/// Renders a plain-text message, as multipart/mixed when there is an attachment.
+fn build_message(from: &str, email: &PendingEmail, attachment: Option<(&str, Vec<u8>)>) -> String {
+    let body = email.body_text.as_deref().unwrap_or_default();
+    let mut message = format!(
+        "From: {}\r\nTo: {}\r\nSubject: {}\r\nDate: {}\r\nMessage-ID: <{}@sanctus>\r\nMIME-Version: 1.0\r\n",
+        from, header_text(&email.recipient_email), encode_subject(&email.subject), Utc::now().to_rfc2822(), email.id
+    );
+
+    let Some((name, bytes)) = attachment else {
+        message.push_str("Content-Type: text/plain; charset=utf-8\r\n\r\n");
+        message.push_str(body);
+        message.push_str("\r\n");
+        return message;
+    };
+
+    let name = header_text(name).replace('"', "'");
+    let boundary = format!("sanctus-{}", email.id.simple());
+    message.push_str(&format!("Content-Type: multipart/mixed; boundary=\"{}\"\r\n\r\n", boundary));
+    message.push_str(&format!("--{}\r\nContent-Type: text/plain; charset=utf-8\r\n\r\n{}\r\n", boundary, body));
+    message.push_str(&format!(
+        "--{}\r\nContent-Type: {}; name=\"{}\"\r\nContent-Transfer-Encoding: base64\r\nContent-Disposition: attachment; filename=\"{}\"\r\n\r\n",
+        boundary, content_type(&name), name, name
+    ));
+
+    let encoded = general_purpose::STANDARD.encode(bytes);
+    for line in encoded.as_bytes().chunks(76) {
+        message.push_str(std::str::from_utf8(line).expect("base64 is ascii"));
+        message.push_str("\r\n");
+    }
+    message.push_str(&format!("--{}--\r\n", boundary));
+    message
+}
+
+/// Header values come from user input (schedule names, recipients), so
+/// line breaks and other control characters are dropped to keep them from
+/// starting a header of their own.
+fn header_text(value: &str) -> String {
+    value.chars().map(|c| if c.is_control() { ' ' } else { c }).collect::<String>().trim().to_string()
+}
+
+/// The subject as RFC 2047 encoded-words when it is not plain ASCII.
+fn encode_subject(subject: &str) -> String {
+    let subject = header_text(subject);
+    if subject.is_ascii() {
+        return subject;
+    }
+    // Encoded-words are limited to 75 characters, so long subjects are
+    // split on character boundaries into folded words.
+    let mut words = Vec::new();
+    let mut chunk = String::new();
+    for c in subject.chars() {
+        if chunk.len() + c.len_utf8() > 45 {
+            words.push(std::mem::take(&mut chunk));
+        }
+        chunk.push(c);
+    }
+    words.push(chunk);
+    words.iter()
+        .map(|w| format!("=?UTF-8?B?{}?=", general_purpose::STANDARD.encode(w)))
+        .collect::<Vec<_>>()
+        .join("\r\n ")
+}
+
+fn content_type(file_name: &str) -> &'static str {
+    match file_name.rsplit('.').next() {
+        Some("pdf") => "application/pdf",
+        Some("csv") => "text/csv",
+        Some("xlsx") => "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet",
+        _ => "application/octet-stream",
+    }
+}
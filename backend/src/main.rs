@@ -14,6 +14,9 @@ use tower_http::services::ServeDir;
 mod models;
 mod sync;
 mod handlers;
+mod jobs;
+mod export;
+mod mailer;
 
 #[derive(Clone)]
 struct AppState {
@@ -51,6 +54,8 @@ async fn main() {
         .expect("Failed to run migrations");
     tracing::info!("Migrations applied successfully");
 
+    jobs::spawn(pool.clone());
+
     let state = AppState { db: pool };
 
     let app = Router::new()
@@ -60,12 +65,46 @@ async fn main() {
         .route("/users", get(handlers::user::list_users).post(handlers::user::create_user))
         .route("/users/:id", delete(handlers::user::delete_user))
         .route("/budgets", get(handlers::budget::list_budgets).post(handlers::budget::create_budget))
-        .route("/budgets/:id", put(handlers::budget::update_budget))
+        .route("/budgets/:id", put(handlers::budget::update_budget).delete(handlers::budget::delete_budget))
+        .route("/budgets/check", get(handlers::budget::check_expense_budget))
+        .route("/budget-alerts", get(handlers::budget::list_budget_alerts))
+        .route("/budget-versions", get(handlers::budget::list_budget_versions).post(handlers::budget::create_budget_version))
+        .route("/budget-versions/copy-forward", post(handlers::budget::copy_forward_budget))
+        .route("/budget-versions/:id", get(handlers::budget::get_budget_version).delete(handlers::budget::delete_budget_version))
+        .route("/budget-versions/:id/submit", post(handlers::budget::submit_budget_version))
+        .route("/budget-versions/:id/approve", post(handlers::budget::approve_budget_version))
+        .route("/budget-versions/:id/reject", post(handlers::budget::reject_budget_version))
+        .route("/budget-versions/:id/revise", post(handlers::budget::revise_budget_version))
+        .route("/budget-versions/:id/revisions", get(handlers::budget::list_budget_revisions))
         .route("/reports/trial-balance", get(handlers::report::get_trial_balance))
         .route("/reports/income-expenditure", get(handlers::report::get_income_expenditure))
         .route("/reports/budget-vs-actual", get(handlers::report::get_budget_vs_actual))
         .route("/reports/balance-sheet", get(handlers::report::get_balance_sheet))
         .route("/reports/cash-flow", get(handlers::report::get_cash_flow))
+        .route("/reports/fund-balances", get(handlers::report::get_fund_balances))
+        .route("/reports/comparative/income-expenditure", get(handlers::report::get_comparative_income_expenditure))
+        .route("/reports/comparative/budget-vs-actual", get(handlers::report::get_comparative_budget_vs_actual))
+        .route("/reports/monthly/income-expenditure", get(handlers::report::get_monthly_income_expenditure))
+        .route("/reports/monthly/budget-vs-actual", get(handlers::report::get_monthly_budget_vs_actual))
+        .route("/reports/consolidated/trial-balance", get(handlers::report::get_consolidated_trial_balance))
+        .route("/reports/consolidated/income-expenditure", get(handlers::report::get_consolidated_income_expenditure))
+        .route("/reports/consolidated/budget-vs-actual", get(handlers::report::get_consolidated_budget_vs_actual))
+        .route("/reports/consolidated/cash-flow", get(handlers::report::get_consolidated_cash_flow))
+        .route("/report-schedules", get(handlers::report_schedule::list_report_schedules).post(handlers::report_schedule::create_report_schedule))
+        .route("/report-schedules/:id", put(handlers::report_schedule::update_report_schedule).delete(handlers::report_schedule::delete_report_schedule))
+        .route("/report-schedules/:id/run", post(handlers::report_schedule::run_report_schedule))
+        .route("/report-schedules/:id/runs", get(handlers::report_schedule::list_report_runs))
+        .route("/report-runs/:id/download", get(handlers::report_schedule::download_report_run))
+        .route("/funds", get(handlers::fund::list_funds).post(handlers::fund::create_fund))
+        .route("/funds/:id", get(handlers::fund::get_fund).put(handlers::fund::update_fund).delete(handlers::fund::delete_fund))
+        .route("/levy/rules", get(handlers::levy::list_levy_rules).post(handlers::levy::create_levy_rule))
+        .route("/levy/rules/:id", put(handlers::levy::update_levy_rule).delete(handlers::levy::delete_levy_rule))
+        .route("/levy/assessments", get(handlers::levy::list_levy_assessments))
+        .route("/levy/assessments/run", post(handlers::levy::run_levy_assessments))
+        .route("/levy/assessments/:id/waive", post(handlers::levy::waive_levy_assessment))
+        .route("/levy/payments", post(handlers::levy::record_levy_payment))
+        .route("/levy/ledger", get(handlers::levy::get_levy_ledger))
+        .route("/levy/arrears", get(handlers::levy::get_levy_arrears))
         .route("/import/members", post(handlers::import::import_members))
         .route("/import/transactions", post(handlers::import::import_transactions))
         .route("/import/clusters", post(handlers::import::import_clusters))
@@ -78,18 +117,118 @@ async fn main() {
         .route("/parishes/:id", get(handlers::parish::get_parish).put(handlers::parish::update_parish).delete(handlers::parish::delete_parish))
         .route("/members", get(handlers::member::list_members).post(handlers::member::create_member))
         .route("/members/:id", get(handlers::member::get_member).put(handlers::member::update_member).delete(handlers::member::delete_member))
+        .route("/members/:id/sponsorships", get(handlers::sponsor::list_sponsorships))
+        .route("/burials", get(handlers::burial::list_burials).post(handlers::burial::create_burial))
+        .route("/burials/:id", get(handlers::burial::get_burial).put(handlers::burial::update_burial).delete(handlers::burial::delete_burial))
+        .route("/marriage-cases", get(handlers::marriage::list_marriage_cases).post(handlers::marriage::create_marriage_case))
+        .route("/marriage-cases/:id", get(handlers::marriage::get_marriage_case).put(handlers::marriage::update_marriage_case).delete(handlers::marriage::delete_marriage_case))
+        .route("/marriage-cases/:id/documents", post(handlers::marriage::create_marriage_document))
+        .route("/marriage-cases/:id/banns", post(handlers::marriage::schedule_banns))
+        .route("/marriage-cases/:id/impediments", post(handlers::marriage::create_impediment))
+        .route("/marriage-cases/:id/complete", post(handlers::marriage::complete_marriage_case))
+        .route("/marriage-documents/:id", put(handlers::marriage::update_marriage_document).delete(handlers::marriage::delete_marriage_document))
+        .route("/banns/:id", put(handlers::marriage::update_banns))
+        .route("/marriage-impediments/:id", put(handlers::marriage::resolve_impediment))
+        .route("/catechesis-classes", get(handlers::catechesis::list_catechesis_classes).post(handlers::catechesis::create_catechesis_class))
+        .route("/catechesis-classes/:id", get(handlers::catechesis::get_catechesis_class).put(handlers::catechesis::update_catechesis_class).delete(handlers::catechesis::delete_catechesis_class))
+        .route("/catechesis-classes/:id/catechists", post(handlers::catechesis::add_catechist))
+        .route("/catechesis-classes/:id/candidates", post(handlers::catechesis::enrol_candidates))
+        .route("/catechesis-classes/:id/sessions", post(handlers::catechesis::record_session))
+        .route("/catechesis-classes/:id/celebrate", post(handlers::catechesis::celebrate))
+        .route("/catechists/:id", delete(handlers::catechesis::remove_catechist))
+        .route("/catechesis-candidates/:id", put(handlers::catechesis::update_candidate))
+        .route("/catechesis-sessions/:id/attendance", put(handlers::catechesis::update_attendance))
+        .route("/mass-schedules", get(handlers::mass::list_mass_schedules).post(handlers::mass::create_mass_schedule))
+        .route("/mass-schedules/:id", put(handlers::mass::update_mass_schedule).delete(handlers::mass::delete_mass_schedule))
+        .route("/masses", get(handlers::mass::list_masses))
+        .route("/mass-intentions", get(handlers::mass::list_mass_intentions).post(handlers::mass::create_mass_intention))
+        .route("/mass-intentions/weekly", get(handlers::mass::weekly_mass_intentions))
+        .route("/mass-intentions/:id", get(handlers::mass::get_mass_intention).put(handlers::mass::update_mass_intention).delete(handlers::mass::delete_mass_intention))
+        .route("/mass-intentions/:id/transfer", post(handlers::mass::transfer_mass_intention))
+        .route("/statistical-returns", get(handlers::statistics::list_statistical_returns).post(handlers::statistics::create_statistical_return))
+        .route("/statistical-returns/:id", get(handlers::statistics::get_statistical_return).delete(handlers::statistics::delete_statistical_return))
+        .route("/statistical-returns/:id/recompute", post(handlers::statistics::recompute_statistical_return))
+        .route("/statistical-returns/:id/submit", post(handlers::statistics::submit_statistical_return))
+        .route("/statistical-returns/:id/lines/:line_code", put(handlers::statistics::adjust_statistical_line))
+        .route("/member-transfers", get(handlers::transfer::list_member_transfers).post(handlers::transfer::create_member_transfer))
+        .route("/member-transfers/:id", get(handlers::transfer::get_member_transfer))
+        .route("/member-transfers/:id/approve", post(handlers::transfer::approve_member_transfer))
+        .route("/member-transfers/:id/reject", post(handlers::transfer::reject_member_transfer))
+        .route("/member-transfers/:id/cancel", post(handlers::transfer::cancel_member_transfer))
+        .route("/members/:id/transfers", get(handlers::transfer::list_member_transfer_history))
         .route("/transactions/income", get(handlers::transaction::list_income_transactions).post(handlers::transaction::create_income_transaction))
         .route("/transactions/income/:id", get(handlers::transaction::get_income_transaction))
         .route("/transactions/expense", get(handlers::transaction::list_expense_vouchers).post(handlers::transaction::create_expense_voucher))
         .route("/transactions/expense/:id", get(handlers::transaction::get_expense_voucher))
+        .route("/transactions/expense/:id/approve-overrun", post(handlers::transaction::approve_voucher_overrun))
+        .route("/recurring-transactions", get(handlers::recurring::list_recurring_transactions).post(handlers::recurring::create_recurring_transaction))
+        .route("/recurring-transactions/run", post(handlers::recurring::run_recurring_transactions))
+        .route("/recurring-transactions/:id", get(handlers::recurring::get_recurring_transaction).put(handlers::recurring::update_recurring_transaction).delete(handlers::recurring::delete_recurring_transaction))
+        .route("/recurring-transactions/:id/occurrences", get(handlers::recurring::list_template_occurrences))
+        .route("/recurring-transactions/:id/upcoming", get(handlers::recurring::list_upcoming_occurrences))
+        .route("/recurring-transactions/:id/adjust", post(handlers::recurring::adjust_occurrence))
+        .route("/recurring-occurrences", get(handlers::recurring::list_occurrences))
+        .route("/recurring-occurrences/:id/receive", post(handlers::recurring::receive_occurrence))
+        .route("/cashbooks", get(handlers::cashbook::list_cashbooks).post(handlers::cashbook::create_cashbook))
+        .route("/cashbooks/:id", get(handlers::cashbook::get_cashbook).put(handlers::cashbook::update_cashbook).delete(handlers::cashbook::delete_cashbook))
+        .route("/cashbooks/:id/top-up", post(handlers::cashbook::imprest_topup))
+        .route("/cashbooks/:id/counts", get(handlers::cashbook::list_cash_counts).post(handlers::cashbook::record_cash_count))
+        .route("/cashbooks/:id/statement", get(handlers::cashbook::get_cashbook_statement))
+        .route("/cash-counts/:id", get(handlers::cashbook::get_cash_count))
+        .route("/cashbook-transfers", get(handlers::cashbook::list_transfers).post(handlers::cashbook::create_transfer))
+        .route("/cashbook-transfers/:id", delete(handlers::cashbook::delete_transfer))
+        .route("/fixed-assets", get(handlers::asset::list_fixed_assets).post(handlers::asset::create_fixed_asset))
+        .route("/fixed-assets/register", get(handlers::asset::get_asset_register))
+        .route("/fixed-assets/:id", get(handlers::asset::get_fixed_asset).put(handlers::asset::update_fixed_asset).delete(handlers::asset::delete_fixed_asset))
+        .route("/fixed-assets/:id/depreciation", get(handlers::asset::get_depreciation_schedule))
+        .route("/fixed-assets/:id/dispose", post(handlers::asset::dispose_fixed_asset))
+        .route("/staff", get(handlers::payroll::list_staff).post(handlers::payroll::create_staff))
+        .route("/staff/:id", get(handlers::payroll::get_staff).put(handlers::payroll::update_staff).delete(handlers::payroll::delete_staff))
+        .route("/statutory-deductions", get(handlers::payroll::list_statutory_deductions).post(handlers::payroll::create_statutory_deduction))
+        .route("/statutory-deductions/:id", put(handlers::payroll::update_statutory_deduction).delete(handlers::payroll::delete_statutory_deduction))
+        .route("/payroll-runs", get(handlers::payroll::list_payroll_runs).post(handlers::payroll::create_payroll_run))
+        .route("/payroll-runs/:id", get(handlers::payroll::get_payroll_run))
+        .route("/payroll-runs/:id/approve", post(handlers::payroll::approve_payroll_run))
+        .route("/payroll-runs/:id/cancel", post(handlers::payroll::cancel_payroll_run))
+        .route("/payslips/:id", get(handlers::payroll::get_payslip).put(handlers::payroll::update_payslip))
+        .route("/suppliers", get(handlers::supplier::list_suppliers).post(handlers::supplier::create_supplier))
+        .route("/suppliers/:id", get(handlers::supplier::get_supplier).put(handlers::supplier::update_supplier).delete(handlers::supplier::delete_supplier))
+        .route("/suppliers/:id/merge", post(handlers::supplier::merge_suppliers))
+        .route("/suppliers/:id/statement", get(handlers::supplier::get_supplier_statement))
+        .route("/reports/spend-by-supplier", get(handlers::supplier::get_spend_by_supplier))
+        .route("/exchange-rates", get(handlers::currency::list_exchange_rates).post(handlers::currency::save_exchange_rate))
+        .route("/exchange-rates/:id", delete(handlers::currency::delete_exchange_rate))
+        .route("/currency-holdings", get(handlers::currency::get_currency_holdings))
+        .route("/currency-conversions", get(handlers::currency::list_conversions).post(handlers::currency::create_conversion))
+        .route("/certificate-templates", get(handlers::certificate::list_certificate_templates).post(handlers::certificate::save_certificate_template))
+        .route("/certificate-templates/defaults", get(handlers::certificate::list_default_certificate_templates))
+        .route("/certificate-templates/:id", delete(handlers::certificate::delete_certificate_template))
+        .route("/sacraments/:id/certificates", post(handlers::certificate::issue_certificate))
+        .route("/certificates", get(handlers::certificate::list_certificates))
+        .route("/certificates/:id", get(handlers::certificate::get_certificate))
+        .route("/certificates/:id/revoke", post(handlers::certificate::revoke_certificate))
+        .route("/certificates/verify/:code", get(handlers::certificate::verify_certificate))
         .route("/sacraments", get(handlers::sacrament::list_sacraments).post(handlers::sacrament::create_sacrament))
+        .route("/sacraments/check", post(handlers::sacrament_rule::check_sacrament))
         .route("/sacraments/:id", get(handlers::sacrament::get_sacrament).put(handlers::sacrament::update_sacrament).delete(handlers::sacrament::delete_sacrament))
+        .route("/sacraments/:id/sponsors", get(handlers::sponsor::list_sponsors))
+        .route("/sacraments/:id/notations", get(handlers::notation::list_notations).post(handlers::notation::create_notation))
+        .route("/notations/:id", delete(handlers::notation::delete_notation))
+        .route("/notation-notices", get(handlers::notation::list_notices))
+        .route("/notation-notices/:id/apply", post(handlers::notation::apply_notice))
+        .route("/notation-notices/:id/reject", post(handlers::notation::reject_notice))
+        .route("/register-books", get(handlers::register::list_register_books).post(handlers::register::create_register_book))
+        .route("/register-books/lookup", get(handlers::register::lookup_register_entry))
+        .route("/register-books/:id", put(handlers::register::update_register_book).delete(handlers::register::delete_register_book))
+        .route("/register-books/:id/audit", get(handlers::register::get_register_book_audit))
         .route("/clusters", get(handlers::cluster::list_clusters).post(handlers::cluster::create_cluster))
         .route("/clusters/:id", get(handlers::cluster::get_cluster).put(handlers::cluster::update_cluster).delete(handlers::cluster::delete_cluster))
         .route("/sccs", get(handlers::scc::list_sccs).post(handlers::scc::create_scc))
         .route("/sccs/:id", get(handlers::scc::get_scc).put(handlers::scc::update_scc).delete(handlers::scc::delete_scc))
         .route("/families", get(handlers::family::list_families).post(handlers::family::create_family))
+        .route("/families/head-vacancies", get(handlers::burial::list_head_vacancies))
         .route("/families/:id", get(handlers::family::get_family).put(handlers::family::update_family).delete(handlers::family::delete_family))
+        .route("/families/:id/head", put(handlers::family::assign_family_head))
         .route("/settings", get(handlers::setting::list_settings).post(handlers::setting::upsert_setting))
         .route("/settings/bulk", post(handlers::setting::bulk_upsert_settings))
         // Permissions & Roles
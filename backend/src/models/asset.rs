@@ -0,0 +1,182 @@
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+use chrono::{NaiveDate, DateTime, Utc};
+use rust_decimal::Decimal;
+use crate::models::transaction::PaymentMethod;
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, sqlx::Type)]
+#[sqlx(type_name = "asset_category", rename_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum AssetCategory {
+    Land,
+    Buildings,
+    Vehicles,
+    Furniture,
+    Equipment,
+    Other,
+}
+
+impl AssetCategory {
+    /// Line name on the balance sheet.
+    pub fn label(self) -> &'static str {
+        match self {
+            AssetCategory::Land => "Land",
+            AssetCategory::Buildings => "Buildings",
+            AssetCategory::Vehicles => "Vehicles",
+            AssetCategory::Furniture => "Furniture and Fittings",
+            AssetCategory::Equipment => "Equipment",
+            AssetCategory::Other => "Other Fixed Assets",
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone, Copy, sqlx::Type)]
+#[sqlx(type_name = "depreciation_method", rename_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum DepreciationMethod {
+    None,
+    StraightLine,
+    ReducingBalance,
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone, Copy, sqlx::Type)]
+#[sqlx(type_name = "asset_disposal_method", rename_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum AssetDisposalMethod {
+    Sold,
+    Donated,
+    Scrapped,
+    Lost,
+}
+
+#[derive(Debug, Serialize, Deserialize, FromRow)]
+pub struct FixedAsset {
+    pub id: Uuid,
+    pub parish_id: Uuid,
+    pub asset_code: String,
+    pub asset_name: String,
+    pub category: AssetCategory,
+    pub description: Option<String>,
+    pub acquisition_date: NaiveDate,
+    pub acquisition_cost: Decimal,
+    pub location: Option<String>,
+    pub custodian_name: Option<String>,
+    pub fund_id: Option<Uuid>,
+    pub funding_source: Option<String>,
+    pub expense_voucher_id: Option<Uuid>,
+    pub depreciation_method: DepreciationMethod,
+    pub useful_life_years: Option<i32>,
+    pub depreciation_rate: Option<Decimal>,
+    pub residual_value: Decimal,
+    pub disposal_date: Option<NaiveDate>,
+    pub disposal_method: Option<AssetDisposalMethod>,
+    pub disposal_proceeds: Option<Decimal>,
+    pub disposal_gain_loss: Option<Decimal>,
+    pub disposal_notes: Option<String>,
+    pub disposal_income_id: Option<Uuid>,
+    pub created_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub deleted_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct FixedAssetWithValue {
+    #[serde(flatten)]
+    pub asset: FixedAsset,
+    pub accumulated_depreciation: Decimal,
+    pub net_book_value: Decimal,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CreateFixedAssetRequest {
+    pub parish_id: Uuid,
+    pub asset_code: Option<String>,
+    pub asset_name: String,
+    pub category: AssetCategory,
+    pub description: Option<String>,
+    pub acquisition_date: NaiveDate,
+    pub acquisition_cost: Decimal,
+    pub location: Option<String>,
+    pub custodian_name: Option<String>,
+    pub fund_id: Option<Uuid>,
+    pub funding_source: Option<String>,
+    pub expense_voucher_id: Option<Uuid>,
+    pub depreciation_method: DepreciationMethod,
+    pub useful_life_years: Option<i32>,
+    pub depreciation_rate: Option<Decimal>,
+    pub residual_value: Option<Decimal>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UpdateFixedAssetRequest {
+    pub asset_name: Option<String>,
+    pub category: Option<AssetCategory>,
+    pub description: Option<String>,
+    pub acquisition_date: Option<NaiveDate>,
+    pub acquisition_cost: Option<Decimal>,
+    pub location: Option<String>,
+    pub custodian_name: Option<String>,
+    pub fund_id: Option<Uuid>,
+    pub funding_source: Option<String>,
+    pub depreciation_method: Option<DepreciationMethod>,
+    pub useful_life_years: Option<i32>,
+    pub depreciation_rate: Option<Decimal>,
+    pub residual_value: Option<Decimal>,
+}
+
+/// Sale proceeds are receipted as income unless the asset left the books
+/// without payment.
+#[derive(Debug, Deserialize)]
+pub struct DisposeAssetRequest {
+    pub disposal_date: NaiveDate,
+    pub disposal_method: AssetDisposalMethod,
+    pub proceeds: Option<Decimal>,
+    pub payment_method: Option<PaymentMethod>,
+    pub cashbook_id: Option<Uuid>,
+    pub notes: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct DepreciationYear {
+    pub year: i32,
+    pub months: u32,
+    pub opening_value: Decimal,
+    pub depreciation: Decimal,
+    pub accumulated_depreciation: Decimal,
+    pub closing_value: Decimal,
+}
+
+#[derive(Debug, Serialize)]
+pub struct DepreciationSchedule {
+    pub asset_id: Uuid,
+    pub asset_code: String,
+    pub asset_name: String,
+    pub depreciation_method: DepreciationMethod,
+    pub acquisition_cost: Decimal,
+    pub residual_value: Decimal,
+    pub years: Vec<DepreciationYear>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AssetRegisterLine {
+    pub asset_code: String,
+    pub asset_name: String,
+    pub category: AssetCategory,
+    pub acquisition_date: NaiveDate,
+    pub location: Option<String>,
+    pub custodian_name: Option<String>,
+    pub acquisition_cost: Decimal,
+    pub accumulated_depreciation: Decimal,
+    pub net_book_value: Decimal,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AssetRegister {
+    pub as_of: NaiveDate,
+    pub lines: Vec<AssetRegisterLine>,
+    pub total_cost: Decimal,
+    pub total_accumulated_depreciation: Decimal,
+    pub total_net_book_value: Decimal,
+}
@@ -1,14 +1,25 @@
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
 use sqlx::FromRow;
 use rust_decimal::Decimal;
 use crate::models::transaction::TransactionCategory;
 
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone, Copy, sqlx::Type)]
+#[sqlx(type_name = "budget_status", rename_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum BudgetStatus {
+    Draft,
+    Submitted,
+    Approved,
+    Superseded,
+}
+
 #[derive(Debug, Serialize, Deserialize, FromRow)]
 pub struct Budget {
     pub id: Uuid,
     pub parish_id: Uuid,
+    pub version_id: Uuid,
     pub category: TransactionCategory,
     pub amount: Decimal,
     pub fiscal_year: i32,
@@ -23,6 +34,9 @@ pub struct Budget {
 #[derive(Debug, Deserialize)]
 pub struct CreateBudgetRequest {
     pub parish_id: Uuid,
+    /// Draft version to add the line to. Defaults to the year's open draft,
+    /// which is created if the year has no budget yet.
+    pub version_id: Option<Uuid>,
     pub category: TransactionCategory,
     pub amount: Decimal,
     pub fiscal_year: i32,
@@ -34,4 +48,127 @@ pub struct CreateBudgetRequest {
 pub struct UpdateBudgetRequest {
     pub amount: Option<Decimal>,
     pub description: Option<String>,
+    pub reason: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, FromRow)]
+pub struct BudgetVersion {
+    pub id: Uuid,
+    pub parish_id: Uuid,
+    pub fiscal_year: i32,
+    pub version_number: i32,
+    pub version_name: Option<String>,
+    pub status: BudgetStatus,
+    pub based_on_version_id: Option<Uuid>,
+    pub revision_reason: Option<String>,
+    pub rejection_reason: Option<String>,
+    pub notes: Option<String>,
+    pub submitted_by: Option<Uuid>,
+    pub submitted_at: Option<DateTime<Utc>>,
+    pub approved_by: Option<Uuid>,
+    pub approved_at: Option<DateTime<Utc>>,
+    pub created_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub deleted_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct BudgetVersionDetail {
+    #[serde(flatten)]
+    pub version: BudgetVersion,
+    pub total: Decimal,
+    pub lines: Vec<Budget>,
+}
+
+#[derive(Debug, Serialize, Deserialize, FromRow)]
+pub struct BudgetRevision {
+    pub id: Uuid,
+    pub version_id: Uuid,
+    pub budget_id: Option<Uuid>,
+    pub category: TransactionCategory,
+    pub fiscal_month: Option<i32>,
+    pub old_amount: Option<Decimal>,
+    pub new_amount: Option<Decimal>,
+    pub reason: Option<String>,
+    pub revised_by: Option<Uuid>,
+    pub revised_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CreateBudgetVersionRequest {
+    pub parish_id: Uuid,
+    pub fiscal_year: i32,
+    pub version_name: Option<String>,
+    pub notes: Option<String>,
+}
+
+/// Starts a mid-year revision of the approved budget.
+#[derive(Debug, Deserialize)]
+pub struct ReviseBudgetRequest {
+    pub reason: String,
+    pub version_name: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RejectBudgetRequest {
+    pub reason: String,
+}
+
+/// Drafts next year's budget from this year's, scaled by `increase_percent`.
+#[derive(Debug, Deserialize)]
+pub struct CopyForwardBudgetRequest {
+    pub parish_id: Uuid,
+    pub from_fiscal_year: i32,
+    pub to_fiscal_year: Option<i32>,
+    pub increase_percent: Option<Decimal>,
+}
+
+/// Per-parish handling of a voucher that would exceed its budget line,
+/// stored in the `finance.budget_overrun_policy` setting.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone, Copy)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum BudgetOverrunPolicy {
+    Warn,
+    RequireApproval,
+    Block,
+}
+
+/// Where a category stands against its approved budget line for the
+/// period an expense falls in, counting the expense itself.
+#[derive(Debug, Serialize)]
+pub struct BudgetCheck {
+    pub version_id: Uuid,
+    pub category: TransactionCategory,
+    pub period_start: NaiveDate,
+    pub period_end: NaiveDate,
+    pub budget: Decimal,
+    pub committed: Decimal,
+    pub amount: Decimal,
+    pub remaining: Decimal,
+    pub percent_used: Decimal,
+    pub exceeds_budget: bool,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ExpenseBudgetCheck {
+    pub policy: BudgetOverrunPolicy,
+    /// `None` when the category has no approved budget line for the period.
+    pub check: Option<BudgetCheck>,
+}
+
+#[derive(Debug, Serialize, Deserialize, FromRow)]
+pub struct BudgetAlert {
+    pub id: Uuid,
+    pub parish_id: Uuid,
+    pub version_id: Uuid,
+    pub category: TransactionCategory,
+    pub period_start: NaiveDate,
+    pub period_end: NaiveDate,
+    pub threshold_percent: i32,
+    pub budget_amount: Decimal,
+    pub committed_amount: Decimal,
+    pub voucher_id: Option<Uuid>,
+    pub emails_queued: i32,
+    pub triggered_at: DateTime<Utc>,
 }
@@ -0,0 +1,99 @@
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+use chrono::{NaiveDate, DateTime, Utc};
+use crate::models::member::FamilyRole;
+
+/// An entry in the parish burial register.
+#[derive(Debug, Serialize, Deserialize, FromRow)]
+pub struct BurialRecord {
+    pub id: Uuid,
+    pub parish_id: Uuid,
+    pub member_id: Uuid,
+    pub entry_year: i32,
+    pub entry_number: i32,
+    pub date_of_death: NaiveDate,
+    pub place_of_death: Option<String>,
+    pub cause_of_death: Option<String>,
+    pub burial_date: Option<NaiveDate>,
+    pub burial_place: Option<String>,
+    pub cemetery_name: Option<String>,
+    pub plot_reference: Option<String>,
+    pub officiant: Option<String>,
+    pub income_transaction_id: Option<Uuid>,
+    pub notes: Option<String>,
+    pub recorded_by: Option<Uuid>,
+    /// Whether the member was active before the death was recorded.
+    pub member_was_active: Option<bool>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub deleted_at: Option<DateTime<Utc>>,
+    #[sqlx(default)]
+    pub member_code: Option<String>,
+    #[sqlx(default)]
+    pub member_name: Option<String>,
+    #[sqlx(default)]
+    pub age_at_death: Option<i32>,
+    /// The last Anointing of the Sick on record.
+    #[sqlx(default)]
+    pub last_anointed_on: Option<NaiveDate>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CreateBurialRequest {
+    pub member_id: Uuid,
+    pub date_of_death: NaiveDate,
+    pub place_of_death: Option<String>,
+    pub cause_of_death: Option<String>,
+    pub burial_date: Option<NaiveDate>,
+    pub burial_place: Option<String>,
+    pub cemetery_name: Option<String>,
+    pub plot_reference: Option<String>,
+    pub officiant: Option<String>,
+    /// A FUNERAL_FEE income transaction.
+    pub income_transaction_id: Option<Uuid>,
+    pub notes: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UpdateBurialRequest {
+    pub date_of_death: Option<NaiveDate>,
+    pub place_of_death: Option<String>,
+    pub cause_of_death: Option<String>,
+    pub burial_date: Option<NaiveDate>,
+    pub burial_place: Option<String>,
+    pub cemetery_name: Option<String>,
+    pub plot_reference: Option<String>,
+    pub officiant: Option<String>,
+    pub income_transaction_id: Option<Uuid>,
+    pub notes: Option<String>,
+}
+
+/// A family whose head has died, with the living members who could take
+/// their place.
+#[derive(Debug, Serialize)]
+pub struct FamilyHeadVacancy {
+    pub family_id: Uuid,
+    pub family_code: String,
+    pub family_name: String,
+    /// The deceased head, where the family still names them.
+    pub head_of_family_id: Option<Uuid>,
+    pub candidates: Vec<HeadCandidate>,
+}
+
+#[derive(Debug, Serialize, FromRow)]
+pub struct HeadCandidate {
+    pub member_id: Uuid,
+    pub member_name: String,
+    pub family_role: Option<FamilyRole>,
+    pub date_of_birth: Option<NaiveDate>,
+}
+
+/// A saved burial record, with the family to update when the deceased
+/// headed one.
+#[derive(Debug, Serialize)]
+pub struct SavedBurial {
+    #[serde(flatten)]
+    pub record: BurialRecord,
+    pub head_vacancy: Option<FamilyHeadVacancy>,
+}
@@ -0,0 +1,185 @@
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+use chrono::{NaiveDate, DateTime, Utc};
+use rust_decimal::Decimal;
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone, Copy, sqlx::Type)]
+#[sqlx(type_name = "cashbook_type", rename_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum CashbookType {
+    PettyCash,
+    MainCash,
+    Bank,
+    MobileMoney,
+}
+
+impl CashbookType {
+    /// Physical cash cannot go below zero; bank and wallet books may show
+    /// an overdraft.
+    pub fn is_cash(self) -> bool {
+        matches!(self, CashbookType::PettyCash | CashbookType::MainCash)
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone, Copy, sqlx::Type)]
+#[sqlx(type_name = "cashbook_transfer_type", rename_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum CashbookTransferType {
+    Transfer,
+    ImprestTopup,
+}
+
+#[derive(Debug, Serialize, Deserialize, FromRow)]
+pub struct Cashbook {
+    pub id: Uuid,
+    pub parish_id: Uuid,
+    pub cashbook_name: String,
+    pub cashbook_type: CashbookType,
+    pub provider: Option<String>,
+    pub account_number: Option<String>,
+    pub imprest_amount: Option<Decimal>,
+    pub opening_balance: Decimal,
+    pub opening_date: NaiveDate,
+    pub custodian_id: Option<Uuid>,
+    pub is_active: bool,
+    pub created_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub deleted_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CashbookWithBalance {
+    #[serde(flatten)]
+    pub cashbook: Cashbook,
+    pub balance: Decimal,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CreateCashbookRequest {
+    pub parish_id: Uuid,
+    pub cashbook_name: String,
+    pub cashbook_type: CashbookType,
+    pub provider: Option<String>,
+    pub account_number: Option<String>,
+    pub imprest_amount: Option<Decimal>,
+    pub opening_balance: Option<Decimal>,
+    pub opening_date: Option<NaiveDate>,
+    pub custodian_id: Option<Uuid>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UpdateCashbookRequest {
+    pub cashbook_name: Option<String>,
+    pub provider: Option<String>,
+    pub account_number: Option<String>,
+    pub imprest_amount: Option<Decimal>,
+    pub custodian_id: Option<Uuid>,
+    pub is_active: Option<bool>,
+}
+
+#[derive(Debug, Serialize, Deserialize, FromRow)]
+pub struct CashbookTransfer {
+    pub id: Uuid,
+    pub parish_id: Uuid,
+    pub from_cashbook_id: Uuid,
+    pub to_cashbook_id: Uuid,
+    pub transfer_type: CashbookTransferType,
+    pub amount: Decimal,
+    pub transfer_date: NaiveDate,
+    pub reference_number: Option<String>,
+    pub description: Option<String>,
+    pub created_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub deleted_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CreateTransferRequest {
+    pub from_cashbook_id: Uuid,
+    pub to_cashbook_id: Uuid,
+    pub amount: Decimal,
+    pub transfer_date: Option<NaiveDate>,
+    pub reference_number: Option<String>,
+    pub description: Option<String>,
+}
+
+/// Restores a petty cash float from another cashbook. Without an amount
+/// the top-up brings the float back to its imprest level.
+#[derive(Debug, Deserialize)]
+pub struct ImprestTopupRequest {
+    pub from_cashbook_id: Uuid,
+    pub amount: Option<Decimal>,
+    pub transfer_date: Option<NaiveDate>,
+    pub reference_number: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, FromRow)]
+pub struct CashCount {
+    pub id: Uuid,
+    pub cashbook_id: Uuid,
+    pub parish_id: Uuid,
+    pub count_date: NaiveDate,
+    pub counted_total: Decimal,
+    pub book_balance: Decimal,
+    pub difference: Decimal,
+    pub notes: Option<String>,
+    pub counted_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, Deserialize, FromRow)]
+pub struct CashCountLine {
+    pub id: Uuid,
+    pub cash_count_id: Uuid,
+    pub denomination: Decimal,
+    pub quantity: i32,
+    pub amount: Decimal,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CashCountDetail {
+    #[serde(flatten)]
+    pub count: CashCount,
+    pub lines: Vec<CashCountLine>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DenominationCount {
+    pub denomination: Decimal,
+    pub quantity: i32,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RecordCashCountRequest {
+    pub count_date: Option<NaiveDate>,
+    pub denominations: Vec<DenominationCount>,
+    pub notes: Option<String>,
+}
+
+#[derive(Debug, Serialize, FromRow)]
+pub struct CashbookStatementLine {
+    pub entry_date: NaiveDate,
+    pub entry_type: String,
+    pub reference: Option<String>,
+    pub description: Option<String>,
+    pub receipts: Decimal,
+    pub payments: Decimal,
+    #[sqlx(default)]
+    pub balance: Decimal,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CashbookStatement {
+    pub cashbook_id: Uuid,
+    pub cashbook_name: String,
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+    pub opening_balance: Decimal,
+    pub lines: Vec<CashbookStatementLine>,
+    pub total_receipts: Decimal,
+    pub total_payments: Decimal,
+    pub closing_balance: Decimal,
+}
@@ -0,0 +1,191 @@
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+use chrono::{NaiveDate, DateTime, Utc};
+use crate::models::member::SacramentType;
+use crate::models::sacrament_rule::{RuleViolation, SavedSacrament};
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone, Copy, sqlx::Type)]
+#[sqlx(type_name = "candidate_readiness", rename_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum CandidateReadiness {
+    /// Not yet assessed.
+    Pending,
+    Ready,
+    NotReady,
+    /// The sacrament is recorded.
+    Celebrated,
+    Withdrawn,
+}
+
+#[derive(Debug, Serialize, Deserialize, FromRow)]
+pub struct CatechesisClass {
+    pub id: Uuid,
+    pub parish_id: Uuid,
+    /// The outstation the class meets at, if not the parish centre.
+    pub cluster_id: Option<Uuid>,
+    pub class_name: String,
+    pub sacrament_type: SacramentType,
+    pub start_date: NaiveDate,
+    pub end_date: Option<NaiveDate>,
+    pub planned_celebration_date: Option<NaiveDate>,
+    pub min_attendance_percent: i32,
+    pub celebrated_on: Option<NaiveDate>,
+    pub notes: Option<String>,
+    pub created_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub deleted_at: Option<DateTime<Utc>>,
+    #[sqlx(default)]
+    pub cluster_name: Option<String>,
+    #[sqlx(default)]
+    pub candidate_count: Option<i64>,
+    #[sqlx(default)]
+    pub ready_count: Option<i64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, FromRow)]
+pub struct Catechist {
+    pub id: Uuid,
+    pub class_id: Uuid,
+    pub member_id: Option<Uuid>,
+    pub full_name: String,
+    pub is_lead: bool,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, Deserialize, FromRow)]
+pub struct Candidate {
+    pub id: Uuid,
+    pub class_id: Uuid,
+    pub member_id: Uuid,
+    pub enrolled_on: NaiveDate,
+    pub sponsor_member_id: Option<Uuid>,
+    pub sponsor_name: Option<String>,
+    pub readiness: CandidateReadiness,
+    pub assessment_notes: Option<String>,
+    pub assessed_on: Option<NaiveDate>,
+    pub assessed_by: Option<Uuid>,
+    pub sacrament_record_id: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    #[sqlx(default)]
+    pub member_code: Option<String>,
+    #[sqlx(default)]
+    pub member_name: Option<String>,
+    #[sqlx(default)]
+    pub sessions_attended: Option<i64>,
+    #[sqlx(default)]
+    pub sessions_held: Option<i64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, FromRow)]
+pub struct CatechesisSession {
+    pub id: Uuid,
+    pub class_id: Uuid,
+    pub session_date: NaiveDate,
+    pub topic: Option<String>,
+    pub catechist_id: Option<Uuid>,
+    pub notes: Option<String>,
+    pub created_at: DateTime<Utc>,
+    #[sqlx(default)]
+    pub present_count: Option<i64>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CatechesisClassDetail {
+    #[serde(flatten)]
+    pub class: CatechesisClass,
+    pub catechists: Vec<Catechist>,
+    pub candidates: Vec<Candidate>,
+    pub sessions: Vec<CatechesisSession>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CreateCatechesisClassRequest {
+    pub parish_id: Uuid,
+    pub cluster_id: Option<Uuid>,
+    pub class_name: String,
+    pub sacrament_type: SacramentType,
+    pub start_date: NaiveDate,
+    pub end_date: Option<NaiveDate>,
+    pub planned_celebration_date: Option<NaiveDate>,
+    pub min_attendance_percent: Option<i32>,
+    pub notes: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UpdateCatechesisClassRequest {
+    pub cluster_id: Option<Uuid>,
+    pub class_name: Option<String>,
+    pub start_date: Option<NaiveDate>,
+    pub end_date: Option<NaiveDate>,
+    pub planned_celebration_date: Option<NaiveDate>,
+    pub min_attendance_percent: Option<i32>,
+    pub notes: Option<String>,
+}
+
+/// A member of the parish by `member_id`, or someone else by name.
+#[derive(Debug, Deserialize)]
+pub struct AddCatechistRequest {
+    pub member_id: Option<Uuid>,
+    pub full_name: Option<String>,
+    #[serde(default)]
+    pub is_lead: bool,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct EnrolCandidatesRequest {
+    pub member_ids: Vec<Uuid>,
+    pub enrolled_on: Option<NaiveDate>,
+}
+
+/// Records the assessment, or the godparent or sponsor chosen.
+#[derive(Debug, Deserialize)]
+pub struct UpdateCandidateRequest {
+    pub readiness: Option<CandidateReadiness>,
+    pub assessment_notes: Option<String>,
+    pub sponsor_member_id: Option<Uuid>,
+    pub sponsor_name: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RecordSessionRequest {
+    pub session_date: NaiveDate,
+    pub topic: Option<String>,
+    pub catechist_id: Option<Uuid>,
+    pub notes: Option<String>,
+    /// Candidates present; everyone else enrolled is marked absent.
+    #[serde(default)]
+    pub present: Vec<Uuid>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UpdateAttendanceRequest {
+    pub present: Vec<Uuid>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CelebrateRequest {
+    pub sacrament_date: NaiveDate,
+    pub officiating_minister: Option<String>,
+    pub church_name: Option<String>,
+    /// Saves records although canonical checks failed.
+    pub override_reason: Option<String>,
+}
+
+/// A ready candidate whose record was not made.
+#[derive(Debug, Serialize)]
+pub struct SkippedCandidate {
+    pub candidate_id: Uuid,
+    pub member_id: Uuid,
+    pub member_name: Option<String>,
+    pub reason: String,
+    pub violations: Vec<RuleViolation>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CelebrationResult {
+    pub records: Vec<SavedSacrament>,
+    pub skipped: Vec<SkippedCandidate>,
+}
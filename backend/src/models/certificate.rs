@@ -0,0 +1,101 @@
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+use chrono::{NaiveDate, DateTime, Utc};
+use crate::models::member::SacramentType;
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone, Copy, sqlx::Type)]
+#[sqlx(type_name = "certificate_language", rename_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum CertificateLanguage {
+    Latin,
+    English,
+    Swahili,
+}
+
+/// Certificate wording. `body` may use the placeholders listed in
+/// `handlers::certificate::PLACEHOLDERS`, e.g. `{full_name}`.
+#[derive(Debug, Serialize, Deserialize, FromRow)]
+pub struct CertificateTemplate {
+    pub id: Uuid,
+    pub parish_id: Uuid,
+    pub sacrament_type: SacramentType,
+    pub language: CertificateLanguage,
+    pub title: String,
+    pub body: String,
+    pub signatory_title: Option<String>,
+    pub created_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub deleted_at: Option<DateTime<Utc>>,
+}
+
+/// Saving a template for a sacrament and language that already has one
+/// replaces its wording.
+#[derive(Debug, Deserialize)]
+pub struct SaveCertificateTemplateRequest {
+    pub parish_id: Uuid,
+    pub sacrament_type: SacramentType,
+    pub language: CertificateLanguage,
+    pub title: String,
+    pub body: String,
+    pub signatory_title: Option<String>,
+}
+
+/// The wording used when a parish has not saved its own.
+#[derive(Debug, Serialize)]
+pub struct DefaultCertificateTemplate {
+    pub sacrament_type: SacramentType,
+    pub language: CertificateLanguage,
+    pub title: String,
+    pub body: String,
+    pub signatory_title: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, FromRow)]
+pub struct CertificateIssuance {
+    pub id: Uuid,
+    pub sacrament_record_id: Uuid,
+    pub parish_id: Uuid,
+    pub certificate_number: String,
+    pub language: CertificateLanguage,
+    pub verification_code: String,
+    pub title: String,
+    pub body: String,
+    pub signatory_title: Option<String>,
+    pub issued_to: Option<String>,
+    pub purpose: Option<String>,
+    pub issued_by: Option<Uuid>,
+    pub issued_at: DateTime<Utc>,
+    pub revoked_at: Option<DateTime<Utc>>,
+    pub revoked_by: Option<Uuid>,
+    pub revocation_reason: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct IssueCertificateRequest {
+    pub language: Option<CertificateLanguage>,
+    /// Who the certificate was handed to, if not the member.
+    pub issued_to: Option<String>,
+    pub purpose: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RevokeCertificateRequest {
+    pub reason: String,
+}
+
+/// What the public verification page shows: enough to match the paper,
+/// nothing more.
+#[derive(Debug, Serialize, FromRow)]
+pub struct CertificateVerification {
+    pub certificate_number: String,
+    pub sacrament_type: SacramentType,
+    pub holder_name: String,
+    pub sacrament_date: NaiveDate,
+    pub parish_name: String,
+    pub issued_at: DateTime<Utc>,
+    pub revoked_at: Option<DateTime<Utc>>,
+    #[sqlx(default)]
+    pub valid: bool,
+}
@@ -0,0 +1,83 @@
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+use chrono::{NaiveDate, DateTime, Utc};
+use rust_decimal::Decimal;
+
+#[derive(Debug, Serialize, Deserialize, FromRow)]
+pub struct ExchangeRate {
+    pub id: Uuid,
+    pub diocese_id: Uuid,
+    pub currency_code: String,
+    pub rate_date: NaiveDate,
+    /// Base currency units per unit of `currency_code`.
+    pub rate: Decimal,
+    pub source: Option<String>,
+    pub created_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Saving a rate for a currency and date that already has one replaces it.
+#[derive(Debug, Deserialize)]
+pub struct SaveExchangeRateRequest {
+    pub diocese_id: Option<Uuid>,
+    pub currency_code: String,
+    pub rate_date: NaiveDate,
+    pub rate: Decimal,
+    pub source: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, FromRow)]
+pub struct CurrencyConversion {
+    pub id: Uuid,
+    pub parish_id: Uuid,
+    pub currency_code: String,
+    pub conversion_date: NaiveDate,
+    pub foreign_amount: Decimal,
+    pub base_amount: Decimal,
+    pub exchange_rate: Decimal,
+    /// What the foreign money converted was carried at in the books.
+    pub book_value: Decimal,
+    /// Positive for a realized gain, negative for a loss.
+    pub gain_loss: Decimal,
+    pub income_transaction_id: Option<Uuid>,
+    pub expense_voucher_id: Option<Uuid>,
+    pub reference_number: Option<String>,
+    pub notes: Option<String>,
+    pub created_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CreateConversionRequest {
+    pub parish_id: Uuid,
+    pub currency_code: String,
+    pub conversion_date: NaiveDate,
+    pub foreign_amount: Decimal,
+    /// Base currency actually received for it.
+    pub base_amount: Decimal,
+    pub reference_number: Option<String>,
+    pub notes: Option<String>,
+}
+
+/// Foreign money a parish holds: received, less paid out and converted.
+#[derive(Debug, Serialize, FromRow)]
+pub struct CurrencyHolding {
+    pub currency_code: String,
+    pub foreign_balance: Decimal,
+    pub book_value: Decimal,
+    #[sqlx(default)]
+    pub current_rate: Option<Decimal>,
+    #[sqlx(default)]
+    pub current_value: Option<Decimal>,
+    #[sqlx(default)]
+    pub unrealized_gain_loss: Option<Decimal>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CurrencyHoldings {
+    pub base_currency: String,
+    pub as_of: NaiveDate,
+    pub holdings: Vec<CurrencyHolding>,
+}
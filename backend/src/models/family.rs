@@ -48,3 +48,10 @@ pub struct UpdateFamilyRequest {
     pub notes: Option<String>,
     pub is_active: Option<bool>,
 }
+
+/// Makes a living member of the family its head, e.g. after the head has
+/// died.
+#[derive(Debug, Deserialize)]
+pub struct AssignFamilyHeadRequest {
+    pub member_id: Uuid,
+}
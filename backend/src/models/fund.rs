@@ -0,0 +1,48 @@
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone, Copy, sqlx::Type)]
+#[sqlx(type_name = "fund_type", rename_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum FundType {
+    Restricted,
+    Unrestricted,
+}
+
+#[derive(Debug, Serialize, Deserialize, FromRow)]
+pub struct Fund {
+    pub id: Uuid,
+    pub parish_id: Uuid,
+    pub fund_code: String,
+    pub fund_name: String,
+    pub fund_type: FundType,
+    pub purpose: Option<String>,
+    pub opening_balance: Decimal,
+    pub is_active: bool,
+    pub created_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub deleted_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CreateFundRequest {
+    pub parish_id: Uuid,
+    pub fund_code: String,
+    pub fund_name: String,
+    pub fund_type: FundType,
+    pub purpose: Option<String>,
+    pub opening_balance: Option<Decimal>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UpdateFundRequest {
+    pub fund_name: Option<String>,
+    pub fund_type: Option<FundType>,
+    pub purpose: Option<String>,
+    pub opening_balance: Option<Decimal>,
+    pub is_active: Option<bool>,
+}
@@ -0,0 +1,161 @@
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+use chrono::{NaiveDate, DateTime, Utc};
+use rust_decimal::Decimal;
+use crate::models::transaction::TransactionCategory;
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone, Copy, sqlx::Type)]
+#[sqlx(type_name = "levy_period", rename_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum LevyPeriod {
+    Monthly,
+    Quarterly,
+    Annual,
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone, Copy, sqlx::Type)]
+#[sqlx(type_name = "levy_status", rename_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum LevyStatus {
+    Outstanding,
+    PartiallyPaid,
+    Paid,
+    Waived,
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone, Copy, sqlx::Type)]
+#[sqlx(type_name = "levy_entry_type", rename_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum LevyEntryType {
+    Assessment,
+    Payment,
+    Adjustment,
+    Waiver,
+}
+
+#[derive(Debug, Serialize, Deserialize, FromRow)]
+pub struct LevyRule {
+    pub id: Uuid,
+    pub diocese_id: Uuid,
+    pub rule_name: String,
+    pub rate: Decimal,
+    pub income_categories: Vec<TransactionCategory>,
+    pub period: LevyPeriod,
+    pub effective_from: NaiveDate,
+    pub effective_to: Option<NaiveDate>,
+    pub due_days: i32,
+    pub is_active: bool,
+    pub created_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub deleted_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Serialize, Deserialize, FromRow)]
+pub struct LevyAssessment {
+    pub id: Uuid,
+    pub levy_rule_id: Uuid,
+    pub diocese_id: Uuid,
+    pub parish_id: Uuid,
+    pub period_start: NaiveDate,
+    pub period_end: NaiveDate,
+    pub assessable_income: Decimal,
+    pub rate: Decimal,
+    pub amount: Decimal,
+    pub amount_paid: Decimal,
+    pub due_date: NaiveDate,
+    pub status: LevyStatus,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, Deserialize, FromRow)]
+pub struct LevyLedgerEntry {
+    pub id: Uuid,
+    pub diocese_id: Uuid,
+    pub parish_id: Uuid,
+    pub assessment_id: Option<Uuid>,
+    pub entry_type: LevyEntryType,
+    pub entry_date: NaiveDate,
+    pub amount: Decimal,
+    pub reference_number: Option<String>,
+    pub expense_voucher_id: Option<Uuid>,
+    pub description: Option<String>,
+    pub recorded_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CreateLevyRuleRequest {
+    pub diocese_id: Option<Uuid>,
+    pub rule_name: String,
+    pub rate: Decimal,
+    pub income_categories: Vec<TransactionCategory>,
+    pub period: LevyPeriod,
+    pub effective_from: NaiveDate,
+    pub effective_to: Option<NaiveDate>,
+    pub due_days: Option<i32>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UpdateLevyRuleRequest {
+    pub rule_name: Option<String>,
+    pub rate: Option<Decimal>,
+    pub income_categories: Option<Vec<TransactionCategory>>,
+    /// Absent keeps the end date; `null` makes the rule open-ended again.
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    pub effective_to: Option<Option<NaiveDate>>,
+    pub due_days: Option<i32>,
+    pub is_active: Option<bool>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RecordLevyPaymentRequest {
+    pub parish_id: Uuid,
+    pub amount: Decimal,
+    pub payment_date: NaiveDate,
+    pub assessment_id: Option<Uuid>,
+    pub expense_voucher_id: Option<Uuid>,
+    pub reference_number: Option<String>,
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct WaiveLevyRequest {
+    pub reason: String,
+}
+
+// --- Response types ---
+
+#[derive(Debug, Serialize)]
+pub struct LevyRunResponse {
+    pub assessments_created: usize,
+}
+
+#[derive(Debug, Serialize)]
+pub struct LevyLedgerLine {
+    #[serde(flatten)]
+    pub entry: LevyLedgerEntry,
+    pub running_balance: Decimal,
+}
+
+#[derive(Debug, Serialize)]
+pub struct LevyLedgerStatement {
+    pub parish_id: Uuid,
+    pub entries: Vec<LevyLedgerLine>,
+    pub balance: Decimal,
+}
+
+#[derive(Debug, Serialize, FromRow)]
+pub struct ParishLevyArrears {
+    pub parish_id: Uuid,
+    pub parish_code: String,
+    pub parish_name: String,
+    pub total_assessed: Decimal,
+    pub total_paid: Decimal,
+    pub outstanding: Decimal,
+    pub overdue_amount: Decimal,
+    pub overdue_assessments: i64,
+    pub oldest_overdue_date: Option<NaiveDate>,
+}
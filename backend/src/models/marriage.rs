@@ -0,0 +1,220 @@
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+use chrono::{NaiveDate, DateTime, Utc};
+use crate::models::sacrament_rule::SavedSacrament;
+use crate::models::sponsor::SponsorInput;
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone, Copy, sqlx::Type)]
+#[sqlx(type_name = "marriage_case_status", rename_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum MarriageCaseStatus {
+    Open,
+    /// The marriage is recorded in the sacrament register.
+    Completed,
+    Cancelled,
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone, Copy, sqlx::Type)]
+#[sqlx(type_name = "marriage_party", rename_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum MarriageParty {
+    Groom,
+    Bride,
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone, Copy, sqlx::Type)]
+#[sqlx(type_name = "marriage_document_type", rename_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum MarriageDocumentType {
+    BaptismCertificate,
+    ConfirmationCertificate,
+    /// Sworn statement that the party is free to marry.
+    FreedomToMarry,
+    CivilNotice,
+    Dispensation,
+    Other,
+}
+
+#[derive(Debug, Serialize, Deserialize, FromRow)]
+pub struct MarriageCase {
+    pub id: Uuid,
+    pub parish_id: Uuid,
+    pub groom_member_id: Option<Uuid>,
+    pub groom_name: String,
+    pub groom_parish_id: Option<Uuid>,
+    pub groom_parish_name: Option<String>,
+    pub bride_member_id: Option<Uuid>,
+    pub bride_name: String,
+    pub bride_parish_id: Option<Uuid>,
+    pub bride_parish_name: Option<String>,
+    pub proposed_date: Option<NaiveDate>,
+    pub church_name: Option<String>,
+    pub officiating_minister: Option<String>,
+    pub pre_cana_course: Option<String>,
+    pub pre_cana_completed_on: Option<NaiveDate>,
+    pub status: MarriageCaseStatus,
+    pub groom_record_id: Option<Uuid>,
+    pub bride_record_id: Option<Uuid>,
+    pub notes: Option<String>,
+    pub created_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub deleted_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Serialize, Deserialize, FromRow)]
+pub struct MarriageDocument {
+    pub id: Uuid,
+    pub marriage_case_id: Uuid,
+    pub party: MarriageParty,
+    pub document_type: MarriageDocumentType,
+    pub required: bool,
+    pub issued_on: Option<NaiveDate>,
+    pub received_on: Option<NaiveDate>,
+    pub reference: Option<String>,
+    pub notes: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, Deserialize, FromRow)]
+pub struct BannsPublication {
+    pub id: Uuid,
+    pub marriage_case_id: Uuid,
+    pub party: Option<MarriageParty>,
+    pub parish_id: Option<Uuid>,
+    pub parish_name: String,
+    pub publication_number: i32,
+    pub publish_on: NaiveDate,
+    pub published_at: Option<DateTime<Utc>>,
+    pub confirmed_by: Option<Uuid>,
+    pub objection: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, Deserialize, FromRow)]
+pub struct MarriageImpediment {
+    pub id: Uuid,
+    pub marriage_case_id: Uuid,
+    pub description: String,
+    pub resolution: Option<String>,
+    pub dispensation_reference: Option<String>,
+    pub resolved_on: Option<NaiveDate>,
+    pub resolved_by: Option<Uuid>,
+    pub created_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// A case file with everything still missing before the wedding.
+#[derive(Debug, Serialize)]
+pub struct MarriageCaseDetail {
+    #[serde(flatten)]
+    pub case: MarriageCase,
+    pub documents: Vec<MarriageDocument>,
+    pub banns: Vec<BannsPublication>,
+    pub impediments: Vec<MarriageImpediment>,
+    pub outstanding: Vec<String>,
+    pub ready: bool,
+}
+
+/// Each party is given by `member_id`, or by name and parish when they
+/// are not a member.
+#[derive(Debug, Deserialize)]
+pub struct CreateMarriageCaseRequest {
+    pub parish_id: Uuid,
+    pub groom_member_id: Option<Uuid>,
+    pub groom_name: Option<String>,
+    pub groom_parish_id: Option<Uuid>,
+    pub groom_parish_name: Option<String>,
+    pub bride_member_id: Option<Uuid>,
+    pub bride_name: Option<String>,
+    pub bride_parish_id: Option<Uuid>,
+    pub bride_parish_name: Option<String>,
+    pub proposed_date: Option<NaiveDate>,
+    pub church_name: Option<String>,
+    pub officiating_minister: Option<String>,
+    pub pre_cana_course: Option<String>,
+    pub notes: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UpdateMarriageCaseRequest {
+    pub groom_parish_name: Option<String>,
+    pub bride_parish_name: Option<String>,
+    pub proposed_date: Option<NaiveDate>,
+    pub church_name: Option<String>,
+    pub officiating_minister: Option<String>,
+    pub pre_cana_course: Option<String>,
+    pub pre_cana_completed_on: Option<NaiveDate>,
+    /// OPEN or CANCELLED; a case is completed by converting it.
+    pub status: Option<MarriageCaseStatus>,
+    pub notes: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CreateMarriageDocumentRequest {
+    pub party: MarriageParty,
+    pub document_type: MarriageDocumentType,
+    pub required: Option<bool>,
+    pub issued_on: Option<NaiveDate>,
+    pub received_on: Option<NaiveDate>,
+    pub reference: Option<String>,
+    pub notes: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UpdateMarriageDocumentRequest {
+    pub required: Option<bool>,
+    pub issued_on: Option<NaiveDate>,
+    pub received_on: Option<NaiveDate>,
+    pub reference: Option<String>,
+    pub notes: Option<String>,
+}
+
+/// Schedules the banns on three consecutive Sundays from `first_sunday`
+/// in each party's parish, replacing any not yet read.
+#[derive(Debug, Deserialize)]
+pub struct ScheduleBannsRequest {
+    pub first_sunday: NaiveDate,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UpdateBannsRequest {
+    /// Confirms the banns were read.
+    pub published: Option<bool>,
+    /// An objection raised; it is also entered as an impediment.
+    pub objection: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CreateImpedimentRequest {
+    pub description: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ResolveImpedimentRequest {
+    pub resolution: String,
+    pub dispensation_reference: Option<String>,
+    pub resolved_on: Option<NaiveDate>,
+}
+
+/// Records the marriage for each party who is a member.
+#[derive(Debug, Deserialize)]
+pub struct CompleteMarriageRequest {
+    pub sacrament_date: NaiveDate,
+    pub officiating_minister: Option<String>,
+    pub church_name: Option<String>,
+    /// The two official witnesses and any others.
+    #[serde(default)]
+    pub witnesses: Vec<SponsorInput>,
+    pub notes: Option<String>,
+    /// Saves the records although canonical checks failed.
+    pub override_reason: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct MarriageCompletion {
+    pub case: MarriageCaseDetail,
+    pub records: Vec<SavedSacrament>,
+}
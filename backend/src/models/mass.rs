@@ -0,0 +1,161 @@
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+use chrono::{NaiveDate, NaiveTime, DateTime, Utc};
+use rust_decimal::Decimal;
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone, Copy, sqlx::Type)]
+#[sqlx(type_name = "mass_intention_status", rename_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum MassIntentionStatus {
+    Booked,
+    Celebrated,
+    /// Passed to another priest to celebrate.
+    Transferred,
+    Cancelled,
+}
+
+#[derive(Debug, Serialize, Deserialize, FromRow)]
+pub struct MassSchedule {
+    pub id: Uuid,
+    pub parish_id: Uuid,
+    pub cluster_id: Option<Uuid>,
+    /// ISO day of the week, Monday = 1 to Sunday = 7.
+    pub day_of_week: i16,
+    pub mass_time: NaiveTime,
+    pub church_name: Option<String>,
+    pub language: Option<String>,
+    pub max_intentions: i32,
+    pub valid_from: Option<NaiveDate>,
+    pub valid_to: Option<NaiveDate>,
+    pub is_active: bool,
+    pub notes: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub deleted_at: Option<DateTime<Utc>>,
+    #[sqlx(default)]
+    pub cluster_name: Option<String>,
+}
+
+/// One celebration of a scheduled Mass and how many intentions it has.
+#[derive(Debug, Serialize, FromRow)]
+pub struct MassSlot {
+    pub mass_schedule_id: Uuid,
+    pub mass_date: NaiveDate,
+    pub mass_time: NaiveTime,
+    pub church_name: Option<String>,
+    pub cluster_name: Option<String>,
+    pub language: Option<String>,
+    pub max_intentions: i32,
+    pub booked: i64,
+    pub available: i64,
+}
+
+#[derive(Debug, Serialize, Deserialize, FromRow)]
+pub struct MassIntention {
+    pub id: Uuid,
+    pub parish_id: Uuid,
+    pub mass_schedule_id: Option<Uuid>,
+    pub mass_date: Option<NaiveDate>,
+    pub intention: String,
+    pub member_id: Option<Uuid>,
+    pub requested_by_member_id: Option<Uuid>,
+    pub requested_by_name: String,
+    pub requested_by_phone: Option<String>,
+    pub stipend_amount: Decimal,
+    pub income_transaction_id: Option<Uuid>,
+    pub status: MassIntentionStatus,
+    pub celebrated_by: Option<String>,
+    pub transferred_to: Option<String>,
+    pub transferred_on: Option<NaiveDate>,
+    pub transfer_reference: Option<String>,
+    pub notes: Option<String>,
+    pub booked_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub deleted_at: Option<DateTime<Utc>>,
+    #[sqlx(default)]
+    pub mass_time: Option<NaiveTime>,
+    #[sqlx(default)]
+    pub church_name: Option<String>,
+    #[sqlx(default)]
+    pub receipt_number: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CreateMassScheduleRequest {
+    pub parish_id: Uuid,
+    pub cluster_id: Option<Uuid>,
+    pub day_of_week: i16,
+    pub mass_time: NaiveTime,
+    pub church_name: Option<String>,
+    pub language: Option<String>,
+    pub max_intentions: Option<i32>,
+    pub valid_from: Option<NaiveDate>,
+    pub valid_to: Option<NaiveDate>,
+    pub notes: Option<String>,
+}
+
+/// Changes apply to future bookings; intentions already booked stay on
+/// their Mass.
+#[derive(Debug, Deserialize)]
+pub struct UpdateMassScheduleRequest {
+    pub cluster_id: Option<Uuid>,
+    pub mass_time: Option<NaiveTime>,
+    pub church_name: Option<String>,
+    pub language: Option<String>,
+    pub max_intentions: Option<i32>,
+    pub valid_from: Option<NaiveDate>,
+    pub valid_to: Option<NaiveDate>,
+    pub is_active: Option<bool>,
+    pub notes: Option<String>,
+}
+
+/// The requester is a member by `requested_by_member_id`, or anyone by
+/// name. A stipend is linked to its Mass offering receipt.
+#[derive(Debug, Deserialize)]
+pub struct CreateMassIntentionRequest {
+    pub mass_schedule_id: Uuid,
+    pub mass_date: NaiveDate,
+    pub intention: String,
+    pub member_id: Option<Uuid>,
+    pub requested_by_member_id: Option<Uuid>,
+    pub requested_by_name: Option<String>,
+    pub requested_by_phone: Option<String>,
+    pub stipend_amount: Option<Decimal>,
+    pub income_transaction_id: Option<Uuid>,
+    pub notes: Option<String>,
+}
+
+/// Moving an intention to another Mass is checked against its capacity.
+#[derive(Debug, Deserialize)]
+pub struct UpdateMassIntentionRequest {
+    pub mass_schedule_id: Option<Uuid>,
+    pub mass_date: Option<NaiveDate>,
+    pub intention: Option<String>,
+    pub requested_by_phone: Option<String>,
+    pub stipend_amount: Option<Decimal>,
+    pub income_transaction_id: Option<Uuid>,
+    /// BOOKED, CELEBRATED or CANCELLED; transfers have their own endpoint.
+    pub status: Option<MassIntentionStatus>,
+    pub celebrated_by: Option<String>,
+    pub notes: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TransferMassIntentionRequest {
+    /// The priest who will celebrate the Mass.
+    pub transferred_to: String,
+    pub transferred_on: Option<NaiveDate>,
+    pub transfer_reference: Option<String>,
+    pub notes: Option<String>,
+}
+
+/// A Mass in the celebrant's weekly list with the intentions to be
+/// offered at it.
+#[derive(Debug, Serialize)]
+pub struct WeeklyMass {
+    #[serde(flatten)]
+    pub mass: MassSlot,
+    pub intentions: Vec<MassIntention>,
+}
@@ -3,7 +3,7 @@ use sqlx::FromRow;
 use uuid::Uuid;
 use chrono::{NaiveDate, DateTime, Utc};
 
-#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, sqlx::Type)]
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone, Copy, sqlx::Type)]
 #[sqlx(type_name = "gender_type", rename_all = "SCREAMING_SNAKE_CASE")]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum GenderType {
@@ -11,7 +11,7 @@ pub enum GenderType {
     Female,
 }
 
-#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, sqlx::Type)]
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone, Copy, sqlx::Type)]
 #[sqlx(type_name = "marital_status", rename_all = "SCREAMING_SNAKE_CASE")]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum MaritalStatus {
@@ -22,7 +22,7 @@ pub enum MaritalStatus {
     Divorced,
 }
 
-#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, sqlx::Type)]
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone, Copy, sqlx::Type)]
 #[sqlx(type_name = "sacrament_type", rename_all = "SCREAMING_SNAKE_CASE")]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum SacramentType {
@@ -65,6 +65,9 @@ pub struct Member {
     pub family_role: Option<FamilyRole>,
     pub notes: Option<String>,
     pub is_active: Option<bool>,
+    /// Set from the burial register.
+    #[serde(default)]
+    pub date_of_death: Option<NaiveDate>,
     pub created_at: Option<DateTime<Utc>>,
     pub updated_at: Option<DateTime<Utc>>,
     pub deleted_at: Option<DateTime<Utc>>,
@@ -86,6 +89,20 @@ pub struct SacramentRecord {
     pub spouse_name: Option<String>,
     pub witnesses: Option<String>,
     pub notes: Option<String>,
+    /// Set when the record was saved although a canonical check failed.
+    #[serde(default)]
+    pub override_reason: Option<String>,
+    #[serde(default)]
+    pub overridden_by: Option<Uuid>,
+    #[serde(default)]
+    pub overridden_at: Option<DateTime<Utc>>,
+    /// Where the record is written in the parish's paper register.
+    #[serde(default)]
+    pub register_book_id: Option<Uuid>,
+    #[serde(default)]
+    pub register_page: Option<i32>,
+    #[serde(default)]
+    pub register_entry: Option<i32>,
     pub created_at: Option<DateTime<Utc>>,
     pub updated_at: Option<DateTime<Utc>>,
     pub deleted_at: Option<DateTime<Utc>>,
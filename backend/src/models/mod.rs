@@ -12,3 +12,23 @@ pub mod family;
 pub mod setting;
 pub mod permission;
 pub mod audit;
+pub mod fund;
+pub mod levy;
+pub mod report_schedule;
+pub mod recurring;
+pub mod cashbook;
+pub mod asset;
+pub mod payroll;
+pub mod supplier;
+pub mod currency;
+pub mod certificate;
+pub mod sacrament_rule;
+pub mod notation;
+pub mod register;
+pub mod sponsor;
+pub mod burial;
+pub mod marriage;
+pub mod catechesis;
+pub mod mass;
+pub mod statistics;
+pub mod transfer;
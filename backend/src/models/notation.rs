@@ -0,0 +1,105 @@
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+use chrono::{NaiveDate, DateTime, Utc};
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone, Copy, sqlx::Type)]
+#[sqlx(type_name = "notation_type", rename_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum NotationType {
+    Confirmation,
+    Marriage,
+    HolyOrders,
+    /// A declaration of nullity of marriage.
+    Nullity,
+    Other,
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone, Copy, sqlx::Type)]
+#[sqlx(type_name = "notation_notice_status", rename_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum NotationNoticeStatus {
+    Pending,
+    Applied,
+    Rejected,
+    /// The sacrament it was made from was corrected or removed.
+    Withdrawn,
+}
+
+#[derive(Debug, Serialize, Deserialize, FromRow)]
+pub struct BaptismNotation {
+    pub id: Uuid,
+    pub baptism_record_id: Uuid,
+    pub notation_type: NotationType,
+    pub notation_date: NaiveDate,
+    pub notation_text: String,
+    pub source_record_id: Option<Uuid>,
+    pub source_parish_id: Option<Uuid>,
+    pub created_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub deleted_at: Option<DateTime<Utc>>,
+}
+
+/// A notation made by hand, e.g. a declaration of nullity. When the
+/// baptism is kept by another parish it is sent there as a notice.
+#[derive(Debug, Deserialize)]
+pub struct CreateNotationRequest {
+    /// The parish making the notation; defaults to the user's parish.
+    pub parish_id: Option<Uuid>,
+    pub notation_type: NotationType,
+    pub notation_date: NaiveDate,
+    pub notation_text: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, FromRow)]
+pub struct NotationNotice {
+    pub id: Uuid,
+    pub from_parish_id: Uuid,
+    pub to_parish_id: Uuid,
+    pub baptism_record_id: Uuid,
+    pub member_id: Uuid,
+    pub notation_type: NotationType,
+    pub notation_date: NaiveDate,
+    pub notation_text: String,
+    pub source_record_id: Option<Uuid>,
+    pub status: NotationNoticeStatus,
+    pub sent_by: Option<Uuid>,
+    pub sent_at: DateTime<Utc>,
+    pub processed_by: Option<Uuid>,
+    pub processed_at: Option<DateTime<Utc>>,
+    pub rejection_reason: Option<String>,
+    pub notation_id: Option<Uuid>,
+    /// Set on a notice asking for this notation to be taken back; applying
+    /// it removes the notation from the register.
+    pub withdraws_notation_id: Option<Uuid>,
+    #[sqlx(default)]
+    pub from_parish_name: Option<String>,
+    #[sqlx(default)]
+    pub to_parish_name: Option<String>,
+    #[sqlx(default)]
+    pub member_name: Option<String>,
+}
+
+/// Either the notation written to the register, or the notice sent to the
+/// parish that keeps the baptism entry.
+#[derive(Debug, Serialize)]
+#[serde(tag = "delivery", rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum NotationOutcome {
+    Recorded { notation: BaptismNotation },
+    Sent { notice: NotationNotice },
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RejectNotationNoticeRequest {
+    pub reason: String,
+}
+
+#[derive(Debug, Deserialize, PartialEq, Eq, Clone, Copy)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum NoticeDirection {
+    /// Notices for baptisms kept by the parish.
+    Incoming,
+    /// Notices the parish has sent.
+    Outgoing,
+}
@@ -0,0 +1,239 @@
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+use chrono::{NaiveDate, DateTime, Utc};
+use rust_decimal::Decimal;
+use crate::models::transaction::ExpenseVoucher;
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone, Copy, sqlx::Type)]
+#[sqlx(type_name = "employment_type", rename_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum EmploymentType {
+    FullTime,
+    PartTime,
+    Casual,
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone, Copy, sqlx::Type)]
+#[sqlx(type_name = "deduction_basis", rename_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum DeductionBasis {
+    Gross,
+    Taxable,
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone, Copy, sqlx::Type)]
+#[sqlx(type_name = "payroll_status", rename_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PayrollStatus {
+    Draft,
+    Approved,
+    Cancelled,
+}
+
+#[derive(Debug, Serialize, Deserialize, FromRow)]
+pub struct Staff {
+    pub id: Uuid,
+    pub parish_id: Uuid,
+    pub staff_number: String,
+    pub member_id: Option<Uuid>,
+    pub full_name: String,
+    pub position: String,
+    pub employment_type: EmploymentType,
+    pub phone: Option<String>,
+    pub national_id: Option<String>,
+    pub tax_number: Option<String>,
+    pub social_security_number: Option<String>,
+    pub bank_name: Option<String>,
+    pub bank_account: Option<String>,
+    pub basic_salary: Decimal,
+    pub allowances: Decimal,
+    pub statutory_exempt: bool,
+    pub start_date: NaiveDate,
+    pub end_date: Option<NaiveDate>,
+    pub is_active: bool,
+    pub created_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub deleted_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CreateStaffRequest {
+    pub parish_id: Uuid,
+    pub staff_number: Option<String>,
+    pub member_id: Option<Uuid>,
+    pub full_name: String,
+    pub position: String,
+    pub employment_type: Option<EmploymentType>,
+    pub phone: Option<String>,
+    pub national_id: Option<String>,
+    pub tax_number: Option<String>,
+    pub social_security_number: Option<String>,
+    pub bank_name: Option<String>,
+    pub bank_account: Option<String>,
+    pub basic_salary: Decimal,
+    pub allowances: Option<Decimal>,
+    pub statutory_exempt: Option<bool>,
+    pub start_date: NaiveDate,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UpdateStaffRequest {
+    pub member_id: Option<Uuid>,
+    pub full_name: Option<String>,
+    pub position: Option<String>,
+    pub employment_type: Option<EmploymentType>,
+    pub phone: Option<String>,
+    pub national_id: Option<String>,
+    pub tax_number: Option<String>,
+    pub social_security_number: Option<String>,
+    pub bank_name: Option<String>,
+    pub bank_account: Option<String>,
+    pub basic_salary: Option<Decimal>,
+    pub allowances: Option<Decimal>,
+    pub statutory_exempt: Option<bool>,
+    pub end_date: Option<NaiveDate>,
+    pub is_active: Option<bool>,
+}
+
+#[derive(Debug, Serialize, Deserialize, FromRow)]
+pub struct StatutoryDeduction {
+    pub id: Uuid,
+    pub parish_id: Option<Uuid>,
+    pub deduction_code: String,
+    pub deduction_name: String,
+    pub basis: DeductionBasis,
+    pub reduces_taxable: bool,
+    pub employer_rate: Decimal,
+    pub payee_name: String,
+    pub is_active: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub deleted_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Serialize, Deserialize, FromRow, Clone)]
+pub struct DeductionBand {
+    #[serde(default)]
+    pub id: Uuid,
+    #[serde(default)]
+    pub deduction_id: Uuid,
+    pub lower_bound: Decimal,
+    pub upper_bound: Option<Decimal>,
+    pub rate: Decimal,
+    #[serde(default)]
+    pub fixed_amount: Decimal,
+}
+
+#[derive(Debug, Serialize)]
+pub struct StatutoryDeductionDetail {
+    #[serde(flatten)]
+    pub deduction: StatutoryDeduction,
+    pub bands: Vec<DeductionBand>,
+}
+
+/// Without a parish the deduction applies to every parish (super admin
+/// only). Bands are replaced wholesale.
+#[derive(Debug, Deserialize)]
+pub struct SaveDeductionRequest {
+    pub parish_id: Option<Uuid>,
+    pub deduction_code: String,
+    pub deduction_name: String,
+    pub basis: DeductionBasis,
+    pub reduces_taxable: Option<bool>,
+    pub employer_rate: Option<Decimal>,
+    pub payee_name: String,
+    pub is_active: Option<bool>,
+    pub bands: Vec<DeductionBand>,
+}
+
+#[derive(Debug, Serialize, Deserialize, FromRow)]
+pub struct PayrollRun {
+    pub id: Uuid,
+    pub parish_id: Uuid,
+    pub period_year: i32,
+    pub period_month: i32,
+    pub payment_date: NaiveDate,
+    pub status: PayrollStatus,
+    pub total_gross: Decimal,
+    pub total_deductions: Decimal,
+    pub total_net: Decimal,
+    pub total_employer_contributions: Decimal,
+    pub notes: Option<String>,
+    pub created_by: Option<Uuid>,
+    pub approved_by: Option<Uuid>,
+    pub approved_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, Deserialize, FromRow)]
+pub struct Payslip {
+    pub id: Uuid,
+    pub payroll_run_id: Uuid,
+    pub staff_id: Uuid,
+    pub basic_salary: Decimal,
+    pub allowances: Decimal,
+    pub other_earnings: Decimal,
+    pub gross_pay: Decimal,
+    pub taxable_pay: Decimal,
+    pub statutory_deductions: Decimal,
+    pub other_deductions: Decimal,
+    pub net_pay: Decimal,
+    pub employer_contributions: Decimal,
+    pub notes: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, Deserialize, FromRow)]
+pub struct PayslipDeduction {
+    pub id: Uuid,
+    pub payslip_id: Uuid,
+    pub deduction_id: Option<Uuid>,
+    pub deduction_code: String,
+    pub deduction_name: String,
+    pub base_amount: Decimal,
+    pub employee_amount: Decimal,
+    pub employer_amount: Decimal,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PayslipDetail {
+    #[serde(flatten)]
+    pub payslip: Payslip,
+    pub staff_number: String,
+    pub full_name: String,
+    pub position: String,
+    pub period_year: i32,
+    pub period_month: i32,
+    pub payment_date: NaiveDate,
+    pub deductions: Vec<PayslipDeduction>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PayrollRunDetail {
+    #[serde(flatten)]
+    pub run: PayrollRun,
+    pub payslips: Vec<PayslipDetail>,
+    pub vouchers: Vec<ExpenseVoucher>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CreatePayrollRunRequest {
+    pub parish_id: Uuid,
+    pub period_year: i32,
+    pub period_month: i32,
+    pub payment_date: Option<NaiveDate>,
+    pub notes: Option<String>,
+}
+
+/// One-off changes to a draft payslip; the deductions are recomputed.
+#[derive(Debug, Deserialize)]
+pub struct UpdatePayslipRequest {
+    pub allowances: Option<Decimal>,
+    pub other_earnings: Option<Decimal>,
+    pub other_deductions: Option<Decimal>,
+    pub notes: Option<String>,
+}
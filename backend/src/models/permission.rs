@@ -24,6 +24,7 @@ pub struct CustomRole {
     pub updated_at: DateTime<Utc>,
 }
 
+#[allow(dead_code)]
 #[derive(Debug, Serialize, Deserialize, FromRow)]
 pub struct RolePermission {
     pub id: Uuid,
@@ -32,6 +33,7 @@ pub struct RolePermission {
     pub created_at: DateTime<Utc>,
 }
 
+#[allow(dead_code)]
 #[derive(Debug, Serialize, Deserialize, FromRow)]
 pub struct UserPermissionOverride {
     pub id: Uuid,
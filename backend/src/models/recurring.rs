@@ -0,0 +1,141 @@
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+use chrono::{NaiveDate, DateTime, Utc};
+use rust_decimal::Decimal;
+use crate::models::transaction::{TransactionCategory, PaymentMethod};
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone, Copy, sqlx::Type)]
+#[sqlx(type_name = "recurring_kind", rename_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum RecurringKind {
+    Expense,
+    Income,
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone, Copy, sqlx::Type)]
+#[sqlx(type_name = "recurrence_frequency", rename_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum RecurrenceFrequency {
+    Weekly,
+    Monthly,
+    Quarterly,
+    Annual,
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone, Copy, sqlx::Type)]
+#[sqlx(type_name = "occurrence_status", rename_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum OccurrenceStatus {
+    Planned,
+    Skipped,
+    Raised,
+    Expected,
+    Received,
+    Failed,
+}
+
+#[derive(Debug, Serialize, Deserialize, FromRow)]
+pub struct RecurringTransaction {
+    pub id: Uuid,
+    pub parish_id: Uuid,
+    pub template_name: String,
+    pub kind: RecurringKind,
+    pub category: TransactionCategory,
+    pub amount: Decimal,
+    pub payment_method: PaymentMethod,
+    pub fund_id: Option<Uuid>,
+    pub cashbook_id: Option<Uuid>,
+    pub payee_name: Option<String>,
+    pub payee_phone: Option<String>,
+    pub member_id: Option<Uuid>,
+    pub family_id: Option<Uuid>,
+    pub description: Option<String>,
+    pub frequency: RecurrenceFrequency,
+    pub start_date: NaiveDate,
+    pub end_date: Option<NaiveDate>,
+    pub next_due_date: Option<NaiveDate>,
+    pub is_active: bool,
+    pub created_by: Uuid,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub deleted_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Serialize, Deserialize, FromRow)]
+pub struct RecurringOccurrence {
+    pub id: Uuid,
+    pub recurring_id: Uuid,
+    pub parish_id: Uuid,
+    pub due_date: NaiveDate,
+    pub status: OccurrenceStatus,
+    pub amount: Option<Decimal>,
+    pub note: Option<String>,
+    pub expense_voucher_id: Option<Uuid>,
+    pub income_transaction_id: Option<Uuid>,
+    pub created_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// A coming due date with any skip or adjustment already recorded for it.
+#[derive(Debug, Serialize)]
+pub struct UpcomingOccurrence {
+    pub due_date: NaiveDate,
+    pub amount: Decimal,
+    pub skipped: bool,
+    pub adjusted: bool,
+    pub note: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CreateRecurringRequest {
+    pub parish_id: Uuid,
+    pub template_name: String,
+    pub kind: RecurringKind,
+    pub category: TransactionCategory,
+    pub amount: Decimal,
+    pub payment_method: Option<PaymentMethod>,
+    pub fund_id: Option<Uuid>,
+    pub cashbook_id: Option<Uuid>,
+    pub payee_name: Option<String>,
+    pub payee_phone: Option<String>,
+    pub member_id: Option<Uuid>,
+    pub family_id: Option<Uuid>,
+    pub description: Option<String>,
+    pub frequency: Option<RecurrenceFrequency>,
+    pub start_date: NaiveDate,
+    pub end_date: Option<NaiveDate>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UpdateRecurringRequest {
+    pub template_name: Option<String>,
+    pub amount: Option<Decimal>,
+    pub payment_method: Option<PaymentMethod>,
+    pub fund_id: Option<Uuid>,
+    pub cashbook_id: Option<Uuid>,
+    pub payee_name: Option<String>,
+    pub payee_phone: Option<String>,
+    pub description: Option<String>,
+    pub end_date: Option<NaiveDate>,
+    pub is_active: Option<bool>,
+}
+
+/// Skips one due date, or changes its amount, before the job reaches it.
+#[derive(Debug, Deserialize)]
+pub struct AdjustOccurrenceRequest {
+    pub due_date: NaiveDate,
+    pub skip: Option<bool>,
+    pub amount: Option<Decimal>,
+    pub note: Option<String>,
+}
+
+/// Confirms an expected income occurrence was received.
+#[derive(Debug, Deserialize)]
+pub struct ReceiveOccurrenceRequest {
+    pub amount: Option<Decimal>,
+    pub transaction_date: Option<NaiveDate>,
+    pub payment_method: Option<PaymentMethod>,
+    pub reference_number: Option<String>,
+}
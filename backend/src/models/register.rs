@@ -0,0 +1,107 @@
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+use chrono::{NaiveDate, DateTime, Utc};
+use crate::models::member::SacramentType;
+
+/// A bound paper register. Only one volume per parish and sacrament is
+/// open at a time; new records are numbered in it.
+#[derive(Debug, Serialize, Deserialize, FromRow)]
+pub struct RegisterBook {
+    pub id: Uuid,
+    pub parish_id: Uuid,
+    pub sacrament_type: SacramentType,
+    pub volume_number: i32,
+    pub title: Option<String>,
+    pub page_count: Option<i32>,
+    pub entries_per_page: Option<i32>,
+    pub first_entry_number: i32,
+    pub opened_on: NaiveDate,
+    pub closed_on: Option<NaiveDate>,
+    pub notes: Option<String>,
+    pub created_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub deleted_at: Option<DateTime<Utc>>,
+    #[sqlx(default)]
+    pub entry_count: Option<i64>,
+    #[sqlx(default)]
+    pub last_entry: Option<i32>,
+    #[sqlx(default)]
+    pub last_page: Option<i32>,
+}
+
+/// Opening a volume closes the one currently open for the sacrament.
+#[derive(Debug, Deserialize)]
+pub struct CreateRegisterBookRequest {
+    pub parish_id: Uuid,
+    pub sacrament_type: SacramentType,
+    /// Defaults to the next volume number.
+    pub volume_number: Option<i32>,
+    pub title: Option<String>,
+    pub page_count: Option<i32>,
+    pub entries_per_page: Option<i32>,
+    /// Defaults to 1; set it where numbering runs on from the previous
+    /// volume.
+    pub first_entry_number: Option<i32>,
+    pub opened_on: Option<NaiveDate>,
+    /// Given for an old, already full volume, which leaves the open one
+    /// as it is.
+    pub closed_on: Option<NaiveDate>,
+    pub notes: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UpdateRegisterBookRequest {
+    pub title: Option<String>,
+    pub page_count: Option<i32>,
+    pub entries_per_page: Option<i32>,
+    pub first_entry_number: Option<i32>,
+    pub opened_on: Option<NaiveDate>,
+    pub closed_on: Option<NaiveDate>,
+    pub notes: Option<String>,
+}
+
+/// Where a record is written on paper.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RegisterEntryRef {
+    pub register_book_id: Option<Uuid>,
+    pub register_page: Option<i32>,
+    pub register_entry: Option<i32>,
+}
+
+/// Consecutive entry numbers with no record.
+#[derive(Debug, Serialize)]
+pub struct EntryGap {
+    pub from_entry: i32,
+    pub to_entry: i32,
+}
+
+#[derive(Debug, Serialize, Clone, FromRow)]
+pub struct RegisterEntryLine {
+    pub record_id: Uuid,
+    pub member_id: Uuid,
+    pub member_name: String,
+    pub sacrament_date: NaiveDate,
+    pub register_page: Option<i32>,
+    pub register_entry: i32,
+    #[serde(skip)]
+    #[sqlx(default)]
+    pub couple_id: Option<Uuid>,
+}
+
+/// Numbering problems in a volume, for checking digitised entries against
+/// the paper.
+#[derive(Debug, Serialize)]
+pub struct RegisterBookAudit {
+    pub book: RegisterBook,
+    pub reference: String,
+    pub gaps: Vec<EntryGap>,
+    /// Entry numbers cited by more than one record.
+    pub duplicates: Vec<RegisterEntryLine>,
+    /// Records whose page does not match the page their entry number
+    /// falls on, when the volume has a fixed number of entries per page.
+    pub page_mismatches: Vec<RegisterEntryLine>,
+    /// Records of this sacrament in the parish that cite no register.
+    pub uncited_records: i64,
+}
@@ -1,5 +1,7 @@
 use serde::Serialize;
 use rust_decimal::Decimal;
+use uuid::Uuid;
+use chrono::NaiveDate;
 
 #[derive(Debug, Serialize)]
 pub struct TrialBalanceEntry {
@@ -87,3 +89,112 @@ pub struct CashFlowStatement {
     pub opening_balance: Decimal,
     pub closing_balance: Decimal,
 }
+
+#[derive(Debug, Serialize)]
+pub struct FundBalanceEntry {
+    pub fund_id: Option<Uuid>, // None = general (untagged) pool
+    pub fund_code: String,
+    pub fund_name: String,
+    pub fund_type: String,
+    pub opening_balance: Decimal,
+    pub income: Decimal,
+    pub expenditure: Decimal,
+    pub closing_balance: Decimal,
+    pub pending_commitments: Decimal,
+}
+
+#[derive(Debug, Serialize)]
+pub struct FundBalanceReport {
+    pub entries: Vec<FundBalanceEntry>,
+    pub total_restricted: Decimal,
+    pub total_unrestricted: Decimal,
+    pub total_closing_balance: Decimal,
+}
+
+// --- Diocese-wide consolidated reports ---
+
+#[derive(Debug, Serialize, sqlx::FromRow)]
+pub struct ConsolidatedParish {
+    pub parish_id: Uuid,
+    pub parish_code: String,
+    pub parish_name: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ConsolidatedLine {
+    pub label: String,
+    pub by_parish: Vec<Decimal>, // Same order as ConsolidatedStatement::parishes
+    pub total: Decimal,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ConsolidatedSection {
+    pub section_name: String,
+    pub lines: Vec<ConsolidatedLine>,
+    pub totals: ConsolidatedLine,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ConsolidatedStatement {
+    pub report: String,
+    pub diocese_id: Uuid,
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+    pub parishes: Vec<ConsolidatedParish>,
+    pub sections: Vec<ConsolidatedSection>,
+    pub net: Option<ConsolidatedLine>,
+}
+
+// --- Comparative (period-over-period) reports ---
+
+#[derive(Debug, Serialize)]
+pub struct ComparativeEntry {
+    pub category: String,
+    pub current: Decimal,
+    pub previous: Decimal,
+    pub change: Decimal,
+    pub change_percent: Option<Decimal>, // None when there is nothing to compare against
+}
+
+#[derive(Debug, Serialize)]
+pub struct ComparativeSection {
+    pub section_name: String,
+    pub entries: Vec<ComparativeEntry>,
+    pub totals: ComparativeEntry,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ComparativeReport {
+    pub report: String,
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+    pub previous_start_date: NaiveDate,
+    pub previous_end_date: NaiveDate,
+    pub sections: Vec<ComparativeSection>,
+    pub net: Option<ComparativeEntry>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct MonthlyLine {
+    pub category: String,
+    pub by_month: Vec<Decimal>, // January..December of the fiscal year
+    pub total: Decimal,
+    pub previous_year_total: Decimal,
+    pub change_percent: Option<Decimal>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct MonthlySection {
+    pub section_name: String,
+    pub lines: Vec<MonthlyLine>,
+    pub totals: MonthlyLine,
+}
+
+#[derive(Debug, Serialize)]
+pub struct MonthlyReport {
+    pub report: String,
+    pub fiscal_year: i32,
+    pub months: Vec<String>,
+    pub sections: Vec<MonthlySection>,
+    pub net: Option<MonthlyLine>,
+}
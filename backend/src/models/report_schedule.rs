@@ -0,0 +1,100 @@
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+use chrono::{NaiveDate, DateTime, Utc};
+use crate::export::ExportFormat;
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone, Copy, sqlx::Type)]
+#[sqlx(type_name = "scheduled_report_type", rename_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ScheduledReportType {
+    TrialBalance,
+    IncomeExpenditure,
+    BudgetVsActual,
+    BalanceSheet,
+    CashFlow,
+    FundBalances,
+    ComparativeIncomeExpenditure,
+    ComparativeBudgetVsActual,
+    MonthlyIncomeExpenditure,
+    MonthlyBudgetVsActual,
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone, Copy, sqlx::Type)]
+#[sqlx(type_name = "report_date_range", rename_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ReportDateRange {
+    PreviousMonth,
+    MonthToDate,
+    PreviousQuarter,
+    QuarterToDate,
+    PreviousYear,
+    YearToDate,
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone, Copy, sqlx::Type)]
+#[sqlx(type_name = "report_run_status", rename_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ReportRunStatus {
+    Succeeded,
+    Failed,
+}
+
+#[derive(Debug, Serialize, Deserialize, FromRow)]
+pub struct ReportSchedule {
+    pub id: Uuid,
+    pub parish_id: Uuid,
+    pub schedule_name: String,
+    pub report_type: ScheduledReportType,
+    pub date_range: ReportDateRange,
+    pub format: ExportFormat,
+    pub cron_expression: String,
+    pub recipients: Vec<String>,
+    pub is_active: bool,
+    pub next_run_at: Option<DateTime<Utc>>,
+    pub last_run_at: Option<DateTime<Utc>>,
+    pub created_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub deleted_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Serialize, Deserialize, FromRow)]
+pub struct ReportRun {
+    pub id: Uuid,
+    pub schedule_id: Uuid,
+    pub parish_id: Uuid,
+    pub period_start: NaiveDate,
+    pub period_end: NaiveDate,
+    pub format: ExportFormat,
+    pub status: ReportRunStatus,
+    #[serde(skip_serializing)]
+    pub file_path: Option<String>,
+    pub file_name: Option<String>,
+    pub file_size: Option<i64>,
+    pub error_message: Option<String>,
+    pub emails_queued: i32,
+    pub generated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CreateReportScheduleRequest {
+    pub parish_id: Uuid,
+    pub schedule_name: String,
+    pub report_type: ScheduledReportType,
+    pub date_range: Option<ReportDateRange>,
+    pub format: Option<ExportFormat>,
+    pub cron_expression: String,
+    pub recipients: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UpdateReportScheduleRequest {
+    pub schedule_name: Option<String>,
+    pub report_type: Option<ScheduledReportType>,
+    pub date_range: Option<ReportDateRange>,
+    pub format: Option<ExportFormat>,
+    pub cron_expression: Option<String>,
+    pub recipients: Option<Vec<String>>,
+    pub is_active: Option<bool>,
+}
@@ -0,0 +1,62 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use chrono::NaiveDate;
+use crate::models::member::{SacramentRecord, SacramentType};
+use crate::models::sponsor::{SacramentSponsor, SponsorInput};
+
+/// Per-parish handling of a sacrament record that fails a canonical check,
+/// stored in the `sacraments.rule_policy` setting.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone, Copy)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SacramentRulePolicy {
+    Warn,
+    Block,
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone, Copy)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum RuleSeverity {
+    /// Shown to the user; the record is saved.
+    Warning,
+    /// Refuses the record under the BLOCK policy unless overridden.
+    Error,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct RuleViolation {
+    /// Stable code for the client, e.g. `BAPTISM_REQUIRED`.
+    pub rule: String,
+    pub severity: RuleSeverity,
+    pub message: String,
+}
+
+/// The outcome of checking a sacrament against the member's record. Sent
+/// with status 422 when a save is refused.
+#[derive(Debug, Serialize)]
+pub struct SacramentCheck {
+    pub policy: SacramentRulePolicy,
+    pub violations: Vec<RuleViolation>,
+    /// False when an error must be overridden with a reason.
+    pub can_save: bool,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CheckSacramentRequest {
+    pub member_id: Uuid,
+    pub sacrament_type: SacramentType,
+    pub sacrament_date: NaiveDate,
+    pub spouse_id: Option<Uuid>,
+    /// The record being edited, so it is not counted against itself.
+    pub record_id: Option<Uuid>,
+    #[serde(default)]
+    pub sponsors: Vec<SponsorInput>,
+}
+
+/// A saved record with the warnings raised while checking it.
+#[derive(Debug, Serialize)]
+pub struct SavedSacrament {
+    #[serde(flatten)]
+    pub record: SacramentRecord,
+    pub sponsors: Vec<SacramentSponsor>,
+    pub warnings: Vec<RuleViolation>,
+}
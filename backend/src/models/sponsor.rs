@@ -0,0 +1,48 @@
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+use chrono::{NaiveDate, DateTime, Utc};
+use crate::models::member::SacramentType;
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone, Copy, sqlx::Type)]
+#[sqlx(type_name = "sponsor_role", rename_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SponsorRole {
+    /// At a baptism.
+    Godparent,
+    /// At a confirmation.
+    Sponsor,
+    Witness,
+}
+
+#[derive(Debug, Serialize, Deserialize, FromRow)]
+pub struct SacramentSponsor {
+    pub id: Uuid,
+    pub sacrament_record_id: Uuid,
+    pub role: SponsorRole,
+    pub member_id: Option<Uuid>,
+    pub full_name: String,
+    pub sort_order: i32,
+    pub created_at: DateTime<Utc>,
+}
+
+/// A parishioner by `member_id`, or an outsider by name.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct SponsorInput {
+    pub role: SponsorRole,
+    pub member_id: Option<Uuid>,
+    pub full_name: Option<String>,
+}
+
+/// A sacrament a member stood for as godparent, sponsor or witness.
+#[derive(Debug, Serialize, FromRow)]
+pub struct Sponsorship {
+    pub sacrament_record_id: Uuid,
+    pub role: SponsorRole,
+    pub sacrament_type: SacramentType,
+    pub sacrament_date: NaiveDate,
+    pub member_id: Uuid,
+    pub member_code: String,
+    pub member_name: String,
+    pub parish_name: String,
+}
@@ -0,0 +1,89 @@
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+use chrono::{DateTime, Utc};
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone, Copy, sqlx::Type)]
+#[sqlx(type_name = "statistical_return_status", rename_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum StatisticalReturnStatus {
+    Draft,
+    /// Sent to the bishops' conference; the figures are locked.
+    Submitted,
+}
+
+#[derive(Debug, Serialize, Deserialize, FromRow)]
+pub struct StatisticalReturn {
+    pub id: Uuid,
+    pub diocese_id: Uuid,
+    /// Absent on the diocesan return.
+    pub parish_id: Option<Uuid>,
+    pub return_year: i32,
+    pub status: StatisticalReturnStatus,
+    /// When the figures were last taken from the registers.
+    pub computed_at: DateTime<Utc>,
+    pub notes: Option<String>,
+    pub submitted_by: Option<Uuid>,
+    pub submitted_at: Option<DateTime<Utc>>,
+    pub created_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub deleted_at: Option<DateTime<Utc>>,
+    #[sqlx(default)]
+    pub parish_name: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, FromRow)]
+pub struct StatisticalLine {
+    pub return_id: Uuid,
+    pub line_code: String,
+    pub computed_value: i64,
+    pub adjustment: i64,
+    pub adjustment_note: Option<String>,
+    pub adjusted_by: Option<Uuid>,
+    pub adjusted_at: Option<DateTime<Utc>>,
+    /// The figure returned: computed plus adjustment.
+    #[sqlx(default)]
+    pub total: i64,
+    #[sqlx(default)]
+    pub section: String,
+    #[sqlx(default)]
+    pub label: String,
+}
+
+/// Where a parish stands for the year, on the diocesan return.
+#[derive(Debug, Serialize, FromRow)]
+pub struct ReturnParish {
+    pub parish_id: Uuid,
+    pub parish_name: String,
+    pub return_id: Option<Uuid>,
+    pub status: Option<StatisticalReturnStatus>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct StatisticalReturnDetail {
+    #[serde(flatten)]
+    pub statistical_return: StatisticalReturn,
+    pub lines: Vec<StatisticalLine>,
+    /// On the diocesan return only.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parishes: Option<Vec<ReturnParish>>,
+}
+
+/// A parish return by `parish_id`, or the diocesan return by
+/// `diocese_id`.
+#[derive(Debug, Deserialize)]
+pub struct CreateStatisticalReturnRequest {
+    pub return_year: i32,
+    pub parish_id: Option<Uuid>,
+    pub diocese_id: Option<Uuid>,
+    pub notes: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AdjustStatisticalLineRequest {
+    /// Added to the computed figure; may be negative.
+    pub adjustment: i64,
+    /// Why the registers are wrong; required unless the adjustment is 0.
+    pub adjustment_note: Option<String>,
+}
@@ -0,0 +1,109 @@
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+use chrono::{NaiveDate, DateTime, Utc};
+use rust_decimal::Decimal;
+
+#[derive(Debug, Serialize, Deserialize, FromRow)]
+pub struct Supplier {
+    pub id: Uuid,
+    pub parish_id: Uuid,
+    pub supplier_name: String,
+    pub name_key: String,
+    pub contact_person: Option<String>,
+    pub phone: Option<String>,
+    pub email: Option<String>,
+    pub address: Option<String>,
+    pub tax_number: Option<String>,
+    pub bank_name: Option<String>,
+    pub bank_account: Option<String>,
+    pub mobile_money_number: Option<String>,
+    pub notes: Option<String>,
+    pub is_active: bool,
+    pub created_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub deleted_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CreateSupplierRequest {
+    pub parish_id: Uuid,
+    pub supplier_name: String,
+    pub contact_person: Option<String>,
+    pub phone: Option<String>,
+    pub email: Option<String>,
+    pub address: Option<String>,
+    pub tax_number: Option<String>,
+    pub bank_name: Option<String>,
+    pub bank_account: Option<String>,
+    pub mobile_money_number: Option<String>,
+    pub notes: Option<String>,
+    /// Link earlier vouchers whose payee name matches this supplier.
+    pub link_existing: Option<bool>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UpdateSupplierRequest {
+    pub supplier_name: Option<String>,
+    pub contact_person: Option<String>,
+    pub phone: Option<String>,
+    pub email: Option<String>,
+    pub address: Option<String>,
+    pub tax_number: Option<String>,
+    pub bank_name: Option<String>,
+    pub bank_account: Option<String>,
+    pub mobile_money_number: Option<String>,
+    pub notes: Option<String>,
+    pub is_active: Option<bool>,
+}
+
+/// Folds duplicate suppliers into the one in the path.
+#[derive(Debug, Deserialize)]
+pub struct MergeSuppliersRequest {
+    pub supplier_ids: Vec<Uuid>,
+}
+
+#[derive(Debug, Serialize, FromRow)]
+pub struct SupplierStatementLine {
+    pub voucher_id: Uuid,
+    pub expense_date: NaiveDate,
+    pub voucher_number: String,
+    pub description: String,
+    pub approval_status: String,
+    pub amount: Decimal,
+    pub paid: bool,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SupplierStatement {
+    pub supplier_id: Uuid,
+    pub supplier_name: String,
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+    pub lines: Vec<SupplierStatementLine>,
+    pub total_approved: Decimal,
+    pub total_paid: Decimal,
+    /// Approved and unpaid at the end date, including earlier vouchers.
+    pub outstanding: Decimal,
+}
+
+#[derive(Debug, Serialize, FromRow)]
+pub struct SpendBySupplierLine {
+    pub supplier_id: Option<Uuid>,
+    pub supplier_name: String,
+    pub voucher_count: i64,
+    pub total_amount: Decimal,
+    pub paid_amount: Decimal,
+    #[sqlx(default)]
+    pub share_percent: Option<Decimal>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SpendBySupplierReport {
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+    pub lines: Vec<SpendBySupplierLine>,
+    pub total_amount: Decimal,
+    pub total_paid: Decimal,
+}
@@ -4,7 +4,7 @@ use uuid::Uuid;
 use chrono::{NaiveDate, DateTime, Utc, NaiveTime};
 use rust_decimal::Decimal;
 
-#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, sqlx::Type)]
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone, Copy, sqlx::Type)]
 #[sqlx(type_name = "transaction_category", rename_all = "SCREAMING_SNAKE_CASE")]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum TransactionCategory {
@@ -29,9 +29,18 @@ pub enum TransactionCategory {
     CharityExpense,
     ConstructionExpense,
     OtherExpense,
+    ExchangeGain,
+    ExchangeLoss,
 }
 
-#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, sqlx::Type)]
+// Needed to bind/read `transaction_category[]` columns (e.g. levy rules)
+impl sqlx::postgres::PgHasArrayType for TransactionCategory {
+    fn array_type_info() -> sqlx::postgres::PgTypeInfo {
+        sqlx::postgres::PgTypeInfo::with_name("_transaction_category")
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone, Copy, sqlx::Type)]
 #[sqlx(type_name = "payment_method", rename_all = "SCREAMING_SNAKE_CASE")]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum PaymentMethod {
@@ -62,9 +71,19 @@ pub struct IncomeTransaction {
     pub parish_id: Uuid,
     pub member_id: Option<Uuid>,
     pub family_id: Option<Uuid>,
+    pub fund_id: Option<Uuid>,
+    pub cashbook_id: Option<Uuid>,
     pub transaction_number: String,
     pub category: TransactionCategory,
+    /// In the diocesan base currency
     pub amount: Decimal,
+    // Set for foreign-currency receipts (absent in sync payloads)
+    #[serde(default)]
+    pub currency_code: Option<String>,
+    #[serde(default)]
+    pub original_amount: Option<Decimal>,
+    #[serde(default)]
+    pub exchange_rate: Option<Decimal>,
     pub payment_method: PaymentMethod,
     pub transaction_date: NaiveDate,
     pub transaction_time: Option<NaiveTime>,
@@ -85,8 +104,19 @@ pub struct ExpenseVoucher {
     pub id: Uuid,
     pub parish_id: Uuid,
     pub voucher_number: String,
+    pub fund_id: Option<Uuid>,
+    pub cashbook_id: Option<Uuid>,
+    pub supplier_id: Option<Uuid>,
     pub category: TransactionCategory,
+    /// In the diocesan base currency
     pub amount: Decimal,
+    // Set for foreign-currency vouchers (absent in sync payloads)
+    #[serde(default)]
+    pub currency_code: Option<String>,
+    #[serde(default)]
+    pub original_amount: Option<Decimal>,
+    #[serde(default)]
+    pub exchange_rate: Option<Decimal>,
     pub payment_method: PaymentMethod,
     pub payee_name: String,
     pub payee_phone: Option<String>,
@@ -100,6 +130,17 @@ pub struct ExpenseVoucher {
     pub rejection_reason: Option<String>,
     pub paid: Option<bool>,
     pub paid_at: Option<DateTime<Utc>>,
+    // Set by the budget check when the voucher is raised (absent in sync payloads)
+    #[serde(default)]
+    pub budget_overrun: bool,
+    #[serde(default)]
+    pub budget_warning: Option<String>,
+    #[serde(default)]
+    pub overrun_approval_required: bool,
+    #[serde(default)]
+    pub overrun_approved_by: Option<Uuid>,
+    #[serde(default)]
+    pub overrun_approved_at: Option<DateTime<Utc>>,
     pub is_synced: Option<bool>,
     pub synced_at: Option<DateTime<Utc>>,
     pub created_at: Option<DateTime<Utc>>,
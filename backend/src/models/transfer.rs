@@ -0,0 +1,79 @@
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+use chrono::{DateTime, Utc};
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone, Copy, sqlx::Type)]
+#[sqlx(type_name = "member_transfer_status", rename_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum MemberTransferStatus {
+    /// Awaiting the sending parish.
+    Requested,
+    /// Approved; the members now belong to the receiving parish.
+    Completed,
+    Rejected,
+    Cancelled,
+}
+
+#[derive(Debug, Serialize, Deserialize, FromRow)]
+pub struct MemberTransfer {
+    pub id: Uuid,
+    pub from_parish_id: Uuid,
+    pub to_parish_id: Uuid,
+    /// Set when a whole family moves.
+    pub family_id: Option<Uuid>,
+    pub old_family_code: Option<String>,
+    pub new_family_code: Option<String>,
+    pub to_scc_id: Option<Uuid>,
+    pub status: MemberTransferStatus,
+    pub reason: Option<String>,
+    pub requested_by: Option<Uuid>,
+    pub decided_by: Option<Uuid>,
+    pub decided_at: Option<DateTime<Utc>>,
+    pub decision_note: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    #[sqlx(default)]
+    pub from_parish_name: Option<String>,
+    #[sqlx(default)]
+    pub to_parish_name: Option<String>,
+    #[sqlx(default)]
+    pub family_name: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, FromRow)]
+pub struct TransferredMember {
+    pub transfer_id: Uuid,
+    pub member_id: Uuid,
+    pub old_member_code: String,
+    pub new_member_code: Option<String>,
+    #[sqlx(default)]
+    pub member_name: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct MemberTransferDetail {
+    #[serde(flatten)]
+    pub transfer: MemberTransfer,
+    pub members: Vec<TransferredMember>,
+}
+
+/// Made by the receiving parish, naming the family or the members by the
+/// codes on the sending parish's letter.
+#[derive(Debug, Deserialize)]
+pub struct CreateMemberTransferRequest {
+    pub from_parish_id: Uuid,
+    /// The receiving parish; defaults to the user's own.
+    pub to_parish_id: Option<Uuid>,
+    pub family_code: Option<String>,
+    #[serde(default)]
+    pub member_codes: Vec<String>,
+    pub to_scc_id: Option<Uuid>,
+    pub reason: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DecideMemberTransferRequest {
+    /// Why the transfer was refused, or a note on the approval.
+    pub decision_note: Option<String>,
+}
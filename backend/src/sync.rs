@@ -6,7 +6,8 @@ use axum::{
 };
 use serde::{Deserialize, Serialize};
 use crate::AppState;
-use crate::models::transaction::{IncomeTransaction, ExpenseVoucher};
+use crate::handlers::fund;
+use crate::models::transaction::{IncomeTransaction, ExpenseVoucher, ApprovalStatus};
 use crate::models::member::{Member, SacramentRecord};
 use sqlx::postgres::PgPool;
 use uuid::Uuid;
@@ -115,12 +116,15 @@ async fn handle_income_transaction(pool: &PgPool, change: &ChangeRecord) -> Resu
              let item: IncomeTransaction = serde_json::from_value(change.data.clone())
                 .map_err(|e| format!("Deserialization error: {}", e))?;
             
+            // Offline clients edit the base amount only; a foreign receipt
+            // keeps its original amount and is rebooked at the implied rate.
             sqlx::query(
                 r#"
                 UPDATE income_transaction SET
                     parish_id = $2, member_id = $3, transaction_number = $4, category = $5, amount = $6,
                     payment_method = $7, transaction_date = $8, transaction_time = $9, description = $10,
                     reference_number = $11, received_by = $12, receipt_printed = $13,
+                    exchange_rate = CASE WHEN amount = $6 THEN exchange_rate ELSE ROUND($6 / original_amount, 6) END,
                     updated_at = $14
                 WHERE id = $1
                 "#
@@ -168,15 +172,32 @@ async fn handle_expense_voucher(pool: &PgPool, change: &ChangeRecord) -> Result<
             let item: ExpenseVoucher = serde_json::from_value(change.data.clone())
                 .map_err(|e| format!("Deserialization error: {}", e))?;
 
+            let mut tx = pool.begin().await.map_err(|e| format!("Database error: {}", e))?;
+
+            // A voucher against a restricted fund passes the same overdraw
+            // guard as one raised online, unless it was already synced.
+            if let Some(fund_id) = item.fund_id {
+                let exists: bool = sqlx::query_scalar("SELECT EXISTS (SELECT 1 FROM expense_voucher WHERE id = $1)")
+                    .bind(item.id)
+                    .fetch_one(&mut *tx)
+                    .await
+                    .map_err(|e| format!("Database error: {}", e))?;
+                if !exists {
+                    fund::guard_expense(&mut tx, item.parish_id, fund_id, item.amount)
+                        .await
+                        .map_err(|(_, e)| e)?;
+                }
+            }
+
             sqlx::query(
                 r#"
                 INSERT INTO expense_voucher (
                     id, parish_id, voucher_number, category, amount, payment_method,
                     payee_name, payee_phone, expense_date, description, reference_number,
                     approval_status, requested_by, approved_by, approved_at, rejection_reason,
-                    paid, paid_at, is_synced, synced_at, created_at, updated_at
+                    paid, paid_at, is_synced, synced_at, created_at, updated_at, fund_id
                 )
-                VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18, $19, NOW(), $20, $21)
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18, $19, NOW(), $20, $21, $22)
                 ON CONFLICT (id) DO NOTHING
                 "#
             )
@@ -201,14 +222,34 @@ async fn handle_expense_voucher(pool: &PgPool, change: &ChangeRecord) -> Result<
             .bind(true)
             .bind(item.created_at)
             .bind(item.updated_at)
-            .execute(pool)
+            .bind(item.fund_id)
+            .execute(&mut *tx)
             .await
             .map_err(|e| format!("Database error: {}", e))?;
+
+            tx.commit().await.map_err(|e| format!("Database error: {}", e))?;
         }
         "update" => {
              let item: ExpenseVoucher = serde_json::from_value(change.data.clone())
                 .map_err(|e| format!("Deserialization error: {}", e))?;
-            
+
+            // A voucher over budget under the REQUIRE_APPROVAL policy is only
+            // approved once the overrun has been signed off.
+            if item.approval_status == Some(ApprovalStatus::Approved) {
+                let held: bool = sqlx::query_scalar(
+                    "SELECT EXISTS (SELECT 1 FROM expense_voucher WHERE id = $1 AND overrun_approval_required AND overrun_approved_at IS NULL)"
+                )
+                .bind(item.id)
+                .fetch_one(pool)
+                .await
+                .map_err(|e| format!("Database error: {}", e))?;
+                if held {
+                    return Err(format!("Voucher {} exceeds its budget and awaits overrun approval", item.voucher_number));
+                }
+            }
+
+            // As for income, a changed amount rebooks a foreign voucher at
+            // the implied rate.
             sqlx::query(
                 r#"
                 UPDATE expense_voucher SET
@@ -216,7 +257,8 @@ async fn handle_expense_voucher(pool: &PgPool, change: &ChangeRecord) -> Result<
                     payment_method = $6, payee_name = $7, payee_phone = $8, expense_date = $9,
                     description = $10, reference_number = $11, approval_status = $12,
                     requested_by = $13, approved_by = $14, approved_at = $15, rejection_reason = $16,
-                    paid = $17, paid_at = $18, is_synced = $19, synced_at = NOW(), updated_at = $20
+                    paid = $17, paid_at = $18, is_synced = $19, synced_at = NOW(), updated_at = $20,
+                    exchange_rate = CASE WHEN amount = $5 THEN exchange_rate ELSE ROUND($5 / original_amount, 6) END
                 WHERE id = $1
                 "#
             )